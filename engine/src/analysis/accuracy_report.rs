@@ -0,0 +1,148 @@
+use serde::Serialize;
+
+use crate::core::{Game, Outcome, Player};
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AccuracyReport {
+    pub positions: usize,
+
+    pub top_1_matches: usize,
+    pub top_3_matches: usize,
+
+    pub valued_positions: usize,
+    pub value_error: f32,
+}
+
+impl AccuracyReport {
+    const TOP_K: usize = 3;
+
+    // NOTE - The player's choice counts as a top-k match when it is among the k actions
+    // with the most weight in the reference's policy, so the reference player must return
+    // an evaluation with its choice. Value error is only measured on positions where the
+    // player returns one too. Finished positions are skipped.
+
+    pub fn analyze<G: Game, P: Player<G>, R: Player<G>>(
+        positions: &[G],
+        player: &mut P,
+        reference: &mut R,
+    ) -> Result<Self, String> {
+        let mut report = AccuracyReport::default();
+
+        for game in positions {
+            if game.outcome() != Outcome::InProgress {
+                continue;
+            }
+
+            let reference_choice = reference.choose_action(game, 0);
+
+            let reference_evaluation = reference_choice
+                .evaluation
+                .ok_or("reference player must return an evaluation")?;
+
+            let choice = player.choose_action(game, 0);
+
+            let mut ranked = reference_evaluation.policy.to_vec();
+            ranked.sort_by(|x, y| y.prior.total_cmp(&x.prior));
+
+            report.positions += 1;
+
+            if choice.action == reference_choice.action {
+                report.top_1_matches += 1;
+            }
+
+            if choice.action == reference_choice.action
+                || ranked
+                    .iter()
+                    .take(Self::TOP_K)
+                    .any(|item| item.action == choice.action)
+            {
+                report.top_3_matches += 1;
+            }
+
+            if let Some(evaluation) = choice.evaluation {
+                report.valued_positions += 1;
+                report.value_error += (evaluation.value - reference_evaluation.value).abs();
+            }
+        }
+
+        Ok(report)
+    }
+
+    pub fn top_1_agreement(&self) -> f32 {
+        self.top_1_matches as f32 / self.positions.max(1) as f32
+    }
+
+    pub fn top_3_agreement(&self) -> f32 {
+        self.top_3_matches as f32 / self.positions.max(1) as f32
+    }
+
+    pub fn value_mae(&self) -> Option<f32> {
+        (self.valued_positions > 0).then(|| self.value_error / self.valued_positions as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fixtures::TIC_TAC_TOE_FIXTURES;
+    use crate::game::tic_tac_toe::TicTacToe;
+    use crate::player::{ClassicMctsPlayer, RandomPlayer, TemperatureSchedule};
+
+    fn make_positions() -> Vec<TicTacToe> {
+        TIC_TAC_TOE_FIXTURES
+            .iter()
+            .map(|fixture| fixture.game().expect("unable to parse fixture"))
+            .collect()
+    }
+
+    fn make_reference() -> ClassicMctsPlayer<TicTacToe> {
+        ClassicMctsPlayer::new(2000)
+            .with_seed(0)
+            .with_temperature_schedule(TemperatureSchedule::Constant(0.0))
+    }
+
+    mod analyze {
+        use super::*;
+
+        #[test]
+        fn should_fully_agree_with_itself() {
+            let report = AccuracyReport::analyze(
+                &make_positions(),
+                &mut make_reference(),
+                &mut make_reference(),
+            )
+            .expect("unable to analyze positions");
+
+            assert_eq!(report.positions, 5);
+            assert_eq!(report.top_1_matches, report.positions);
+            assert_eq!(report.top_3_matches, report.positions);
+            assert!(report.value_mae().expect("missing value error") < f32::EPSILON);
+        }
+
+        #[test]
+        fn should_skip_value_error_without_evaluations() {
+            let report = AccuracyReport::analyze(
+                &make_positions(),
+                &mut RandomPlayer::new().with_seed(0),
+                &mut make_reference(),
+            )
+            .expect("unable to analyze positions");
+
+            assert!(report.top_1_matches <= report.top_3_matches);
+            assert_eq!(report.valued_positions, 0);
+            assert!(report.value_mae().is_none());
+        }
+
+        #[test]
+        fn should_require_a_reference_evaluation() {
+            let result = AccuracyReport::analyze(
+                &make_positions(),
+                &mut make_reference(),
+                &mut RandomPlayer::new().with_seed(0),
+            );
+
+            assert!(result.is_err());
+        }
+    }
+}
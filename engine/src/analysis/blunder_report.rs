@@ -0,0 +1,165 @@
+use serde::Serialize;
+
+use crate::core::{Game, Outcome, Player, Turn};
+use crate::record::GameRecord;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Blunder {
+    pub game: usize,
+    pub ply: usize,
+    pub player: u8,
+
+    pub action: String,
+    pub best_action: String,
+
+    pub value_before: f32,
+    pub value_after: f32,
+}
+
+impl Blunder {
+    pub fn value_drop(&self) -> f32 {
+        self.value_before - self.value_after
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BlunderReport {
+    pub games: usize,
+    pub moves: usize,
+
+    pub blunders: Vec<Blunder>,
+}
+
+impl BlunderReport {
+    pub const DEFAULT_THRESHOLD: f32 = 0.5;
+
+    // NOTE - Every position is evaluated once, from the side to move. A move's value drop
+    // is the mover's value before it minus their value after it, so the reference player
+    // must return an evaluation with its choice.
+
+    pub fn analyze<G: Game, P: Player<G>>(
+        records: &[GameRecord<G>],
+        reference: &mut P,
+        threshold: f32,
+    ) -> Result<Self, String> {
+        let mut report = BlunderReport::default();
+
+        for (game_index, record) in records.iter().enumerate() {
+            let positions = record
+                .positions()
+                .map_err(|error| format!("game {}: {error}", game_index + 1))?;
+
+            let mut values = Vec::with_capacity(positions.len());
+            let mut best_actions = Vec::with_capacity(positions.len());
+
+            for (ply, (game, _)) in positions.iter().enumerate() {
+                if let Some(value) = Self::terminal_value(game) {
+                    values.push(value);
+                    best_actions.push(None);
+
+                    continue;
+                }
+
+                let choice = reference.choose_action(game, u32::try_from(ply).unwrap());
+
+                let evaluation = choice
+                    .evaluation
+                    .ok_or("reference player must return an evaluation")?;
+
+                values.push(evaluation.value);
+                best_actions.push(Some(choice.action));
+            }
+
+            for (ply, recorded_move) in record.moves.iter().enumerate() {
+                let (_, turn_before) = positions[ply];
+                let (_, turn_after) = positions[ply + 1];
+
+                let value_before = values[ply];
+                let value_after = if turn_after == turn_before {
+                    values[ply + 1]
+                } else {
+                    -values[ply + 1]
+                };
+
+                report.moves += 1;
+
+                if value_before - value_after <= threshold {
+                    continue;
+                }
+
+                report.blunders.push(Blunder {
+                    game: game_index,
+                    ply,
+                    player: match recorded_move.turn {
+                        Turn::Player1 => 1,
+                        Turn::Player2 => 2,
+                    },
+
+                    action: recorded_move.action.to_string(),
+                    best_action: best_actions[ply]
+                        .map(|action| action.to_string())
+                        .unwrap_or_default(),
+
+                    value_before,
+                    value_after,
+                });
+            }
+
+            report.games += 1;
+        }
+
+        Ok(report)
+    }
+
+    pub fn blunder_rate(&self) -> f32 {
+        self.blunders.len() as f32 / self.moves.max(1) as f32
+    }
+
+    fn terminal_value<G: Game>(game: &G) -> Option<f32> {
+        match game.outcome() {
+            Outcome::Win => Some(1.0),
+            Outcome::Loss => Some(-1.0),
+            Outcome::Draw => Some(0.0),
+            Outcome::InProgress => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::TicTacToeAction;
+    use crate::player::{ClassicMctsPlayer, TemperatureSchedule};
+
+    mod analyze {
+        use super::*;
+
+        #[test]
+        fn should_flag_a_missed_block() {
+            let record = GameRecord::from_places(&[0, 4, 1, 8, 2], Outcome::InProgress);
+
+            let mut reference = ClassicMctsPlayer::new(2000)
+                .with_seed(0)
+                .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+            let report = BlunderReport::analyze(&[record], &mut reference, 0.75)
+                .expect("unable to analyze games");
+
+            assert_eq!(report.games, 1);
+            assert_eq!(report.moves, 5);
+            assert_eq!(
+                report
+                    .blunders
+                    .iter()
+                    .map(|blunder| (blunder.ply, blunder.player))
+                    .collect::<Vec<_>>(),
+                [(3, 2)]
+            );
+            assert_eq!(
+                report.blunders[0].best_action,
+                TicTacToeAction::Place { index: 2 }.to_string()
+            );
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use serde::Serialize;
+
+use crate::core::{Game, Outcome, Turn};
+use crate::neural_network::{NeuralNetwork, StateEncoder};
+use crate::record::GameRecord;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CalibrationBin {
+    pub lower: f32,
+    pub upper: f32,
+
+    pub positions: usize,
+
+    pub mean_predicted: Option<f32>,
+    pub mean_outcome: Option<f32>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CalibrationReport {
+    pub games: usize,
+    pub positions: usize,
+
+    pub brier_score: f32,
+
+    pub bins: Vec<CalibrationBin>,
+}
+
+impl CalibrationReport {
+    pub const DEFAULT_BINS: usize = 10;
+
+    // NOTE - Values are on a -1 to 1 scale from the side to move. The Brier score maps
+    // them onto win probabilities, counting a draw as half a win. Unfinished games have
+    // no realized outcome and are skipped.
+
+    pub fn analyze<G: Game, SE: StateEncoder<G>, NN: NeuralNetwork>(
+        records: &[GameRecord<G>],
+        state_encoder: SE,
+        neural_network: &mut NN,
+        bins: usize,
+    ) -> Result<Self, String> {
+        if bins == 0 {
+            return Err("calibration needs at least one bin".to_string());
+        }
+
+        let mut report = CalibrationReport::default();
+
+        let mut counts = vec![0; bins];
+        let mut predicted_sums = vec![0.0; bins];
+        let mut outcome_sums = vec![0.0; bins];
+
+        let mut squared_error = 0.0;
+
+        for (game_index, record) in records.iter().enumerate() {
+            let player_1_outcome = match record.outcome {
+                Outcome::Win => 1.0,
+                Outcome::Loss => -1.0,
+                Outcome::Draw => 0.0,
+                Outcome::InProgress => continue,
+            };
+
+            let positions = record
+                .positions()
+                .map_err(|error| format!("game {}: {error}", game_index + 1))?;
+
+            for (game, turn) in &positions {
+                if game.outcome() != Outcome::InProgress {
+                    continue;
+                }
+
+                let predicted = neural_network
+                    .predict(&state_encoder.encode(game))
+                    .value
+                    .clamp(-1.0, 1.0);
+
+                let outcome = match turn {
+                    Turn::Player1 => player_1_outcome,
+                    Turn::Player2 => -player_1_outcome,
+                };
+
+                let bin = Self::bin_index(predicted, bins);
+
+                counts[bin] += 1;
+                predicted_sums[bin] += predicted;
+                outcome_sums[bin] += outcome;
+
+                squared_error += ((predicted - outcome) / 2.0).powi(2);
+
+                report.positions += 1;
+            }
+
+            report.games += 1;
+        }
+
+        report.brier_score = squared_error / report.positions.max(1) as f32;
+
+        report.bins = (0..bins)
+            .map(|bin| {
+                let width = 2.0 / bins as f32;
+                let count = counts[bin];
+
+                CalibrationBin {
+                    lower: -1.0 + bin as f32 * width,
+                    upper: -1.0 + (bin + 1) as f32 * width,
+
+                    positions: count,
+
+                    mean_predicted: (count > 0).then(|| predicted_sums[bin] / count as f32),
+                    mean_outcome: (count > 0).then(|| outcome_sums[bin] / count as f32),
+                }
+            })
+            .collect();
+
+        Ok(report)
+    }
+
+    // NOTE - Mean gap between predicted value and realized outcome, weighted by the
+    // positions in each bin.
+
+    pub fn calibration_error(&self) -> f32 {
+        let error: f32 = self
+            .bins
+            .iter()
+            .filter_map(|bin| {
+                Some(bin.positions as f32 * (bin.mean_predicted? - bin.mean_outcome?).abs())
+            })
+            .sum();
+
+        error / self.positions.max(1) as f32
+    }
+
+    // NOTE - Values are clamped to -1 to 1 before binning, so the cast cannot wrap.
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn bin_index(value: f32, bins: usize) -> usize {
+        let index = ((value + 1.0) * bins as f32 / 2.0) as usize;
+
+        index.min(bins - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::TicTacToeStateEncoder;
+    use crate::neural_network::ConstantNeuralNetwork;
+
+    mod analyze {
+        use super::*;
+
+        #[test]
+        fn should_compare_predictions_against_outcomes() {
+            let records = [
+                GameRecord::from_places(&[0, 3, 1, 4, 2], Outcome::Win),
+                GameRecord::from_places(&[0, 3], Outcome::InProgress),
+            ];
+
+            let mut neural_network = ConstantNeuralNetwork::new(9).with_value(0.5);
+
+            let report = CalibrationReport::analyze(
+                &records,
+                TicTacToeStateEncoder::new(),
+                &mut neural_network,
+                CalibrationReport::DEFAULT_BINS,
+            )
+            .expect("unable to analyze");
+
+            assert_eq!(report.games, 1);
+            assert_eq!(report.positions, 5);
+
+            assert!((report.brier_score - 0.2625).abs() < 1e-6);
+
+            let bin = &report.bins[7];
+
+            assert_eq!(bin.positions, 5);
+            assert_eq!(bin.mean_predicted, Some(0.5));
+            assert!((bin.mean_outcome.unwrap() - 0.2).abs() < 1e-6);
+
+            assert!((report.calibration_error() - 0.3).abs() < 1e-6);
+        }
+    }
+}
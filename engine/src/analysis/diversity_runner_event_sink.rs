@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::core::{
+    EventSink, Game, RunnerEvent, RunnerEventContext, RunnerEventKind, hash_position,
+};
+
+pub struct DiversityRunnerEventSink<G: Game + Hash> {
+    games: u32,
+    positions: u32,
+    evaluated_positions: u32,
+
+    policy_entropy: f32,
+
+    seen_positions: HashSet<u64>,
+    seen_games: HashSet<u64>,
+
+    current_game: u64,
+
+    _phantom: PhantomData<G>,
+}
+
+impl<G: Game + Hash> DiversityRunnerEventSink<G> {
+    pub fn new() -> Self {
+        Self {
+            games: 0,
+            positions: 0,
+            evaluated_positions: 0,
+
+            policy_entropy: 0.0,
+
+            seen_positions: HashSet::new(),
+            seen_games: HashSet::new(),
+
+            current_game: 0,
+
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn games(&self) -> u32 {
+        self.games
+    }
+
+    pub fn positions(&self) -> u32 {
+        self.positions
+    }
+
+    pub fn unique_position_rate(&self) -> f32 {
+        self.seen_positions.len() as f32 / self.positions.max(1) as f32
+    }
+
+    pub fn unique_game_rate(&self) -> f32 {
+        self.seen_games.len() as f32 / self.games.max(1) as f32
+    }
+
+    pub fn mean_game_length(&self) -> f32 {
+        self.positions as f32 / self.games.max(1) as f32
+    }
+
+    // NOTE - Entropy of the search policy, in nats, averaged over the positions a player
+    // evaluated. Higher means self-play spreads its visits across more moves.
+
+    pub fn mean_policy_entropy(&self) -> f32 {
+        self.policy_entropy / self.evaluated_positions.max(1) as f32
+    }
+
+    // NOTE - Games are stored from the side to move, so the same board reached by either
+    // player is the same training position.
+
+    fn position_key(game: &G) -> u64 {
+        hash_position(game)
+    }
+}
+
+impl<G: Game + Hash> Default for DiversityRunnerEventSink<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Game + Hash> EventSink<RunnerEvent<G>> for DiversityRunnerEventSink<G> {
+    fn emit(&mut self, event: RunnerEvent<G>) {
+        let RunnerEvent { kind, context } = event;
+
+        match kind {
+            RunnerEventKind::GameStarted => {
+                self.current_game = 0;
+            }
+            RunnerEventKind::PositionEvaluated { evaluation } => {
+                self.evaluated_positions += 1;
+
+                self.policy_entropy -= evaluation
+                    .policy
+                    .iter()
+                    .filter(|item| item.prior > 0.0)
+                    .map(|item| item.prior * item.prior.ln())
+                    .sum::<f32>();
+            }
+            RunnerEventKind::ActionApplied { .. } => {
+                let RunnerEventContext { game, .. } = context.expect("event is missing context");
+
+                let position = Self::position_key(&game);
+
+                self.positions += 1;
+                self.seen_positions.insert(position);
+
+                // NOTE - Chain position keys so that a game is identified by its whole line.
+
+                self.current_game = hash_position(&(self.current_game, position));
+            }
+            RunnerEventKind::GameFinished { .. } => {
+                self.games += 1;
+                self.seen_games.insert(self.current_game);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Runner;
+    use crate::game::tic_tac_toe::TicTacToe;
+    use crate::player::{ClassicMctsPlayer, TemperatureSchedule};
+
+    mod emit {
+        use super::*;
+
+        #[test]
+        fn should_measure_repeated_games() {
+            let player = ClassicMctsPlayer::<TicTacToe>::new(200)
+                .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+            let mut runner =
+                Runner::new(4, player.clone(), player, DiversityRunnerEventSink::new())
+                    .with_seed(5)
+                    .with_opening_plies(2);
+
+            runner.run();
+
+            let sink = runner.sink();
+
+            assert_eq!(sink.games(), 4);
+            assert!((5.0..=9.0).contains(&sink.mean_game_length()));
+            assert!(sink.unique_position_rate() < 1.0);
+            assert!(sink.mean_policy_entropy() > 0.0);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use serde::Serialize;
+
+use crate::core::{Game, Player, Runner, Scoring, StatisticsRunnerEventSink};
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LadderRung {
+    pub anchor: String,
+
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+impl LadderRung {
+    pub const DEFAULT_Z: f32 = Scoring::DEFAULT_Z;
+
+    // NOTE - The candidate is always player 1, and the runner alternates who moves first.
+
+    pub fn measure<G, C, A>(
+        anchor_name: impl Into<String>,
+        candidate: C,
+        anchor: A,
+        games: u32,
+        max_turns: u32,
+        seed: Option<u64>,
+    ) -> Self
+    where
+        G: Game + Send,
+        G::Action: Send,
+        C: Player<G> + Clone + Send,
+        A: Player<G> + Clone + Send,
+    {
+        let mut runner = Runner::new(games, candidate, anchor, StatisticsRunnerEventSink::new())
+            .with_max_turns(max_turns);
+
+        if let Some(seed) = seed {
+            runner = runner.with_seed(seed);
+        }
+
+        runner.run();
+
+        let statistics = runner.sink();
+
+        Self {
+            anchor: anchor_name.into(),
+
+            games: statistics.total_games,
+            wins: statistics.player_1_wins,
+            losses: statistics.player_2_wins,
+            draws: statistics.draws,
+        }
+    }
+
+    pub fn score(&self) -> f32 {
+        (self.wins as f32 + 0.5 * self.draws as f32) / self.games.max(1) as f32
+    }
+
+    // NOTE - Wilson score interval, treating draws as half a win.
+
+    pub fn confidence_interval(&self, z: f32) -> (f32, f32) {
+        Scoring::wilson_interval(self.score(), self.games, z)
+    }
+
+    pub fn elo_difference(score: f32) -> f32 {
+        let score = score.clamp(0.001, 0.999);
+
+        -400.0 * (1.0 / score - 1.0).log10()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_rung(wins: u32, losses: u32, draws: u32) -> LadderRung {
+        LadderRung {
+            anchor: "anchor".to_string(),
+
+            games: wins + losses + draws,
+            wins,
+            losses,
+            draws,
+        }
+    }
+
+    mod confidence_interval {
+        use super::*;
+
+        #[test]
+        fn should_contain_the_score_and_narrow_with_more_games() {
+            let small = make_rung(6, 3, 1);
+            let large = make_rung(60, 30, 10);
+
+            let (small_low, small_high) = small.confidence_interval(LadderRung::DEFAULT_Z);
+            let (large_low, large_high) = large.confidence_interval(LadderRung::DEFAULT_Z);
+
+            assert!((small.score() - 0.65).abs() < f32::EPSILON);
+            assert!(small_low < small.score() && small.score() < small_high);
+            assert!(large_high - large_low < small_high - small_low);
+        }
+
+        #[test]
+        fn should_stay_within_bounds_for_a_perfect_score() {
+            let (low, high) = make_rung(20, 0, 0).confidence_interval(LadderRung::DEFAULT_Z);
+
+            assert!(low > 0.8);
+            assert!((high - 1.0).abs() < f32::EPSILON);
+        }
+    }
+}
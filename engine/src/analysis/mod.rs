@@ -0,0 +1,17 @@
+mod accuracy_report;
+mod blunder_report;
+mod calibration_report;
+mod diversity_runner_event_sink;
+mod ladder_rung;
+mod opening_tree;
+mod pairing_result;
+mod perft;
+
+pub use accuracy_report::AccuracyReport;
+pub use blunder_report::{Blunder, BlunderReport};
+pub use calibration_report::{CalibrationBin, CalibrationReport};
+pub use diversity_runner_event_sink::DiversityRunnerEventSink;
+pub use ladder_rung::LadderRung;
+pub use opening_tree::{OpeningTree, OpeningTreeMove, OpeningTreeNode};
+pub use pairing_result::PairingResult;
+pub use perft::perft;
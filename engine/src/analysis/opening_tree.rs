@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use serde::Serialize;
+
+use crate::core::{Game, Outcome, Turn, hash_position};
+use crate::record::GameRecord;
+
+// NOTE - Positions are keyed by hash rather than by move sequence, so transpositions
+// share statistics.
+
+pub struct OpeningTree<G: Game + Hash> {
+    max_depth: usize,
+
+    root: Option<u64>,
+    positions: HashMap<u64, OpeningPosition<G>>,
+}
+
+struct OpeningPosition<G: Game> {
+    games: u32,
+    moves: Vec<OpeningMove<G>>,
+}
+
+struct OpeningMove<G: Game> {
+    action: G::Action,
+    position: u64,
+
+    count: u32,
+    wins: u32,
+    draws: u32,
+
+    value_total: f32,
+    value_count: u32,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct OpeningTreeNode {
+    pub position: String,
+    pub games: u32,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub moves: Vec<OpeningTreeMove>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct OpeningTreeMove {
+    pub action: String,
+
+    pub count: u32,
+    pub win_rate: f32,
+    pub draw_rate: f32,
+    pub average_value: Option<f32>,
+
+    pub next: OpeningTreeNode,
+}
+
+impl<G: Game + Hash> OpeningTree<G> {
+    pub fn new(max_depth: usize) -> Self {
+        Self {
+            max_depth,
+
+            root: None,
+            positions: HashMap::new(),
+        }
+    }
+
+    pub fn add_record(&mut self, record: &GameRecord<G>) -> Result<(), String> {
+        let positions = record.positions()?;
+
+        let winner = match record.outcome {
+            Outcome::Win => Some(Turn::Player1),
+            Outcome::Loss => Some(Turn::Player2),
+            Outcome::Draw | Outcome::InProgress => None,
+        };
+
+        let keys: Vec<u64> = positions
+            .iter()
+            .take(self.max_depth + 1)
+            .map(|(game, turn)| Self::position_key(game, *turn))
+            .collect();
+
+        self.root.get_or_insert(keys[0]);
+
+        for (ply, recorded_move) in record.moves.iter().take(self.max_depth).enumerate() {
+            let position = self
+                .positions
+                .entry(keys[ply])
+                .or_insert_with(|| OpeningPosition {
+                    games: 0,
+                    moves: vec![],
+                });
+
+            position.games += 1;
+
+            let index = if let Some(index) = position
+                .moves
+                .iter()
+                .position(|opening_move| opening_move.action == recorded_move.action)
+            {
+                index
+            } else {
+                position.moves.push(OpeningMove {
+                    action: recorded_move.action,
+                    position: keys[ply + 1],
+
+                    count: 0,
+                    wins: 0,
+                    draws: 0,
+
+                    value_total: 0.0,
+                    value_count: 0,
+                });
+
+                position.moves.len() - 1
+            };
+
+            let opening_move = &mut position.moves[index];
+
+            opening_move.count += 1;
+
+            match winner {
+                Some(turn) if turn == recorded_move.turn => opening_move.wins += 1,
+                None if record.outcome == Outcome::Draw => opening_move.draws += 1,
+                _ => {}
+            }
+
+            if let Some(value) = recorded_move.value {
+                opening_move.value_total += value;
+                opening_move.value_count += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn export(&self, min_count: u32) -> Option<OpeningTreeNode> {
+        self.root
+            .map(|root| self.export_position(root, self.max_depth, min_count))
+    }
+
+    fn export_position(&self, key: u64, depth: usize, min_count: u32) -> OpeningTreeNode {
+        let position = self.positions.get(&key);
+
+        let mut moves: Vec<&OpeningMove<G>> = position
+            .filter(|_| depth > 0)
+            .map(|position| {
+                position
+                    .moves
+                    .iter()
+                    .filter(|opening_move| opening_move.count >= min_count)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        moves.sort_by_key(|opening_move| std::cmp::Reverse(opening_move.count));
+
+        OpeningTreeNode {
+            position: format!("{key:016x}"),
+            games: position.map_or(0, |position| position.games),
+
+            moves: moves
+                .into_iter()
+                .map(|opening_move| OpeningTreeMove {
+                    action: opening_move.action.to_string(),
+
+                    count: opening_move.count,
+                    win_rate: opening_move.wins as f32 / opening_move.count as f32,
+                    draw_rate: opening_move.draws as f32 / opening_move.count as f32,
+                    average_value: (opening_move.value_count > 0)
+                        .then(|| opening_move.value_total / opening_move.value_count as f32),
+
+                    next: self.export_position(opening_move.position, depth - 1, min_count),
+                })
+                .collect(),
+        }
+    }
+
+    fn position_key(game: &G, turn: Turn) -> u64 {
+        hash_position(&(game, turn == Turn::Player1))
+    }
+}
+
+impl OpeningTreeNode {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("unable to serialize opening tree")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod export {
+        use super::*;
+
+        #[test]
+        fn should_merge_transpositions_and_count_results() {
+            let mut tree = OpeningTree::new(3);
+
+            for (indices, outcome) in [
+                ([4, 0, 8], Outcome::Win),
+                ([4, 0, 2], Outcome::Loss),
+                ([8, 0, 4], Outcome::Draw),
+            ] {
+                let mut record = GameRecord::from_places(&indices, outcome);
+
+                for recorded_move in &mut record.moves {
+                    recorded_move.value = Some(0.5);
+                }
+
+                tree.add_record(&record).expect("unable to add record");
+            }
+
+            let root = tree.export(1).expect("tree should have a root");
+
+            assert_eq!(root.games, 3);
+            assert_eq!(root.moves.len(), 2);
+
+            let center = &root.moves[0];
+
+            assert_eq!(center.count, 2);
+            assert!((center.win_rate - 0.5).abs() < f32::EPSILON);
+            assert!(center.draw_rate.abs() < f32::EPSILON);
+            assert_eq!(center.average_value, Some(0.5));
+
+            let corner = &root.moves[1];
+
+            assert!((corner.draw_rate - 1.0).abs() < f32::EPSILON);
+
+            // NOTE - 4, 0, 8 and 8, 0, 4 reach the same position.
+
+            assert_eq!(
+                center.next.moves[0].next.moves[0].next.position,
+                corner.next.moves[0].next.moves[0].next.position
+            );
+        }
+
+        #[test]
+        fn should_filter_rare_moves() {
+            let mut tree = OpeningTree::new(2);
+
+            tree.add_record(&GameRecord::from_places(&[4, 0], Outcome::Draw))
+                .expect("unable to add record");
+            tree.add_record(&GameRecord::from_places(&[0, 4], Outcome::Draw))
+                .expect("unable to add record");
+            tree.add_record(&GameRecord::from_places(&[4, 8], Outcome::Draw))
+                .expect("unable to add record");
+
+            let root = tree.export(2).expect("tree should have a root");
+
+            assert_eq!(root.moves.len(), 1);
+            assert!(root.moves[0].next.moves.is_empty());
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PairingResult {
+    pub player_1: usize,
+    pub player_2: usize,
+
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+impl PairingResult {
+    const ITERATIONS: usize = 1000;
+    const TOLERANCE: f32 = 1e-6;
+
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+
+    // NOTE - Maximum likelihood Bradley-Terry ratings via minorization-maximization, with
+    // draws as half a win. Every pairing also gets one virtual draw, so that a player who
+    // never scored still has a finite rating. Ratings are on the Elo scale with the first
+    // player at zero.
+
+    pub fn fit_elo(players: usize, results: &[PairingResult]) -> Vec<f32> {
+        if players == 0 {
+            return vec![];
+        }
+
+        let mut scores = vec![0.0; players];
+
+        for result in results {
+            let draws = result.draws as f32 + 1.0;
+
+            scores[result.player_1] += result.wins as f32 + draws / 2.0;
+            scores[result.player_2] += result.losses as f32 + draws / 2.0;
+        }
+
+        let mut strengths = vec![1.0; players];
+
+        for _ in 0..Self::ITERATIONS {
+            let mut denominators = vec![0.0; players];
+
+            for result in results {
+                let games = result.games() as f32 + 1.0;
+                let total = strengths[result.player_1] + strengths[result.player_2];
+
+                denominators[result.player_1] += games / total;
+                denominators[result.player_2] += games / total;
+            }
+
+            let mut change: f32 = 0.0;
+
+            for player in 0..players {
+                if denominators[player] == 0.0 {
+                    continue;
+                }
+
+                let strength = scores[player] / denominators[player];
+
+                change = change.max((strength - strengths[player]).abs());
+                strengths[player] = strength;
+            }
+
+            // NOTE - Renormalize, since the ratings are only defined up to a constant factor.
+
+            let anchor = strengths[0];
+
+            for strength in &mut strengths {
+                *strength /= anchor;
+            }
+
+            if change < Self::TOLERANCE {
+                break;
+            }
+        }
+
+        strengths
+            .iter()
+            .map(|strength| 400.0 * strength.log10())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod fit_elo {
+        use super::*;
+
+        #[test]
+        fn should_rank_players_by_results() {
+            let results = [
+                PairingResult {
+                    player_1: 0,
+                    player_2: 1,
+                    wins: 2,
+                    losses: 8,
+                    draws: 0,
+                },
+                PairingResult {
+                    player_1: 1,
+                    player_2: 2,
+                    wins: 2,
+                    losses: 8,
+                    draws: 0,
+                },
+            ];
+
+            let ratings = PairingResult::fit_elo(3, &results);
+
+            assert!(ratings[0].abs() < f32::EPSILON);
+            assert!(ratings[1] > 150.0);
+            assert!(ratings[2] > ratings[1] + 150.0);
+        }
+
+        #[test]
+        fn should_keep_ratings_finite_without_wins() {
+            let results = [PairingResult {
+                player_1: 0,
+                player_2: 1,
+                wins: 0,
+                losses: 10,
+                draws: 0,
+            }];
+
+            let ratings = PairingResult::fit_elo(2, &results);
+
+            assert!(ratings[1].is_finite());
+            assert!(ratings[1] > 0.0);
+        }
+    }
+}
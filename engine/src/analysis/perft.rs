@@ -0,0 +1,87 @@
+use crate::core::{ActionList, Game, Outcome};
+
+// NOTE - Counts the positions reached after exactly `depth` actions, the usual check on move
+// generation. Every action is a ply, including those that leave the turn unfinished, and
+// the outcomes of a chance node count as its actions. Finished games have no children.
+
+pub fn perft<G: Game>(game: &G, depth: u32) -> u64 {
+    count_leaves(&mut game.clone(), depth)
+}
+
+fn count_leaves<G: Game>(game: &mut G, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    if game.outcome() != Outcome::InProgress {
+        return 0;
+    }
+
+    let chance_outcomes = game.chance_outcomes();
+
+    let actions: ActionList<G::Action> = if chance_outcomes.is_empty() {
+        game.get_possible_actions()
+    } else {
+        chance_outcomes
+            .into_iter()
+            .map(|(action, _)| action)
+            .collect()
+    };
+
+    if depth == 1 {
+        return actions.len() as u64;
+    }
+
+    let checkpoint = game.create_checkpoint();
+
+    let mut leaves = 0;
+
+    for action in actions {
+        if game.apply_action(action) {
+            game.end_turn();
+        }
+
+        leaves += count_leaves(game, depth - 1);
+
+        game.restore_checkpoint(checkpoint);
+    }
+
+    leaves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::boop::Boop;
+    use crate::game::chess::Chess;
+    use crate::game::tic_tac_toe::TicTacToe;
+
+    mod perft {
+        use super::*;
+
+        #[test]
+        fn should_match_known_tic_tac_toe_counts() {
+            let counts: Vec<u64> = (0..=9)
+                .map(|depth| perft(&TicTacToe::new(), depth))
+                .collect();
+
+            assert_eq!(
+                counts,
+                [1, 9, 72, 504, 3024, 15120, 54720, 148_176, 200_448, 127_872]
+            );
+        }
+
+        #[test]
+        fn should_match_known_chess_counts() {
+            let counts: Vec<u64> = (1..=3).map(|depth| perft(&Chess::new(), depth)).collect();
+
+            assert_eq!(counts, [20, 400, 8902]);
+        }
+
+        #[test]
+        fn should_count_boop_kitten_placements() {
+            assert_eq!(perft(&Boop::new(), 2), 36 * 35);
+        }
+    }
+}
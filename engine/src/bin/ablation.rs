@@ -0,0 +1,342 @@
+use std::f32;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+
+use hermes_engine::boop::{Boop, BoopActionEncoder, BoopStateEncoder};
+use hermes_engine::{
+    DirichletNoise, DiversityRunnerEventSink, LadderRung, ModelZoo, NeuralNetworkMctsPlayer,
+    OnnxNeuralNetwork, Runner, StatisticsRunnerEventSink, TemperatureSchedule,
+};
+
+mod common;
+
+use common::PathArgs;
+
+#[derive(Clone, Copy, Serialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+enum Parameter {
+    Alpha,
+    Epsilon,
+    TemperatureCutoff,
+    Cpuct,
+}
+
+#[derive(Parser)]
+#[command(name = "ablation")]
+#[command(about = "Compare self-play batches that differ in a single exploration parameter.")]
+struct Args {
+    /// Model file path, URL, or registry name (e.g. `boop/iter_42`).
+    #[arg(short, long)]
+    model: String,
+
+    /// Exploration parameter to vary.
+    #[arg(short, long, value_enum)]
+    parameter: Parameter,
+
+    /// Baseline value of the parameter. Defaults to the self-play setting.
+    #[arg(long)]
+    baseline: Option<f32>,
+
+    /// Value of the parameter to compare against the baseline. Repeat for several.
+    #[arg(long = "value", required = true)]
+    values: Vec<f32>,
+
+    /// Number of self-play games per batch, and of head-to-head games per variant.
+    #[arg(short, long, default_value_t = 100)]
+    games: u32,
+
+    #[arg(short, long, default_value_t = 100)]
+    simulations: u32,
+
+    #[arg(long, default_value_t = 150)]
+    max_turns: u32,
+
+    /// Random plies that open each game. Every batch plays the same openings.
+    #[arg(long, default_value_t = 4)]
+    opening_plies: u32,
+
+    /// Master seed shared by every batch.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    #[arg(short, long, default_value_t = 1)]
+    threads: usize,
+
+    #[command(flatten)]
+    paths: PathArgs,
+
+    /// JSON file to write the report. Relative paths are resolved against the run
+    /// directory.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+// -- Exploration settings --
+
+#[derive(Clone, Copy, Serialize)]
+struct Exploration {
+    alpha: f32,
+    epsilon: f32,
+    temperature_cutoff: u32,
+    c_puct: f32,
+}
+
+impl Exploration {
+    // NOTE - Matches the settings `self-play` uses.
+
+    const DEFAULT: Exploration = Exploration {
+        alpha: 0.3,
+        epsilon: 0.25,
+        temperature_cutoff: 30,
+        c_puct: f32::consts::SQRT_2,
+    };
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn with(mut self, parameter: Parameter, value: f32) -> Self {
+        match parameter {
+            Parameter::Alpha => self.alpha = value,
+            Parameter::Epsilon => self.epsilon = value,
+            Parameter::TemperatureCutoff => self.temperature_cutoff = value.max(0.0).round() as u32,
+            Parameter::Cpuct => self.c_puct = value,
+        }
+
+        self
+    }
+}
+
+type BoopNnPlayer = NeuralNetworkMctsPlayer<
+    Boop,
+    BoopStateEncoder,
+    BoopActionEncoder,
+    OnnxNeuralNetwork<Boop, BoopStateEncoder>,
+>;
+
+fn create_player(model: &Path, simulations: u32, exploration: Exploration) -> BoopNnPlayer {
+    let state_encoder = BoopStateEncoder::new();
+    let action_encoder = BoopActionEncoder::new();
+
+    let neural_network = OnnxNeuralNetwork::new(model, state_encoder, action_encoder)
+        .expect("failed to load onnx model");
+
+    NeuralNetworkMctsPlayer::new(simulations, state_encoder, action_encoder, neural_network)
+        .with_c_puct(exploration.c_puct)
+        .with_dirichlet_noise(DirichletNoise {
+            alpha: exploration.alpha,
+            epsilon: exploration.epsilon,
+        })
+        .with_temperature_schedule(TemperatureSchedule::Step {
+            threshold: exploration.temperature_cutoff,
+            hi: 1.0,
+            lo: 0.0,
+        })
+}
+
+// -- Output types --
+
+#[derive(Serialize)]
+struct BatchReport {
+    value: f32,
+    exploration: Exploration,
+    games: u32,
+    positions: u32,
+    unique_position_rate: f32,
+    unique_game_rate: f32,
+    mean_game_length: f32,
+    mean_policy_entropy: f32,
+}
+
+#[derive(Serialize)]
+struct HeadToHeadReport {
+    wins: u32,
+    losses: u32,
+    draws: u32,
+    score: f32,
+    confidence_interval: (f32, f32),
+    elo_difference: f32,
+}
+
+#[derive(Serialize)]
+struct VariantReport {
+    #[serde(flatten)]
+    batch: BatchReport,
+    head_to_head: HeadToHeadReport,
+}
+
+#[derive(Serialize)]
+struct AblationOutput {
+    parameter: Parameter,
+    baseline: BatchReport,
+    variants: Vec<VariantReport>,
+}
+
+fn self_play(
+    args: &Args,
+    player: BoopNnPlayer,
+    value: f32,
+    exploration: Exploration,
+) -> BatchReport {
+    let mut runner = Runner::new(
+        args.games,
+        player.clone(),
+        player,
+        DiversityRunnerEventSink::new(),
+    )
+    .with_max_turns(args.max_turns)
+    .with_threads(args.threads)
+    .with_seed(args.seed)
+    .with_opening_plies(args.opening_plies);
+
+    runner.run_parallel();
+
+    let sink = runner.sink();
+
+    println!(
+        "\t{value}: {:.1}% unique positions, {:.1}% unique games, {:.1} plies per game, {:.3} policy entropy",
+        sink.unique_position_rate() * 100.0,
+        sink.unique_game_rate() * 100.0,
+        sink.mean_game_length(),
+        sink.mean_policy_entropy()
+    );
+
+    BatchReport {
+        value,
+        exploration,
+        games: sink.games(),
+        positions: sink.positions(),
+        unique_position_rate: sink.unique_position_rate(),
+        unique_game_rate: sink.unique_game_rate(),
+        mean_game_length: sink.mean_game_length(),
+        mean_policy_entropy: sink.mean_policy_entropy(),
+    }
+}
+
+fn head_to_head(
+    args: &Args,
+    variant: BoopNnPlayer,
+    baseline: BoopNnPlayer,
+    value: f32,
+) -> HeadToHeadReport {
+    let mut runner = Runner::new(
+        args.games,
+        variant,
+        baseline,
+        StatisticsRunnerEventSink::new(),
+    )
+    .with_max_turns(args.max_turns)
+    .with_threads(args.threads)
+    .with_seed(args.seed)
+    .with_opening_plies(args.opening_plies);
+
+    runner.run_parallel();
+
+    let statistics = runner.sink();
+
+    let rung = LadderRung {
+        anchor: "baseline".to_string(),
+
+        games: statistics.total_games,
+        wins: statistics.player_1_wins,
+        losses: statistics.player_2_wins,
+        draws: statistics.draws,
+    };
+
+    let score = rung.score();
+    let (lower, upper) = rung.confidence_interval(LadderRung::DEFAULT_Z);
+
+    println!(
+        "\t{value}: {}W {}L {}D, score {:.3} [{lower:.3}, {upper:.3}], Elo {:+.0}",
+        rung.wins,
+        rung.losses,
+        rung.draws,
+        score,
+        LadderRung::elo_difference(score)
+    );
+
+    HeadToHeadReport {
+        wins: rung.wins,
+        losses: rung.losses,
+        draws: rung.draws,
+        score,
+        confidence_interval: (lower, upper),
+        elo_difference: LadderRung::elo_difference(score),
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let paths = args.paths.load();
+
+    let mut model_zoo = ModelZoo::new(paths.model_dir());
+
+    if let Some(path) = paths.model_registry() {
+        model_zoo = model_zoo
+            .with_registry_file(path)
+            .expect("failed to load model registry");
+    }
+
+    let model = model_zoo
+        .resolve(&args.model)
+        .expect("failed to resolve model");
+
+    let baseline_value = args.baseline.unwrap_or(match args.parameter {
+        Parameter::Alpha => Exploration::DEFAULT.alpha,
+        Parameter::Epsilon => Exploration::DEFAULT.epsilon,
+        Parameter::TemperatureCutoff => Exploration::DEFAULT.temperature_cutoff as f32,
+        Parameter::Cpuct => Exploration::DEFAULT.c_puct,
+    });
+
+    let baseline_exploration = Exploration::DEFAULT.with(args.parameter, baseline_value);
+    let baseline_player = create_player(&model, args.simulations, baseline_exploration);
+
+    println!("Self-play:");
+
+    let baseline = self_play(
+        &args,
+        baseline_player.clone(),
+        baseline_value,
+        baseline_exploration,
+    );
+
+    let variants: Vec<_> = args
+        .values
+        .iter()
+        .map(|&value| {
+            let exploration = Exploration::DEFAULT.with(args.parameter, value);
+            let player = create_player(&model, args.simulations, exploration);
+
+            (value, exploration, player)
+        })
+        .collect();
+
+    let batches: Vec<_> = variants
+        .iter()
+        .map(|(value, exploration, player)| self_play(&args, player.clone(), *value, *exploration))
+        .collect();
+
+    println!("\nHead-to-head against {baseline_value}:");
+
+    let variants = variants
+        .into_iter()
+        .zip(batches)
+        .map(|((value, _, player), batch)| VariantReport {
+            batch,
+            head_to_head: head_to_head(&args, player, baseline_player.clone(), value),
+        })
+        .collect();
+
+    if let Some(output_path) = args.output.as_deref().map(|path| paths.run_file(path)) {
+        let output = AblationOutput {
+            parameter: args.parameter,
+            baseline,
+            variants,
+        };
+
+        let json = serde_json::to_string_pretty(&output).expect("failed to serialize report");
+
+        fs::write(output_path, json).expect("failed to write output file");
+    }
+}
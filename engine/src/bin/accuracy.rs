@@ -0,0 +1,146 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use rand::seq::SliceRandom;
+
+use hermes_engine::boop::Boop;
+use hermes_engine::{
+    AccuracyReport, BOOP_FIXTURES, DefaultRngFactory, Game, GameRecord, ModelZoo, Outcome,
+    RngFactory,
+};
+
+mod common;
+
+use common::{PathArgs, PlayerSpec, build_player};
+
+#[derive(Parser)]
+#[command(name = "accuracy")]
+#[command(about = "Compare a player's moves and values against a reference player.")]
+struct Args {
+    /// Game record files (JSON lines) to sample positions from. Uses the fixture positions
+    /// when omitted.
+    records: Vec<PathBuf>,
+
+    /// Player under test: `random`, `greedy`, `minimax:<depth>`, `mcts:<simulations>`, or a
+    /// model file path, URL, or registry name.
+    #[arg(short, long)]
+    player: PlayerSpec,
+
+    /// Reference player, in the same format. It must return an evaluation, so `random`,
+    /// `greedy` and `minimax` cannot be used.
+    #[arg(short, long, default_value = "mcts:5000")]
+    reference: PlayerSpec,
+
+    /// Number of MCTS simulations per position (only applies to neural network players).
+    #[arg(short, long, default_value_t = 800)]
+    simulations: u32,
+
+    /// Number of positions sampled from the game records.
+    #[arg(long, default_value_t = 200)]
+    sample: usize,
+
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    #[command(flatten)]
+    paths: PathArgs,
+
+    /// JSON file to write the report. Relative paths are resolved against the run
+    /// directory.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn load_records(path: &Path) -> Vec<GameRecord<Boop>> {
+    fs::read_to_string(path)
+        .expect("failed to read game records")
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            GameRecord::from_portable_json(line).unwrap_or_else(|error| {
+                panic!(
+                    "{}:{}: failed to parse game record: {error}",
+                    path.display(),
+                    i + 1
+                )
+            })
+        })
+        .collect()
+}
+
+// NOTE - Samples unfinished positions uniformly across every record, so long games
+// contribute more positions than short ones.
+
+fn sample_positions(paths: &[PathBuf], sample: usize, seed: u64) -> Vec<Boop> {
+    let mut positions: Vec<Boop> = paths
+        .iter()
+        .flat_map(|path| load_records(path))
+        .flat_map(|record| record.positions().expect("failed to replay game record"))
+        .map(|(game, _)| game)
+        .filter(|game| game.outcome() == Outcome::InProgress)
+        .collect();
+
+    let mut rng = DefaultRngFactory::default().create_seeded(seed);
+
+    positions.shuffle(&mut rng);
+    positions.truncate(sample);
+
+    positions
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let paths = args.paths.load();
+
+    let mut model_zoo = ModelZoo::new(paths.model_dir());
+
+    if let Some(path) = paths.model_registry() {
+        model_zoo = model_zoo
+            .with_registry_file(path)
+            .expect("failed to load model registry");
+    }
+
+    let positions = if args.records.is_empty() {
+        BOOP_FIXTURES
+            .iter()
+            .map(|fixture| fixture.game().expect("failed to parse fixture"))
+            .collect()
+    } else {
+        sample_positions(&args.records, args.sample, args.seed)
+    };
+
+    let mut player = build_player(&args.player, args.simulations, &model_zoo);
+    let mut reference = build_player(&args.reference, args.simulations, &model_zoo);
+
+    let report = AccuracyReport::analyze(&positions, &mut player, &mut reference)
+        .expect("failed to analyze positions");
+
+    println!(
+        "{} vs {} across {} positions",
+        args.player.default_name(),
+        args.reference.default_name(),
+        report.positions
+    );
+    println!(
+        "\tTop-1 agreement: {:.2}%",
+        report.top_1_agreement() * 100.0
+    );
+    println!(
+        "\tTop-3 agreement: {:.2}%",
+        report.top_3_agreement() * 100.0
+    );
+
+    match report.value_mae() {
+        Some(value_mae) => println!("\tValue MAE: {value_mae:.4}"),
+        None => println!("\tValue MAE: n/a (player returns no values)"),
+    }
+
+    if let Some(output_path) = args.output.as_deref().map(|path| paths.run_file(path)) {
+        let json = serde_json::to_string_pretty(&report).expect("failed to serialize report");
+
+        fs::write(output_path, json).expect("failed to write output file");
+    }
+}
@@ -0,0 +1,98 @@
+use clap::Parser;
+
+use hermes_engine::boop::Boop;
+use hermes_engine::{BOOP_FIXTURES, Fixture, Game, ModelZoo, Player, PolicyOverlay, Turn};
+
+mod common;
+
+use common::{PathArgs, PlayerSpec, build_player};
+
+#[derive(Parser)]
+#[command(name = "analyze")]
+#[command(about = "Search a named position and print the player's policy and value.")]
+struct Args {
+    /// Name of the position to analyze. Lists the available positions when omitted.
+    #[arg(short, long)]
+    fixture: Option<String>,
+
+    /// Player: `random`, `greedy`, `minimax:<depth>`, `mcts:<simulations>`, or a model file
+    /// path, URL, or registry name.
+    #[arg(short, long, default_value = "mcts:800")]
+    player: PlayerSpec,
+
+    /// Number of MCTS simulations (only applies to neural network players).
+    #[arg(short, long, default_value_t = 800)]
+    simulations: u32,
+
+    /// Print the policy as shades instead of percentages.
+    #[arg(long)]
+    shades: bool,
+
+    #[command(flatten)]
+    paths: PathArgs,
+}
+
+fn list_fixtures() {
+    let name_width = BOOP_FIXTURES
+        .iter()
+        .map(|fixture| fixture.name.len())
+        .max()
+        .unwrap_or_default();
+
+    println!("Available fixtures:");
+
+    for fixture in &BOOP_FIXTURES {
+        println!("\t{:<name_width$} {}", fixture.name, fixture.description);
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let Some(name) = args.fixture.as_deref() else {
+        list_fixtures();
+
+        return;
+    };
+
+    let Some(fixture) = Fixture::find(&BOOP_FIXTURES, name) else {
+        eprintln!("unknown fixture: {name}\n");
+        list_fixtures();
+
+        std::process::exit(2);
+    };
+
+    let game: Boop = fixture.game().expect("failed to parse fixture");
+
+    let paths = args.paths.load();
+
+    let mut model_zoo = ModelZoo::new(paths.model_dir());
+
+    if let Some(path) = paths.model_registry() {
+        model_zoo = model_zoo
+            .with_registry_file(path)
+            .expect("failed to load model registry");
+    }
+
+    let mut player = build_player(&args.player, args.simulations, &model_zoo);
+
+    let player_name = args.player.default_name();
+
+    println!("{}: {}\n", fixture.name, fixture.description);
+    println!("{}", game.display(Turn::Player1));
+
+    let choice = player.choose_action(&game, 0);
+
+    if let Some(evaluation) = &choice.evaluation {
+        let overlay = if args.shades {
+            PolicyOverlay::Shades
+        } else {
+            PolicyOverlay::Percentages
+        };
+
+        println!("{player_name} policy (value {:+.3})\n", evaluation.value);
+        println!("{}", overlay.render(&game, evaluation));
+    }
+
+    println!("{player_name} {}", choice.action);
+}
@@ -0,0 +1,200 @@
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use serde::Serialize;
+
+use hermes_engine::boop::{Boop, BoopActionEncoder, BoopStateEncoder};
+use hermes_engine::{
+    Blunder, BlunderReport, CachedNeuralNetwork, ClassicMctsPlayer, GameRecord, ModelZoo,
+    NeuralNetworkMctsPlayer, OnnxNeuralNetwork, Player, TemperatureSchedule,
+};
+
+mod common;
+
+use common::PathArgs;
+
+#[derive(Parser)]
+#[command(name = "blunders")]
+#[command(about = "Re-evaluate recorded games with a reference player and flag blunders.")]
+struct Args {
+    /// Game record files (JSON lines), typically one per training iteration.
+    #[arg(required = true)]
+    records: Vec<PathBuf>,
+
+    /// Reference model file path, URL, or registry name. Defaults to classic MCTS.
+    #[arg(short, long)]
+    model: Option<String>,
+
+    /// Number of MCTS simulations per position for the reference player.
+    #[arg(short, long, default_value_t = 800)]
+    simulations: u32,
+
+    /// Minimum drop in the mover's value, on a -1 to 1 scale, for a move to count as a
+    /// blunder.
+    #[arg(long, default_value_t = BlunderReport::DEFAULT_THRESHOLD)]
+    threshold: f32,
+
+    /// Number of worst blunders to include per file.
+    #[arg(long, default_value_t = 10)]
+    top: usize,
+
+    /// Prediction cache file for the reference model. Loaded if it exists and saved after
+    /// the analysis, so repeated sessions skip positions already evaluated. Relative paths
+    /// are resolved against the run directory.
+    #[arg(long)]
+    cache: Option<PathBuf>,
+
+    #[command(flatten)]
+    paths: PathArgs,
+
+    /// JSON file to write the report. Relative paths are resolved against the run
+    /// directory.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct FileReport {
+    file: PathBuf,
+    games: usize,
+    moves: usize,
+    blunders: usize,
+    blunder_rate: f32,
+    worst: Vec<Blunder>,
+}
+
+fn load_records(path: &Path) -> Vec<GameRecord<Boop>> {
+    fs::read_to_string(path)
+        .expect("failed to read game records")
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            GameRecord::from_portable_json(line).unwrap_or_else(|error| {
+                panic!(
+                    "{}:{}: failed to parse game record: {error}",
+                    path.display(),
+                    i + 1
+                )
+            })
+        })
+        .collect()
+}
+
+fn analyze_files<P: Player<Boop>>(args: &Args, reference: &mut P) -> Vec<FileReport> {
+    args.records
+        .iter()
+        .map(|path| {
+            let records = load_records(path);
+
+            let report = BlunderReport::analyze(&records, reference, args.threshold)
+                .expect("failed to analyze games");
+
+            let mut worst = report.blunders.clone();
+
+            worst.sort_by(|x, y| y.value_drop().total_cmp(&x.value_drop()));
+            worst.truncate(args.top);
+
+            println!(
+                "{}: {} blunders in {} moves ({:.2}%) across {} games",
+                path.display(),
+                report.blunders.len(),
+                report.moves,
+                report.blunder_rate() * 100.0,
+                report.games
+            );
+
+            FileReport {
+                file: path.clone(),
+                games: report.games,
+                moves: report.moves,
+                blunders: report.blunders.len(),
+                blunder_rate: report.blunder_rate(),
+                worst,
+            }
+        })
+        .collect()
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let paths = args.paths.load();
+
+    let reports = if let Some(model) = &args.model {
+        let mut model_zoo = ModelZoo::new(paths.model_dir());
+
+        if let Some(path) = paths.model_registry() {
+            model_zoo = model_zoo
+                .with_registry_file(path)
+                .expect("failed to load model registry");
+        }
+
+        let path = model_zoo.resolve(model).expect("failed to resolve model");
+
+        let state_encoder = BoopStateEncoder::new();
+        let action_encoder = BoopActionEncoder::new();
+
+        let model_hash = ModelZoo::checksum(&path).expect("failed to hash model");
+
+        let mut neural_network = CachedNeuralNetwork::new(
+            OnnxNeuralNetwork::new(path, state_encoder, action_encoder)
+                .expect("failed to load onnx model"),
+        )
+        .with_model_hash(model_hash);
+
+        let cache_path = args.cache.as_deref().map(|path| paths.run_file(path));
+
+        if let Some(path) = cache_path.as_deref().filter(|path| path.exists()) {
+            let file = File::open(path).expect("failed to open prediction cache");
+
+            neural_network
+                .read_from(&mut BufReader::new(file))
+                .expect("failed to read prediction cache");
+        }
+
+        let mut reference = NeuralNetworkMctsPlayer::new(
+            args.simulations,
+            state_encoder,
+            action_encoder,
+            neural_network,
+        )
+        .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+        let reports = analyze_files(&args, &mut reference);
+
+        let neural_network = reference.neural_network();
+
+        println!(
+            "Prediction cache: {} hits, {} misses, {} entries",
+            neural_network.hits(),
+            neural_network.misses(),
+            neural_network.len()
+        );
+
+        if let Some(path) = &cache_path {
+            let mut writer =
+                BufWriter::new(File::create(path).expect("failed to create prediction cache"));
+
+            neural_network
+                .write_to(&mut writer)
+                .and_then(|()| writer.flush())
+                .expect("failed to write prediction cache");
+        }
+
+        reports
+    } else {
+        let mut reference = ClassicMctsPlayer::new(args.simulations)
+            .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+        analyze_files(&args, &mut reference)
+    };
+
+    if let Some(output_path) = args.output.as_deref().map(|path| paths.run_file(path)) {
+        let json = serde_json::to_string_pretty(&reports).expect("failed to serialize report");
+
+        fs::write(output_path, json).expect("failed to write output file");
+    }
+}
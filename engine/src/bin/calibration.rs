@@ -0,0 +1,135 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use serde::Serialize;
+
+use hermes_engine::boop::{Boop, BoopActionEncoder, BoopStateEncoder};
+use hermes_engine::{CalibrationBin, CalibrationReport, GameRecord, ModelZoo, OnnxNeuralNetwork};
+
+mod common;
+
+use common::PathArgs;
+
+#[derive(Parser)]
+#[command(name = "calibration")]
+#[command(about = "Compare value head predictions against the outcomes of recorded games.")]
+struct Args {
+    /// Game record files (JSON lines).
+    #[arg(required = true)]
+    records: Vec<PathBuf>,
+
+    /// Model file path, URL, or registry name. Repeat to compare iterations.
+    #[arg(short, long = "model", required = true)]
+    models: Vec<String>,
+
+    /// Number of equal-width bins the predicted values are grouped into.
+    #[arg(short, long, default_value_t = CalibrationReport::DEFAULT_BINS)]
+    bins: usize,
+
+    #[command(flatten)]
+    paths: PathArgs,
+
+    /// JSON file to write the report. Relative paths are resolved against the run
+    /// directory.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct ModelReport {
+    model: String,
+    games: usize,
+    positions: usize,
+    brier_score: f32,
+    calibration_error: f32,
+    bins: Vec<CalibrationBin>,
+}
+
+fn load_records(path: &Path) -> Vec<GameRecord<Boop>> {
+    fs::read_to_string(path)
+        .expect("failed to read game records")
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            GameRecord::from_portable_json(line).unwrap_or_else(|error| {
+                panic!(
+                    "{}:{}: failed to parse game record: {error}",
+                    path.display(),
+                    i + 1
+                )
+            })
+        })
+        .collect()
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let paths = args.paths.load();
+
+    let mut model_zoo = ModelZoo::new(paths.model_dir());
+
+    if let Some(path) = paths.model_registry() {
+        model_zoo = model_zoo
+            .with_registry_file(path)
+            .expect("failed to load model registry");
+    }
+
+    let records: Vec<_> = args
+        .records
+        .iter()
+        .flat_map(|path| load_records(path))
+        .collect();
+
+    let reports: Vec<_> = args
+        .models
+        .iter()
+        .map(|model| {
+            let path = model_zoo.resolve(model).expect("failed to resolve model");
+
+            let state_encoder = BoopStateEncoder::new();
+
+            let mut neural_network =
+                OnnxNeuralNetwork::new(path, state_encoder, BoopActionEncoder::new())
+                    .expect("failed to load onnx model");
+
+            let report =
+                CalibrationReport::analyze(&records, state_encoder, &mut neural_network, args.bins)
+                    .expect("failed to analyze games");
+
+            println!(
+                "{model}: Brier score {:.4}, calibration error {:.4} across {} positions in {} games",
+                report.brier_score,
+                report.calibration_error(),
+                report.positions,
+                report.games
+            );
+
+            for bin in &report.bins {
+                if let (Some(predicted), Some(outcome)) = (bin.mean_predicted, bin.mean_outcome) {
+                    println!(
+                        "\t[{:+.1}, {:+.1}): predicted {predicted:+.3}, realized {outcome:+.3} ({} positions)",
+                        bin.lower, bin.upper, bin.positions
+                    );
+                }
+            }
+
+            ModelReport {
+                model: model.clone(),
+                games: report.games,
+                positions: report.positions,
+                brier_score: report.brier_score,
+                calibration_error: report.calibration_error(),
+                bins: report.bins,
+            }
+        })
+        .collect();
+
+    if let Some(output_path) = args.output.as_deref().map(|path| paths.run_file(path)) {
+        let json = serde_json::to_string_pretty(&reports).expect("failed to serialize report");
+
+        fs::write(output_path, json).expect("failed to write output file");
+    }
+}
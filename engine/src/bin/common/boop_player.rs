@@ -0,0 +1,90 @@
+use hermes_engine::boop::{Boop, BoopActionEncoder, BoopStateEncoder};
+use hermes_engine::{
+    Choice, ClassicMctsPlayer, GreedyPlayer, MinimaxPlayer, ModelZoo, NeuralNetworkMctsPlayer,
+    OnnxNeuralNetwork, Player, RandomPlayer, TemperatureSchedule,
+};
+
+use crate::common::player_spec::PlayerSpec;
+
+// -- Concrete player enum (enables mixing player types at runtime) --
+
+type BoopNnPlayer = NeuralNetworkMctsPlayer<
+    Boop,
+    BoopStateEncoder,
+    BoopActionEncoder,
+    OnnxNeuralNetwork<Boop, BoopStateEncoder>,
+>;
+
+#[derive(Clone)]
+pub enum BoopPlayer {
+    Random(RandomPlayer),
+    Greedy(GreedyPlayer),
+    Minimax(MinimaxPlayer),
+    ClassicMcts(ClassicMctsPlayer<Boop>),
+    NeuralNetwork(BoopNnPlayer),
+}
+
+impl Player<Boop> for BoopPlayer {
+    fn name(&self) -> &str {
+        match self {
+            BoopPlayer::Random(p) => <RandomPlayer as Player<Boop>>::name(p),
+            BoopPlayer::Greedy(p) => <GreedyPlayer as Player<Boop>>::name(p),
+            BoopPlayer::Minimax(p) => <MinimaxPlayer as Player<Boop>>::name(p),
+            BoopPlayer::ClassicMcts(p) => p.name(),
+            BoopPlayer::NeuralNetwork(p) => p.name(),
+        }
+    }
+
+    fn choose_action(&mut self, game: &Boop, turn_number: u32) -> Choice<Boop> {
+        match self {
+            BoopPlayer::Random(p) => p.choose_action(game, turn_number),
+            BoopPlayer::Greedy(p) => p.choose_action(game, turn_number),
+            BoopPlayer::Minimax(p) => p.choose_action(game, turn_number),
+            BoopPlayer::ClassicMcts(p) => p.choose_action(game, turn_number),
+            BoopPlayer::NeuralNetwork(p) => p.choose_action(game, turn_number),
+        }
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        match self {
+            BoopPlayer::Random(p) => <RandomPlayer as Player<Boop>>::reseed(p, seed),
+            BoopPlayer::Greedy(p) => <GreedyPlayer as Player<Boop>>::reseed(p, seed),
+            BoopPlayer::Minimax(p) => <MinimaxPlayer as Player<Boop>>::reseed(p, seed),
+            BoopPlayer::ClassicMcts(p) => p.reseed(seed),
+            BoopPlayer::NeuralNetwork(p) => p.reseed(seed),
+        }
+    }
+
+    fn new_game(&mut self) {
+        match self {
+            BoopPlayer::ClassicMcts(p) => p.new_game(),
+            BoopPlayer::NeuralNetwork(p) => p.new_game(),
+            BoopPlayer::Random(_) | BoopPlayer::Greedy(_) | BoopPlayer::Minimax(_) => {}
+        }
+    }
+}
+
+pub fn build_player(spec: &PlayerSpec, simulations: u32, model_zoo: &ModelZoo) -> BoopPlayer {
+    match spec {
+        PlayerSpec::Random => BoopPlayer::Random(RandomPlayer::new()),
+        PlayerSpec::Greedy => BoopPlayer::Greedy(GreedyPlayer::new()),
+        PlayerSpec::Minimax(depth) => BoopPlayer::Minimax(MinimaxPlayer::new(*depth)),
+        PlayerSpec::ClassicMcts(simulations) => BoopPlayer::ClassicMcts(
+            ClassicMctsPlayer::new(*simulations)
+                .with_temperature_schedule(TemperatureSchedule::Constant(0.0)),
+        ),
+        PlayerSpec::NeuralNetwork(model) => {
+            let path = model_zoo.resolve(model).expect("failed to resolve model");
+
+            let state_encoder = BoopStateEncoder::new();
+            let action_encoder = BoopActionEncoder::new();
+            let nn = OnnxNeuralNetwork::new(path, state_encoder, action_encoder)
+                .expect("failed to load ONNX model");
+            BoopPlayer::NeuralNetwork(
+                // No Dirichlet noise, constant temperature=0 (greedy) for fair evaluation.
+                NeuralNetworkMctsPlayer::new(simulations, state_encoder, action_encoder, nn)
+                    .with_temperature_schedule(TemperatureSchedule::Constant(0.0)),
+            )
+        }
+    }
+}
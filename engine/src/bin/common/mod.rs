@@ -0,0 +1,11 @@
+// NOTE - Every binary compiles its own copy of this module and most use only part of it.
+
+#![allow(dead_code, unused_imports)]
+
+mod boop_player;
+mod path_args;
+mod player_spec;
+
+pub use boop_player::build_player;
+pub use path_args::PathArgs;
+pub use player_spec::PlayerSpec;
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use hermes_engine::HermesPaths;
+
+// NOTE - The path overrides every binary accepts, flattened into its own arguments. Flags
+// take precedence over the config file and environment variables.
+
+#[derive(Args)]
+pub struct PathArgs {
+    /// JSON config file with path overrides. Defaults to `$HERMES_CONFIG` or `hermes.json`.
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// Run directory that other paths default to. Defaults to `$HERMES_RUN_DIR` or `.`.
+    #[arg(long)]
+    pub run_dir: Option<PathBuf>,
+
+    /// Directory where downloaded models are cached. Defaults to `<run-dir>/models`.
+    #[arg(long, alias = "model-cache")]
+    pub model_dir: Option<PathBuf>,
+
+    /// JSON file mapping registry names to model URLs and checksums.
+    #[arg(long)]
+    pub model_registry: Option<PathBuf>,
+}
+
+impl PathArgs {
+    pub fn load(&self) -> HermesPaths {
+        HermesPaths::load(self.config.as_deref())
+            .expect("failed to load config")
+            .merge(HermesPaths {
+                run_dir: self.run_dir.clone(),
+                model_dir: self.model_dir.clone(),
+                data_dir: None,
+                model_registry: self.model_registry.clone(),
+            })
+    }
+}
@@ -0,0 +1,44 @@
+use std::str::FromStr;
+
+#[derive(Clone)]
+pub enum PlayerSpec {
+    Random,
+    Greedy,
+    Minimax(usize),
+    ClassicMcts(u32),
+    NeuralNetwork(String),
+}
+
+impl PlayerSpec {
+    pub fn default_name(&self) -> String {
+        match self {
+            PlayerSpec::Random => "random".to_string(),
+            PlayerSpec::Greedy => "greedy".to_string(),
+            PlayerSpec::Minimax(depth) => format!("minimax-{depth}"),
+            PlayerSpec::ClassicMcts(simulations) => format!("mcts-{simulations}"),
+            PlayerSpec::NeuralNetwork(model) => model.clone(),
+        }
+    }
+}
+
+impl FromStr for PlayerSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "random" {
+            return Ok(PlayerSpec::Random);
+        }
+        if s == "greedy" {
+            return Ok(PlayerSpec::Greedy);
+        }
+        if let Some(depth_str) = s.strip_prefix("minimax:") {
+            let depth = depth_str.parse::<usize>().map_err(|e| e.to_string())?;
+            return Ok(PlayerSpec::Minimax(depth));
+        }
+        if let Some(simulations_str) = s.strip_prefix("mcts:") {
+            let simulations = simulations_str.parse::<u32>().map_err(|e| e.to_string())?;
+            return Ok(PlayerSpec::ClassicMcts(simulations));
+        }
+        Ok(PlayerSpec::NeuralNetwork(s.to_string()))
+    }
+}
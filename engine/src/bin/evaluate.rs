@@ -1,101 +1,15 @@
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
-use std::str::FromStr;
 
 use clap::Parser;
 use serde::Serialize;
 
-use hermes_engine::boop::{Boop, BoopActionEncoder, BoopStateEncoder};
-use hermes_engine::{
-    Choice, MinimaxPlayer, NeuralNetworkMctsPlayer, OnnxNeuralNetwork, Player, RandomPlayer,
-    Runner, StatisticsRunnerEventSink, TemperatureSchedule,
-};
+use hermes_engine::{ModelZoo, Runner, Scoring, ShutdownSignal, StatisticsRunnerEventSink};
 
-// -- Player spec --
+mod common;
 
-#[derive(Clone)]
-enum PlayerSpec {
-    Random,
-    Minimax(usize),
-    NeuralNetwork(PathBuf),
-}
-
-impl PlayerSpec {
-    fn default_name(&self) -> String {
-        match self {
-            PlayerSpec::Random => "random".to_string(),
-            PlayerSpec::Minimax(depth) => format!("minimax-{depth}"),
-            PlayerSpec::NeuralNetwork(path) => path.display().to_string(),
-        }
-    }
-}
-
-impl FromStr for PlayerSpec {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s == "random" {
-            return Ok(PlayerSpec::Random);
-        }
-        if let Some(depth_str) = s.strip_prefix("minimax:") {
-            let depth = depth_str.parse::<usize>().map_err(|e| e.to_string())?;
-            return Ok(PlayerSpec::Minimax(depth));
-        }
-        Ok(PlayerSpec::NeuralNetwork(PathBuf::from(s)))
-    }
-}
-
-// -- Concrete player enum (enables mixing player types at runtime) --
-
-type BoopNnPlayer = NeuralNetworkMctsPlayer<
-    Boop,
-    BoopStateEncoder,
-    BoopActionEncoder,
-    OnnxNeuralNetwork<Boop, BoopStateEncoder>,
->;
-
-enum BoopPlayer {
-    Random(RandomPlayer),
-    Minimax(MinimaxPlayer),
-    NeuralNetwork(BoopNnPlayer),
-}
-
-impl Player<Boop> for BoopPlayer {
-    fn name(&self) -> &str {
-        match self {
-            BoopPlayer::Random(p) => <RandomPlayer as Player<Boop>>::name(p),
-            BoopPlayer::Minimax(p) => <MinimaxPlayer as Player<Boop>>::name(p),
-            BoopPlayer::NeuralNetwork(p) => p.name(),
-        }
-    }
-
-    fn choose_action(&mut self, game: &Boop, turn_number: u32) -> Choice<Boop> {
-        match self {
-            BoopPlayer::Random(p) => p.choose_action(game, turn_number),
-            BoopPlayer::Minimax(p) => p.choose_action(game, turn_number),
-            BoopPlayer::NeuralNetwork(p) => p.choose_action(game, turn_number),
-        }
-    }
-}
-
-fn build_player(spec: &PlayerSpec, simulations: u32) -> BoopPlayer {
-    match spec {
-        PlayerSpec::Random => BoopPlayer::Random(RandomPlayer::new()),
-        PlayerSpec::Minimax(depth) => BoopPlayer::Minimax(MinimaxPlayer::new(*depth)),
-        PlayerSpec::NeuralNetwork(path) => {
-            let state_encoder = BoopStateEncoder::new();
-            let action_encoder = BoopActionEncoder::new();
-            let nn =
-                OnnxNeuralNetwork::new(path, state_encoder).expect("failed to load ONNX model");
-            BoopPlayer::NeuralNetwork(
-                // No Dirichlet noise, constant temperature=0 (greedy) for fair evaluation.
-                NeuralNetworkMctsPlayer::new(simulations, state_encoder, action_encoder, nn)
-                    .with_temperature_schedule(TemperatureSchedule::Constant(0.0)),
-            )
-        }
-    }
-}
+use common::{PathArgs, PlayerSpec, build_player};
 
 // -- ELO --
 
@@ -136,6 +50,10 @@ struct EvalOutput {
     draws: u32,
     draw_rate: f32,
     total_games: u32,
+    draw_score: f32,
+    score_difference: f32,
+    score_difference_low: f32,
+    score_difference_high: f32,
 }
 
 // -- CLI --
@@ -171,11 +89,24 @@ struct Args {
     #[arg(short, long, default_value_t = 1)]
     threads: usize,
 
-    /// JSON file to read and update ELO ratings. Created if it does not exist.
+    /// Points for a draw, between a loss (0) and a win (1). Used for the score and ELO.
+    #[arg(long, default_value_t = 0.5)]
+    draw_score: f32,
+
+    /// Master seed for reproducible runs. Games are identical for any number of threads.
+    #[arg(long)]
+    seed: Option<u64>,
+
+    #[command(flatten)]
+    paths: PathArgs,
+
+    /// JSON file to read and update ELO ratings. Created if it does not exist. Relative
+    /// paths are resolved against the run directory.
     #[arg(long)]
     ratings: Option<PathBuf>,
 
-    /// JSON file to write evaluation results.
+    /// JSON file to write evaluation results. Relative paths are resolved against the run
+    /// directory.
     #[arg(short, long)]
     output: Option<PathBuf>,
 }
@@ -190,14 +121,32 @@ fn main() {
         .player2_name
         .unwrap_or_else(|| args.player2.default_name());
 
-    let p1 = build_player(&args.player1, args.simulations);
-    let p2 = build_player(&args.player2, args.simulations);
+    let paths = args.paths.load();
 
-    let mut runner = Runner::new(args.games, p1, p2, StatisticsRunnerEventSink::new())
+    let mut model_zoo = ModelZoo::new(paths.model_dir());
+
+    if let Some(path) = paths.model_registry() {
+        model_zoo = model_zoo
+            .with_registry_file(path)
+            .expect("failed to load model registry");
+    }
+
+    let p1 = build_player(&args.player1, args.simulations, &model_zoo);
+    let p2 = build_player(&args.player2, args.simulations, &model_zoo);
+
+    let sink =
+        StatisticsRunnerEventSink::new().with_scoring(Scoring::new().with_draw(args.draw_score));
+
+    let mut runner = Runner::new(args.games, p1, p2, sink)
         .with_max_turns(args.max_turns)
-        .with_threads(args.threads);
+        .with_threads(args.threads)
+        .with_shutdown_signal(ShutdownSignal::install());
 
-    runner.run();
+    if let Some(seed) = args.seed {
+        runner = runner.with_seed(seed);
+    }
+
+    runner.run_parallel();
 
     let stats = runner.sink();
     let total = stats.total_games;
@@ -206,7 +155,9 @@ fn main() {
     let draws = stats.draws;
 
     // ELO (only if --ratings provided)
-    let mut ratings: HashMap<String, f32> = if let Some(path) = &args.ratings {
+    let ratings_path = args.ratings.as_deref().map(|path| paths.run_file(path));
+
+    let mut ratings: HashMap<String, f32> = if let Some(path) = &ratings_path {
         if path.exists() {
             let json = fs::read_to_string(path).expect("failed to read ratings file");
             serde_json::from_str(&json).expect("failed to parse ratings file")
@@ -217,12 +168,12 @@ fn main() {
         HashMap::new()
     };
 
-    let elo_enabled = args.ratings.is_some();
+    let elo_enabled = ratings_path.is_some();
 
     let (elo1_before, elo1_after, elo2_before, elo2_after) = if elo_enabled {
         let r1 = *ratings.get(&name1).unwrap_or(&ELO_DEFAULT);
         let r2 = *ratings.get(&name2).unwrap_or(&ELO_DEFAULT);
-        let score1 = (p1_wins as f32 + 0.5 * draws as f32) / total as f32;
+        let score1 = stats.player_1_score();
         let (r1_new, r2_new) = elo_update(r1, r2, score1);
         (Some(r1), Some(r1_new), Some(r2), Some(r2_new))
     } else {
@@ -233,7 +184,7 @@ fn main() {
         ratings.insert(name1.clone(), elo1_after.unwrap());
         ratings.insert(name2.clone(), elo2_after.unwrap());
 
-        if let Some(path) = &args.ratings {
+        if let Some(path) = &ratings_path {
             let json = serde_json::to_string_pretty(&ratings).expect("failed to serialize ratings");
             fs::write(path, json).expect("failed to write ratings file");
         }
@@ -255,7 +206,10 @@ fn main() {
         );
     }
 
-    if let Some(output_path) = &args.output {
+    if let Some(output_path) = args.output.as_deref().map(|path| paths.run_file(path)) {
+        let (score_difference_low, score_difference_high) =
+            stats.score_difference_interval(Scoring::DEFAULT_Z);
+
         let output = EvalOutput {
             player1: PlayerResult {
                 name: name1,
@@ -274,6 +228,10 @@ fn main() {
             draws,
             draw_rate: draws as f32 / total as f32,
             total_games: total,
+            draw_score: args.draw_score,
+            score_difference: stats.score_difference(),
+            score_difference_low,
+            score_difference_high,
         };
 
         let json = serde_json::to_string_pretty(&output).expect("failed to serialize output");
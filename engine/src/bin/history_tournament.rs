@@ -0,0 +1,312 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+
+use hermes_engine::boop::{Boop, BoopActionEncoder, BoopStateEncoder};
+use hermes_engine::{
+    NeuralNetworkMctsPlayer, OnnxNeuralNetwork, PairingResult, Runner, ShutdownSignal,
+    StatisticsRunnerEventSink, TemperatureSchedule,
+};
+
+mod common;
+
+use common::PathArgs;
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Schedule {
+    /// Pair each iteration with the next `--window` iterations.
+    Neighbors,
+    /// Pair every iteration with every other iteration.
+    RoundRobin,
+}
+
+#[derive(Parser)]
+#[command(name = "history-tournament")]
+#[command(about = "Play a run's model checkpoints against each other and fit Elo ratings.")]
+struct Args {
+    /// Games played per pairing.
+    #[arg(short, long, default_value_t = 20)]
+    games: u32,
+
+    #[arg(long, value_enum, default_value_t = Schedule::Neighbors)]
+    schedule: Schedule,
+
+    /// Number of following iterations each iteration plays in the neighbors schedule.
+    #[arg(long, default_value_t = 2)]
+    window: usize,
+
+    /// Maximum number of models held in memory at once.
+    #[arg(long, default_value_t = 4)]
+    max_loaded_models: usize,
+
+    #[arg(short, long, default_value_t = 100)]
+    simulations: u32,
+
+    #[arg(short, long, default_value_t = 150)]
+    max_turns: u32,
+
+    #[arg(short, long, default_value_t = 1)]
+    threads: usize,
+
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    #[command(flatten)]
+    paths: PathArgs,
+
+    /// JSON file to write ratings and pairing results. Relative paths are resolved against
+    /// the run directory.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+type BoopNnPlayer = NeuralNetworkMctsPlayer<
+    Boop,
+    BoopStateEncoder,
+    BoopActionEncoder,
+    OnnxNeuralNetwork<Boop, BoopStateEncoder>,
+>;
+
+// -- Checkpoints --
+
+// NOTE - Checkpoints are ordered by the last number in their file name (e.g. `iter_12`),
+// then by name.
+
+fn find_checkpoints(model_dir: &Path) -> Vec<PathBuf> {
+    let mut checkpoints: Vec<PathBuf> = fs::read_dir(model_dir)
+        .expect("failed to read model directory")
+        .map(|entry| entry.expect("failed to read model directory").path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "onnx")
+        })
+        .collect();
+
+    checkpoints.sort_by_cached_key(|path| (iteration_number(path), path.clone()));
+
+    checkpoints
+}
+
+fn iteration_number(path: &Path) -> Option<u64> {
+    let stem = path.file_stem()?.to_str()?;
+
+    let digits: String = stem
+        .chars()
+        .rev()
+        .skip_while(|character| !character.is_ascii_digit())
+        .take_while(char::is_ascii_digit)
+        .collect();
+
+    digits.chars().rev().collect::<String>().parse().ok()
+}
+
+fn checkpoint_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+// NOTE - Keeps at most `capacity` models loaded, evicting the least recently used. Clones
+// share the loaded model, so a pairing only adds the search trees.
+
+struct ModelCache {
+    capacity: usize,
+    simulations: u32,
+
+    players: Vec<(usize, BoopNnPlayer)>,
+}
+
+impl ModelCache {
+    fn new(capacity: usize, simulations: u32) -> Self {
+        Self {
+            capacity: capacity.max(2),
+            simulations,
+
+            players: vec![],
+        }
+    }
+
+    fn get(&mut self, index: usize, path: &Path) -> BoopNnPlayer {
+        if let Some(position) = self.players.iter().position(|(i, _)| *i == index) {
+            let entry = self.players.remove(position);
+
+            self.players.push(entry);
+        } else {
+            if self.players.len() == self.capacity {
+                self.players.remove(0);
+            }
+
+            let state_encoder = BoopStateEncoder::new();
+            let action_encoder = BoopActionEncoder::new();
+
+            let neural_network = OnnxNeuralNetwork::new(path, state_encoder, action_encoder)
+                .expect("failed to load onnx model");
+
+            let player = NeuralNetworkMctsPlayer::new(
+                self.simulations,
+                state_encoder,
+                action_encoder,
+                neural_network,
+            )
+            .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+            self.players.push((index, player));
+        }
+
+        self.players
+            .last()
+            .map(|(_, player)| player.clone())
+            .expect("player was just loaded")
+    }
+}
+
+fn pairings(schedule: Schedule, count: usize, window: usize) -> Vec<(usize, usize)> {
+    (0..count)
+        .flat_map(|i| {
+            let last = match schedule {
+                Schedule::Neighbors => (i + window).min(count - 1),
+                Schedule::RoundRobin => count - 1,
+            };
+
+            (i + 1..=last).map(move |j| (i, j))
+        })
+        .collect()
+}
+
+// NOTE - Bars span from the lowest rating to the highest, with a minimum of one block.
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn bar_width(elo: f32, min_elo: f32, max_elo: f32) -> usize {
+    const MAX_WIDTH: f32 = 40.0;
+
+    let share = (elo - min_elo) / (max_elo - min_elo).max(1.0);
+
+    1 + (share * MAX_WIDTH).round() as usize
+}
+
+// -- Output types --
+
+#[derive(Serialize)]
+struct CheckpointRating {
+    name: String,
+    path: PathBuf,
+    elo: f32,
+}
+
+#[derive(Serialize)]
+struct TournamentOutput {
+    ratings: Vec<CheckpointRating>,
+    pairings: Vec<PairingResult>,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let paths = args.paths.load();
+
+    let checkpoints = find_checkpoints(&paths.model_dir());
+
+    assert!(
+        checkpoints.len() >= 2,
+        "need at least two checkpoints in {}",
+        paths.model_dir().display()
+    );
+
+    let schedule = pairings(args.schedule, checkpoints.len(), args.window.max(1));
+
+    println!(
+        "{} checkpoints, {} pairings of {} games",
+        checkpoints.len(),
+        schedule.len(),
+        args.games
+    );
+
+    let mut model_cache = ModelCache::new(args.max_loaded_models, args.simulations);
+
+    let shutdown_signal = ShutdownSignal::install();
+
+    // NOTE - An interrupted tournament fits ratings to the pairings played so far, including
+    // the games finished in the pairing that was in flight.
+
+    let results: Vec<PairingResult> = schedule
+        .into_iter()
+        .take_while(|_| !shutdown_signal.is_requested())
+        .map(|(i, j)| {
+            let player_1 = model_cache.get(i, &checkpoints[i]);
+            let player_2 = model_cache.get(j, &checkpoints[j]);
+
+            let mut runner = Runner::new(
+                args.games,
+                player_1,
+                player_2,
+                StatisticsRunnerEventSink::new(),
+            )
+            .with_max_turns(args.max_turns)
+            .with_threads(args.threads)
+            .with_seed(args.seed)
+            .with_shutdown_signal(shutdown_signal.clone());
+
+            runner.run_parallel();
+
+            let statistics = runner.sink();
+
+            println!(
+                "{} vs {}: {}W {}L {}D",
+                checkpoint_name(&checkpoints[i]),
+                checkpoint_name(&checkpoints[j]),
+                statistics.player_1_wins,
+                statistics.player_2_wins,
+                statistics.draws
+            );
+
+            PairingResult {
+                player_1: i,
+                player_2: j,
+
+                wins: statistics.player_1_wins,
+                losses: statistics.player_2_wins,
+                draws: statistics.draws,
+            }
+        })
+        .collect();
+
+    let ratings = PairingResult::fit_elo(checkpoints.len(), &results);
+
+    let min_elo = ratings.iter().copied().fold(f32::INFINITY, f32::min);
+    let max_elo = ratings.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+
+    let name_width = checkpoints
+        .iter()
+        .map(|path| checkpoint_name(path).len())
+        .max()
+        .unwrap_or_default();
+
+    println!("\nElo progression:");
+
+    for (path, elo) in checkpoints.iter().zip(&ratings) {
+        let bar = "█".repeat(bar_width(*elo, min_elo, max_elo));
+
+        println!("\t{:<name_width$} {elo:>+7.0} {bar}", checkpoint_name(path));
+    }
+
+    if let Some(output_path) = args.output.as_deref().map(|path| paths.run_file(path)) {
+        let output = TournamentOutput {
+            ratings: checkpoints
+                .iter()
+                .zip(&ratings)
+                .map(|(path, &elo)| CheckpointRating {
+                    name: checkpoint_name(path),
+                    path: path.clone(),
+                    elo,
+                })
+                .collect(),
+            pairings: results,
+        };
+
+        let json = serde_json::to_string_pretty(&output).expect("failed to serialize output");
+
+        fs::write(output_path, json).expect("failed to write output file");
+    }
+}
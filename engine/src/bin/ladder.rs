@@ -0,0 +1,151 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Parser;
+use serde::Serialize;
+
+use hermes_engine::{LadderRung, ModelZoo, ShutdownSignal};
+
+mod common;
+
+use common::{PathArgs, PlayerSpec, build_player};
+
+#[derive(Parser)]
+#[command(name = "ladder")]
+#[command(about = "Measure a candidate player against a fixed ladder of anchor opponents.")]
+struct Args {
+    /// Candidate player: `random`, `greedy`, `minimax:<depth>`, `mcts:<simulations>`, or a
+    /// model file path, URL, or registry name.
+    #[arg(long)]
+    candidate: PlayerSpec,
+
+    /// Anchor opponents, weakest first.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "random,greedy,minimax:2,minimax:4,mcts:200,mcts:1000"
+    )]
+    anchors: Vec<PlayerSpec>,
+
+    /// Games played against each anchor.
+    #[arg(short, long, default_value_t = 100)]
+    games: u32,
+
+    /// Number of MCTS simulations per move (only applies to neural network players).
+    #[arg(short, long, default_value_t = 100)]
+    simulations: u32,
+
+    #[arg(short, long, default_value_t = 150)]
+    max_turns: u32,
+
+    /// Master seed, so ladder results are reproducible across code changes.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    #[command(flatten)]
+    paths: PathArgs,
+
+    /// JSON file to write the strength profile. Relative paths are resolved against the run
+    /// directory.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct RungResult {
+    #[serde(flatten)]
+    rung: LadderRung,
+    score: f32,
+    score_low: f32,
+    score_high: f32,
+    elo_difference: f32,
+}
+
+#[derive(Serialize)]
+struct LadderOutput {
+    candidate: String,
+    rungs: Vec<RungResult>,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let paths = args.paths.load();
+
+    let mut model_zoo = ModelZoo::new(paths.model_dir());
+
+    if let Some(path) = paths.model_registry() {
+        model_zoo = model_zoo
+            .with_registry_file(path)
+            .expect("failed to load model registry");
+    }
+
+    let candidate = build_player(&args.candidate, args.simulations, &model_zoo);
+
+    let shutdown_signal = ShutdownSignal::install();
+
+    let mut rungs = vec![];
+
+    // NOTE - An interrupted ladder reports the rungs measured so far.
+
+    for anchor_spec in &args.anchors {
+        if shutdown_signal.is_requested() {
+            break;
+        }
+
+        let anchor = build_player(anchor_spec, args.simulations, &model_zoo);
+
+        println!("\n--- {} ---", anchor_spec.default_name());
+
+        let rung = LadderRung::measure(
+            anchor_spec.default_name(),
+            candidate.clone(),
+            anchor,
+            args.games,
+            args.max_turns,
+            Some(args.seed),
+        );
+
+        let score = rung.score();
+        let (score_low, score_high) = rung.confidence_interval(LadderRung::DEFAULT_Z);
+
+        rungs.push(RungResult {
+            rung,
+            score,
+            score_low,
+            score_high,
+            elo_difference: LadderRung::elo_difference(score),
+        });
+    }
+
+    println!("\nLadder ({}):", args.candidate.default_name());
+
+    for RungResult {
+        rung,
+        score,
+        score_low,
+        score_high,
+        elo_difference,
+    } in &rungs
+    {
+        println!(
+            "\t{}: {:.1}% [{:.1}%, {:.1}%] ({:+.0} ELO)",
+            rung.anchor,
+            score * 100.0,
+            score_low * 100.0,
+            score_high * 100.0,
+            elo_difference
+        );
+    }
+
+    if let Some(output_path) = args.output.as_deref().map(|path| paths.run_file(path)) {
+        let output = LadderOutput {
+            candidate: args.candidate.default_name(),
+            rungs,
+        };
+
+        let json = serde_json::to_string_pretty(&output).expect("failed to serialize output");
+
+        fs::write(output_path, json).expect("failed to write output file");
+    }
+}
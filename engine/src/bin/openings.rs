@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use hermes_engine::boop::Boop;
+use hermes_engine::{GameRecord, OpeningTree};
+
+mod common;
+
+use common::PathArgs;
+
+#[derive(Parser)]
+#[command(name = "openings")]
+#[command(about = "Aggregate recorded games into an opening tree of move statistics.")]
+struct Args {
+    /// Game record files (JSON lines).
+    #[arg(required = true)]
+    records: Vec<PathBuf>,
+
+    /// Number of plies from the start of the game to aggregate.
+    #[arg(long, default_value_t = 8)]
+    max_depth: usize,
+
+    /// Moves played fewer times than this are left out of the export.
+    #[arg(long, default_value_t = 2)]
+    min_count: u32,
+
+    #[command(flatten)]
+    paths: PathArgs,
+
+    /// JSON file to write the opening tree. Relative paths are resolved against the run
+    /// directory. Defaults to stdout.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let paths = args.paths.load();
+
+    let mut tree = OpeningTree::<Boop>::new(args.max_depth);
+
+    for path in &args.records {
+        let records = fs::read_to_string(path).expect("failed to read game records");
+
+        for (i, line) in records.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            GameRecord::from_portable_json(line)
+                .and_then(|record| tree.add_record(&record))
+                .unwrap_or_else(|error| {
+                    panic!("{}:{}: invalid game record: {error}", path.display(), i + 1)
+                });
+        }
+    }
+
+    let json = tree
+        .export(args.min_count)
+        .expect("no games were recorded")
+        .to_json();
+
+    if let Some(output_path) = args.output.as_deref().map(|path| paths.run_file(path)) {
+        fs::write(output_path, json).expect("failed to write output file");
+    } else {
+        println!("{json}");
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::Instant;
+
+use clap::{Parser, ValueEnum};
+
+use hermes_engine::backgammon::Backgammon;
+use hermes_engine::boop::Boop;
+use hermes_engine::breakthrough::Breakthrough;
+use hermes_engine::checkers::Checkers;
+use hermes_engine::chess::Chess;
+use hermes_engine::go::Go;
+use hermes_engine::gomoku::Gomoku;
+use hermes_engine::hex::Hex;
+use hermes_engine::hive::Hive;
+use hermes_engine::mnk::MnkGame;
+use hermes_engine::nim::Nim;
+use hermes_engine::tak::Tak;
+use hermes_engine::tic_tac_toe::TicTacToe;
+use hermes_engine::{Game, perft};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum GameKind {
+    Backgammon,
+    Boop,
+    Breakthrough,
+    Checkers,
+    Chess,
+    Go,
+    Gomoku,
+    Hex,
+    Hive,
+    Mnk,
+    Nim,
+    Tak,
+    TicTacToe,
+}
+
+#[derive(Parser)]
+#[command(name = "perft")]
+#[command(about = "Count the positions reached at each depth to check move generation.")]
+struct Args {
+    #[arg(value_enum)]
+    game: GameKind,
+
+    /// Deepest ply to count.
+    #[arg(short, long, default_value_t = 4)]
+    depth: u32,
+
+    /// Starting position in the game's notation. Defaults to the initial position.
+    #[arg(long)]
+    notation: Option<String>,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    match args.game {
+        GameKind::Backgammon => run::<Backgammon>(&args),
+        GameKind::Boop => run::<Boop>(&args),
+        GameKind::Breakthrough => run::<Breakthrough>(&args),
+        GameKind::Checkers => run::<Checkers>(&args),
+        GameKind::Chess => run::<Chess>(&args),
+        GameKind::Go => run::<Go>(&args),
+        GameKind::Gomoku => run::<Gomoku>(&args),
+        GameKind::Hex => run::<Hex>(&args),
+        GameKind::Hive => run::<Hive>(&args),
+        GameKind::Mnk => run::<MnkGame>(&args),
+        GameKind::Nim => run::<Nim>(&args),
+        GameKind::Tak => run::<Tak>(&args),
+        GameKind::TicTacToe => run::<TicTacToe>(&args),
+    }
+}
+
+fn run<G: Game>(args: &Args) {
+    let game = match &args.notation {
+        Some(notation) => G::from_notation(notation).expect("invalid notation"),
+        None => G::new(),
+    };
+
+    println!("{game}");
+
+    for depth in 1..=args.depth {
+        let start = Instant::now();
+
+        let leaves = perft(&game, depth);
+
+        println!(
+            "Depth {depth}: {leaves} positions in {:.3}s",
+            start.elapsed().as_secs_f64()
+        );
+    }
+}
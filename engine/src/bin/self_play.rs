@@ -1,14 +1,28 @@
-use std::fs::File;
-use std::path::PathBuf;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 
 use hermes_engine::boop::{Boop, BoopActionEncoder, BoopStateEncoder};
 use hermes_engine::{
-    DirichletNoise, JsonSampleSink, NeuralNetworkMctsPlayer, OnnxNeuralNetwork, Runner,
-    SampleRunnerEventSink, StatisticsRunnerEventSink, TemperatureSchedule,
+    ActionEncoder, BinarySampleSink, CachedNeuralNetwork, DirichletNoise, EventSink,
+    GameRecordRunnerEventSink, HermesPaths, InterleavedRunner, JsonGameRecordSink, JsonSampleSink,
+    ModelZoo, NeuralNetworkMctsPlayer, OnnxNeuralNetwork, Runner, RunnerEvent, Sample,
+    SampleRunnerEventSink, ShutdownSignal, StateEncoder, StatisticsRunnerEventSink, TeeEventSink,
+    TemperatureSchedule,
 };
 
+mod common;
+
+use common::PathArgs;
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SampleFormat {
+    Json,
+    Binary,
+}
+
 #[derive(Parser)]
 #[command(name = "self-play")]
 #[command(about = "Run self-play games and generate training data.")]
@@ -16,8 +30,16 @@ struct Args {
     #[arg(short, long, default_value_t = 1)]
     games: u32,
 
+    /// Model file path, URL, or registry name (e.g. `boop/iter_42`).
     #[arg(short, long)]
-    model: PathBuf,
+    model: String,
+
+    #[command(flatten)]
+    paths: PathArgs,
+
+    /// Directory where samples are written. Defaults to `<run-dir>/data`.
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
 
     #[arg(short, long, default_value_t = 100)]
     simulations: u32,
@@ -28,73 +50,249 @@ struct Args {
     #[arg(short, long, default_value_t = false)]
     use_symmetries: bool,
 
+    /// Worker threads that encode samples off the game threads. 0 encodes inline.
+    #[arg(long, default_value_t = 0)]
+    encoding_threads: usize,
+
     #[arg(short, long, default_value_t = 1)]
     threads: usize,
 
+    /// Games played at once on a single thread, with their leaf evaluations batched into
+    /// one inference call. Ignores `--threads`.
+    #[arg(long)]
+    interleaved_games: Option<usize>,
+
+    /// Master seed for reproducible runs. Games are identical for any number of threads.
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Sample file to write. Relative paths are resolved against the data directory.
     #[arg(short, long, default_value = None)]
     output: Option<PathBuf>,
+
+    /// Sample file format. Binary writes contiguous batches, which is much faster to
+    /// write and load at scale.
+    #[arg(long, value_enum, default_value_t = SampleFormat::Json)]
+    format: SampleFormat,
+
+    /// Game record file (JSON lines) to write, for opening and blunder analysis. Relative
+    /// paths are resolved against the data directory.
+    #[arg(long)]
+    records: Option<PathBuf>,
+
+    /// Prediction cache file shared by both players. Loaded if it was saved for the same
+    /// model and saved after the batch, so the next batch on this model skips positions
+    /// already evaluated. Relative paths are resolved against the run directory.
+    #[arg(long)]
+    cache: Option<PathBuf>,
 }
 
-fn create_player(
-    model: &PathBuf,
-    simulations: u32,
-) -> NeuralNetworkMctsPlayer<
-    Boop,
-    BoopStateEncoder,
-    BoopActionEncoder,
-    OnnxNeuralNetwork<Boop, BoopStateEncoder>,
-> {
+type BoopNetwork = CachedNeuralNetwork<OnnxNeuralNetwork<Boop, BoopStateEncoder>>;
+
+type BoopNnPlayer = NeuralNetworkMctsPlayer<Boop, BoopStateEncoder, BoopActionEncoder, BoopNetwork>;
+
+type BoopRecordSink = GameRecordRunnerEventSink<Boop, JsonGameRecordSink<File>>;
+
+// NOTE - Without a cache file, nothing is kept, since the cache would only be dropped at
+// the end of the batch.
+
+fn create_network(model: &Path, cache_path: Option<&Path>) -> BoopNetwork {
+    let model_hash = ModelZoo::checksum(model).expect("failed to hash model");
+
+    let onnx_network =
+        OnnxNeuralNetwork::new(model, BoopStateEncoder::new(), BoopActionEncoder::new())
+            .expect("failed to load onnx model");
+
+    let mut neural_network = CachedNeuralNetwork::new(onnx_network).with_model_hash(model_hash);
+
+    let Some(path) = cache_path else {
+        return neural_network.with_max_entries(0);
+    };
+
+    if path.exists() {
+        let file = File::open(path).expect("failed to open prediction cache");
+
+        if let Err(error) = neural_network.read_from(&mut BufReader::new(file)) {
+            eprintln!("Ignoring prediction cache: {error}");
+
+            neural_network.clear();
+        }
+    }
+
+    neural_network
+}
+
+fn save_cache(neural_network: &BoopNetwork, path: &Path) {
+    println!(
+        "Prediction cache: {} hits, {} misses, {} entries",
+        neural_network.hits(),
+        neural_network.misses(),
+        neural_network.len()
+    );
+
+    let mut writer = BufWriter::new(File::create(path).expect("failed to create prediction cache"));
+
+    neural_network
+        .write_to(&mut writer)
+        .and_then(|()| writer.flush())
+        .expect("failed to write prediction cache");
+}
+
+fn create_player(neural_network: &BoopNetwork, simulations: u32) -> BoopNnPlayer {
     let state_encoder = BoopStateEncoder::new();
     let action_encoder = BoopActionEncoder::new();
 
-    let neural_network =
-        OnnxNeuralNetwork::new(model, state_encoder).expect("failed to load onnx model");
-
-    NeuralNetworkMctsPlayer::new(simulations, state_encoder, action_encoder, neural_network)
-        .with_dirichlet_noise(DirichletNoise {
-            alpha: 0.3,
-            epsilon: 0.25,
-        })
-        .with_temperature_schedule(TemperatureSchedule::Step {
-            threshold: 30,
-            hi: 1.0,
-            lo: 0.0,
-        })
+    NeuralNetworkMctsPlayer::new(
+        simulations,
+        state_encoder,
+        action_encoder,
+        neural_network.clone(),
+    )
+    .with_dirichlet_noise(DirichletNoise {
+        alpha: 0.3,
+        epsilon: 0.25,
+    })
+    .with_temperature_schedule(TemperatureSchedule::Step {
+        threshold: 30,
+        hi: 1.0,
+        lo: 0.0,
+    })
 }
 
 fn main() {
     let args = Args::parse();
 
+    let paths = args.paths.load().merge(HermesPaths {
+        data_dir: args.data_dir.clone(),
+        ..HermesPaths::new()
+    });
+
+    let mut model_zoo = ModelZoo::new(paths.model_dir());
+
+    if let Some(path) = paths.model_registry() {
+        model_zoo = model_zoo
+            .with_registry_file(path)
+            .expect("failed to load model registry");
+    }
+
+    let model = model_zoo
+        .resolve(&args.model)
+        .expect("failed to resolve model");
+
     let state_encoder = BoopStateEncoder::new();
     let action_encoder = BoopActionEncoder::new();
 
-    let player_1 = create_player(&args.model, args.simulations);
-    let player_2 = create_player(&args.model, args.simulations);
+    let cache_path = args.cache.as_deref().map(|path| paths.run_file(path));
+
+    let neural_network = create_network(&model, cache_path.as_deref());
+
+    let player_1 = create_player(&neural_network, args.simulations);
+    let player_2 = create_player(&neural_network, args.simulations);
+
+    let records = args.records.as_ref().map(|path| {
+        let file = create_data_file(&paths.data_file(path));
+
+        GameRecordRunnerEventSink::new(JsonGameRecordSink::new(file))
+    });
 
     if let Some(path) = &args.output {
-        let file = File::create(path).expect("failed to create output file");
+        let file = create_data_file(&paths.data_file(path));
 
-        let json_sink = JsonSampleSink::new(file);
+        match args.format {
+            SampleFormat::Json => {
+                let json_sink = JsonSampleSink::new(file);
 
-        let sample_sink = SampleRunnerEventSink::new(
-            state_encoder,
-            action_encoder,
-            args.use_symmetries,
-            json_sink,
-        );
+                run_with_samples(&args, player_1, player_2, json_sink, records);
+            }
+            SampleFormat::Binary => {
+                let state_size = state_encoder.shape().iter().product();
 
-        let mut runner = Runner::new(args.games, player_1, player_2, sample_sink)
-            .with_max_turns(args.max_turns)
-            .with_threads(args.threads);
+                let binary_sink = BinarySampleSink::new(file, state_size, action_encoder.size());
 
-        runner.run();
+                run_with_samples(&args, player_1, player_2, binary_sink, records);
+            }
+        }
     } else {
         let statistics_sink = StatisticsRunnerEventSink::new();
 
-        let mut runner = Runner::new(args.games, player_1, player_2, statistics_sink)
+        run_with_records(&args, player_1, player_2, statistics_sink, records);
+    }
+
+    if let Some(path) = &cache_path {
+        save_cache(&neural_network, path);
+    }
+}
+
+fn create_data_file(path: &Path) -> File {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("failed to create data directory");
+    }
+
+    File::create(path).expect("failed to create output file")
+}
+
+fn run_with_samples<S: EventSink<Sample>>(
+    args: &Args,
+    player_1: BoopNnPlayer,
+    player_2: BoopNnPlayer,
+    sink: S,
+    records: Option<BoopRecordSink>,
+) {
+    let sample_sink = SampleRunnerEventSink::new(
+        BoopStateEncoder::new(),
+        BoopActionEncoder::new(),
+        args.use_symmetries,
+        sink,
+    )
+    .with_encoding_threads(args.encoding_threads);
+
+    run_with_records(args, player_1, player_2, sample_sink, records);
+}
+
+fn run_with_records<S: EventSink<RunnerEvent<Boop>>>(
+    args: &Args,
+    player_1: BoopNnPlayer,
+    player_2: BoopNnPlayer,
+    sink: S,
+    records: Option<BoopRecordSink>,
+) {
+    match records {
+        Some(records) => run(args, player_1, player_2, TeeEventSink::new(sink, records)),
+        None => run(args, player_1, player_2, sink),
+    }
+}
+
+fn run<S: EventSink<RunnerEvent<Boop>>>(
+    args: &Args,
+    player_1: BoopNnPlayer,
+    player_2: BoopNnPlayer,
+    sink: S,
+) {
+    if let Some(concurrent_games) = args.interleaved_games {
+        let mut runner = InterleavedRunner::new(args.games, player_1, player_2, sink)
+            .with_concurrent_games(concurrent_games)
             .with_max_turns(args.max_turns)
-            .with_threads(args.threads);
+            .with_shutdown_signal(ShutdownSignal::install());
+
+        if let Some(seed) = args.seed {
+            runner = runner.with_seed(seed);
+        }
 
         runner.run();
+
+        println!("Mean inference batch size: {:.2}", runner.mean_batch_size());
+
+        return;
     }
+
+    let mut runner = Runner::new(args.games, player_1, player_2, sink)
+        .with_max_turns(args.max_turns)
+        .with_threads(args.threads)
+        .with_shutdown_signal(ShutdownSignal::install());
+
+    if let Some(seed) = args.seed {
+        runner = runner.with_seed(seed);
+    }
+
+    runner.run_parallel();
 }
@@ -0,0 +1,72 @@
+use clap::{ArgAction, Parser};
+
+use hermes_engine::{ModelZoo, Runner, StdoutRunnerEventSink};
+
+mod common;
+
+use common::{PathArgs, PlayerSpec, build_player};
+
+#[derive(Parser)]
+#[command(name = "watch")]
+#[command(about = "Play a game between two players and print it move by move.")]
+struct Args {
+    /// Player 1: `random`, `greedy`, `minimax:<depth>`, `mcts:<simulations>`, or a model file
+    /// path, URL, or registry name.
+    #[arg(long, default_value = "mcts:800")]
+    player1: PlayerSpec,
+
+    /// Player 2, in the same format as player 1.
+    #[arg(long, default_value = "mcts:800")]
+    player2: PlayerSpec,
+
+    /// Number of MCTS simulations per move (only applies to neural network players).
+    #[arg(short, long, default_value_t = 100)]
+    simulations: u32,
+
+    #[arg(short, long, default_value_t = 150)]
+    max_turns: u32,
+
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Print the search policy over the board before each move. Repeat for percentages
+    /// instead of shades.
+    #[arg(short, long, action = ArgAction::Count)]
+    verbose: u8,
+
+    #[command(flatten)]
+    paths: PathArgs,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let paths = args.paths.load();
+
+    let mut model_zoo = ModelZoo::new(paths.model_dir());
+
+    if let Some(path) = paths.model_registry() {
+        model_zoo = model_zoo
+            .with_registry_file(path)
+            .expect("failed to load model registry");
+    }
+
+    let player_1 = build_player(&args.player1, args.simulations, &model_zoo);
+    let player_2 = build_player(&args.player2, args.simulations, &model_zoo);
+
+    println!(
+        "{} vs {}\n",
+        args.player1.default_name(),
+        args.player2.default_name()
+    );
+
+    let sink = StdoutRunnerEventSink::new().with_verbosity(args.verbose);
+
+    let mut runner = Runner::new(1, player_1, player_2, sink).with_max_turns(args.max_turns);
+
+    if let Some(seed) = args.seed {
+        runner = runner.with_seed(seed);
+    }
+
+    runner.run();
+}
@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use crate::analysis::{BlunderReport, CalibrationReport};
+use crate::core::Game;
+use crate::neural_network::{ActionEncoder, NeuralNetwork, StateEncoder};
+use crate::player::{NeuralNetworkMctsPlayer, TemperatureSchedule};
+use crate::record::GameRecord;
+
+pub struct AnalysisBuilder<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork> {
+    state_encoder: SE,
+    action_encoder: AE,
+    neural_network: NN,
+
+    simulations: u32,
+    blunder_threshold: f32,
+    bins: usize,
+
+    _phantom: PhantomData<G>,
+}
+
+impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork + Clone>
+    AnalysisBuilder<G, SE, AE, NN>
+{
+    pub fn new(state_encoder: SE, action_encoder: AE, neural_network: NN) -> Self {
+        Self {
+            state_encoder,
+            action_encoder,
+            neural_network,
+
+            simulations: 800,
+            blunder_threshold: BlunderReport::DEFAULT_THRESHOLD,
+            bins: CalibrationReport::DEFAULT_BINS,
+
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn with_simulations(mut self, simulations: u32) -> Self {
+        self.simulations = simulations;
+
+        self
+    }
+
+    pub fn with_blunder_threshold(mut self, blunder_threshold: f32) -> Self {
+        self.blunder_threshold = blunder_threshold;
+
+        self
+    }
+
+    pub fn with_bins(mut self, bins: usize) -> Self {
+        self.bins = bins;
+
+        self
+    }
+
+    // NOTE - The reference player always plays its best move, without noise.
+
+    pub fn reference_player(&self) -> NeuralNetworkMctsPlayer<G, SE, AE, NN> {
+        NeuralNetworkMctsPlayer::new(
+            self.simulations,
+            self.state_encoder,
+            self.action_encoder,
+            self.neural_network.clone(),
+        )
+        .with_temperature_schedule(TemperatureSchedule::Constant(0.0))
+    }
+
+    pub fn blunders(&self, records: &[GameRecord<G>]) -> Result<BlunderReport, String> {
+        BlunderReport::analyze(
+            records,
+            &mut self.reference_player(),
+            self.blunder_threshold,
+        )
+    }
+
+    pub fn calibration(&self, records: &[GameRecord<G>]) -> Result<CalibrationReport, String> {
+        let mut neural_network = self.neural_network.clone();
+
+        CalibrationReport::analyze(records, self.state_encoder, &mut neural_network, self.bins)
+    }
+}
@@ -0,0 +1,144 @@
+use std::marker::PhantomData;
+
+use crate::core::{Game, Player, Runner, Scoring, ShutdownSignal, StatisticsRunnerEventSink};
+
+pub struct MatchBuilder<G: Game, P1: Player<G>, P2: Player<G>> {
+    player_1: P1,
+    player_2: P2,
+
+    games: u32,
+    max_turns: Option<u32>,
+    threads: usize,
+    seed: Option<u64>,
+    opening_plies: u32,
+
+    scoring: Scoring,
+    shutdown_signal: Option<ShutdownSignal>,
+
+    _phantom: PhantomData<G>,
+}
+
+impl<G: Game, P1: Player<G>, P2: Player<G>> MatchBuilder<G, P1, P2> {
+    pub fn new(player_1: P1, player_2: P2) -> Self {
+        Self {
+            player_1,
+            player_2,
+
+            games: 100,
+            max_turns: None,
+            threads: 1,
+            seed: None,
+            opening_plies: 0,
+
+            scoring: Scoring::default(),
+            shutdown_signal: None,
+
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn with_games(mut self, games: u32) -> Self {
+        self.games = games;
+
+        self
+    }
+
+    pub fn with_max_turns(mut self, max_turns: u32) -> Self {
+        self.max_turns = Some(max_turns);
+
+        self
+    }
+
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+
+        self
+    }
+
+    pub fn with_opening_plies(mut self, opening_plies: u32) -> Self {
+        self.opening_plies = opening_plies;
+
+        self
+    }
+
+    pub fn with_scoring(mut self, scoring: Scoring) -> Self {
+        self.scoring = scoring;
+
+        self
+    }
+
+    pub fn with_shutdown_signal(mut self, shutdown_signal: ShutdownSignal) -> Self {
+        self.shutdown_signal = Some(shutdown_signal);
+
+        self
+    }
+
+    pub fn build(self) -> Runner<G, P1, P2, StatisticsRunnerEventSink> {
+        let sink = StatisticsRunnerEventSink::new().with_scoring(self.scoring);
+
+        let mut runner = Runner::new(self.games, self.player_1, self.player_2, sink)
+            .with_threads(self.threads)
+            .with_opening_plies(self.opening_plies);
+
+        if let Some(max_turns) = self.max_turns {
+            runner = runner.with_max_turns(max_turns);
+        }
+
+        if let Some(seed) = self.seed {
+            runner = runner.with_seed(seed);
+        }
+
+        if let Some(shutdown_signal) = self.shutdown_signal {
+            runner = runner.with_shutdown_signal(shutdown_signal);
+        }
+
+        runner
+    }
+
+    pub fn run(self) -> StatisticsRunnerEventSink
+    where
+        G: Send,
+        G::Action: Send,
+        P1: Clone + Send,
+        P2: Clone + Send,
+    {
+        let mut runner = self.build();
+
+        runner.run_parallel();
+
+        *runner.sink()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::TicTacToe;
+    use crate::player::RandomPlayer;
+
+    mod run {
+        use super::*;
+
+        #[test]
+        fn should_play_the_configured_number_of_games() {
+            let statistics =
+                MatchBuilder::<TicTacToe, _, _>::new(RandomPlayer::new(), RandomPlayer::new())
+                    .with_games(6)
+                    .with_seed(5)
+                    .run();
+
+            assert_eq!(statistics.total_games, 6);
+            assert_eq!(
+                statistics.player_1_wins + statistics.player_2_wins + statistics.draws,
+                6
+            );
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod analysis_builder;
+mod match_builder;
+mod self_play_builder;
+
+pub use analysis_builder::AnalysisBuilder;
+pub use match_builder::MatchBuilder;
+pub use self_play_builder::SelfPlayBuilder;
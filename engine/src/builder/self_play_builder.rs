@@ -0,0 +1,226 @@
+use std::marker::PhantomData;
+
+use crate::core::{EventSink, Game, Runner, ShutdownSignal};
+use crate::neural_network::{ActionEncoder, NeuralNetwork, StateEncoder};
+use crate::player::{DirichletNoise, NeuralNetworkMctsPlayer, TemperatureSchedule};
+use crate::self_play::{Sample, SampleRunnerEventSink};
+
+pub struct SelfPlayBuilder<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork> {
+    state_encoder: SE,
+    action_encoder: AE,
+    neural_network: NN,
+
+    games: u32,
+    simulations: u32,
+    max_turns: Option<u32>,
+    threads: usize,
+    encoding_threads: usize,
+    seed: Option<u64>,
+
+    dirichlet_noise: DirichletNoise,
+    temperature_schedule: TemperatureSchedule,
+    use_symmetries: bool,
+
+    shutdown_signal: Option<ShutdownSignal>,
+
+    _phantom: PhantomData<G>,
+}
+
+type SelfPlayPlayer<G, SE, AE, NN> = NeuralNetworkMctsPlayer<G, SE, AE, NN>;
+
+type SelfPlayRunner<G, SE, AE, NN, S> = Runner<
+    G,
+    SelfPlayPlayer<G, SE, AE, NN>,
+    SelfPlayPlayer<G, SE, AE, NN>,
+    SampleRunnerEventSink<G, SE, AE, S>,
+>;
+
+impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork + Clone>
+    SelfPlayBuilder<G, SE, AE, NN>
+{
+    // NOTE - Defaults match the self-play binary: root noise, exploratory moves for the
+    // first 30 turns, and symmetry augmentation.
+
+    pub fn new(state_encoder: SE, action_encoder: AE, neural_network: NN) -> Self {
+        Self {
+            state_encoder,
+            action_encoder,
+            neural_network,
+
+            games: 1,
+            simulations: 100,
+            max_turns: None,
+            threads: 1,
+            encoding_threads: 0,
+            seed: None,
+
+            dirichlet_noise: DirichletNoise {
+                alpha: 0.3,
+                epsilon: 0.25,
+            },
+            temperature_schedule: TemperatureSchedule::Step {
+                threshold: 30,
+                hi: 1.0,
+                lo: 0.0,
+            },
+            use_symmetries: true,
+
+            shutdown_signal: None,
+
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn with_games(mut self, games: u32) -> Self {
+        self.games = games;
+
+        self
+    }
+
+    pub fn with_simulations(mut self, simulations: u32) -> Self {
+        self.simulations = simulations;
+
+        self
+    }
+
+    pub fn with_max_turns(mut self, max_turns: u32) -> Self {
+        self.max_turns = Some(max_turns);
+
+        self
+    }
+
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+
+        self
+    }
+
+    pub fn with_encoding_threads(mut self, encoding_threads: usize) -> Self {
+        self.encoding_threads = encoding_threads;
+
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+
+        self
+    }
+
+    pub fn with_dirichlet_noise(mut self, dirichlet_noise: DirichletNoise) -> Self {
+        self.dirichlet_noise = dirichlet_noise;
+
+        self
+    }
+
+    pub fn with_temperature_schedule(mut self, temperature_schedule: TemperatureSchedule) -> Self {
+        self.temperature_schedule = temperature_schedule;
+
+        self
+    }
+
+    pub fn with_symmetries(mut self, use_symmetries: bool) -> Self {
+        self.use_symmetries = use_symmetries;
+
+        self
+    }
+
+    pub fn with_shutdown_signal(mut self, shutdown_signal: ShutdownSignal) -> Self {
+        self.shutdown_signal = Some(shutdown_signal);
+
+        self
+    }
+
+    pub fn player(&self) -> SelfPlayPlayer<G, SE, AE, NN> {
+        NeuralNetworkMctsPlayer::new(
+            self.simulations,
+            self.state_encoder,
+            self.action_encoder,
+            self.neural_network.clone(),
+        )
+        .with_dirichlet_noise(self.dirichlet_noise)
+        .with_temperature_schedule(self.temperature_schedule.clone())
+    }
+
+    // NOTE - The games are only spread across the configured threads when the runner is
+    // started with `run_parallel`.
+
+    pub fn build<S: EventSink<Sample>>(self, sink: S) -> SelfPlayRunner<G, SE, AE, NN, S>
+    where
+        G: Send + 'static,
+        G::Action: Send,
+        SE: Send + 'static,
+        AE: Send + 'static,
+    {
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut sample_sink = SampleRunnerEventSink::new(
+            self.state_encoder,
+            self.action_encoder,
+            self.use_symmetries,
+            sink,
+        );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            sample_sink = sample_sink.with_encoding_threads(self.encoding_threads);
+        }
+
+        let mut runner = Runner::new(self.games, self.player(), self.player(), sample_sink)
+            .with_threads(self.threads);
+
+        if let Some(max_turns) = self.max_turns {
+            runner = runner.with_max_turns(max_turns);
+        }
+
+        if let Some(seed) = self.seed {
+            runner = runner.with_seed(seed);
+        }
+
+        if let Some(shutdown_signal) = self.shutdown_signal {
+            runner = runner.with_shutdown_signal(shutdown_signal);
+        }
+
+        runner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeActionEncoder, TicTacToeStateEncoder};
+    use crate::neural_network::ConstantNeuralNetwork;
+
+    #[derive(Default)]
+    struct SampleCounter {
+        samples: usize,
+    }
+
+    impl EventSink<Sample> for SampleCounter {
+        fn emit(&mut self, _: Sample) {
+            self.samples += 1;
+        }
+    }
+
+    mod build {
+        use super::*;
+
+        #[test]
+        fn should_write_samples_for_every_game() {
+            let mut runner = SelfPlayBuilder::<TicTacToe, _, _, _>::new(
+                TicTacToeStateEncoder::new(),
+                TicTacToeActionEncoder,
+                ConstantNeuralNetwork::new(9),
+            )
+            .with_games(2)
+            .with_simulations(10)
+            .with_symmetries(false)
+            .with_seed(1)
+            .build(SampleCounter::default());
+
+            runner.run();
+
+            assert!(runner.sink().sink().samples >= 10);
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+// NOTE - Each field is an override. Unset fields fall back to defaults under the
+// run directory, so changing the run directory moves everything with it.
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HermesPaths {
+    pub run_dir: Option<PathBuf>,
+    pub model_dir: Option<PathBuf>,
+    pub data_dir: Option<PathBuf>,
+    pub model_registry: Option<PathBuf>,
+}
+
+impl HermesPaths {
+    const CONFIG_FILE: &'static str = "hermes.json";
+    const REGISTRY_FILE: &'static str = "registry.json";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // NOTE - Precedence is config file, then environment variables. Callers layer
+    // CLI flags on top with `merge`.
+
+    pub fn load(config_path: Option<&Path>) -> Result<Self, Box<dyn Error>> {
+        let config_path = config_path
+            .map(Path::to_path_buf)
+            .or_else(|| env::var_os("HERMES_CONFIG").map(PathBuf::from));
+
+        let paths = match config_path {
+            Some(path) => Self::from_config_file(&path)?,
+            None if Path::new(Self::CONFIG_FILE).is_file() => {
+                Self::from_config_file(Path::new(Self::CONFIG_FILE))?
+            }
+            None => Self::new(),
+        };
+
+        Ok(paths.merge(Self::from_env()))
+    }
+
+    pub fn from_config_file(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let json = fs::read_to_string(path)
+            .map_err(|error| format!("unable to read {}: {error}", path.display()))?;
+
+        let mut paths: Self = serde_json::from_str(&json)?;
+
+        // NOTE - Relative paths in a config file are relative to the file itself.
+
+        if let Some(base_dir) = path.parent() {
+            for field in [
+                &mut paths.run_dir,
+                &mut paths.model_dir,
+                &mut paths.data_dir,
+                &mut paths.model_registry,
+            ] {
+                if let Some(value) = field.as_mut().filter(|value| value.is_relative()) {
+                    *value = base_dir.join(&value);
+                }
+            }
+        }
+
+        Ok(paths)
+    }
+
+    pub fn from_env() -> Self {
+        let var = |name: &str| env::var_os(name).map(PathBuf::from);
+
+        Self {
+            run_dir: var("HERMES_RUN_DIR"),
+            model_dir: var("HERMES_MODEL_DIR"),
+            data_dir: var("HERMES_DATA_DIR"),
+            model_registry: var("HERMES_MODEL_REGISTRY"),
+        }
+    }
+
+    pub fn merge(self, overrides: Self) -> Self {
+        Self {
+            run_dir: overrides.run_dir.or(self.run_dir),
+            model_dir: overrides.model_dir.or(self.model_dir),
+            data_dir: overrides.data_dir.or(self.data_dir),
+            model_registry: overrides.model_registry.or(self.model_registry),
+        }
+    }
+
+    pub fn run_dir(&self) -> PathBuf {
+        self.run_dir.clone().unwrap_or_else(|| PathBuf::from("."))
+    }
+
+    pub fn model_dir(&self) -> PathBuf {
+        self.model_dir
+            .clone()
+            .unwrap_or_else(|| self.run_dir().join("models"))
+    }
+
+    pub fn data_dir(&self) -> PathBuf {
+        self.data_dir
+            .clone()
+            .unwrap_or_else(|| self.run_dir().join("data"))
+    }
+
+    pub fn model_registry(&self) -> Option<PathBuf> {
+        self.model_registry.clone().or_else(|| {
+            Some(self.model_dir().join(Self::REGISTRY_FILE)).filter(|path| path.is_file())
+        })
+    }
+
+    pub fn data_file(&self, path: &Path) -> PathBuf {
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.data_dir().join(path)
+        }
+    }
+
+    pub fn run_file(&self, path: &Path) -> PathBuf {
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.run_dir().join(path)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod model_dir {
+        use super::*;
+
+        #[test]
+        fn should_default_under_run_dir() {
+            let paths = HermesPaths {
+                run_dir: Some(PathBuf::from("runs/boop")),
+                ..HermesPaths::new()
+            };
+
+            assert_eq!(paths.model_dir(), PathBuf::from("runs/boop/models"));
+            assert_eq!(paths.data_dir(), PathBuf::from("runs/boop/data"));
+        }
+    }
+
+    mod merge {
+        use super::*;
+
+        #[test]
+        fn should_prefer_overrides() {
+            let base = HermesPaths {
+                run_dir: Some(PathBuf::from("a")),
+                model_dir: Some(PathBuf::from("b")),
+                ..HermesPaths::new()
+            };
+
+            let overrides = HermesPaths {
+                run_dir: Some(PathBuf::from("c")),
+                ..HermesPaths::new()
+            };
+
+            let paths = base.merge(overrides);
+
+            assert_eq!(paths.run_dir(), PathBuf::from("c"));
+            assert_eq!(paths.model_dir(), PathBuf::from("b"));
+            assert_eq!(paths.data_dir(), PathBuf::from("c/data"));
+        }
+    }
+
+    mod data_file {
+        use super::*;
+
+        #[test]
+        fn should_resolve_relative_paths_against_data_dir() {
+            let paths = HermesPaths::new();
+
+            assert_eq!(
+                paths.data_file(Path::new("samples.jsonl")),
+                PathBuf::from("./data/samples.jsonl")
+            );
+            assert_eq!(
+                paths.data_file(Path::new("/tmp/samples.jsonl")),
+                PathBuf::from("/tmp/samples.jsonl")
+            );
+        }
+    }
+}
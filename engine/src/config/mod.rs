@@ -0,0 +1,3 @@
+mod hermes_paths;
+
+pub use hermes_paths::HermesPaths;
@@ -1,7 +1,12 @@
-use crate::core::game::Game;
+use smallvec::SmallVec;
 
+use crate::core::game::{ACTION_LIST_CAPACITY, Game};
+
+pub type Policy<G> = SmallVec<[PolicyItem<G>; ACTION_LIST_CAPACITY]>;
+
+#[derive(Clone)]
 pub struct Evaluation<G: Game> {
-    pub policy: Vec<PolicyItem<G>>,
+    pub policy: Policy<G>,
     pub value: f32,
 }
 
@@ -1,5 +1,7 @@
 mod event_sink;
 mod null_event_sink;
+mod tee_event_sink;
 
 pub use event_sink::EventSink;
 pub use null_event_sink::NullEventSink;
+pub use tee_event_sink::TeeEventSink;
@@ -0,0 +1,29 @@
+use crate::core::EventSink;
+
+// NOTE - Sends every event to both sinks, so a single run can feed several consumers.
+
+pub struct TeeEventSink<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> TeeEventSink<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<E: Clone, A: EventSink<E>, B: EventSink<E>> EventSink<E> for TeeEventSink<A, B> {
+    fn emit(&mut self, event: E) {
+        self.first.emit(event.clone());
+        self.second.emit(event);
+    }
+}
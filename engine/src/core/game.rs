@@ -1,15 +1,37 @@
 use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use rand::Rng;
+use rand::distr::Distribution;
+use rand::distr::weighted::WeightedIndex;
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
 
 use crate::core::turn::Turn;
 
+// NOTE - Sized to hold the legal actions of most positions inline, which avoids a heap
+// allocation per node during search.
+
+pub const ACTION_LIST_CAPACITY: usize = 32;
+
+pub type ActionList<A> = SmallVec<[A; ACTION_LIST_CAPACITY]>;
+
 pub trait Game: Clone + fmt::Display {
     type Phase;
-    type Action: Copy + Eq + fmt::Display;
+    type Action: Copy + Eq + Hash + fmt::Display;
     type Checkpoint: Copy;
+    type Config: Clone + Default;
+
+    // NOTE - Parameterized games, such as board sizes or komi, are built from a config, and
+    // `new` is the default config.
+
+    fn new() -> Self {
+        Self::new_with_config(Self::Config::default())
+    }
 
-    fn new() -> Self;
+    fn new_with_config(config: Self::Config) -> Self;
 
-    fn get_possible_actions(&self) -> Vec<Self::Action>;
+    fn get_possible_actions(&self) -> ActionList<Self::Action>;
 
     fn apply_action(&mut self, action: Self::Action) -> bool;
 
@@ -17,20 +39,126 @@ pub trait Game: Clone + fmt::Display {
 
     fn outcome(&self) -> Outcome;
 
+    // NOTE - A non-empty list marks a chance node, such as a dice roll: instead of the side
+    // to move choosing, one of these actions is drawn with its probability and applied like
+    // any other. The probabilities sum to one.
+
+    fn chance_outcomes(&self) -> Vec<(Self::Action, f32)> {
+        vec![]
+    }
+
+    // NOTE - Games drawn by repetition give each position a key, equal for positions that
+    // count as the same, and the runner draws the game when one occurs for the third time.
+    // Games that track repetitions themselves, or cannot repeat, keep the default.
+
+    fn repetition_key(&self) -> Option<u64> {
+        None
+    }
+
+    // NOTE - Games that can never return to an earlier position give each position a hash,
+    // equal only for identical positions, and search merges the transpositions it finds.
+    // Games whose positions can repeat keep the default, since merging would close cycles.
+
+    fn hash(&self) -> Option<u64> {
+        None
+    }
+
+    // NOTE - A hash of the position for caches that look a position up by value, such as
+    // the minimax transposition table. Unlike `hash`, it makes no promise that positions
+    // never repeat, so games whose positions can repeat may still give one.
+
+    fn position_key(&self) -> Option<u64> {
+        self.hash()
+    }
+
     fn create_checkpoint(&self) -> Self::Checkpoint;
 
     fn restore_checkpoint(&mut self, checkpoint: Self::Checkpoint);
 
+    // NOTE - For hidden information: `observation` blanks out what the side to move cannot
+    // see, and `determinize` fills an observation back in with a sample consistent with it.
+    // Players are handed observations, and search plays out determinizations of them.
+    // Perfect-information games keep the defaults.
+
+    fn observation(&self) -> Self {
+        self.clone()
+    }
+
+    fn determinize(&self, _rng: &mut impl Rng) -> Self {
+        self.clone()
+    }
+
     fn symmetries(&self) -> u8;
 
     fn transform(&self, symmetry: u8) -> Self;
 
     fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action;
 
+    // NOTE - The smallest of the symmetric states, so every state in a symmetry class maps to
+    // the same one. The returned symmetry carries actions into the canonical frame.
+
+    fn canonicalize(&self) -> (Self, u8)
+    where
+        Self: Ord,
+    {
+        (0..self.symmetries())
+            .map(|symmetry| (self.transform(symmetry), symmetry))
+            .min()
+            .expect("every game has the identity symmetry")
+    }
+
     fn display(&self, turn: Turn) -> String;
+
+    // NOTE - A compact single-line form of the position for command lines, logs and test
+    // suites. Like `Display`, it is written from the side to move's point of view.
+
+    fn to_notation(&self) -> String;
+
+    fn from_notation(notation: &str) -> Result<Self, String>;
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) fn hash_position(position: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    position.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+// NOTE - The smallest position key over a position's symmetries, with the symmetry that
+// gives it, so symmetric positions share a key without `canonicalize` and its `Ord` bound.
+// Games without a position key have none.
+
+pub(crate) fn canonical_position_key<G: Game>(game: &G) -> Option<(u64, u8)> {
+    (0..game.symmetries())
+        .map(|symmetry| {
+            let key = if symmetry == 0 {
+                game.position_key()
+            } else {
+                game.transform(symmetry).position_key()
+            };
+
+            key.map(|key| (key, symmetry))
+        })
+        .min()
+        .flatten()
+}
+
+pub(crate) fn sample_chance_outcome<G: Game>(game: &G, rng: &mut impl Rng) -> Option<G::Action> {
+    let outcomes = game.chance_outcomes();
+
+    if outcomes.is_empty() {
+        return None;
+    }
+
+    let distribution = WeightedIndex::new(outcomes.iter().map(|&(_, probability)| probability))
+        .expect("unable to create chance distribution");
+
+    Some(outcomes[distribution.sample(rng)].0)
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Outcome {
     InProgress,
     Win,
@@ -39,6 +167,14 @@ pub enum Outcome {
 }
 
 impl Outcome {
+    pub fn flip(self) -> Self {
+        match self {
+            Outcome::Win => Outcome::Loss,
+            Outcome::Loss => Outcome::Win,
+            outcome => outcome,
+        }
+    }
+
     pub fn display(&self, turn: Turn) -> String {
         match (self, turn) {
             (Outcome::InProgress, _) => "Game is in progress.".to_string(),
@@ -1,16 +1,27 @@
 mod evaluation;
 mod event;
 mod game;
+mod notation;
+mod pie_rule;
 mod player;
+mod rng;
 mod runner;
+mod shutdown_signal;
 mod turn;
 
-pub use evaluation::{Evaluation, PolicyItem};
-pub use event::{EventSink, NullEventSink};
-pub use game::{Game, Outcome};
+pub use evaluation::{Evaluation, Policy, PolicyItem};
+pub use event::{EventSink, NullEventSink, TeeEventSink};
+pub use game::{ActionList, Game, Outcome};
+pub(crate) use game::{canonical_position_key, hash_position, sample_chance_outcome};
+pub(crate) use notation::{format_rows, parse_grid, parse_rows, parse_square};
+pub use pie_rule::{Action as PieRuleAction, Phase as PieRulePhase, PieRule};
 pub use player::{Choice, Player};
+pub(crate) use rng::derive_seed;
+pub use rng::{DefaultRngFactory, RngFactory, SmallRngFactory, StdRngFactory};
 pub use runner::{
-    Runner, RunnerEvent, RunnerEventContext, RunnerEventKind, StatisticsRunnerEventSink,
-    StdoutRunnerEventSink,
+    RepetitionCounter, ResultCounts, Runner, RunnerEvent, RunnerEventContext, RunnerEventKind,
+    Scoring, StatisticsRunnerEventSink, StdoutRunnerEventSink, TimeControl,
 };
+pub(crate) use runner::{RunningGame, random_opening};
+pub use shutdown_signal::ShutdownSignal;
 pub use turn::Turn;
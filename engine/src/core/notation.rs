@@ -0,0 +1,107 @@
+use std::iter::repeat_n;
+
+// NOTE - Boards in the FEN style of `Game::to_notation`: rows from the top separated by `/`,
+// with each run of empty cells written as its length.
+
+pub(crate) fn format_rows<R>(rows: impl IntoIterator<Item = R>) -> String
+where
+    R: IntoIterator<Item = Option<char>>,
+{
+    let rows: Vec<String> = rows
+        .into_iter()
+        .map(|row| {
+            let mut value = String::new();
+            let mut empty = 0;
+
+            for cell in row {
+                if let Some(character) = cell {
+                    if empty > 0 {
+                        value.push_str(&empty.to_string());
+                        empty = 0;
+                    }
+
+                    value.push(character);
+                } else {
+                    empty += 1;
+                }
+            }
+
+            if empty > 0 {
+                value.push_str(&empty.to_string());
+            }
+
+            value
+        })
+        .collect();
+
+    rows.join("/")
+}
+
+pub(crate) fn parse_rows(value: &str) -> Result<Vec<Vec<Option<char>>>, String> {
+    value
+        .split('/')
+        .enumerate()
+        .map(|(row, line)| {
+            let mut cells = vec![];
+            let mut empty = 0;
+
+            for character in line.chars() {
+                if let Some(digit) = character.to_digit(10) {
+                    empty = empty * 10 + digit as usize;
+                } else {
+                    cells.extend(repeat_n(None, empty));
+                    cells.push(Some(character));
+
+                    empty = 0;
+                }
+            }
+
+            cells.extend(repeat_n(None, empty));
+
+            if cells.is_empty() {
+                return Err(format!("malformed board row {}", row + 1));
+            }
+
+            Ok(cells)
+        })
+        .collect()
+}
+
+pub(crate) fn parse_grid(
+    value: &str,
+    width: usize,
+    height: usize,
+) -> Result<Vec<Vec<Option<char>>>, String> {
+    let rows = parse_rows(value)?;
+
+    if rows.len() != height {
+        return Err(format!(
+            "expected {height} board rows, found {}",
+            rows.len()
+        ));
+    }
+
+    if let Some(row) = rows.iter().position(|cells| cells.len() != width) {
+        return Err(format!("malformed board row {}", row + 1));
+    }
+
+    Ok(rows)
+}
+
+// NOTE - Squares are written as a column letter and a row number, with `a1` at the top left
+// as on the printed boards.
+
+pub(crate) fn parse_square(value: &str, size: usize) -> Result<(usize, usize), String> {
+    let (col, row) = value
+        .split_at_checked(1)
+        .ok_or_else(|| format!("invalid square: {value}"))?;
+
+    let col = col.as_bytes()[0].wrapping_sub(b'a') as usize;
+    let row = row.parse::<usize>().unwrap_or_default();
+
+    if col >= size || !(1..=size).contains(&row) {
+        return Err(format!("invalid square: {value}"));
+    }
+
+    Ok((row - 1, col))
+}
@@ -0,0 +1,297 @@
+use std::fmt;
+
+use rand::Rng;
+
+use crate::core::{ActionList, Game, Outcome, Turn, hash_position};
+
+// NOTE - Wraps a game with the pie rule: after the first turn, the second player may swap
+// sides instead of moving, taking over the first player's position. Games are stored from
+// the side to move's point of view, so a swap leaves the wrapped game untouched and only
+// skips the perspective flip that ending the turn would otherwise make.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PieRule<G: Game> {
+    pub game: G,
+    pub phase: Phase,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Opening,
+    Offer,
+    Swapped,
+    Play,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action<A> {
+    Play(A),
+    Swap,
+}
+
+impl<A: fmt::Display> fmt::Display for Action<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Play(action) => write!(f, "{action}"),
+            Action::Swap => write!(f, "swaps sides."),
+        }
+    }
+}
+
+impl<G: Game> PieRule<G> {
+    fn phase_label(phase: Phase) -> &'static str {
+        match phase {
+            Phase::Opening => "opening",
+            Phase::Offer => "offer",
+            Phase::Swapped => "swapped",
+            Phase::Play => "play",
+        }
+    }
+}
+
+impl<G: Game> Game for PieRule<G> {
+    type Phase = Phase;
+    type Action = Action<G::Action>;
+    type Checkpoint = (G::Checkpoint, Phase);
+    type Config = G::Config;
+
+    fn new_with_config(config: G::Config) -> Self {
+        PieRule {
+            game: G::new_with_config(config),
+            phase: Phase::Opening,
+        }
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Self::Action> {
+        let mut actions: ActionList<Self::Action> = self
+            .game
+            .get_possible_actions()
+            .into_iter()
+            .map(Action::Play)
+            .collect();
+
+        if self.phase == Phase::Offer && !actions.is_empty() {
+            actions.push(Action::Swap);
+        }
+
+        actions
+    }
+
+    // NOTE - The offer lapses once the second player starts their turn, so games with
+    // several actions per turn only see it before the first.
+
+    fn apply_action(&mut self, action: Self::Action) -> bool {
+        match action {
+            Action::Play(action) => {
+                if self.phase == Phase::Offer {
+                    self.phase = Phase::Play;
+                }
+
+                self.game.apply_action(action)
+            }
+            Action::Swap => {
+                self.phase = Phase::Swapped;
+
+                true
+            }
+        }
+    }
+
+    fn end_turn(&mut self) {
+        match self.phase {
+            Phase::Opening => {
+                self.game.end_turn();
+
+                self.phase = Phase::Offer;
+            }
+            Phase::Swapped => self.phase = Phase::Play,
+            Phase::Offer | Phase::Play => self.game.end_turn(),
+        }
+    }
+
+    fn outcome(&self) -> Outcome {
+        self.game.outcome()
+    }
+
+    fn chance_outcomes(&self) -> Vec<(Self::Action, f32)> {
+        self.game
+            .chance_outcomes()
+            .into_iter()
+            .map(|(action, probability)| (Action::Play(action), probability))
+            .collect()
+    }
+
+    fn repetition_key(&self) -> Option<u64> {
+        self.game.repetition_key()
+    }
+
+    fn hash(&self) -> Option<u64> {
+        self.game
+            .hash()
+            .map(|hash| hash_position(&(hash, self.phase)))
+    }
+
+    fn position_key(&self) -> Option<u64> {
+        self.game
+            .position_key()
+            .map(|key| hash_position(&(key, self.phase)))
+    }
+
+    fn create_checkpoint(&self) -> Self::Checkpoint {
+        (self.game.create_checkpoint(), self.phase)
+    }
+
+    fn restore_checkpoint(&mut self, (checkpoint, phase): Self::Checkpoint) {
+        self.game.restore_checkpoint(checkpoint);
+        self.phase = phase;
+    }
+
+    fn observation(&self) -> Self {
+        PieRule {
+            game: self.game.observation(),
+            phase: self.phase,
+        }
+    }
+
+    fn determinize(&self, rng: &mut impl Rng) -> Self {
+        PieRule {
+            game: self.game.determinize(rng),
+            phase: self.phase,
+        }
+    }
+
+    fn symmetries(&self) -> u8 {
+        self.game.symmetries()
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        PieRule {
+            game: self.game.transform(symmetry),
+            phase: self.phase,
+        }
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        match action {
+            Action::Play(action) => Action::Play(self.game.transform_action(action, symmetry)),
+            Action::Swap => Action::Swap,
+        }
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        self.game.display(turn)
+    }
+
+    fn to_notation(&self) -> String {
+        format!(
+            "{} {}",
+            self.game.to_notation(),
+            Self::phase_label(self.phase)
+        )
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let (game, phase) = notation
+            .trim_end()
+            .rsplit_once(' ')
+            .ok_or_else(|| "missing phase".to_string())?;
+
+        let phase = match phase {
+            "opening" => Phase::Opening,
+            "offer" => Phase::Offer,
+            "swapped" => Phase::Swapped,
+            "play" => Phase::Play,
+            _ => return Err(format!("invalid phase: {phase}")),
+        };
+
+        Ok(PieRule {
+            game: G::from_notation(game)?,
+            phase,
+        })
+    }
+}
+
+impl<G: Game> fmt::Display for PieRule<G> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.game)?;
+
+        if self.phase == Phase::Offer {
+            writeln!(formatter)?;
+            write!(formatter, "The side to move may swap.")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::hex::{Hex, HexAction};
+
+    type PieHex = PieRule<Hex<3>>;
+
+    fn place(row: u8, col: u8) -> Action<HexAction> {
+        Action::Play(HexAction::Place { row, col })
+    }
+
+    fn play(game: &mut PieHex, action: Action<HexAction>) {
+        if game.apply_action(action) {
+            game.end_turn();
+        }
+    }
+
+    mod get_possible_actions {
+        use super::*;
+
+        #[test]
+        fn should_only_offer_the_swap_on_the_second_turn() {
+            let mut game = PieHex::new();
+
+            assert!(!game.get_possible_actions().contains(&Action::Swap));
+
+            play(&mut game, place(1, 1));
+
+            assert!(game.get_possible_actions().contains(&Action::Swap));
+
+            play(&mut game, place(0, 0));
+
+            assert!(!game.get_possible_actions().contains(&Action::Swap));
+        }
+    }
+
+    mod apply_action {
+        use super::*;
+
+        #[test]
+        fn should_hand_the_first_move_to_the_second_player() {
+            let mut game = PieHex::new();
+
+            play(&mut game, place(1, 1));
+
+            let offered = game.game.clone();
+
+            play(&mut game, Action::Swap);
+
+            assert_eq!(game.game, offered);
+            assert_eq!(game.phase, Phase::Play);
+            assert!(!game.get_possible_actions().contains(&Action::Swap));
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = PieHex::new();
+
+            play(&mut game, place(0, 2));
+
+            let notation = game.to_notation();
+
+            assert_eq!(PieHex::from_notation(&notation), Ok(game));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::core::evaluation::Evaluation;
 use crate::core::game::Game;
 
@@ -5,9 +7,30 @@ pub trait Player<G: Game> {
     fn name(&self) -> &str;
 
     fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G>;
+
+    // NOTE - Deterministic players can ignore this. Anything that samples must restart
+    // its random stream so the runner can reproduce a game from its seed alone.
+
+    fn reseed(&mut self, _seed: u64) {}
+
+    // NOTE - Called once before each game, whether or not the runner is seeded, for
+    // players that refresh state between games, such as a network that follows a model
+    // file.
+
+    fn new_game(&mut self) {}
+
+    // NOTE - Called before each move of a timed game with the time left on the mover's
+    // clock and the increment it gains per turn, for players that size their search to
+    // the clock.
+
+    fn set_clock(&mut self, _remaining: Duration, _increment: Duration) {}
 }
 
+// NOTE - A player resigns by setting `resign`, in which case the runner ends the game as
+// a loss for them and `action` is ignored.
+
 pub struct Choice<G: Game> {
     pub evaluation: Option<Evaluation<G>>,
     pub action: G::Action,
+    pub resign: bool,
 }
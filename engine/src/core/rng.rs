@@ -0,0 +1,57 @@
+use std::fmt;
+
+use rand::rngs::{SmallRng, StdRng};
+use rand::{Rng, SeedableRng, rng};
+
+pub trait RngFactory: Clone {
+    type Rng: Rng + fmt::Debug;
+
+    fn create(&self) -> Self::Rng;
+
+    fn create_seeded(&self, seed: u64) -> Self::Rng;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdRngFactory;
+
+impl RngFactory for StdRngFactory {
+    type Rng = StdRng;
+
+    fn create(&self) -> StdRng {
+        StdRng::from_rng(&mut rng())
+    }
+
+    fn create_seeded(&self, seed: u64) -> StdRng {
+        StdRng::seed_from_u64(seed)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SmallRngFactory;
+
+impl RngFactory for SmallRngFactory {
+    type Rng = SmallRng;
+
+    fn create(&self) -> SmallRng {
+        SmallRng::from_rng(&mut rng())
+    }
+
+    fn create_seeded(&self, seed: u64) -> SmallRng {
+        SmallRng::seed_from_u64(seed)
+    }
+}
+
+// NOTE - SplitMix64, so that neighbouring streams of the same seed are uncorrelated.
+
+pub fn derive_seed(seed: u64, stream: u64) -> u64 {
+    let mut z = seed.wrapping_add(stream.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    z ^ (z >> 31)
+}
+
+// NOTE - Search only needs statistical quality, so default to the faster generator.
+
+pub type DefaultRngFactory = SmallRngFactory;
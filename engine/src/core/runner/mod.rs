@@ -1,8 +1,17 @@
+mod repetition_counter;
+mod result_counts;
 #[allow(clippy::module_inception)]
 mod runner;
+mod scoring;
 mod statistics_runner_event_sink;
 mod stdout_runner_event_sink;
+mod time_control;
 
+pub use repetition_counter::RepetitionCounter;
+pub use result_counts::ResultCounts;
 pub use runner::{Runner, RunnerEvent, RunnerEventContext, RunnerEventKind};
+pub(crate) use runner::{RunningGame, random_opening};
+pub use scoring::Scoring;
 pub use statistics_runner_event_sink::StatisticsRunnerEventSink;
 pub use stdout_runner_event_sink::StdoutRunnerEventSink;
+pub use time_control::TimeControl;
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use crate::core::game::Game;
+
+// NOTE - Counts how often each position has occurred, keyed by `Game::repetition_key`, so
+// games without a history of their own can be drawn by repetition.
+
+#[derive(Clone, Debug, Default)]
+pub struct RepetitionCounter {
+    counts: HashMap<u64, u32>,
+}
+
+impl RepetitionCounter {
+    pub const LIMIT: u32 = 3;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // NOTE - Returns how many times the position has now occurred, or 0 for games that
+    // are not drawn by repetition.
+
+    pub fn record<G: Game>(&mut self, game: &G) -> u32 {
+        let Some(key) = game.repetition_key() else {
+            return 0;
+        };
+
+        let count = self.counts.entry(key).or_default();
+
+        *count += 1;
+
+        *count
+    }
+
+    pub fn is_repeated<G: Game>(&mut self, game: &G) -> bool {
+        self.record(game) >= Self::LIMIT
+    }
+}
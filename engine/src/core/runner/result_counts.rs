@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ResultCounts {
+    pub games: u32,
+    pub player_1_wins: u32,
+    pub player_2_wins: u32,
+    pub draws: u32,
+}
@@ -1,10 +1,18 @@
 use std::marker::PhantomData;
+use std::mem;
+use std::time::{Duration, Instant};
+
+use rand::seq::IndexedRandom;
 
-use crate::core::Evaluation;
 use crate::core::event::EventSink;
-use crate::core::game::{Game, Outcome};
+use crate::core::game::{ActionList, Game, Outcome, sample_chance_outcome};
 use crate::core::player::Player;
+use crate::core::rng::{DefaultRngFactory, RngFactory, derive_seed};
+use crate::core::runner::repetition_counter::RepetitionCounter;
+use crate::core::runner::time_control::TimeControl;
+use crate::core::shutdown_signal::ShutdownSignal;
 use crate::core::turn::Turn;
+use crate::core::{Choice, Evaluation};
 
 pub struct Runner<G, P1, P2, S>
 where
@@ -16,6 +24,11 @@ where
     games: u32,
     max_turns: Option<u32>,
     threads: usize,
+    seed: Option<u64>,
+    opening_plies: u32,
+    time_control: Option<TimeControl>,
+    shutdown_signal: Option<ShutdownSignal>,
+    initial_game: G,
 
     player_1: P1,
     player_2: P2,
@@ -37,6 +50,11 @@ where
             games,
             max_turns: None,
             threads: 1,
+            seed: None,
+            opening_plies: 0,
+            time_control: None,
+            shutdown_signal: None,
+            initial_game: G::new(),
 
             player_1,
             player_2,
@@ -59,45 +77,106 @@ where
         self
     }
 
-    pub fn sink(&self) -> &S {
-        &self.sink
+    // NOTE - Each game reseeds both players from the master seed and its game number, so
+    // a run replays identically regardless of how many threads it is spread across.
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+
+        self
     }
 
-    pub fn run(&mut self)
-    where
-        G: Send,
-        G::Action: Send,
-        P1: Send,
-        P2: Send,
-    {
-        #[cfg(not(target_arch = "wasm32"))]
-        if self.threads > 1 {
-            // self.run_parallel();
-            return;
-        }
+    // NOTE - The first plies of each game are played at random instead of by the players.
+    // Consecutive games share an opening with the first player swapped, and runs with the
+    // same seed share openings whatever the players, so configurations can be compared on
+    // matched positions.
 
-        self.run_serial();
+    pub fn with_opening_plies(mut self, opening_plies: u32) -> Self {
+        self.opening_plies = opening_plies;
+
+        self
     }
 
-    fn run_serial(&mut self) {
+    // NOTE - Players are told their remaining time before each move and charged for the
+    // time they take choosing it. The clock is never read in untimed runs.
+
+    pub fn with_time_control(mut self, time_control: TimeControl) -> Self {
+        self.time_control = Some(time_control);
+
+        self
+    }
+
+    // NOTE - Once a shutdown is requested, no new games are started. Games already in
+    // flight are played out and emitted, followed by `RunnerFinished` as usual.
+
+    pub fn with_shutdown_signal(mut self, shutdown_signal: ShutdownSignal) -> Self {
+        self.shutdown_signal = Some(shutdown_signal);
+
+        self
+    }
+
+    // NOTE - Every game starts from a copy of this position rather than `G::new()`, which
+    // is how games configured at runtime are played.
+
+    pub fn with_initial_game(mut self, initial_game: G) -> Self {
+        self.initial_game = initial_game;
+
+        self
+    }
+
+    pub fn with_game_config(self, config: G::Config) -> Self {
+        self.with_initial_game(G::new_with_config(config))
+    }
+
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    // NOTE - Plays the games one after another on the calling thread, whatever the thread
+    // count, so players need not be cloneable or sendable between threads, as console and
+    // network players are not.
+
+    pub fn run(&mut self) {
         self.sink.emit(RunnerEvent {
             kind: RunnerEventKind::RunnerStarted,
             context: None,
         });
 
+        let opening_seed = self.opening_seed();
+
         for game_number in 0..self.games {
+            if self.shutdown_requested() {
+                break;
+            }
+
             let initial_turn = if game_number % 2 == 0 {
                 Turn::Player1
             } else {
                 Turn::Player2
             };
 
-            let events = run_single_game(
+            let opening = random_opening(
+                &self.initial_game,
+                self.opening_plies,
+                opening_seed,
+                game_number / 2,
+            );
+
+            let running_game = RunningGame::new(
                 game_number,
+                self.initial_game.clone(),
                 initial_turn,
+                opening,
+                self.max_turns,
+                self.seed,
+            )
+            .with_time_control(self.time_control);
+
+            let events = run_single_game(
+                running_game,
                 &mut self.player_1,
                 &mut self.player_2,
-                self.max_turns,
+                self.seed,
             );
 
             for event in events {
@@ -111,8 +190,27 @@ where
         });
     }
 
+    // NOTE - Spreads the games across the configured threads, each game played by its own
+    // clones of the players. With one thread, or on wasm32, this is the same as `run`.
+
+    pub fn run_parallel(&mut self)
+    where
+        G: Send,
+        G::Action: Send,
+        P1: Clone + Send,
+        P2: Clone + Send,
+    {
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.threads > 1 {
+            self.run_threaded();
+            return;
+        }
+
+        self.run();
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
-    fn run_parallel(&mut self)
+    fn run_threaded(&mut self)
     where
         G: Send,
         G::Action: Send,
@@ -126,11 +224,22 @@ where
             context: None,
         });
 
-        let player_pairs: Vec<(P1, P2)> = (0..self.games)
-            .map(|_| (self.player_1.clone(), self.player_2.clone()))
+        let game_setups: Vec<(G, P1, P2)> = (0..self.games)
+            .map(|_| {
+                (
+                    self.initial_game.clone(),
+                    self.player_1.clone(),
+                    self.player_2.clone(),
+                )
+            })
             .collect();
 
         let max_turns = self.max_turns;
+        let seed = self.seed;
+        let opening_plies = self.opening_plies;
+        let time_control = self.time_control;
+        let opening_seed = self.opening_seed();
+        let shutdown_signal = self.shutdown_signal.clone();
 
         let pool = rayon::ThreadPoolBuilder::new()
             .num_threads(self.threads)
@@ -138,27 +247,45 @@ where
             .expect("failed to build thread pool");
 
         let game_events: Vec<Vec<RunnerEvent<G>>> = pool.install(|| {
-            player_pairs
+            game_setups
                 .into_par_iter()
                 .enumerate()
-                .map(|(game_number, (mut p1, mut p2))| {
+                .map(|(game_number, (initial_game, mut p1, mut p2))| {
+                    if shutdown_signal
+                        .as_ref()
+                        .is_some_and(ShutdownSignal::is_requested)
+                    {
+                        return vec![];
+                    }
+
                     let initial_turn = if game_number % 2 == 0 {
                         Turn::Player1
                     } else {
                         Turn::Player2
                     };
 
-                    run_single_game(
-                        game_number as u32,
+                    let game_number = u32::try_from(game_number).unwrap();
+
+                    let opening =
+                        random_opening(&initial_game, opening_plies, opening_seed, game_number / 2);
+
+                    let running_game = RunningGame::new(
+                        game_number,
+                        initial_game,
                         initial_turn,
-                        &mut p1,
-                        &mut p2,
+                        opening,
                         max_turns,
+                        seed,
                     )
+                    .with_time_control(time_control);
+
+                    run_single_game(running_game, &mut p1, &mut p2, seed)
                 })
                 .collect()
         });
 
+        // NOTE - Emit in game order so sinks aggregate in the same order as a serial run.
+
         for events in game_events {
             for event in events {
                 self.sink.emit(event);
@@ -170,160 +297,380 @@ where
             context: None,
         });
     }
+
+    fn shutdown_requested(&self) -> bool {
+        self.shutdown_signal
+            .as_ref()
+            .is_some_and(ShutdownSignal::is_requested)
+    }
+
+    fn opening_seed(&self) -> u64 {
+        match self.seed {
+            Some(seed) => derive_seed(seed, u64::MAX),
+            None => rand::random(),
+        }
+    }
+}
+
+pub(crate) fn random_opening<G: Game>(
+    initial_game: &G,
+    plies: u32,
+    seed: u64,
+    opening_number: u32,
+) -> Vec<G::Action> {
+    let mut rng =
+        DefaultRngFactory::default().create_seeded(derive_seed(seed, u64::from(opening_number)));
+
+    let mut game = initial_game.clone();
+    let mut opening = vec![];
+
+    for _ in 0..plies {
+        if game.outcome() != Outcome::InProgress {
+            break;
+        }
+
+        let Some(action) = sample_chance_outcome(&game, &mut rng)
+            .or_else(|| game.get_possible_actions().choose(&mut rng).copied())
+        else {
+            break;
+        };
+
+        if game.apply_action(action) && game.outcome() == Outcome::InProgress {
+            game.end_turn();
+        }
+
+        opening.push(action);
+    }
+
+    opening
 }
 
 fn run_single_game<G, P1, P2>(
-    game_number: u32,
-    initial_turn: Turn,
+    mut running_game: RunningGame<G>,
     player_1: &mut P1,
     player_2: &mut P2,
-    max_turns: Option<u32>,
+    seed: Option<u64>,
 ) -> Vec<RunnerEvent<G>>
 where
     G: Game,
     P1: Player<G>,
     P2: Player<G>,
 {
-    let mut events = vec![];
+    player_1.new_game();
+    player_2.new_game();
 
-    let mut game = G::new();
-    let mut turn_number = 0;
-    let mut turn = initial_turn;
+    if let Some(seed) = seed {
+        let game_number = u64::from(running_game.game_number);
 
-    events.push(RunnerEvent {
-        kind: RunnerEventKind::GameStarted,
-        context: Some(RunnerEventContext {
-            game_number,
-            game: game.clone(),
-            turn_number,
-            turn,
-        }),
-    });
-
-    events.push(RunnerEvent {
-        kind: RunnerEventKind::TurnStarted,
-        context: Some(RunnerEventContext {
+        player_1.reseed(derive_seed(seed, game_number * 2));
+        player_2.reseed(derive_seed(seed, game_number * 2 + 1));
+    }
+
+    while !running_game.is_finished() {
+        let forced_action = running_game
+            .opening_action()
+            .or_else(|| running_game.chance_action());
+
+        let choice = match (forced_action, running_game.turn) {
+            (Some(action), _) => Choice {
+                action,
+                evaluation: None,
+                resign: false,
+            },
+            (None, Turn::Player1) => choose_action(&mut running_game, player_1),
+            (None, Turn::Player2) => choose_action(&mut running_game, player_2),
+        };
+
+        if !running_game.is_finished() {
+            running_game.play(choice);
+        }
+    }
+
+    running_game.events
+}
+
+// NOTE - In timed games, a player that overruns its clock loses before its choice is
+// played.
+
+fn choose_action<G: Game, P: Player<G>>(
+    running_game: &mut RunningGame<G>,
+    player: &mut P,
+) -> Choice<G> {
+    let game = running_game.game.observation();
+
+    let Some((remaining, increment)) = running_game.clock() else {
+        return player.choose_action(&game, running_game.turn_number);
+    };
+
+    player.set_clock(remaining, increment);
+
+    let start = Instant::now();
+
+    let choice = player.choose_action(&game, running_game.turn_number);
+
+    running_game.charge_time(start.elapsed());
+
+    choice
+}
+
+// NOTE - A game advanced one choice at a time, collecting the events it produces, so that
+// callers can interleave several games on one thread.
+
+pub(crate) struct RunningGame<G: Game> {
+    pub game_number: u32,
+    pub game: G,
+
+    pub turn_number: u32,
+    pub turn: Turn,
+
+    pub events: Vec<RunnerEvent<G>>,
+
+    opening: Vec<G::Action>,
+    max_turns: Option<u32>,
+
+    chance_rng: <DefaultRngFactory as RngFactory>::Rng,
+    repetitions: RepetitionCounter,
+
+    turn_actions: ActionList<G::Action>,
+    ply: usize,
+    finished: bool,
+
+    time_control: Option<TimeControl>,
+    clocks: [Duration; 2],
+}
+
+impl<G: Game> RunningGame<G> {
+    pub fn new(
+        game_number: u32,
+        game: G,
+        initial_turn: Turn,
+        opening: Vec<G::Action>,
+        max_turns: Option<u32>,
+        seed: Option<u64>,
+    ) -> Self {
+        // NOTE - Chance events draw from their own stream, apart from the players' and the
+        // openings', so seeded runs replay the same dice whatever the players do.
+
+        let chance_rng = match seed {
+            Some(seed) => DefaultRngFactory::default().create_seeded(derive_seed(
+                derive_seed(seed, u64::MAX - 1),
+                u64::from(game_number),
+            )),
+            None => DefaultRngFactory::default().create(),
+        };
+
+        let mut repetitions = RepetitionCounter::new();
+
+        repetitions.record(&game);
+
+        let mut running_game = Self {
             game_number,
-            game: game.clone(),
-            turn_number,
-            turn,
-        }),
-    });
-
-    loop {
-        let choice = match turn {
-            Turn::Player1 => player_1.choose_action(&game, turn_number),
-            Turn::Player2 => player_2.choose_action(&game, turn_number),
+            game,
+
+            turn_number: 0,
+            turn: initial_turn,
+
+            events: vec![],
+
+            opening,
+            max_turns,
+
+            chance_rng,
+            repetitions,
+
+            turn_actions: ActionList::new(),
+            ply: 0,
+            finished: false,
+
+            time_control: None,
+            clocks: [Duration::ZERO; 2],
         };
 
-        if let Some(evaluation) = choice.evaluation {
-            events.push(RunnerEvent {
-                kind: RunnerEventKind::PositionEvaluated { evaluation },
-                context: Some(RunnerEventContext {
-                    game_number,
-                    game: game.clone(),
-                    turn_number,
-                    turn,
-                }),
+        running_game.push_event(RunnerEventKind::GameStarted);
+        running_game.push_event(RunnerEventKind::TurnStarted);
+
+        running_game
+    }
+
+    pub fn with_time_control(mut self, time_control: Option<TimeControl>) -> Self {
+        self.time_control = time_control;
+
+        if let Some(time_control) = time_control {
+            self.clocks = [time_control.initial; 2];
+        }
+
+        self
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    // NOTE - The time left to the side to move and the increment it gains per turn, if
+    // the game is timed.
+
+    pub fn clock(&self) -> Option<(Duration, Duration)> {
+        self.time_control
+            .map(|time_control| (self.clocks[self.turn_index()], time_control.increment))
+    }
+
+    pub fn charge_time(&mut self, elapsed: Duration) {
+        let clock = &mut self.clocks[self.turn_index()];
+
+        if elapsed > *clock {
+            *clock = Duration::ZERO;
+
+            self.push_event(RunnerEventKind::GameFinished {
+                outcome: Outcome::Loss,
             });
+
+            self.finished = true;
+        } else {
+            *clock -= elapsed;
         }
+    }
 
-        let turn_complete = game.apply_action(choice.action);
+    fn turn_index(&self) -> usize {
+        match self.turn {
+            Turn::Player1 => 0,
+            Turn::Player2 => 1,
+        }
+    }
 
-        events.push(RunnerEvent {
-            kind: RunnerEventKind::ActionApplied {
-                action: choice.action,
-            },
-            context: Some(RunnerEventContext {
-                game_number,
-                game: game.clone(),
-                turn_number,
-                turn,
-            }),
+    // NOTE - The action the opening prescribes for the next ply, if it is still being
+    // played.
+
+    pub fn opening_action(&self) -> Option<G::Action> {
+        self.opening.get(self.ply).copied()
+    }
+
+    // NOTE - The action drawn for a chance node, which is played in place of asking the
+    // side to move.
+
+    pub fn chance_action(&mut self) -> Option<G::Action> {
+        sample_chance_outcome(&self.game, &mut self.chance_rng)
+    }
+
+    pub fn play(&mut self, choice: Choice<G>) {
+        debug_assert!(!self.finished, "game is already finished");
+
+        // NOTE - Outcomes are from the perspective of the player to move, who is the one
+        // resigning.
+
+        if choice.resign {
+            self.push_event(RunnerEventKind::GameFinished {
+                outcome: Outcome::Loss,
+            });
+
+            self.finished = true;
+
+            return;
+        }
+
+        self.ply += 1;
+
+        if let Some(evaluation) = choice.evaluation {
+            self.push_event(RunnerEventKind::PositionEvaluated { evaluation });
+        }
+
+        let turn_complete = self.game.apply_action(choice.action);
+
+        self.turn_actions.push(choice.action);
+
+        self.push_event(RunnerEventKind::ActionApplied {
+            action: choice.action,
         });
 
-        if let Some(max_turns) = max_turns
-            && turn_number > max_turns
+        // NOTE - A move also ends when the game does, even if the turn had steps left.
+
+        if turn_complete || self.game.outcome() != Outcome::InProgress {
+            let actions = mem::take(&mut self.turn_actions);
+
+            self.push_event(RunnerEventKind::MoveCompleted { actions });
+        }
+
+        if let Some(max_turns) = self.max_turns
+            && self.turn_number > max_turns
         {
-            events.push(RunnerEvent {
-                kind: RunnerEventKind::GameFinished {
-                    outcome: Outcome::Draw,
-                },
-                context: Some(RunnerEventContext {
-                    game_number,
-                    game: game.clone(),
-                    turn_number,
-                    turn,
-                }),
+            self.push_event(RunnerEventKind::GameFinished {
+                outcome: Outcome::Draw,
             });
 
-            break;
+            self.finished = true;
+
+            return;
         }
 
-        match game.outcome() {
+        match self.game.outcome() {
             Outcome::InProgress => {}
             outcome => {
-                events.push(RunnerEvent {
-                    kind: RunnerEventKind::GameFinished { outcome },
-                    context: Some(RunnerEventContext {
-                        game_number,
-                        game: game.clone(),
-                        turn_number,
-                        turn,
-                    }),
-                });
+                self.push_event(RunnerEventKind::GameFinished { outcome });
 
-                break;
+                self.finished = true;
+
+                return;
             }
         }
 
         if turn_complete {
-            events.push(RunnerEvent {
-                kind: RunnerEventKind::TurnFinished,
-                context: Some(RunnerEventContext {
-                    game_number,
-                    game: game.clone(),
-                    turn_number,
-                    turn,
-                }),
-            });
+            self.push_event(RunnerEventKind::TurnFinished);
 
-            game.end_turn();
+            if let Some(time_control) = self.time_control {
+                self.clocks[self.turn_index()] += time_control.increment;
+            }
 
-            turn = turn.advance();
-            turn_number += 1;
-
-            events.push(RunnerEvent {
-                kind: RunnerEventKind::TurnStarted,
-                context: Some(RunnerEventContext {
-                    game_number,
-                    game: game.clone(),
-                    turn_number,
-                    turn,
-                }),
-            });
+            self.game.end_turn();
+
+            self.turn = self.turn.advance();
+            self.turn_number += 1;
+
+            if self.repetitions.is_repeated(&self.game) {
+                self.push_event(RunnerEventKind::GameFinished {
+                    outcome: Outcome::Draw,
+                });
+
+                self.finished = true;
+
+                return;
+            }
+
+            self.push_event(RunnerEventKind::TurnStarted);
         }
     }
 
-    events
+    fn push_event(&mut self, kind: RunnerEventKind<G>) {
+        self.events.push(RunnerEvent {
+            kind,
+            context: Some(RunnerEventContext {
+                game_number: self.game_number,
+                game: self.game.clone(),
+                turn_number: self.turn_number,
+                turn: self.turn,
+            }),
+        });
+    }
 }
 
+#[derive(Clone)]
 pub struct RunnerEvent<G: Game> {
     pub kind: RunnerEventKind<G>,
     pub context: Option<RunnerEventContext<G>>,
 }
 
+#[derive(Clone)]
 pub enum RunnerEventKind<G: Game> {
     RunnerStarted,
     GameStarted,
     TurnStarted,
     PositionEvaluated { evaluation: Evaluation<G> },
     ActionApplied { action: G::Action },
+    MoveCompleted { actions: ActionList<G::Action> },
     TurnFinished,
     GameFinished { outcome: Outcome },
     RunnerFinished,
 }
 
+#[derive(Clone)]
 pub struct RunnerEventContext<G: Game> {
     pub game_number: u32,
     pub game: G,
@@ -331,3 +678,384 @@ pub struct RunnerEventContext<G: Game> {
     pub turn_number: u32,
     pub turn: Turn,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    use crate::core::EventSink;
+    use crate::game::boop::Boop;
+    use crate::game::checkers::{Checkers, CheckersAction};
+    use crate::game::tic_tac_toe::TicTacToe;
+    use crate::player::{ClassicMctsPlayer, RandomPlayer};
+
+    #[derive(Default)]
+    struct ActionRecorder {
+        actions: Vec<(u32, String)>,
+    }
+
+    impl EventSink<RunnerEvent<TicTacToe>> for ActionRecorder {
+        fn emit(&mut self, event: RunnerEvent<TicTacToe>) {
+            if let (RunnerEventKind::ActionApplied { action }, Some(context)) =
+                (event.kind, event.context)
+            {
+                self.actions.push((context.game_number, action.to_string()));
+            }
+        }
+    }
+
+    fn record_games(threads: usize, seed: u64) -> Vec<(u32, String)> {
+        let mut runner = Runner::new(
+            6,
+            ClassicMctsPlayer::<TicTacToe>::new(25),
+            ClassicMctsPlayer::<TicTacToe>::new(25),
+            ActionRecorder::default(),
+        )
+        .with_threads(threads)
+        .with_seed(seed);
+
+        runner.run_parallel();
+
+        runner.sink().actions.clone()
+    }
+
+    fn openings<P: Player<TicTacToe> + Clone + Send>(player: P, plies: usize) -> Vec<Vec<String>> {
+        let mut runner = Runner::new(4, player.clone(), player, ActionRecorder::default())
+            .with_seed(3)
+            .with_opening_plies(u32::try_from(plies).unwrap());
+
+        runner.run();
+
+        (0..4)
+            .map(|game_number| {
+                runner
+                    .sink()
+                    .actions
+                    .iter()
+                    .filter(|(number, _)| *number == game_number)
+                    .take(plies)
+                    .map(|(_, action)| action.clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[derive(Default)]
+    struct MoveRecorder {
+        actions: Vec<String>,
+        moves: Vec<Vec<String>>,
+        turns_finished: usize,
+    }
+
+    impl EventSink<RunnerEvent<Boop>> for MoveRecorder {
+        fn emit(&mut self, event: RunnerEvent<Boop>) {
+            match event.kind {
+                RunnerEventKind::ActionApplied { action } => {
+                    self.actions.push(action.to_string());
+                }
+                RunnerEventKind::MoveCompleted { actions } => {
+                    self.moves
+                        .push(actions.iter().map(ToString::to_string).collect());
+                }
+                RunnerEventKind::TurnFinished => {
+                    self.turns_finished += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    struct ShutdownAfterFirstGame {
+        shutdown_signal: ShutdownSignal,
+        games_finished: u32,
+    }
+
+    impl EventSink<RunnerEvent<TicTacToe>> for ShutdownAfterFirstGame {
+        fn emit(&mut self, event: RunnerEvent<TicTacToe>) {
+            if let RunnerEventKind::GameFinished { .. } = event.kind {
+                self.games_finished += 1;
+                self.shutdown_signal.request();
+            }
+        }
+    }
+
+    // NOTE - Moves a piece and then moves it straight back, so lone kings shuffle between
+    // the same squares.
+
+    #[derive(Clone, Default)]
+    struct ShufflePlayer {
+        last_move: Option<CheckersAction>,
+    }
+
+    impl Player<Checkers> for ShufflePlayer {
+        fn name(&self) -> &'static str {
+            "Shuffle"
+        }
+
+        fn choose_action(&mut self, game: &Checkers, _turn_number: u32) -> Choice<Checkers> {
+            let actions = game.get_possible_actions();
+
+            let action = match self.last_move {
+                Some(CheckersAction::Move { from, to }) => {
+                    CheckersAction::Move { from: to, to: from }
+                }
+                _ => actions[0],
+            };
+
+            let action = if actions.contains(&action) {
+                action
+            } else {
+                actions[0]
+            };
+
+            self.last_move = Some(action);
+
+            Choice {
+                action,
+                evaluation: None,
+                resign: false,
+            }
+        }
+    }
+
+    // NOTE - Resigns as soon as it is asked for a move.
+
+    #[derive(Clone)]
+    struct ResigningPlayer;
+
+    impl Player<Checkers> for ResigningPlayer {
+        fn name(&self) -> &'static str {
+            "Resigning"
+        }
+
+        fn choose_action(&mut self, game: &Checkers, _turn_number: u32) -> Choice<Checkers> {
+            Choice {
+                action: game.get_possible_actions()[0],
+                evaluation: None,
+                resign: true,
+            }
+        }
+    }
+
+    // NOTE - Shares its game and move counts through an `Rc`, so it can neither be sent to
+    // another thread nor cloned per game.
+
+    struct CountingPlayer {
+        player: RandomPlayer,
+        games: Rc<Cell<u32>>,
+        moves: Rc<Cell<u32>>,
+    }
+
+    impl Player<TicTacToe> for CountingPlayer {
+        fn name(&self) -> &'static str {
+            "Counting"
+        }
+
+        fn choose_action(&mut self, game: &TicTacToe, turn_number: u32) -> Choice<TicTacToe> {
+            self.moves.set(self.moves.get() + 1);
+
+            self.player.choose_action(game, turn_number)
+        }
+
+        fn new_game(&mut self) {
+            self.games.set(self.games.get() + 1);
+        }
+    }
+
+    #[derive(Clone)]
+    struct SlowPlayer;
+
+    impl Player<Checkers> for SlowPlayer {
+        fn name(&self) -> &'static str {
+            "Slow"
+        }
+
+        fn choose_action(&mut self, game: &Checkers, _turn_number: u32) -> Choice<Checkers> {
+            std::thread::sleep(Duration::from_millis(50));
+
+            Choice {
+                action: game.get_possible_actions()[0],
+                evaluation: None,
+                resign: false,
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct FinishRecorder {
+        finishes: Vec<(u32, Outcome)>,
+    }
+
+    impl EventSink<RunnerEvent<Checkers>> for FinishRecorder {
+        fn emit(&mut self, event: RunnerEvent<Checkers>) {
+            if let (RunnerEventKind::GameFinished { outcome }, Some(context)) =
+                (event.kind, event.context)
+            {
+                self.finishes.push((context.turn_number, outcome));
+            }
+        }
+    }
+
+    mod run {
+        use super::*;
+
+        #[test]
+        fn should_group_partial_actions_into_moves() {
+            let mut runner = Runner::new(
+                5,
+                RandomPlayer::new(),
+                RandomPlayer::new(),
+                MoveRecorder::default(),
+            )
+            .with_max_turns(200)
+            .with_seed(11);
+
+            runner.run();
+
+            let MoveRecorder {
+                actions,
+                moves,
+                turns_finished,
+            } = runner.sink();
+
+            assert_eq!(&moves.concat(), actions);
+            assert!(moves.len() >= *turns_finished);
+            assert!(moves.iter().any(|actions| actions.len() > 1));
+        }
+
+        #[test]
+        fn should_share_openings_between_paired_games_and_players() {
+            let openings = openings(RandomPlayer::new(), 3);
+
+            assert_eq!(openings[0].len(), 3);
+            assert_eq!(openings[0], openings[1]);
+            assert_eq!(openings[2], openings[3]);
+            assert_ne!(openings[0], openings[2]);
+
+            assert_eq!(openings, self::openings(ClassicMctsPlayer::new(25), 3));
+        }
+
+        #[test]
+        fn should_play_players_that_are_neither_cloneable_nor_sendable() {
+            let games = Rc::new(Cell::new(0));
+            let moves = Rc::new(Cell::new(0));
+
+            let player = CountingPlayer {
+                player: RandomPlayer::new(),
+                games: Rc::clone(&games),
+                moves: Rc::clone(&moves),
+            };
+
+            let mut runner = Runner::new(2, player, RandomPlayer::new(), ActionRecorder::default());
+
+            runner.run();
+
+            assert_eq!(games.get(), 2);
+            assert!(moves.get() >= 4);
+            assert_eq!(
+                runner
+                    .sink()
+                    .actions
+                    .iter()
+                    .map(|(game_number, _)| game_number)
+                    .max(),
+                Some(&1)
+            );
+        }
+
+        #[test]
+        fn should_stop_starting_games_once_shutdown_is_requested() {
+            let shutdown_signal = ShutdownSignal::new();
+
+            let sink = ShutdownAfterFirstGame {
+                shutdown_signal: shutdown_signal.clone(),
+                games_finished: 0,
+            };
+
+            let mut runner = Runner::new(4, RandomPlayer::new(), RandomPlayer::new(), sink)
+                .with_shutdown_signal(shutdown_signal);
+
+            runner.run();
+
+            assert_eq!(runner.sink().games_finished, 1);
+        }
+
+        #[test]
+        fn should_draw_on_the_third_repetition() {
+            let game = Checkers::from_notation("8/8/8/8/3X4/8/8/O7 up move 0")
+                .expect("unable to parse notation");
+
+            let mut runner = Runner::new(
+                1,
+                ShufflePlayer::default(),
+                ShufflePlayer::default(),
+                FinishRecorder::default(),
+            )
+            .with_initial_game(game);
+
+            runner.run();
+
+            let [(turn_number, outcome)] = runner.sink().finishes[..] else {
+                panic!("expected one finished game");
+            };
+
+            assert_eq!(outcome, Outcome::Draw);
+            assert_eq!(turn_number, 8);
+        }
+
+        #[test]
+        fn should_end_the_game_as_a_loss_for_the_resigning_player() {
+            let mut runner = Runner::new(
+                1,
+                ShufflePlayer::default(),
+                ResigningPlayer,
+                FinishRecorder::default(),
+            );
+
+            runner.run();
+
+            let [(turn_number, outcome)] = runner.sink().finishes[..] else {
+                panic!("expected one finished game");
+            };
+
+            assert_eq!(outcome, Outcome::Loss);
+            assert_eq!(turn_number, 1);
+        }
+
+        #[test]
+        fn should_end_the_game_as_a_loss_when_a_clock_runs_out() {
+            let mut runner = Runner::new(
+                1,
+                ShufflePlayer::default(),
+                SlowPlayer,
+                FinishRecorder::default(),
+            )
+            .with_time_control(TimeControl::new(Duration::from_millis(20), Duration::ZERO));
+
+            runner.run();
+
+            let [(turn_number, outcome)] = runner.sink().finishes[..] else {
+                panic!("expected one finished game");
+            };
+
+            assert_eq!(outcome, Outcome::Loss);
+            assert_eq!(turn_number, 1);
+        }
+    }
+
+    mod run_parallel {
+        use super::*;
+
+        #[test]
+        fn should_replay_seeded_games_across_thread_counts() {
+            let serial = record_games(1, 7);
+
+            assert!(!serial.is_empty());
+            assert_eq!(serial, record_games(1, 7));
+            assert_eq!(serial, record_games(3, 7));
+        }
+    }
+}
@@ -0,0 +1,84 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scoring {
+    pub win: f32,
+    pub draw: f32,
+    pub loss: f32,
+}
+
+impl Default for Scoring {
+    fn default() -> Self {
+        Self {
+            win: 1.0,
+            draw: 0.5,
+            loss: 0.0,
+        }
+    }
+}
+
+impl Scoring {
+    // NOTE - Two-sided 95% confidence.
+
+    pub const DEFAULT_Z: f32 = 1.96;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_draw(mut self, draw: f32) -> Self {
+        self.draw = draw;
+
+        self
+    }
+
+    pub fn points(&self, wins: u32, losses: u32, draws: u32) -> f32 {
+        self.win * wins as f32 + self.draw * draws as f32 + self.loss * losses as f32
+    }
+
+    // NOTE - Points rescaled so that losing every game is 0 and winning every game is 1.
+
+    pub fn share(&self, wins: u32, losses: u32, draws: u32) -> f32 {
+        let games = wins + losses + draws;
+
+        if games == 0 || self.win <= self.loss {
+            return 0.5;
+        }
+
+        let points = self.points(wins, losses, draws) - self.loss * games as f32;
+
+        points / ((self.win - self.loss) * games as f32)
+    }
+
+    // NOTE - Wilson score interval for a share of `games`.
+
+    pub fn wilson_interval(share: f32, games: u32, z: f32) -> (f32, f32) {
+        if games == 0 {
+            return (0.0, 1.0);
+        }
+
+        let n = games as f32;
+        let p = share.clamp(0.0, 1.0);
+
+        let denominator = 1.0 + z * z / n;
+        let center = (p + z * z / (2.0 * n)) / denominator;
+        let margin = z * (p * (1.0 - p) / n + z * z / (4.0 * n * n)).sqrt() / denominator;
+
+        ((center - margin).max(0.0), (center + margin).min(1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod share {
+        use super::*;
+
+        #[test]
+        fn should_count_draws_as_configured() {
+            let scoring = Scoring::new();
+
+            assert!((scoring.share(2, 2, 6) - 0.5).abs() < f32::EPSILON);
+            assert!((scoring.with_draw(0.0).share(2, 2, 6) - 0.2).abs() < f32::EPSILON);
+        }
+    }
+}
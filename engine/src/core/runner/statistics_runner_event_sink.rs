@@ -1,7 +1,9 @@
 use crate::core::event::EventSink;
 use crate::core::game::Game;
 use crate::core::game::Outcome;
+use crate::core::runner::result_counts::ResultCounts;
 use crate::core::runner::runner::{RunnerEvent, RunnerEventContext, RunnerEventKind};
+use crate::core::runner::scoring::Scoring;
 use crate::core::turn::Turn;
 
 #[derive(Clone, Copy, Default)]
@@ -10,6 +12,13 @@ pub struct StatisticsRunnerEventSink {
     pub player_1_wins: u32,
     pub player_2_wins: u32,
     pub draws: u32,
+
+    // NOTE - Results split by which player moved first.
+    pub player_1_first: ResultCounts,
+    pub player_2_first: ResultCounts,
+
+    scoring: Scoring,
+    first_turn: Option<Turn>,
 }
 
 impl StatisticsRunnerEventSink {
@@ -19,8 +28,58 @@ impl StatisticsRunnerEventSink {
             player_1_wins: 0,
             player_2_wins: 0,
             draws: 0,
+
+            player_1_first: ResultCounts::default(),
+            player_2_first: ResultCounts::default(),
+
+            scoring: Scoring::default(),
+            first_turn: None,
         }
     }
+
+    pub fn with_scoring(mut self, scoring: Scoring) -> Self {
+        self.scoring = scoring;
+
+        self
+    }
+
+    pub fn scoring(&self) -> Scoring {
+        self.scoring
+    }
+
+    pub fn player_1_points(&self) -> f32 {
+        self.scoring
+            .points(self.player_1_wins, self.player_2_wins, self.draws)
+    }
+
+    pub fn player_2_points(&self) -> f32 {
+        self.scoring
+            .points(self.player_2_wins, self.player_1_wins, self.draws)
+    }
+
+    pub fn player_1_score(&self) -> f32 {
+        self.scoring
+            .share(self.player_1_wins, self.player_2_wins, self.draws)
+    }
+
+    // NOTE - Player 1's score minus player 2's, from -1 (player 2 won everything) to 1.
+
+    pub fn score_difference(&self) -> f32 {
+        2.0 * self.player_1_score() - 1.0
+    }
+
+    pub fn score_difference_interval(&self, z: f32) -> (f32, f32) {
+        let (low, high) = Scoring::wilson_interval(self.player_1_score(), self.total_games, z);
+
+        (2.0 * low - 1.0, 2.0 * high - 1.0)
+    }
+
+    fn print_counts(label: &str, counts: &ResultCounts) {
+        println!(
+            "\t{label}: {} games, {} / {} / {} (P1 / P2 / Draw)",
+            counts.games, counts.player_1_wins, counts.player_2_wins, counts.draws
+        );
+    }
 }
 
 impl<G: Game> EventSink<RunnerEvent<G>> for StatisticsRunnerEventSink {
@@ -28,19 +87,33 @@ impl<G: Game> EventSink<RunnerEvent<G>> for StatisticsRunnerEventSink {
         let RunnerEvent { kind, context, .. } = event;
 
         match kind {
+            RunnerEventKind::GameStarted => {
+                self.first_turn = context.map(|RunnerEventContext { turn, .. }| turn);
+            }
             RunnerEventKind::GameFinished { outcome } => {
                 let RunnerEventContext { turn, .. } = context.expect("event is missing context");
 
+                let counts = match self.first_turn {
+                    Some(Turn::Player2) => &mut self.player_2_first,
+                    _ => &mut self.player_1_first,
+                };
+
                 self.total_games += 1;
+                counts.games += 1;
 
                 match (outcome, turn) {
                     (Outcome::Win, Turn::Player1) | (Outcome::Loss, Turn::Player2) => {
                         self.player_1_wins += 1;
+                        counts.player_1_wins += 1;
                     }
                     (Outcome::Win, Turn::Player2) | (Outcome::Loss, Turn::Player1) => {
                         self.player_2_wins += 1;
+                        counts.player_2_wins += 1;
+                    }
+                    (Outcome::Draw, _) => {
+                        self.draws += 1;
+                        counts.draws += 1;
                     }
-                    (Outcome::Draw, _) => self.draws += 1,
                     _ => {}
                 }
             }
@@ -62,8 +135,86 @@ impl<G: Game> EventSink<RunnerEvent<G>> for StatisticsRunnerEventSink {
                     self.draws,
                     self.draws as f32 / self.total_games as f32 * 100.0
                 );
+                println!(
+                    "\tScore: {:.1} - {:.1}",
+                    self.player_1_points(),
+                    self.player_2_points()
+                );
+
+                let (low, high) = self.score_difference_interval(Scoring::DEFAULT_Z);
+
+                println!(
+                    "\tScore Difference: {:+.2}% (95% CI {:+.2}% to {:+.2}%)",
+                    self.score_difference() * 100.0,
+                    low * 100.0,
+                    high * 100.0
+                );
+
+                Self::print_counts("Player 1 First", &self.player_1_first);
+                Self::print_counts("Player 2 First", &self.player_2_first);
             }
             _ => {}
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Runner;
+    use crate::game::tic_tac_toe::TicTacToe;
+    use crate::player::RandomPlayer;
+
+    mod emit {
+        use super::*;
+
+        #[test]
+        fn should_split_results_by_first_player() {
+            let mut runner = Runner::<TicTacToe, _, _, _>::new(
+                10,
+                RandomPlayer::new(),
+                RandomPlayer::new(),
+                StatisticsRunnerEventSink::new(),
+            )
+            .with_seed(3);
+
+            runner.run();
+
+            let statistics = runner.sink();
+
+            let ResultCounts {
+                games,
+                player_1_wins,
+                player_2_wins,
+                draws,
+            } = statistics.player_1_first;
+
+            assert_eq!(games, 5);
+            assert_eq!(statistics.player_2_first.games, 5);
+            assert_eq!(
+                player_1_wins + statistics.player_2_first.player_1_wins,
+                statistics.player_1_wins
+            );
+            assert_eq!(player_1_wins + player_2_wins + draws, games);
+        }
+    }
+
+    mod score_difference_interval {
+        use super::*;
+
+        #[test]
+        fn should_contain_an_even_score_for_an_all_draw_match() {
+            let statistics = StatisticsRunnerEventSink {
+                total_games: 20,
+                draws: 20,
+                ..StatisticsRunnerEventSink::new()
+            };
+
+            let (low, high) = statistics.score_difference_interval(Scoring::DEFAULT_Z);
+
+            assert!(statistics.score_difference().abs() < f32::EPSILON);
+            assert!(low < 0.0 && high > 0.0);
+        }
+    }
+}
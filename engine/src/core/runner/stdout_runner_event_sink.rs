@@ -1,17 +1,28 @@
 use crate::core::event::EventSink;
-use crate::core::game::Game;
 use crate::core::runner::runner::{RunnerEvent, RunnerEventContext, RunnerEventKind};
+use crate::render::{PolicyOverlay, RenderableGame};
 
 #[derive(Default)]
-pub struct StdoutRunnerEventSink;
+pub struct StdoutRunnerEventSink {
+    verbosity: u8,
+}
 
 impl StdoutRunnerEventSink {
     pub fn new() -> Self {
-        StdoutRunnerEventSink
+        StdoutRunnerEventSink { verbosity: 0 }
+    }
+
+    // NOTE - At verbosity 1, each evaluated position also prints the search policy as shades
+    // over the board, and at 2 and above as percentages.
+
+    pub fn with_verbosity(mut self, verbosity: u8) -> Self {
+        self.verbosity = verbosity;
+
+        self
     }
 }
 
-impl<G: Game> EventSink<RunnerEvent<G>> for StdoutRunnerEventSink {
+impl<G: RenderableGame> EventSink<RunnerEvent<G>> for StdoutRunnerEventSink {
     fn emit(&mut self, event: RunnerEvent<G>) {
         let RunnerEvent { kind, context } = event;
 
@@ -32,6 +43,16 @@ impl<G: Game> EventSink<RunnerEvent<G>> for StdoutRunnerEventSink {
             RunnerEventKind::TurnStarted => {
                 println!("--- Turn #{} ---\n", turn_number + 1);
             }
+            RunnerEventKind::PositionEvaluated { evaluation } if self.verbosity > 0 => {
+                let overlay = if self.verbosity == 1 {
+                    PolicyOverlay::Shades
+                } else {
+                    PolicyOverlay::Percentages
+                };
+
+                println!("{turn:?} policy (value {:+.3})\n", evaluation.value);
+                println!("{}", overlay.render(&game, &evaluation));
+            }
             RunnerEventKind::ActionApplied { action } => {
                 println!("{turn:?} {action}\n");
                 println!("{}", game.display(turn));
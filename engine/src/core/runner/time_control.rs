@@ -0,0 +1,16 @@
+use std::time::Duration;
+
+// NOTE - Each side starts a game with `initial` on its clock and gains `increment` after
+// every turn it completes. Running out of time loses the game.
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TimeControl {
+    pub initial: Duration,
+    pub increment: Duration,
+}
+
+impl TimeControl {
+    pub fn new(initial: Duration, increment: Duration) -> Self {
+        Self { initial, increment }
+    }
+}
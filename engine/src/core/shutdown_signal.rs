@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[derive(Clone, Debug, Default)]
+pub struct ShutdownSignal {
+    requested: Arc<AtomicBool>,
+}
+
+impl ShutdownSignal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // NOTE - The first SIGINT or SIGTERM requests a shutdown, so runs stop starting new
+    // games and finish the ones in flight. A second one exits immediately.
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn install() -> Self {
+        let signal = Self::new();
+        let handler_signal = signal.clone();
+
+        ctrlc::set_handler(move || {
+            if handler_signal.requested.swap(true, Ordering::SeqCst) {
+                eprintln!("\nInterrupted again, exiting without finishing.");
+                std::process::exit(130);
+            }
+
+            eprintln!("\nShutting down after in-flight games. Interrupt again to exit now.");
+        })
+        .expect("failed to install signal handler");
+
+        signal
+    }
+
+    pub fn request(&self) {
+        self.requested.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+}
@@ -0,0 +1,334 @@
+use crate::fixtures::fixture::Fixture;
+
+pub static BOOP_FIXTURES: [Fixture; 7] = [
+    Fixture {
+        name: "opening",
+        description: "Empty board with full pools.",
+        position: "
+            Player: x x x x x x x x
+            Opponent: o o o o o o o o
+
+            ╔═══╤═══╤═══╤═══╤═══╤═══╗
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╚═══╧═══╧═══╧═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "opening-reply",
+        description: "Opponent kitten in the center after the first move.",
+        position: "
+            Player: x x x x x x x x
+            Opponent: o o o o o o o
+
+            ╔═══╤═══╤═══╤═══╤═══╤═══╗
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │ o │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╚═══╧═══╧═══╧═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "tactical-break-line",
+        description: "Opponent threatens three kittens in a row; a diagonal placement boops both apart.",
+        position: "
+            Player: x x x x x x x
+            Opponent: o o o o o o
+
+            ╔═══╤═══╤═══╤═══╤═══╤═══╗
+            ║   │   │   │   │   │ x ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │ o │ o │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╚═══╧═══╧═══╧═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "pre-graduation",
+        description: "Two kittens in a row with both ends open, one placement from graduating.",
+        position: "
+            Player: x x x x x x
+            Opponent: o o o o o o
+
+            ╔═══╤═══╤═══╤═══╤═══╤═══╗
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │ x │ x │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │ o │ o ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╚═══╧═══╧═══╧═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "graduate-choice",
+        description: "Four kittens in a row, choosing which three graduate.",
+        position: "
+            Player: x x x x
+            Opponent: o o o o
+            Phase: Graduate
+
+            ╔═══╤═══╤═══╤═══╤═══╤═══╗
+            ║   │   │   │   │   │ o ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │ x │ x │ x │ x │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │ o ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║ o │   │ o │   │   │   ║
+            ╚═══╧═══╧═══╧═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "near-win",
+        description: "Two cats in a row with a cat in the pool, one placement from winning.",
+        position: "
+            Player: X x x x x x
+            Opponent: o o o o o
+
+            ╔═══╤═══╤═══╤═══╤═══╤═══╗
+            ║ o │   │   │   │   │ o ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │ X │ X │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │ o ║
+            ╚═══╧═══╧═══╧═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "max-pool",
+        description: "All eight kittens on the board without a line, so one must graduate alone.",
+        position: "
+            Player:
+            Opponent: o o o o o
+            Phase: Graduate
+
+            ╔═══╤═══╤═══╤═══╤═══╤═══╗
+            ║ x │ x │   │ x │ x │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║ x │ x │   │ x │ x │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║ o │   │ o │   │   │   ║
+            ╟───┼───┼───┼───┼───┼───╢
+            ║   │   │   │   │   │ o ║
+            ╚═══╧═══╧═══╧═══╧═══╧═══╝
+        ",
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{Game, Outcome};
+    use crate::game::boop::{Boop, BoopAction, BoopActionEncoder, BoopPhase, BoopStateEncoder};
+    use crate::neural_network::{ActionEncoder, StateEncoder};
+
+    fn parse_fixture(name: &str) -> Boop {
+        Fixture::find(&BOOP_FIXTURES, name)
+            .expect("missing fixture")
+            .game()
+            .expect("unable to parse fixture")
+    }
+
+    mod game {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_every_fixture_through_display() {
+            for fixture in &BOOP_FIXTURES {
+                let game: Boop = fixture.game().expect("unable to parse fixture");
+
+                let parsed: Boop = format!("{game}").parse().expect("unable to parse display");
+
+                assert_eq!(parsed, game, "{}", fixture.name);
+            }
+        }
+
+        #[test]
+        fn should_describe_positions_in_progress() {
+            for fixture in &BOOP_FIXTURES {
+                let game: Boop = fixture.game().expect("unable to parse fixture");
+
+                assert_eq!(game.outcome(), Outcome::InProgress, "{}", fixture.name);
+                assert!(!game.get_possible_actions().is_empty(), "{}", fixture.name);
+            }
+        }
+    }
+
+    mod positions {
+        use super::*;
+
+        #[test]
+        fn should_offer_a_winning_placement_in_near_win() {
+            let game = parse_fixture("near-win");
+
+            let wins = game.get_possible_actions().into_iter().any(|action| {
+                let mut game = game.clone();
+
+                game.apply_action(action);
+
+                game.outcome() == Outcome::Win
+            });
+
+            assert!(wins);
+        }
+
+        #[test]
+        fn should_offer_a_graduation_in_pre_graduation() {
+            let game = parse_fixture("pre-graduation");
+
+            let graduates = game.get_possible_actions().into_iter().any(|action| {
+                let mut game = game.clone();
+
+                !game.apply_action(action) && game.phase == BoopPhase::Graduate
+            });
+
+            assert!(graduates);
+        }
+
+        #[test]
+        fn should_only_graduate_single_pieces_in_max_pool() {
+            let game = parse_fixture("max-pool");
+
+            let actions = game.get_possible_actions();
+
+            assert_eq!(actions.len(), usize::from(Boop::POOL_SIZE));
+            assert!(actions.iter().all(
+                |action| matches!(action, BoopAction::Graduate { mask } if mask.is_power_of_two())
+            ));
+        }
+    }
+
+    mod encoders {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_every_legal_action() {
+            let action_encoder = BoopActionEncoder::new();
+
+            for fixture in &BOOP_FIXTURES {
+                let game: Boop = fixture.game().expect("unable to parse fixture");
+
+                let mut action_ids = vec![];
+
+                for action in game.get_possible_actions() {
+                    let action_id = action_encoder.encode(&action);
+
+                    assert!(
+                        action_id < BoopActionEncoder::ACTION_COUNT,
+                        "{}",
+                        fixture.name
+                    );
+                    assert_eq!(action_encoder.decode(action_id), action, "{}", fixture.name);
+
+                    action_ids.push(action_id);
+                }
+
+                action_ids.sort_unstable();
+                action_ids.dedup();
+
+                assert_eq!(
+                    action_ids.len(),
+                    game.get_possible_actions().len(),
+                    "{}",
+                    fixture.name
+                );
+            }
+        }
+
+        #[test]
+        fn should_encode_pieces_and_pools() {
+            let state_encoder = BoopStateEncoder::new();
+
+            let plane_size = Boop::BOARD_SIZE * Boop::BOARD_SIZE;
+
+            for fixture in &BOOP_FIXTURES {
+                let game: Boop = fixture.game().expect("unable to parse fixture");
+
+                let planes = state_encoder.encode(&game);
+
+                assert_eq!(
+                    planes.len(),
+                    state_encoder.shape().iter().product::<usize>(),
+                    "{}",
+                    fixture.name
+                );
+
+                let plane_sum = |index: usize| {
+                    planes[index * plane_size..(index + 1) * plane_size]
+                        .iter()
+                        .sum::<f32>()
+                };
+
+                for (index, bitboard) in [
+                    game.player_cats,
+                    game.player_kittens,
+                    game.opponent_cats,
+                    game.opponent_kittens,
+                ]
+                .into_iter()
+                .enumerate()
+                {
+                    assert!(
+                        (plane_sum(index) - bitboard.count_ones() as f32).abs() < f32::EPSILON,
+                        "{}",
+                        fixture.name
+                    );
+                }
+
+                let kittens_available = f32::from(game.player_pool().kittens_available);
+
+                assert!(
+                    (planes[6 * plane_size] * f32::from(Boop::POOL_SIZE) - kittens_available).abs()
+                        < 1e-6,
+                    "{}",
+                    fixture.name
+                );
+            }
+        }
+    }
+}
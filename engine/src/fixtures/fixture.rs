@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fixture {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub position: &'static str,
+}
+
+impl Fixture {
+    pub fn find(fixtures: &'static [Fixture], name: &str) -> Option<&'static Fixture> {
+        fixtures.iter().find(|fixture| fixture.name == name)
+    }
+
+    // NOTE - Positions are written in the board notation parsed by each game, indented to
+    // sit in the source, so every line is trimmed before parsing. The side to move is X.
+
+    pub fn game<G: FromStr<Err = String>>(&self) -> Result<G, String> {
+        self.position
+            .trim()
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join("\n")
+            .parse()
+            .map_err(|error| format!("fixture {}: {error}", self.name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fixtures::{BOOP_FIXTURES, TIC_TAC_TOE_FIXTURES};
+
+    mod find {
+        use super::*;
+
+        #[test]
+        fn should_find_fixtures_by_name() {
+            let fixture = Fixture::find(&BOOP_FIXTURES, "max-pool").expect("missing fixture");
+
+            assert_eq!(fixture.name, "max-pool");
+            assert!(Fixture::find(&BOOP_FIXTURES, "missing").is_none());
+        }
+
+        #[test]
+        fn should_have_unique_names() {
+            for fixtures in [&BOOP_FIXTURES[..], &TIC_TAC_TOE_FIXTURES[..]] {
+                let mut names: Vec<&str> = fixtures.iter().map(|fixture| fixture.name).collect();
+
+                names.sort_unstable();
+                names.dedup();
+
+                assert_eq!(names.len(), fixtures.len());
+            }
+        }
+    }
+}
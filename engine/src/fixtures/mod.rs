@@ -0,0 +1,7 @@
+mod boop_fixtures;
+mod fixture;
+mod tic_tac_toe_fixtures;
+
+pub use boop_fixtures::BOOP_FIXTURES;
+pub use fixture::Fixture;
+pub use tic_tac_toe_fixtures::TIC_TAC_TOE_FIXTURES;
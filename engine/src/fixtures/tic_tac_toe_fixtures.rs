@@ -0,0 +1,197 @@
+use crate::fixtures::fixture::Fixture;
+
+pub static TIC_TAC_TOE_FIXTURES: [Fixture; 6] = [
+    Fixture {
+        name: "opening",
+        description: "Empty board.",
+        position: "
+            ╔═══╤═══╤═══╗
+            ║   │   │   ║
+            ╟───┼───┼───╢
+            ║   │   │   ║
+            ╟───┼───┼───╢
+            ║   │   │   ║
+            ╚═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "center-reply",
+        description: "Opponent mark in the center after the first move.",
+        position: "
+            ╔═══╤═══╤═══╗
+            ║   │   │   ║
+            ╟───┼───┼───╢
+            ║   │ O │   ║
+            ╟───┼───┼───╢
+            ║   │   │   ║
+            ╚═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "must-block",
+        description: "Opponent threatens the top row.",
+        position: "
+            ╔═══╤═══╤═══╗
+            ║ O │ O │   ║
+            ╟───┼───┼───╢
+            ║   │ X │   ║
+            ╟───┼───┼───╢
+            ║   │   │   ║
+            ╚═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "block-and-fork",
+        description: "Blocking the opponent's diagonal also creates two threats.",
+        position: "
+            ╔═══╤═══╤═══╗
+            ║ X │   │ O ║
+            ╟───┼───┼───╢
+            ║   │ O │   ║
+            ╟───┼───┼───╢
+            ║   │   │ X ║
+            ╚═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "near-win",
+        description: "Two marks in the top row with the third square open.",
+        position: "
+            ╔═══╤═══╤═══╗
+            ║ X │ X │   ║
+            ╟───┼───┼───╢
+            ║   │ O │   ║
+            ╟───┼───┼───╢
+            ║   │   │ O ║
+            ╚═══╧═══╧═══╝
+        ",
+    },
+    Fixture {
+        name: "drawn",
+        description: "Full board without a line.",
+        position: "
+            ╔═══╤═══╤═══╗
+            ║ O │ X │ O ║
+            ╟───┼───┼───╢
+            ║ O │ X │ X ║
+            ╟───┼───┼───╢
+            ║ X │ O │ O ║
+            ╚═══╧═══╧═══╝
+        ",
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{Game, Outcome};
+    use crate::game::tic_tac_toe::{
+        TicTacToe, TicTacToeAction, TicTacToeActionEncoder, TicTacToeStateEncoder,
+    };
+    use crate::neural_network::{ActionEncoder, StateEncoder};
+
+    fn parse_fixture(name: &str) -> TicTacToe {
+        Fixture::find(&TIC_TAC_TOE_FIXTURES, name)
+            .expect("missing fixture")
+            .game()
+            .expect("unable to parse fixture")
+    }
+
+    fn winning_actions(game: &TicTacToe) -> usize {
+        game.get_possible_actions()
+            .into_iter()
+            .filter(|&action| {
+                let mut game = game.clone();
+
+                game.apply_action(action);
+
+                game.outcome() == Outcome::Win
+            })
+            .count()
+    }
+
+    mod game {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_every_fixture_through_display() {
+            for fixture in &TIC_TAC_TOE_FIXTURES {
+                let game: TicTacToe = fixture.game().expect("unable to parse fixture");
+
+                let parsed: TicTacToe = format!("{game}").parse().expect("unable to parse display");
+
+                assert_eq!(parsed, game, "{}", fixture.name);
+            }
+        }
+    }
+
+    mod positions {
+        use super::*;
+
+        #[test]
+        fn should_offer_a_win_in_near_win() {
+            assert_eq!(winning_actions(&parse_fixture("near-win")), 1);
+        }
+
+        #[test]
+        fn should_create_two_threats_in_block_and_fork() {
+            let mut game = parse_fixture("block-and-fork");
+
+            game.apply_action(TicTacToeAction::Place { index: 6 });
+
+            assert_eq!(winning_actions(&game), 2);
+        }
+
+        #[test]
+        fn should_finish_drawn() {
+            assert_eq!(parse_fixture("drawn").outcome(), Outcome::Draw);
+        }
+    }
+
+    mod encoders {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_every_legal_action() {
+            let action_encoder = TicTacToeActionEncoder;
+
+            for fixture in &TIC_TAC_TOE_FIXTURES {
+                let game: TicTacToe = fixture.game().expect("unable to parse fixture");
+
+                for action in game.get_possible_actions() {
+                    let action_id = action_encoder.encode(&action);
+
+                    assert!(action_id < TicTacToeActionEncoder::ACTION_COUNT);
+                    assert_eq!(action_encoder.decode(action_id), action, "{}", fixture.name);
+                }
+            }
+        }
+
+        #[test]
+        fn should_encode_marks() {
+            let state_encoder = TicTacToeStateEncoder::new();
+
+            for fixture in &TIC_TAC_TOE_FIXTURES {
+                let game: TicTacToe = fixture.game().expect("unable to parse fixture");
+
+                let planes = state_encoder.encode(&game);
+
+                let plane_size = TicTacToe::BOARD_SIZE * TicTacToe::BOARD_SIZE;
+
+                for (index, bitboard) in [game.player_marks, game.opponent_marks]
+                    .into_iter()
+                    .enumerate()
+                {
+                    let encoded = planes[index * plane_size..(index + 1) * plane_size]
+                        .iter()
+                        .enumerate()
+                        .filter(|&(_, &value)| value > 0.5)
+                        .fold(0u16, |bits, (square, _)| bits | (1 << square));
+
+                    assert_eq!(encoded, bitboard, "{}", fixture.name);
+                }
+            }
+        }
+    }
+}
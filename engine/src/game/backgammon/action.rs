@@ -0,0 +1,17 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action {
+    Roll { high: u8, low: u8 },
+    Move { from: u8, die: u8 },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Action::Roll { high, low } => write!(f, "rolls {high}-{low}."),
+            Action::Move { from, die } if die >= from => write!(f, "bears off from {from}."),
+            Action::Move { from, die } => write!(f, "moves {from}/{}.", from - die),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::game::backgammon::action::Action;
+use crate::game::backgammon::backgammon::Backgammon;
+use crate::neural_network::ActionEncoder;
+
+// NOTE - One id per starting point and die, `(from - 1) * 6 + (die - 1)`. Rolls are chance
+// outcomes, which search takes from `chance_outcomes` rather than the policy, so they are
+// never encoded.
+
+#[derive(Clone, Copy, Default)]
+pub struct BackgammonActionEncoder;
+
+impl BackgammonActionEncoder {
+    pub fn new() -> Self {
+        BackgammonActionEncoder
+    }
+}
+
+impl ActionEncoder<Backgammon> for BackgammonActionEncoder {
+    const ACTION_COUNT: usize = Backgammon::POINT_COUNT * Backgammon::DIE_FACES;
+
+    fn encode(&self, action: &Action) -> usize {
+        match *action {
+            Action::Move { from, die } => {
+                (from as usize - 1) * Backgammon::DIE_FACES + (die as usize - 1)
+            }
+            Action::Roll { .. } => unreachable!("rolls are chance outcomes"),
+        }
+    }
+
+    fn decode(&self, action_id: usize) -> Action {
+        debug_assert!(action_id < Self::ACTION_COUNT);
+
+        Action::Move {
+            from: u8::try_from(action_id / Backgammon::DIE_FACES + 1).unwrap(),
+            die: u8::try_from(action_id % Backgammon::DIE_FACES + 1).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,593 @@
+use std::{fmt, mem, str};
+
+use crate::core::{ActionList, Game, Outcome, Turn, hash_position};
+use crate::game::backgammon::action::Action;
+
+// NOTE - A Backgammon race, where both sides have already passed each other, so no checker
+// can be hit or blocked and the game is decided by rolling and bearing off. Each turn
+// starts with a roll, a chance node that search weighs by its probability rather than
+// choosing. Without contact every die can always be played, so the rules that force the
+// larger die or both dice never apply. The doubling cube and gammons are left out, and a
+// race is a plain win or loss.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Backgammon {
+    // NOTE - Checkers per point, numbered 1 to 24 from each side's own point of view and
+    // counting down towards home, with index 0 holding the checkers borne off.
+    pub player_points: [u8; 25],
+    pub opponent_points: [u8; 25],
+
+    // NOTE - The unused dice of the current roll in descending order, padded with zeros.
+    // All zeros before the roll.
+    pub dice: [u8; 4],
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Roll,
+    Move,
+    Finished,
+}
+
+impl Backgammon {
+    pub const POINT_COUNT: usize = 24;
+    pub const CHECKER_COUNT: u8 = 15;
+    pub const DIE_FACES: usize = 6;
+
+    const HOME_POINT_COUNT: usize = 6;
+
+    const STARTING_POINTS: [(usize, u8); 4] = [(12, 5), (8, 3), (6, 5), (5, 2)];
+
+    const SYMMETRY_COUNT: u8 = 1;
+
+    pub fn phase(&self) -> Phase {
+        if self.outcome() != Outcome::InProgress {
+            Phase::Finished
+        } else if self.dice[0] == 0 {
+            Phase::Roll
+        } else {
+            Phase::Move
+        }
+    }
+
+    pub fn player_pip_count(&self) -> u32 {
+        Self::pip_count(&self.player_points)
+    }
+
+    pub fn opponent_pip_count(&self) -> u32 {
+        Self::pip_count(&self.opponent_points)
+    }
+
+    fn pip_count(points: &[u8; 25]) -> u32 {
+        points
+            .iter()
+            .enumerate()
+            .map(|(point, &count)| u32::try_from(point).unwrap() * u32::from(count))
+            .sum()
+    }
+
+    fn highest_point(points: &[u8; 25]) -> usize {
+        (1..=Self::POINT_COUNT)
+            .rev()
+            .find(|&point| points[point] > 0)
+            .unwrap_or(0)
+    }
+
+    // NOTE - A checker bears off with the exact die, or with a larger one from the highest
+    // occupied point, and only once all of the side's checkers are home.
+
+    fn can_move(&self, from: usize, die: usize) -> bool {
+        if self.player_points[from] == 0 {
+            return false;
+        }
+
+        if from > die {
+            return true;
+        }
+
+        let highest_point = Self::highest_point(&self.player_points);
+
+        highest_point <= Self::HOME_POINT_COUNT && (from == die || from == highest_point)
+    }
+
+    fn use_die(&mut self, die: u8) {
+        let index = self
+            .dice
+            .iter()
+            .position(|&x| x == die)
+            .expect("die must have been rolled");
+
+        self.dice[index] = 0;
+        self.dice.sort_unstable_by(|x, y| y.cmp(x));
+    }
+
+    fn format_points(points: &[u8; 25]) -> String {
+        let mut entries: Vec<String> = (1..=Self::POINT_COUNT)
+            .rev()
+            .filter(|&point| points[point] > 0)
+            .map(|point| format!("{point}:{}", points[point]))
+            .collect();
+
+        if points[0] > 0 {
+            entries.push(format!("off:{}", points[0]));
+        }
+
+        entries.join(",")
+    }
+
+    fn parse_points(field: &str) -> Result<[u8; 25], String> {
+        let mut points = [0; 25];
+
+        for entry in field.split(',') {
+            let (point, count) = entry
+                .split_once(':')
+                .ok_or_else(|| format!("invalid point: {entry}"))?;
+
+            let point = match point {
+                "off" => 0,
+                point => match point.parse() {
+                    Ok(point) if (1..=Self::POINT_COUNT).contains(&point) => point,
+                    _ => return Err(format!("invalid point: {entry}")),
+                },
+            };
+
+            points[point] = count
+                .parse()
+                .map_err(|_| format!("invalid count: {entry}"))?;
+        }
+
+        let total: u32 = points.iter().copied().map(u32::from).sum();
+
+        if total != u32::from(Self::CHECKER_COUNT) {
+            return Err(format!(
+                "expected {} checkers, found {total}",
+                Self::CHECKER_COUNT
+            ));
+        }
+
+        Ok(points)
+    }
+
+    fn format_dice(dice: [u8; 4]) -> String {
+        if dice[0] == 0 {
+            return "-".to_string();
+        }
+
+        let dice: Vec<String> = dice
+            .iter()
+            .filter(|&&die| die > 0)
+            .map(u8::to_string)
+            .collect();
+
+        dice.join("-")
+    }
+
+    fn parse_dice(field: &str) -> Result<[u8; 4], String> {
+        let mut dice = [0; 4];
+
+        if field == "-" {
+            return Ok(dice);
+        }
+
+        let values: Vec<&str> = field.split('-').collect();
+
+        if values.len() > dice.len() {
+            return Err(format!("invalid dice: {field}"));
+        }
+
+        for (die, value) in dice.iter_mut().zip(values) {
+            *die = match value.parse() {
+                Ok(die) if (1..=6).contains(&die) => die,
+                _ => return Err(format!("invalid dice: {field}")),
+            };
+        }
+
+        dice.sort_unstable_by(|x, y| y.cmp(x));
+
+        Ok(dice)
+    }
+
+    fn from_fields(player: &str, opponent: &str, dice: &str) -> Result<Self, String> {
+        let game = Backgammon {
+            player_points: Self::parse_points(player)?,
+            opponent_points: Self::parse_points(opponent)?,
+
+            dice: Self::parse_dice(dice)?,
+        };
+
+        if Self::highest_point(&game.player_points) + Self::highest_point(&game.opponent_points)
+            > Self::POINT_COUNT
+        {
+            return Err("checkers are still in contact".to_string());
+        }
+
+        Ok(game)
+    }
+}
+
+impl Game for Backgammon {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Backgammon;
+    type Config = ();
+
+    fn new_with_config((): ()) -> Self {
+        let mut points = [0; 25];
+
+        for (point, count) in Self::STARTING_POINTS {
+            points[point] = count;
+        }
+
+        Backgammon {
+            player_points: points,
+            opponent_points: points,
+
+            dice: [0; 4],
+        }
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        let mut actions = ActionList::new();
+
+        if self.phase() != Phase::Move {
+            return actions;
+        }
+
+        for (i, &die) in self.dice.iter().enumerate() {
+            if die == 0 || (i > 0 && self.dice[i - 1] == die) {
+                continue;
+            }
+
+            for from in (1..=Self::POINT_COUNT).rev() {
+                if self.can_move(from, die as usize) {
+                    actions.push(Action::Move {
+                        from: u8::try_from(from).unwrap(),
+                        die,
+                    });
+                }
+            }
+        }
+
+        actions
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Roll { high, low } => {
+                self.dice = if high == low {
+                    [high; 4]
+                } else {
+                    [high, low, 0, 0]
+                };
+
+                false
+            }
+            Action::Move { from, die } => {
+                let to = (from as usize).saturating_sub(die as usize);
+
+                self.player_points[from as usize] -= 1;
+                self.player_points[to] += 1;
+
+                self.use_die(die);
+
+                if self.dice[0] == 0 || self.player_points[0] == Self::CHECKER_COUNT {
+                    self.dice = [0; 4];
+
+                    return true;
+                }
+
+                false
+            }
+        }
+    }
+
+    fn end_turn(&mut self) {
+        mem::swap(&mut self.player_points, &mut self.opponent_points);
+
+        self.dice = [0; 4];
+    }
+
+    fn outcome(&self) -> Outcome {
+        if self.player_points[0] == Self::CHECKER_COUNT {
+            Outcome::Win
+        } else if self.opponent_points[0] == Self::CHECKER_COUNT {
+            Outcome::Loss
+        } else {
+            Outcome::InProgress
+        }
+    }
+
+    fn chance_outcomes(&self) -> Vec<(Action, f32)> {
+        if self.phase() != Phase::Roll {
+            return vec![];
+        }
+
+        let mut outcomes = Vec::with_capacity(21);
+
+        for high in 1..=6 {
+            for low in 1..=high {
+                let probability = if high == low { 1.0 / 36.0 } else { 2.0 / 36.0 };
+
+                outcomes.push((Action::Roll { high, low }, probability));
+            }
+        }
+
+        outcomes
+    }
+
+    // NOTE - Every move lowers a pip count, so positions never repeat.
+
+    fn hash(&self) -> Option<u64> {
+        Some(hash_position(self))
+    }
+
+    fn create_checkpoint(&self) -> Backgammon {
+        *self
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Backgammon) {
+        *self = checkpoint;
+    }
+
+    fn symmetries(&self) -> u8 {
+        Self::SYMMETRY_COUNT
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        debug_assert!(symmetry < Self::SYMMETRY_COUNT);
+
+        *self
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        debug_assert!(symmetry < Self::SYMMETRY_COUNT);
+
+        action
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        let (player_1, player_2) = match turn {
+            Turn::Player1 => (&self.player_points, &self.opponent_points),
+            Turn::Player2 => (&self.opponent_points, &self.player_points),
+        };
+
+        format!(
+            "Player 1: {}\nPlayer 2: {}\nDice: {}\n",
+            Self::format_points(player_1),
+            Self::format_points(player_2),
+            Self::format_dice(self.dice)
+        )
+    }
+
+    fn to_notation(&self) -> String {
+        format!(
+            "{} {} {}",
+            Self::format_points(&self.player_points),
+            Self::format_points(&self.opponent_points),
+            Self::format_dice(self.dice)
+        )
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = notation.split_whitespace().collect();
+
+        if fields.len() != 3 {
+            return Err(format!("expected 3 fields, found {}", fields.len()));
+        }
+
+        Self::from_fields(fields[0], fields[1], fields[2])
+    }
+}
+
+impl fmt::Display for Backgammon {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            formatter,
+            "Player: {}",
+            Self::format_points(&self.player_points)
+        )?;
+        writeln!(
+            formatter,
+            "Opponent: {}",
+            Self::format_points(&self.opponent_points)
+        )?;
+        writeln!(formatter, "Dice: {}", Self::format_dice(self.dice))
+    }
+}
+
+impl str::FromStr for Backgammon {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut player = None;
+        let mut opponent = None;
+        let mut dice = "-";
+
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if let Some(value) = line.strip_prefix("Player:") {
+                player = Some(value.trim());
+            } else if let Some(value) = line.strip_prefix("Opponent:") {
+                opponent = Some(value.trim());
+            } else if let Some(value) = line.strip_prefix("Dice:") {
+                dice = value.trim();
+            } else {
+                return Err(format!("unexpected line: {line}"));
+            }
+        }
+
+        Self::from_fields(
+            player.ok_or("missing player")?,
+            opponent.ok_or("missing opponent")?,
+            dice,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{Runner, StatisticsRunnerEventSink};
+    use crate::player::{ClassicMctsPlayer, RandomPlayer};
+
+    fn roll(high: u8, low: u8) -> Action {
+        Action::Roll { high, low }
+    }
+
+    fn step(from: u8, die: u8) -> Action {
+        Action::Move { from, die }
+    }
+
+    mod chance_outcomes {
+        use super::*;
+
+        #[test]
+        fn should_weigh_each_roll_by_its_probability() {
+            let outcomes = Backgammon::new().chance_outcomes();
+
+            let total: f32 = outcomes.iter().map(|&(_, probability)| probability).sum();
+
+            assert_eq!(outcomes.len(), 21);
+            assert!((total - 1.0).abs() < 1e-6);
+        }
+
+        #[test]
+        fn should_only_roll_at_the_start_of_a_turn() {
+            let mut game = Backgammon::new();
+
+            game.apply_action(roll(6, 5));
+
+            assert!(game.chance_outcomes().is_empty());
+            assert_eq!(game.phase(), Phase::Move);
+        }
+    }
+
+    mod get_possible_actions {
+        use super::*;
+
+        #[test]
+        fn should_offer_each_distinct_die_once() {
+            let mut game = Backgammon::new();
+
+            game.apply_action(roll(3, 3));
+
+            assert_eq!(
+                game.get_possible_actions().as_slice(),
+                &[step(12, 3), step(8, 3), step(6, 3), step(5, 3)]
+            );
+        }
+
+        #[test]
+        fn should_only_bear_off_once_every_checker_is_home() {
+            let mut game = Backgammon::from_notation("7:1,5:14 12:15 6-6").unwrap();
+
+            assert_eq!(game.get_possible_actions().as_slice(), &[step(7, 6)]);
+
+            game.apply_action(step(7, 6));
+
+            assert!(game.get_possible_actions().contains(&step(5, 6)));
+        }
+
+        #[test]
+        fn should_bear_off_from_lower_points_with_a_larger_die() {
+            let game = Backgammon::from_notation("4:1,2:2,off:12 12:15 6-1").unwrap();
+
+            assert_eq!(
+                game.get_possible_actions().as_slice(),
+                &[step(4, 6), step(4, 1), step(2, 1)]
+            );
+        }
+    }
+
+    mod apply_action {
+        use super::*;
+
+        #[test]
+        fn should_play_all_four_steps_of_a_double() {
+            let mut game = Backgammon::new();
+
+            game.apply_action(roll(2, 2));
+
+            for _ in 0..3 {
+                assert!(!game.apply_action(step(12, 2)));
+            }
+
+            assert!(game.apply_action(step(12, 2)));
+            assert_eq!(game.player_points[10], 4);
+            assert_eq!(game.player_pip_count(), 124 - 8);
+        }
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        fn should_win_by_bearing_off_the_last_checker() {
+            let mut game = Backgammon::from_notation("1:1,off:14 12:15 2-1").unwrap();
+
+            assert!(game.apply_action(step(1, 2)));
+            assert_eq!(game.outcome(), Outcome::Win);
+
+            game.end_turn();
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+            assert!(game.chance_outcomes().is_empty());
+        }
+    }
+
+    mod run {
+        use super::*;
+
+        #[test]
+        fn should_play_full_games_through_the_runner() {
+            let mut runner = Runner::<Backgammon, _, _, _>::new(
+                2,
+                ClassicMctsPlayer::new(50),
+                RandomPlayer::new(),
+                StatisticsRunnerEventSink::new(),
+            )
+            .with_seed(0);
+
+            runner.run();
+
+            assert_eq!(runner.sink().total_games, 2);
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = Backgammon::new();
+
+            game.apply_action(roll(4, 4));
+            game.apply_action(step(12, 4));
+
+            let parsed =
+                Backgammon::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+
+        #[test]
+        fn should_reject_positions_with_contact() {
+            assert!(Backgammon::from_notation("13:15 12:15 -").is_err());
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = Backgammon::new();
+
+            game.apply_action(roll(6, 1));
+
+            let parsed: Backgammon = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+    }
+}
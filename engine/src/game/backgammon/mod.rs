@@ -0,0 +1,10 @@
+mod action;
+mod action_encoder;
+#[allow(clippy::module_inception)]
+mod backgammon;
+mod state_encoder;
+
+pub use action::Action as BackgammonAction;
+pub use action_encoder::BackgammonActionEncoder;
+pub use backgammon::{Backgammon, Phase as BackgammonPhase};
+pub use state_encoder::BackgammonStateEncoder;
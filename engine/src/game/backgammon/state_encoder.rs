@@ -0,0 +1,76 @@
+use crate::game::backgammon::backgammon::Backgammon;
+use crate::neural_network::StateEncoder;
+
+// NOTE - A single row with a column per point and column 0 for borne off checkers. Each
+// side's checkers are spread over four planes as in TD-Gammon: one, two and three or more
+// checkers, then half of any beyond three. Borne off checkers are a fraction of the side's
+// checkers in the first plane. Two planes hold the pip counts and six the unused dice.
+
+#[derive(Clone, Copy, Default)]
+pub struct BackgammonStateEncoder;
+
+impl BackgammonStateEncoder {
+    const PLANE_COUNT: usize = 16;
+
+    const PLANE_SIZE: usize = Backgammon::POINT_COUNT + 1;
+
+    const MAX_PIP_COUNT: f32 = 360.0;
+
+    pub fn new() -> Self {
+        BackgammonStateEncoder
+    }
+
+    fn plane_slice(planes: &mut [f32], plane_index: usize) -> &mut [f32] {
+        let start = plane_index * Self::PLANE_SIZE;
+        let end = start + Self::PLANE_SIZE;
+
+        &mut planes[start..end]
+    }
+
+    fn points_to_planes(points: &[u8; 25], planes: &mut [f32], first_plane: usize) {
+        planes[first_plane * Self::PLANE_SIZE] =
+            f32::from(points[0]) / f32::from(Backgammon::CHECKER_COUNT);
+
+        for (point, &count) in points.iter().enumerate().skip(1) {
+            let count = f32::from(count);
+
+            for plane in 0..3 {
+                Self::plane_slice(planes, first_plane + plane)[point] =
+                    f32::from(u8::from(count > 0.5 + plane as f32));
+            }
+
+            Self::plane_slice(planes, first_plane + 3)[point] = ((count - 3.0) / 2.0).max(0.0);
+        }
+    }
+}
+
+impl StateEncoder<Backgammon> for BackgammonStateEncoder {
+    fn shape(&self) -> Vec<usize> {
+        vec![1, Self::PLANE_COUNT, 1, Self::PLANE_SIZE]
+    }
+
+    fn encode(&self, state: &Backgammon) -> Vec<f32> {
+        let mut planes = vec![0.0; Self::PLANE_SIZE * Self::PLANE_COUNT];
+
+        Self::points_to_planes(&state.player_points, &mut planes, 0);
+        Self::points_to_planes(&state.opponent_points, &mut planes, 4);
+
+        #[allow(clippy::cast_precision_loss)]
+        let pip_counts = [
+            state.player_pip_count() as f32,
+            state.opponent_pip_count() as f32,
+        ];
+
+        for (plane, pip_count) in pip_counts.into_iter().enumerate() {
+            Self::plane_slice(&mut planes, 8 + plane).fill(pip_count / Self::MAX_PIP_COUNT);
+        }
+
+        for &die in state.dice.iter().filter(|&&die| die > 0) {
+            for value in Self::plane_slice(&mut planes, 9 + die as usize) {
+                *value += 0.25;
+            }
+        }
+
+        planes
+    }
+}
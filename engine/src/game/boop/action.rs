@@ -1,6 +1,12 @@
-use std::fmt;
+use std::{fmt, str};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+use serde::{Deserialize, Serialize};
+
+use crate::core::parse_square;
+use crate::game::boop::Boop;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Action {
     Place { piece: Piece, index: u8 },
     Graduate { mask: u64 },
@@ -43,8 +49,124 @@ impl fmt::Display for Action {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+// NOTE - Typed actions: `k c3` or `c c3` places a kitten or cat, and `g c3` or `g a1-a3`
+// graduates a single piece or a line of three given by its ends.
+
+impl str::FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let square = |value: &str| {
+            parse_square(value, Boop::BOARD_SIZE).map(|(row, col)| row * Boop::BOARD_SIZE + col)
+        };
+
+        let (kind, squares) = s
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| format!("invalid action: {s}"))?;
+
+        let squares = squares.trim();
+
+        match kind {
+            "k" | "c" => Ok(Action::Place {
+                piece: if kind == "k" {
+                    Piece::Kitten
+                } else {
+                    Piece::Cat
+                },
+                index: u8::try_from(square(squares)?).unwrap(),
+            }),
+            "g" => {
+                let Some((from, to)) = squares.split_once('-') else {
+                    return Ok(Action::Graduate {
+                        mask: 1u64 << square(squares)?,
+                    });
+                };
+
+                let (from, to) = (square(from)?, square(to)?);
+
+                let (from_row, from_col) = (from / Boop::BOARD_SIZE, from % Boop::BOARD_SIZE);
+                let (to_row, to_col) = (to / Boop::BOARD_SIZE, to % Boop::BOARD_SIZE);
+
+                if from == to
+                    || ![0, 2].contains(&from_row.abs_diff(to_row))
+                    || ![0, 2].contains(&from_col.abs_diff(to_col))
+                {
+                    return Err(format!("not a line of three: {squares}"));
+                }
+
+                Ok(Action::Graduate {
+                    mask: (1u64 << from) | (1u64 << from.midpoint(to)) | (1u64 << to),
+                })
+            }
+            _ => Err(format!("invalid action: {s}")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Piece {
     Kitten,
     Cat,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_parse_placements() {
+            assert_eq!(
+                "k c3".parse(),
+                Ok(Action::Place {
+                    piece: Piece::Kitten,
+                    index: 14,
+                })
+            );
+
+            assert_eq!(
+                "c f6".parse(),
+                Ok(Action::Place {
+                    piece: Piece::Cat,
+                    index: 35,
+                })
+            );
+        }
+
+        #[test]
+        fn should_parse_graduations() {
+            assert_eq!("g b2".parse(), Ok(Action::Graduate { mask: 1 << 7 }));
+
+            assert_eq!(
+                "g a1-a3".parse(),
+                Ok(Action::Graduate {
+                    mask: (1 << 0) | (1 << 6) | (1 << 12),
+                })
+            );
+
+            assert_eq!(
+                "g c1-a3".parse(),
+                Ok(Action::Graduate {
+                    mask: (1 << 2) | (1 << 7) | (1 << 12),
+                })
+            );
+        }
+
+        #[test]
+        fn should_reject_malformed_actions() {
+            for (input, error) in [
+                ("c3", "invalid action: c3"),
+                ("x c3", "invalid action: x c3"),
+                ("k g1", "invalid square: g1"),
+                ("k a7", "invalid square: a7"),
+                ("g a1-b3", "not a line of three: a1-b3"),
+            ] {
+                assert_eq!(input.parse::<Action>(), Err(error.to_string()));
+            }
+        }
+    }
+}
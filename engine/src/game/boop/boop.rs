@@ -2,10 +2,12 @@ use std::iter::from_fn;
 use std::mem::swap;
 use std::{fmt, str};
 
-use crate::core::{Game, Outcome, Turn};
+use serde::{Deserialize, Serialize};
+
+use crate::core::{ActionList, Game, Outcome, Turn, format_rows, hash_position, parse_grid};
 use crate::game::boop::action::{Action, Piece};
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Boop {
     pub phase: Phase,
 
@@ -18,7 +20,8 @@ pub struct Boop {
     pub opponent_graduations: u8,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Phase {
     Place,
     Graduate,
@@ -51,8 +54,20 @@ impl Boop {
 
     const BOARD_MASK: u64 = (1u64 << 36) - 1;
     const NEIGHBOR_MASKS: [u64; Self::BOARD_SIZE * Self::BOARD_SIZE] = Self::make_neighbor_masks();
+    const BOOP_DESTINATION_MASKS: [[u64; Self::BOARD_SIZE * Self::BOARD_SIZE];
+        Self::BOARD_SIZE * Self::BOARD_SIZE] = Self::make_boop_destination_masks();
     pub const THREE_IN_A_ROW_MASKS: [u64; 80] = Self::make_three_in_a_row_masks();
 
+    // NOTE - Each line is identified by its lowest square. The shift steps along the line,
+    // and the start mask holds the squares where a line in that direction fits.
+
+    const LINE_DIRECTIONS: [(u32, u64); 4] = [
+        (1, Self::make_line_start_mask(0, 1)),
+        (6, Self::make_line_start_mask(1, 0)),
+        (7, Self::make_line_start_mask(1, 1)),
+        (5, Self::make_line_start_mask(1, -1)),
+    ];
+
     pub fn player_pool(&self) -> Pool {
         let kittens_played = u8::try_from(self.player_kittens.count_ones()).unwrap();
         let kittens_available = Self::POOL_SIZE - self.player_graduations - kittens_played;
@@ -79,7 +94,7 @@ impl Boop {
         }
     }
 
-    fn get_possible_place_actions(&self) -> Vec<Action> {
+    fn get_possible_place_actions(&self) -> ActionList<Action> {
         debug_assert!(self.phase == Phase::Place);
 
         let Pool {
@@ -95,7 +110,7 @@ impl Boop {
                 & Self::BOARD_MASK;
         let empty_square_count = empty_squares.count_ones() as usize;
 
-        let mut actions = Vec::with_capacity(
+        let mut actions = ActionList::with_capacity(
             empty_square_count
                 * (usize::from(has_kitten_available) + usize::from(has_cat_available)),
         );
@@ -119,16 +134,13 @@ impl Boop {
         actions
     }
 
-    fn get_possible_graduate_actions(&self) -> Vec<Action> {
+    fn get_possible_graduate_actions(&self) -> ActionList<Action> {
         let mut player_pieces = self.player_kittens | self.player_cats;
 
-        let mut actions =
-            Vec::with_capacity(Self::THREE_IN_A_ROW_MASKS.len() + Self::POOL_SIZE as usize);
+        let mut actions = ActionList::new();
 
-        for &mask in &Self::THREE_IN_A_ROW_MASKS {
-            if (player_pieces & mask) == mask {
-                actions.push(Action::Graduate { mask });
-            }
+        for mask in Self::into_three_in_a_row_masks(player_pieces) {
+            actions.push(Action::Graduate { mask });
         }
 
         if u8::try_from(player_pieces.count_ones()).unwrap() == Self::POOL_SIZE {
@@ -163,7 +175,7 @@ impl Boop {
             Piece::Cat => all_pieces,
         };
 
-        let (x, y) = Self::index_to_xy(index);
+        let destination_masks = &Self::BOOP_DESTINATION_MASKS[index as usize];
 
         let mut neighbor_pieces = boopable_pieces & Self::NEIGHBOR_MASKS[index as usize];
 
@@ -181,26 +193,13 @@ impl Boop {
                 &mut self.opponent_cats
             };
 
-            let adjacent_index = u8::try_from(mask.trailing_zeros()).unwrap();
-            let (adj_x, adj_y) = Self::index_to_xy(adjacent_index);
-
-            let dx = adj_x.cast_signed() - x.cast_signed();
-            let dy = adj_y.cast_signed() - y.cast_signed();
-
-            let x_ = adj_x.cast_signed() + dx;
-            let y_ = adj_y.cast_signed() + dy;
+            let destination_mask = destination_masks[mask.trailing_zeros() as usize];
 
-            if x_ < 0
-                || x_ >= Self::BOARD_SIZE.cast_signed()
-                || y_ < 0
-                || y_ >= Self::BOARD_SIZE.cast_signed()
-            {
+            if destination_mask == 0 {
                 *target_board &= !mask;
                 continue;
             }
 
-            let destination_mask = Self::xy_to_mask(x_.cast_unsigned(), y_.cast_unsigned());
-
             if (destination_mask & all_pieces) != 0 {
                 continue;
             }
@@ -256,6 +255,24 @@ impl Boop {
         swap(&mut self.player_graduations, &mut self.opponent_graduations);
     }
 
+    fn has_three_in_a_row(bitboard: u64) -> bool {
+        Self::LINE_DIRECTIONS.iter().any(|&(shift, start_mask)| {
+            bitboard & (bitboard >> shift) & (bitboard >> (2 * shift)) & start_mask != 0
+        })
+    }
+
+    fn into_three_in_a_row_masks(bitboard: u64) -> impl Iterator<Item = u64> {
+        Self::LINE_DIRECTIONS
+            .iter()
+            .flat_map(move |&(shift, start_mask)| {
+                let starts =
+                    bitboard & (bitboard >> shift) & (bitboard >> (2 * shift)) & start_mask;
+                let line_mask = 1 | (1 << shift) | (1 << (2 * shift));
+
+                Self::into_indices(starts).map(move |index| line_mask << index)
+            })
+    }
+
     fn into_indices(mut bitboard: u64) -> impl Iterator<Item = u8> {
         from_fn(move || {
             if bitboard == 0 {
@@ -269,11 +286,46 @@ impl Boop {
         })
     }
 
-    fn index_to_xy(index: u8) -> (usize, usize) {
-        let x = (index as usize) / Self::BOARD_SIZE;
-        let y = (index as usize) % Self::BOARD_SIZE;
+    // NOTE - Indexed by placed square, then neighbor square. A mask of zero means the
+    // neighbor is booped off the board.
+
+    const fn make_boop_destination_masks()
+    -> [[u64; Self::BOARD_SIZE * Self::BOARD_SIZE]; Self::BOARD_SIZE * Self::BOARD_SIZE] {
+        let mut masks =
+            [[0u64; Self::BOARD_SIZE * Self::BOARD_SIZE]; Self::BOARD_SIZE * Self::BOARD_SIZE];
+
+        let mut index = 0;
+
+        while index < Self::BOARD_SIZE * Self::BOARD_SIZE {
+            let x = (index / Self::BOARD_SIZE).cast_signed();
+            let y = (index % Self::BOARD_SIZE).cast_signed();
+
+            let mut neighbors = Self::NEIGHBOR_MASKS[index];
+
+            while neighbors != 0 {
+                let adjacent_index = neighbors.trailing_zeros() as usize;
+                neighbors &= neighbors - 1;
+
+                let adj_x = (adjacent_index / Self::BOARD_SIZE).cast_signed();
+                let adj_y = (adjacent_index % Self::BOARD_SIZE).cast_signed();
 
-        (x, y)
+                let x_ = 2 * adj_x - x;
+                let y_ = 2 * adj_y - y;
+
+                if x_ >= 0
+                    && x_ < Self::BOARD_SIZE.cast_signed()
+                    && y_ >= 0
+                    && y_ < Self::BOARD_SIZE.cast_signed()
+                {
+                    masks[index][adjacent_index] =
+                        Self::xy_to_mask(x_.cast_unsigned(), y_.cast_unsigned());
+                }
+            }
+
+            index += 1;
+        }
+
+        masks
     }
 
     const fn make_neighbor_masks() -> [u64; Self::BOARD_SIZE * Self::BOARD_SIZE] {
@@ -330,6 +382,35 @@ impl Boop {
         masks
     }
 
+    const fn make_line_start_mask(dx: isize, dy: isize) -> u64 {
+        let mut mask = 0u64;
+
+        let mut x = 0;
+        let mut y = 0;
+
+        while x < Self::BOARD_SIZE {
+            while y < Self::BOARD_SIZE {
+                let x_ = x.cast_signed() + 2 * dx;
+                let y_ = y.cast_signed() + 2 * dy;
+
+                if x_ >= 0
+                    && x_ < Self::BOARD_SIZE.cast_signed()
+                    && y_ >= 0
+                    && y_ < Self::BOARD_SIZE.cast_signed()
+                {
+                    mask |= Self::xy_to_mask(x, y);
+                }
+
+                y += 1;
+            }
+
+            x += 1;
+            y = 0;
+        }
+
+        mask
+    }
+
     const fn make_three_in_a_row_masks() -> [u64; 80] {
         let mut masks = [0u64; 80];
 
@@ -395,6 +476,69 @@ impl Boop {
         masks
     }
 
+    fn parse_pool(
+        line: Option<&str>,
+        prefix: &str,
+        kitten: char,
+        cat: char,
+    ) -> Result<(u8, u8), String> {
+        let pool = line
+            .and_then(|line| line.strip_prefix(prefix))
+            .ok_or_else(|| format!("missing {prefix} pool"))?;
+
+        let mut kittens_available = 0u8;
+        let mut cats_available = 0u8;
+
+        for token in pool.split_whitespace() {
+            match token.chars().collect::<Vec<_>>()[..] {
+                [character] if character == kitten => kittens_available += 1,
+                [character] if character == cat => cats_available += 1,
+                _ => return Err(format!("invalid piece in {prefix} pool: {token}")),
+            }
+
+            if kittens_available + cats_available > Self::POOL_SIZE {
+                return Err(format!("too many pieces in {prefix} pool"));
+            }
+        }
+
+        Ok((kittens_available, cats_available))
+    }
+
+    fn parse_row(line: &str) -> Option<Vec<char>> {
+        let cells = line.trim().strip_prefix('║')?.strip_suffix('║')?;
+
+        cells
+            .split('│')
+            .map(|cell| match cell.chars().collect::<Vec<_>>()[..] {
+                [' ', character, ' '] => Some(character),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .filter(|cells| cells.len() == Self::BOARD_SIZE)
+    }
+
+    fn count_pieces(
+        side: &str,
+        kittens: u64,
+        kittens_available: u8,
+        cats: u64,
+        cats_available: u8,
+    ) -> Result<u8, String> {
+        let kittens_played = u8::try_from(kittens.count_ones()).unwrap();
+        let cats_played = u8::try_from(cats.count_ones()).unwrap();
+
+        let total = kittens_played + kittens_available + cats_played + cats_available;
+
+        if total != Self::POOL_SIZE {
+            return Err(format!(
+                "{side} has {total} pieces, expected {}",
+                Self::POOL_SIZE
+            ));
+        }
+
+        Ok(cats_played + cats_available)
+    }
+
     const fn xy_to_mask(x: usize, y: usize) -> u64 {
         1u64 << (x * Self::BOARD_SIZE + y)
     }
@@ -404,8 +548,9 @@ impl Game for Boop {
     type Phase = Phase;
     type Action = Action;
     type Checkpoint = Checkpoint;
+    type Config = ();
 
-    fn new() -> Self {
+    fn new_with_config(_config: ()) -> Self {
         Boop {
             phase: Phase::Place,
 
@@ -419,9 +564,9 @@ impl Game for Boop {
         }
     }
 
-    fn get_possible_actions(&self) -> Vec<Action> {
+    fn get_possible_actions(&self) -> ActionList<Action> {
         if self.outcome() != Outcome::InProgress {
-            return vec![];
+            return ActionList::new();
         }
 
         match self.phase {
@@ -462,10 +607,8 @@ impl Game for Boop {
     fn outcome(&self) -> Outcome {
         // NOTE - Opponent
 
-        for &mask in &Self::THREE_IN_A_ROW_MASKS {
-            if (self.opponent_cats & mask) == mask {
-                return Outcome::Loss;
-            }
+        if Self::has_three_in_a_row(self.opponent_cats) {
+            return Outcome::Loss;
         }
 
         if u8::try_from(self.opponent_cats.count_ones()).unwrap() == Self::POOL_SIZE {
@@ -474,10 +617,8 @@ impl Game for Boop {
 
         // NOTE - Player
 
-        for &mask in &Self::THREE_IN_A_ROW_MASKS {
-            if (self.player_cats & mask) == mask {
-                return Outcome::Win;
-            }
+        if Self::has_three_in_a_row(self.player_cats) {
+            return Outcome::Win;
         }
 
         if u8::try_from(self.player_cats.count_ones()).unwrap() == Self::POOL_SIZE {
@@ -487,6 +628,13 @@ impl Game for Boop {
         Outcome::InProgress
     }
 
+    // NOTE - Booped pieces return to the pool, so positions can repeat and Boop has no
+    // `hash`, but the fields hold the whole position from the side to move.
+
+    fn position_key(&self) -> Option<u64> {
+        Some(hash_position(self))
+    }
+
     fn create_checkpoint(&self) -> Checkpoint {
         Checkpoint {
             phase: self.phase,
@@ -562,6 +710,109 @@ impl Game for Boop {
 
         format!("{game}")
     }
+
+    fn to_notation(&self) -> String {
+        let board = format_rows((0..Self::BOARD_SIZE).map(|x| {
+            (0..Self::BOARD_SIZE).map(move |y| {
+                let mask = Self::xy_to_mask(x, y);
+
+                if self.player_cats & mask != 0 {
+                    Some('X')
+                } else if self.player_kittens & mask != 0 {
+                    Some('x')
+                } else if self.opponent_cats & mask != 0 {
+                    Some('O')
+                } else if self.opponent_kittens & mask != 0 {
+                    Some('o')
+                } else {
+                    None
+                }
+            })
+        }));
+
+        let phase = match self.phase {
+            Phase::Place => "place",
+            Phase::Graduate => "graduate",
+        };
+
+        format!(
+            "{board} {} {} {phase}",
+            self.player_graduations, self.opponent_graduations
+        )
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = notation.split_whitespace().collect();
+
+        if fields.len() != 4 {
+            return Err(format!("expected 4 fields, found {}", fields.len()));
+        }
+
+        let rows = parse_grid(fields[0], Self::BOARD_SIZE, Self::BOARD_SIZE)?;
+
+        let mut game = Boop {
+            phase: Phase::Place,
+
+            player_cats: 0,
+            player_kittens: 0,
+            player_graduations: 0,
+
+            opponent_cats: 0,
+            opponent_kittens: 0,
+            opponent_graduations: 0,
+        };
+
+        for (x, cells) in rows.into_iter().enumerate() {
+            for (y, cell) in cells.into_iter().enumerate() {
+                let mask = Self::xy_to_mask(x, y);
+
+                match cell {
+                    None => {}
+                    Some('X') => game.player_cats |= mask,
+                    Some('x') => game.player_kittens |= mask,
+                    Some('O') => game.opponent_cats |= mask,
+                    Some('o') => game.opponent_kittens |= mask,
+                    Some(character) => {
+                        return Err(format!("invalid character at row {}: {character}", x + 1));
+                    }
+                }
+            }
+        }
+
+        for (side, field, cats, kittens, graduations) in [
+            (
+                "player",
+                fields[1],
+                game.player_cats,
+                game.player_kittens,
+                &mut game.player_graduations,
+            ),
+            (
+                "opponent",
+                fields[2],
+                game.opponent_cats,
+                game.opponent_kittens,
+                &mut game.opponent_graduations,
+            ),
+        ] {
+            *graduations = field
+                .parse()
+                .ok()
+                .filter(|&count: &u8| {
+                    u32::from(count) >= cats.count_ones()
+                        && u32::from(count) + kittens.count_ones() <= u32::from(Self::POOL_SIZE)
+                })
+                .ok_or_else(|| format!("invalid {side} graduations: {field}"))?;
+        }
+
+        game.phase = match fields[3] {
+            "place" => Phase::Place,
+            "graduate" => Phase::Graduate,
+            field => return Err(format!("invalid phase: {field}")),
+        };
+
+        Ok(game)
+    }
 }
 
 impl fmt::Display for Boop {
@@ -598,6 +849,12 @@ impl fmt::Display for Boop {
 
         writeln!(formatter)?;
 
+        // NOTE - Phase
+
+        if self.phase == Phase::Graduate {
+            writeln!(formatter, "Phase: Graduate")?;
+        }
+
         // NOTE - Board
 
         writeln!(formatter, "╔═══╤═══╤═══╤═══╤═══╤═══╗")?;
@@ -644,145 +901,90 @@ impl str::FromStr for Boop {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut player_kittens = 0u64;
-        let mut player_cats = 0u64;
-
-        let mut player_kittens_played = 0u8;
-        let mut player_kittens_available = 0u8;
-        let mut player_cats_played = 0u8;
-        let mut player_cats_available = 0u8;
-        let mut player_graduations = 0u8;
-
-        let mut opponent_kittens = 0u64;
-        let mut opponent_cats = 0u64;
+        let mut lines = s.lines().map(str::trim_end).filter(|line| !line.is_empty());
 
-        let mut opponent_kittens_played = 0u8;
-        let mut opponent_kittens_available = 0u8;
-        let mut opponent_cats_played = 0u8;
-        let mut opponent_cats_available = 0u8;
-        let mut opponent_graduations = 0u8;
+        // NOTE - Pools
 
-        let lines: Vec<&str> = s.lines().collect();
+        let (player_kittens_available, player_cats_available) =
+            Self::parse_pool(lines.next(), "Player:", 'x', 'X')?;
 
-        if lines.len() != 2 * Self::BOARD_SIZE + 4 {
-            return Err("unexpected number of lines".to_string());
-        }
+        let (opponent_kittens_available, opponent_cats_available) =
+            Self::parse_pool(lines.next(), "Opponent:", 'o', 'O')?;
 
-        if let Some(player_line) = lines.first() {
-            if player_line.starts_with("Player:") {
-                let pool = player_line.trim_start_matches("Player:").trim();
+        // NOTE - Phase
 
-                player_kittens_available = u8::try_from(pool.matches('x').count()).unwrap();
-                player_cats_available = u8::try_from(pool.matches('X').count()).unwrap();
+        let mut lines = lines.peekable();
 
-                player_graduations = player_cats_available;
-            } else {
-                return Err("missing player pool".to_string());
-            }
-        }
+        let phase = match lines.next_if(|line| line.starts_with("Phase:")) {
+            Some(line) => match line.trim_start_matches("Phase:").trim() {
+                "Place" => Phase::Place,
+                "Graduate" => Phase::Graduate,
+                phase => return Err(format!("invalid phase: {phase}")),
+            },
+            None => Phase::Place,
+        };
 
-        if let Some(opponent_line) = lines.get(1) {
-            if opponent_line.starts_with("Opponent:") {
-                let pool = opponent_line.trim_start_matches("Opponent:").trim();
+        // NOTE - Board
 
-                opponent_kittens_available = u8::try_from(pool.matches('o').count()).unwrap();
-                opponent_cats_available = u8::try_from(pool.matches('O').count()).unwrap();
+        let board_lines: Vec<&str> = lines.collect();
 
-                opponent_graduations = opponent_cats_available;
-            } else {
-                return Err("missing opponent pool".to_string());
-            }
+        if board_lines.len() != 2 * Self::BOARD_SIZE + 1 {
+            return Err(format!(
+                "expected {} board lines, found {}",
+                2 * Self::BOARD_SIZE + 1,
+                board_lines.len()
+            ));
         }
 
-        let board_lines: Vec<&str> = lines
-            .iter()
-            .skip(3)
-            .filter(|line| !line.contains('═') && !line.contains('─'))
-            .copied()
-            .collect();
+        let mut game = Boop {
+            phase,
 
-        for (x, line) in board_lines.iter().enumerate() {
-            let characters: Vec<char> = line
-                .chars()
-                .filter(|&character| character != '║' && character != '│')
-                .collect();
+            player_cats: 0,
+            player_kittens: 0,
+            player_graduations: 0,
 
-            for y in 0..Self::BOARD_SIZE {
-                let character = characters.get(y * 3 + 1).copied().unwrap_or(' ');
+            opponent_cats: 0,
+            opponent_kittens: 0,
+            opponent_graduations: 0,
+        };
 
-                if character == ' ' {
-                    continue;
-                }
+        for (x, line) in board_lines.iter().skip(1).step_by(2).enumerate() {
+            let cells =
+                Self::parse_row(line).ok_or_else(|| format!("malformed board row {}", x + 1))?;
 
+            for (y, character) in cells.into_iter().enumerate() {
                 let mask = Self::xy_to_mask(x, y);
 
                 match character {
-                    'X' => {
-                        player_cats |= mask;
-
-                        player_cats_played += 1;
-
-                        player_graduations += 1;
-                    }
-                    'x' => {
-                        player_kittens |= mask;
-
-                        player_kittens_played += 1;
-                    }
-                    'O' => {
-                        opponent_cats |= mask;
-
-                        opponent_cats_played += 1;
-
-                        opponent_graduations += 1;
-                    }
-                    'o' => {
-                        opponent_kittens |= mask;
-
-                        opponent_kittens_played += 1;
-                    }
-                    _ => return Err(format!("invalid character: {character}")),
+                    ' ' => {}
+                    'X' => game.player_cats |= mask,
+                    'x' => game.player_kittens |= mask,
+                    'O' => game.opponent_cats |= mask,
+                    'o' => game.opponent_kittens |= mask,
+                    _ => return Err(format!("invalid character at row {}: {character}", x + 1)),
                 }
             }
         }
 
-        assert_eq!(
-            player_kittens_played
-                + player_kittens_available
-                + player_cats_played
-                + player_cats_available,
-            Self::POOL_SIZE
-        );
-
-        assert_eq!(
-            player_cats_available + player_cats_played,
-            player_graduations
-        );
-
-        assert_eq!(
-            opponent_kittens_played
-                + opponent_kittens_available
-                + opponent_cats_played
-                + opponent_cats_available,
-            Self::POOL_SIZE
-        );
-
-        assert_eq!(
-            opponent_cats_available + opponent_cats_played,
-            opponent_graduations
-        );
-
-        Ok(Boop {
-            phase: Phase::Place,
-
-            player_kittens,
-            player_cats,
-            player_graduations,
-
-            opponent_kittens,
-            opponent_cats,
-            opponent_graduations,
-        })
+        // NOTE - Every cat on the board or in the pool is a graduated kitten.
+
+        game.player_graduations = Self::count_pieces(
+            "player",
+            game.player_kittens,
+            player_kittens_available,
+            game.player_cats,
+            player_cats_available,
+        )?;
+
+        game.opponent_graduations = Self::count_pieces(
+            "opponent",
+            game.opponent_kittens,
+            opponent_kittens_available,
+            game.opponent_cats,
+            opponent_cats_available,
+        )?;
+
+        Ok(game)
     }
 }
 
@@ -1884,6 +2086,204 @@ mod tests {
         }
     }
 
+    mod make_boop_destination_masks {
+        use super::*;
+
+        #[test]
+        fn should_push_neighbors_away_from_placed_square() {
+            let masks = &Boop::BOOP_DESTINATION_MASKS[xy_to_index(2, 2) as usize];
+
+            assert_eq!(masks[xy_to_index(1, 1) as usize], xys_to_mask(&[(0, 0)]));
+            assert_eq!(masks[xy_to_index(2, 3) as usize], xys_to_mask(&[(2, 4)]));
+            assert_eq!(masks[xy_to_index(3, 2) as usize], xys_to_mask(&[(4, 2)]));
+        }
+
+        #[test]
+        fn should_push_edge_neighbors_off_board() {
+            let masks = &Boop::BOOP_DESTINATION_MASKS[xy_to_index(1, 1) as usize];
+
+            assert_eq!(masks[xy_to_index(0, 0) as usize], 0);
+            assert_eq!(masks[xy_to_index(0, 2) as usize], 0);
+            assert_eq!(masks[xy_to_index(1, 0) as usize], 0);
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = Boop::new();
+
+            for action in [
+                Action::Place {
+                    piece: Piece::Kitten,
+                    index: xy_to_index(2, 2),
+                },
+                Action::Place {
+                    piece: Piece::Kitten,
+                    index: xy_to_index(0, 5),
+                },
+            ] {
+                game.apply_action(action);
+                game.end_turn();
+            }
+
+            game.player_kittens &= !xys_to_mask(&[(2, 2)]);
+            game.player_cats |= xys_to_mask(&[(2, 2)]);
+            game.player_graduations = 2;
+            game.phase = Phase::Graduate;
+
+            let parsed =
+                Boop::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = Boop::new();
+
+            for action in [
+                Action::Place {
+                    piece: Piece::Kitten,
+                    index: xy_to_index(2, 2),
+                },
+                Action::Place {
+                    piece: Piece::Kitten,
+                    index: xy_to_index(0, 5),
+                },
+            ] {
+                game.apply_action(action);
+                game.end_turn();
+            }
+
+            game.player_kittens &= !xys_to_mask(&[(2, 2)]);
+            game.player_cats |= xys_to_mask(&[(2, 2)]);
+            game.player_graduations = 2;
+            game.phase = Phase::Graduate;
+
+            let parsed: Boop = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+
+        #[test]
+        fn should_reject_wrong_piece_counts() {
+            let result = "
+                Player: x x x x x x x x
+                Opponent: o o o o o o o o
+
+                ╔═══╤═══╤═══╤═══╤═══╤═══╗
+                ║ x │   │   │   │   │   ║
+                ╟───┼───┼───┼───┼───┼───╢
+                ║   │   │   │   │   │   ║
+                ╟───┼───┼───┼───┼───┼───╢
+                ║   │   │   │   │   │   ║
+                ╟───┼───┼───┼───┼───┼───╢
+                ║   │   │   │   │   │   ║
+                ╟───┼───┼───┼───┼───┼───╢
+                ║   │   │   │   │   │   ║
+                ╟───┼───┼───┼───┼───┼───╢
+                ║   │   │   │   │   │   ║
+                ╚═══╧═══╧═══╧═══╧═══╧═══╝
+            "
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join("\n")
+            .parse::<Boop>();
+
+            assert_eq!(result, Err("player has 9 pieces, expected 8".to_string()));
+        }
+
+        #[test]
+        fn should_reject_malformed_input() {
+            let game = Boop::new().to_string();
+
+            for (input, error) in [
+                (
+                    game.replace("Opponent:", "Rival:"),
+                    "missing Opponent: pool",
+                ),
+                (
+                    game.replace("Player: x", "Player: y"),
+                    "invalid piece in Player: pool: y",
+                ),
+                (game.replacen("║   │", "║   ", 1), "malformed board row 1"),
+                (
+                    game.replacen("║   │", "║ ? │", 1),
+                    "invalid character at row 1: ?",
+                ),
+                (
+                    game.replacen("║   │   │   │   │   │   ║\n", "", 1),
+                    "expected 13 board lines, found 12",
+                ),
+            ] {
+                assert_eq!(input.parse::<Boop>(), Err(error.to_string()));
+            }
+        }
+    }
+
+    mod serialize {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_through_json() {
+            let mut game = Boop::new();
+
+            let action = Action::Place {
+                piece: Piece::Kitten,
+                index: xy_to_index(2, 3),
+            };
+
+            game.apply_action(action);
+            game.end_turn();
+
+            let json = serde_json::to_string(&(&game, action)).expect("unable to serialize");
+            let parsed: (Boop, Action) =
+                serde_json::from_str(&json).expect("unable to deserialize");
+
+            assert_eq!(parsed, (game, action));
+        }
+    }
+
+    mod into_three_in_a_row_masks {
+        use super::*;
+
+        #[test]
+        fn should_match_three_in_a_row_masks() {
+            let bitboards = [
+                0,
+                Boop::BOARD_MASK,
+                xys_to_mask(&[(0, 0), (0, 1), (0, 2), (1, 1), (2, 2)]),
+                xys_to_mask(&[(0, 5), (1, 4), (2, 3), (3, 2), (0, 4), (0, 3)]),
+                xys_to_mask(&[(0, 4), (0, 5), (1, 0), (3, 5), (4, 0), (5, 5)]),
+                0x000F_0F0F_0F0F & Boop::BOARD_MASK,
+            ];
+
+            for bitboard in bitboards {
+                let mut expected: Vec<u64> = Boop::THREE_IN_A_ROW_MASKS
+                    .iter()
+                    .copied()
+                    .filter(|&mask| bitboard & mask == mask)
+                    .collect();
+
+                let mut actual: Vec<u64> = Boop::into_three_in_a_row_masks(bitboard).collect();
+
+                expected.sort_unstable();
+                actual.sort_unstable();
+
+                assert_eq!(actual, expected);
+                assert_eq!(Boop::has_three_in_a_row(bitboard), !expected.is_empty());
+            }
+        }
+    }
+
     mod outcome {
         use super::*;
 
@@ -0,0 +1,81 @@
+use crate::game::boop::boop::{Boop, Phase};
+use crate::player::Heuristic;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BoopHeuristic;
+
+impl BoopHeuristic {
+    const GRADUATION_WEIGHT: f32 = 0.25;
+    const CAT_ON_BOARD_WEIGHT: f32 = 0.1;
+
+    pub fn new() -> Self {
+        BoopHeuristic
+    }
+}
+
+// NOTE - Every graduation is progress toward eight cats, and cats on the board can also
+// line up, so both count in the side to move's favor. The sum is squashed to stay within
+// -1 and 1.
+
+impl Heuristic<Boop> for BoopHeuristic {
+    fn evaluate(&self, game: &Boop) -> f32 {
+        let graduations = f32::from(game.player_graduations) - f32::from(game.opponent_graduations);
+
+        let cats_on_board =
+            game.player_cats.count_ones() as f32 - game.opponent_cats.count_ones() as f32;
+
+        (Self::GRADUATION_WEIGHT * graduations + Self::CAT_ON_BOARD_WEIGHT * cats_on_board).tanh()
+    }
+
+    // NOTE - A pending graduation has not been counted yet, so the position is scored
+    // once the pieces are chosen.
+
+    fn is_unstable(&self, game: &Boop) -> bool {
+        game.phase == Phase::Graduate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+
+    mod evaluate {
+        use super::*;
+
+        #[test]
+        fn should_favor_the_side_with_more_cats() {
+            let mut game = Boop::new();
+
+            game.player_graduations = 3;
+            game.player_cats = 1;
+
+            let heuristic = BoopHeuristic::new();
+
+            let value = heuristic.evaluate(&game);
+
+            game.end_turn();
+
+            assert!(value > 0.0 && value < 1.0);
+            assert!((heuristic.evaluate(&game) + value).abs() < f32::EPSILON);
+        }
+    }
+
+    mod is_unstable {
+        use super::*;
+
+        #[test]
+        fn should_only_mark_pending_graduations() {
+            let mut game = Boop::new();
+
+            let heuristic = BoopHeuristic::new();
+
+            assert!(!heuristic.is_unstable(&game));
+
+            game.phase = Phase::Graduate;
+
+            assert!(heuristic.is_unstable(&game));
+        }
+    }
+}
@@ -2,9 +2,13 @@ mod action;
 mod action_encoder;
 #[allow(clippy::module_inception)]
 mod boop;
+mod heuristic;
+mod portable;
+mod renderable;
 mod state_encoder;
 
 pub use action::{Action as BoopAction, Piece as BoopPiece};
 pub use action_encoder::BoopActionEncoder;
 pub use boop::{Boop, Phase as BoopPhase};
+pub use heuristic::BoopHeuristic;
 pub use state_encoder::BoopStateEncoder;
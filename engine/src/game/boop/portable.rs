@@ -0,0 +1,62 @@
+use crate::game::boop::Boop;
+use crate::game::boop::action::{Action, Piece};
+use crate::record::PortableGame;
+
+impl PortableGame for Boop {
+    const NAME: &'static str = "boop";
+
+    fn board_size() -> usize {
+        Self::BOARD_SIZE
+    }
+
+    fn format_action(action: Action) -> String {
+        match action {
+            Action::Place { piece, index } => {
+                let prefix = match piece {
+                    Piece::Kitten => 'k',
+                    Piece::Cat => 'c',
+                };
+
+                format!("{prefix}{}", Self::format_point(index))
+            }
+            Action::Graduate { mask } => {
+                let mut value = String::from("g");
+
+                for index in 0..36 {
+                    if (mask >> index) & 1 == 1 {
+                        value.push_str(&Self::format_point(index));
+                    }
+                }
+
+                value
+            }
+        }
+    }
+
+    fn parse_action(value: &str) -> Result<Action, String> {
+        let (prefix, points) = value.split_at_checked(1).ok_or("empty action")?;
+
+        let indices = points
+            .as_bytes()
+            .chunks(2)
+            .map(|point| Self::parse_point(str::from_utf8(point).unwrap_or_default()))
+            .collect::<Result<Vec<u8>, String>>()?;
+
+        match (prefix, indices.as_slice()) {
+            ("k", &[index]) => Ok(Action::Place {
+                piece: Piece::Kitten,
+                index,
+            }),
+            ("c", &[index]) => Ok(Action::Place {
+                piece: Piece::Cat,
+                index,
+            }),
+            ("g", [_] | [_, _, _]) => Ok(Action::Graduate {
+                mask: indices
+                    .iter()
+                    .fold(0u64, |mask, &index| mask | (1u64 << index)),
+            }),
+            _ => Err(format!("invalid action: {value}")),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::core::Turn;
+use crate::game::boop::Boop;
+use crate::game::boop::action::Action;
+use crate::render::{PieceShape, RenderableGame, RenderedPiece};
+
+impl RenderableGame for Boop {
+    fn grid_size(&self) -> (usize, usize) {
+        (Self::BOARD_SIZE, Self::BOARD_SIZE)
+    }
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece> {
+        let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+        let (owner, shape) = if self.player_cats & mask != 0 {
+            (turn, PieceShape::Large)
+        } else if self.player_kittens & mask != 0 {
+            (turn, PieceShape::Small)
+        } else if self.opponent_cats & mask != 0 {
+            (turn.advance(), PieceShape::Large)
+        } else if self.opponent_kittens & mask != 0 {
+            (turn.advance(), PieceShape::Small)
+        } else {
+            return None;
+        };
+
+        Some(RenderedPiece { owner, shape })
+    }
+
+    fn action_squares(&self, action: Action) -> Vec<(usize, usize)> {
+        let mask = match action {
+            Action::Place { index, .. } => 1u64 << index,
+            Action::Graduate { mask } => mask,
+        };
+
+        (0..Self::BOARD_SIZE * Self::BOARD_SIZE)
+            .filter(|&index| (mask >> index) & 1 == 1)
+            .map(|index| (index / Self::BOARD_SIZE, index % Self::BOARD_SIZE))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::render::weighted_squares;
+
+    mod square_weights {
+        use super::*;
+
+        #[test]
+        fn should_map_actions_onto_their_squares() {
+            let game = Boop::new();
+
+            assert_eq!(game.grid_size(), (6, 6));
+            assert_eq!(
+                weighted_squares(
+                    &game,
+                    &[(
+                        Action::Graduate {
+                            mask: 0b11 | 1 << 6
+                        },
+                        0.75
+                    )],
+                ),
+                vec![(0, 0.25), (1, 0.25), (6, 0.25)]
+            );
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt;
+
+use crate::game::breakthrough::Breakthrough;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action {
+    Move { from: u8, to: u8 },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Move { from, to } => write!(
+                f,
+                "moves from {} to {}.",
+                Breakthrough::square_name(*from),
+                Breakthrough::square_name(*to)
+            ),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::game::breakthrough::Breakthrough;
+use crate::game::breakthrough::action::Action;
+use crate::neural_network::ActionEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct BreakthroughActionEncoder;
+
+// NOTE - Actions are identified by the square they start from and the way they step: up
+// or down the board, then left, straight or right.
+
+impl BreakthroughActionEncoder {
+    const SQUARE_COUNT: usize = Breakthrough::BOARD_SIZE * Breakthrough::BOARD_SIZE;
+    const STEP_COUNT: usize = 6;
+
+    pub fn new() -> Self {
+        BreakthroughActionEncoder
+    }
+}
+
+impl ActionEncoder<Breakthrough> for BreakthroughActionEncoder {
+    const ACTION_COUNT: usize = Self::SQUARE_COUNT * Self::STEP_COUNT;
+
+    fn encode(&self, action: &Action) -> usize {
+        match *action {
+            Action::Move { from, to } => {
+                let down = usize::from(to > from);
+                let col_step = (to as usize % Breakthrough::BOARD_SIZE + 1)
+                    - from as usize % Breakthrough::BOARD_SIZE;
+
+                from as usize * Self::STEP_COUNT + down * 3 + col_step
+            }
+        }
+    }
+
+    fn decode(&self, action_id: usize) -> Action {
+        debug_assert!(action_id < Self::ACTION_COUNT);
+
+        let from = action_id / Self::STEP_COUNT;
+        let step = action_id % Self::STEP_COUNT;
+
+        let row = from / Breakthrough::BOARD_SIZE;
+        let col = from % Breakthrough::BOARD_SIZE;
+
+        let to_row = if step < 3 {
+            row.wrapping_sub(1)
+        } else {
+            row + 1
+        };
+        let to_col = (col + step % 3).wrapping_sub(1);
+
+        let to = if to_row < Breakthrough::BOARD_SIZE && to_col < Breakthrough::BOARD_SIZE {
+            to_row * Breakthrough::BOARD_SIZE + to_col
+        } else {
+            from
+        };
+
+        Action::Move {
+            from: u8::try_from(from).unwrap(),
+            to: u8::try_from(to).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,693 @@
+use std::iter::from_fn;
+use std::mem::swap;
+use std::{fmt, str};
+
+use crate::core::{ActionList, Game, Outcome, Turn, format_rows, parse_grid};
+use crate::game::breakthrough::action::Action;
+
+// NOTE - Pawns fill the two home rows of an 8x8 board, indexed row major from the top left,
+// and the side to move starts at the bottom when moving up. A pawn steps one square forward
+// or diagonally forward onto an empty square, and captures diagonally forward only. Reaching
+// the far row or capturing every opposing pawn wins, and the frontmost pawn can always
+// move, so there are no draws.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Breakthrough {
+    pub phase: Phase,
+
+    pub player_pieces: u64,
+    pub opponent_pieces: u64,
+
+    pub player_direction: Direction,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Move,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn other(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    }
+
+    fn goal_row(self) -> u64 {
+        match self {
+            Direction::Up => Breakthrough::TOP_ROW,
+            Direction::Down => Breakthrough::BOTTOM_ROW,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    player_pieces: u64,
+    opponent_pieces: u64,
+
+    player_direction: Direction,
+}
+
+impl Breakthrough {
+    pub const BOARD_SIZE: usize = 8;
+
+    const SYMMETRY_COUNT: u8 = 2;
+
+    const TOP_ROW: u64 = 0xFF;
+    const BOTTOM_ROW: u64 = 0xFF << 56;
+
+    const LEFT_COLUMN: u64 = 0x0101_0101_0101_0101;
+    const RIGHT_COLUMN: u64 = Self::LEFT_COLUMN << 7;
+
+    pub(crate) fn square_name(square: u8) -> String {
+        let row = square as usize / Self::BOARD_SIZE;
+        let col = u8::try_from(square as usize % Self::BOARD_SIZE).unwrap();
+
+        format!("{}{}", char::from(b'a' + col), row + 1)
+    }
+
+    fn flip_perspective(&mut self) {
+        swap(&mut self.player_pieces, &mut self.opponent_pieces);
+
+        self.player_direction = self.player_direction.other();
+    }
+
+    // NOTE - Destinations of every pawn stepping left, straight and right, as seen from the
+    // board. A destination square `to` was reached from `to - offset`.
+
+    fn steps(&self) -> [(u64, i8); 3] {
+        let pieces = self.player_pieces;
+
+        match self.player_direction {
+            Direction::Up => [
+                ((pieces & !Self::LEFT_COLUMN) >> 9, 9),
+                (pieces >> 8, 8),
+                ((pieces & !Self::RIGHT_COLUMN) >> 7, 7),
+            ],
+            Direction::Down => [
+                ((pieces & !Self::LEFT_COLUMN) << 7, -7),
+                (pieces << 8, -8),
+                ((pieces & !Self::RIGHT_COLUMN) << 9, -9),
+            ],
+        }
+    }
+
+    fn into_indices(mut bitboard: u64) -> impl Iterator<Item = u8> {
+        from_fn(move || {
+            if bitboard == 0 {
+                None
+            } else {
+                let mask = bitboard & (!bitboard + 1);
+                bitboard ^= mask;
+
+                Some(u8::try_from(mask.trailing_zeros()).unwrap())
+            }
+        })
+    }
+
+    // NOTE - Mirrors each row, swapping the left and right sides of the board.
+
+    fn mirror(bitboard: u64) -> u64 {
+        bitboard.reverse_bits().swap_bytes()
+    }
+
+    fn mirror_square(square: u8) -> u8 {
+        square ^ 7
+    }
+}
+
+impl Game for Breakthrough {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Checkpoint;
+    type Config = ();
+
+    fn new_with_config(_config: ()) -> Self {
+        Breakthrough {
+            phase: Phase::Move,
+
+            player_pieces: Self::BOTTOM_ROW | Self::BOTTOM_ROW >> 8,
+            opponent_pieces: Self::TOP_ROW | Self::TOP_ROW << 8,
+
+            player_direction: Direction::Up,
+        }
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        let mut actions = ActionList::new();
+
+        if self.outcome() != Outcome::InProgress {
+            return actions;
+        }
+
+        let empty = !(self.player_pieces | self.opponent_pieces);
+
+        for (index, (destinations, offset)) in self.steps().into_iter().enumerate() {
+            let targets = if index == 1 {
+                destinations & empty
+            } else {
+                destinations & !self.player_pieces
+            };
+
+            for to in Self::into_indices(targets) {
+                actions.push(Action::Move {
+                    from: to.wrapping_add_signed(offset),
+                    to,
+                });
+            }
+        }
+
+        actions
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Move { from, to } => {
+                self.player_pieces ^= (1u64 << from) | (1u64 << to);
+                self.opponent_pieces &= !(1u64 << to);
+            }
+        }
+
+        true
+    }
+
+    fn end_turn(&mut self) {
+        self.flip_perspective();
+    }
+
+    fn outcome(&self) -> Outcome {
+        if self.player_pieces & self.player_direction.goal_row() != 0 || self.opponent_pieces == 0 {
+            return Outcome::Win;
+        }
+
+        if self.opponent_pieces & self.player_direction.other().goal_row() != 0
+            || self.player_pieces == 0
+        {
+            return Outcome::Loss;
+        }
+
+        Outcome::InProgress
+    }
+
+    fn create_checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            player_pieces: self.player_pieces,
+            opponent_pieces: self.opponent_pieces,
+
+            player_direction: self.player_direction,
+        }
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Checkpoint) {
+        self.player_pieces = checkpoint.player_pieces;
+        self.opponent_pieces = checkpoint.opponent_pieces;
+
+        self.player_direction = checkpoint.player_direction;
+    }
+
+    // NOTE - Pawns only move forward, so the left-right reflection is the only symmetry
+    // besides the identity.
+
+    fn symmetries(&self) -> u8 {
+        Self::SYMMETRY_COUNT
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        debug_assert!(symmetry < Self::SYMMETRY_COUNT);
+
+        let mut game = self.clone();
+
+        if symmetry == 1 {
+            game.player_pieces = Self::mirror(self.player_pieces);
+            game.opponent_pieces = Self::mirror(self.opponent_pieces);
+        }
+
+        game
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        debug_assert!(symmetry < Self::SYMMETRY_COUNT);
+
+        match action {
+            Action::Move { from, to } if symmetry == 1 => Action::Move {
+                from: Self::mirror_square(from),
+                to: Self::mirror_square(to),
+            },
+            Action::Move { .. } => action,
+        }
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        let mut game = self.clone();
+
+        if turn == Turn::Player2 {
+            game.flip_perspective();
+        }
+
+        format!("{game}")
+    }
+
+    fn to_notation(&self) -> String {
+        let board = format_rows((0..Self::BOARD_SIZE).map(|row| {
+            (0..Self::BOARD_SIZE).map(move |col| {
+                let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+                if self.player_pieces & mask != 0 {
+                    Some('X')
+                } else if self.opponent_pieces & mask != 0 {
+                    Some('O')
+                } else {
+                    None
+                }
+            })
+        }));
+
+        let direction = match self.player_direction {
+            Direction::Up => "up",
+            Direction::Down => "down",
+        };
+
+        format!("{board} {direction}")
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let mut fields = notation.split_whitespace();
+
+        let rows = parse_grid(
+            fields.next().unwrap_or_default(),
+            Self::BOARD_SIZE,
+            Self::BOARD_SIZE,
+        )?;
+
+        let mut game = Breakthrough {
+            phase: Phase::Move,
+
+            player_pieces: 0,
+            opponent_pieces: 0,
+
+            player_direction: Direction::Up,
+        };
+
+        for (row, cells) in rows.into_iter().enumerate() {
+            for (col, cell) in cells.into_iter().enumerate() {
+                let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+                match cell {
+                    None => {}
+                    Some('X') => game.player_pieces |= mask,
+                    Some('O') => game.opponent_pieces |= mask,
+                    Some(character) => {
+                        return Err(format!("invalid character at row {}: {character}", row + 1));
+                    }
+                }
+            }
+        }
+
+        game.player_direction = match fields.next() {
+            Some("up") => Direction::Up,
+            Some("down") => Direction::Down,
+            field => return Err(format!("invalid direction: {}", field.unwrap_or_default())),
+        };
+
+        if let Some(field) = fields.next() {
+            return Err(format!("unexpected field: {field}"));
+        }
+
+        Ok(game)
+    }
+}
+
+impl fmt::Display for Breakthrough {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // NOTE - Board
+
+        write!(formatter, "  ")?;
+
+        for col in 0..Self::BOARD_SIZE {
+            write!(
+                formatter,
+                " {}",
+                char::from(b'a' + u8::try_from(col).unwrap())
+            )?;
+        }
+
+        writeln!(formatter)?;
+
+        for row in 0..Self::BOARD_SIZE {
+            write!(formatter, "{:>2}", row + 1)?;
+
+            for col in 0..Self::BOARD_SIZE {
+                let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+                let character = if self.player_pieces & mask != 0 {
+                    'X'
+                } else if self.opponent_pieces & mask != 0 {
+                    'O'
+                } else {
+                    '.'
+                };
+
+                write!(formatter, " {character}")?;
+            }
+
+            writeln!(formatter)?;
+        }
+
+        // NOTE - Direction
+
+        let direction = match self.player_direction {
+            Direction::Up => "up",
+            Direction::Down => "down",
+        };
+
+        writeln!(formatter, "X: {direction}")
+    }
+}
+
+impl str::FromStr for Breakthrough {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        if lines.len() != Self::BOARD_SIZE + 2 {
+            return Err(format!(
+                "expected {} lines, found {}",
+                Self::BOARD_SIZE + 2,
+                lines.len()
+            ));
+        }
+
+        let mut game = Breakthrough {
+            phase: Phase::Move,
+
+            player_pieces: 0,
+            opponent_pieces: 0,
+
+            player_direction: Direction::Up,
+        };
+
+        // NOTE - Board
+
+        for (row, line) in lines[1..=Self::BOARD_SIZE].iter().enumerate() {
+            let cells: Vec<&str> = line.split_whitespace().skip(1).collect();
+
+            if cells.len() != Self::BOARD_SIZE {
+                return Err(format!("malformed board row {}", row + 1));
+            }
+
+            for (col, cell) in cells.into_iter().enumerate() {
+                let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+                match cell {
+                    "." => {}
+                    "X" => game.player_pieces |= mask,
+                    "O" => game.opponent_pieces |= mask,
+                    _ => return Err(format!("invalid character at row {}: {cell}", row + 1)),
+                }
+            }
+        }
+
+        // NOTE - Direction
+
+        game.player_direction = match lines[Self::BOARD_SIZE + 1] {
+            "X: up" => Direction::Up,
+            "X: down" => Direction::Down,
+            line => return Err(format!("invalid direction: {line}")),
+        };
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::seq::IndexedRandom;
+
+    use crate::core::{DefaultRngFactory, RngFactory};
+
+    fn parse_game(value: &str) -> Breakthrough {
+        value.parse().expect("unable to parse game")
+    }
+
+    fn square(row: usize, col: usize) -> u8 {
+        u8::try_from(row * Breakthrough::BOARD_SIZE + col).unwrap()
+    }
+
+    mod get_possible_actions {
+        use super::*;
+
+        #[test]
+        fn should_step_front_pawns_from_the_start() {
+            let game = Breakthrough::new();
+
+            let actions = game.get_possible_actions();
+
+            assert_eq!(actions.len(), 22);
+            assert!(actions.iter().all(
+                |action| matches!(action, Action::Move { from, to } if *from / 8 == 6 && *to / 8 == 5)
+            ));
+        }
+
+        #[test]
+        fn should_capture_diagonally_but_not_straight() {
+            let game = parse_game(
+                "
+                       a b c d e f g h
+                     1 O . . . . . . .
+                     2 . . . . . . . .
+                     3 . . . . . . . .
+                     4 . . O O . . . .
+                     5 . . . X . . . .
+                     6 . . . . . . . .
+                     7 . . . . . . . .
+                     8 . . . . . . . .
+                    X: up
+                ",
+            );
+
+            let mut actions = game.get_possible_actions().to_vec();
+            actions.sort_by_key(ToString::to_string);
+
+            assert_eq!(
+                actions,
+                [
+                    Action::Move {
+                        from: square(4, 3),
+                        to: square(3, 2)
+                    },
+                    Action::Move {
+                        from: square(4, 3),
+                        to: square(3, 4)
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn should_move_down_the_board() {
+            let game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 X . . . . . . .
+                     3 . . . . . . . .
+                     4 . . . . . . . .
+                     5 . . . . . . . .
+                     6 . . . . . . . .
+                     7 . . . . . . . .
+                     8 . . . . . . . O
+                    X: down
+                ",
+            );
+
+            let mut actions = game.get_possible_actions().to_vec();
+            actions.sort_by_key(ToString::to_string);
+
+            assert_eq!(
+                actions,
+                [
+                    Action::Move {
+                        from: square(1, 0),
+                        to: square(2, 0)
+                    },
+                    Action::Move {
+                        from: square(1, 0),
+                        to: square(2, 1)
+                    },
+                ]
+            );
+        }
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        fn should_win_on_reaching_the_far_row() {
+            let mut game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 . . . . X . . .
+                     3 . . . . . . . .
+                     4 . . . . . . . .
+                     5 . . . . . . . .
+                     6 . . . . . . . .
+                     7 O . . . . . . .
+                     8 . . . . . . . .
+                    X: up
+                ",
+            );
+
+            game.apply_action(Action::Move {
+                from: square(1, 4),
+                to: square(0, 4),
+            });
+
+            assert_eq!(game.outcome(), Outcome::Win);
+
+            game.end_turn();
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+            assert!(game.get_possible_actions().is_empty());
+        }
+
+        #[test]
+        fn should_win_on_capturing_every_pawn() {
+            let mut game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 . . . . . . . .
+                     3 . . . . . . . .
+                     4 . . O . . . . .
+                     5 . . . X . . . .
+                     6 . . . . . . . .
+                     7 . . . . . . . .
+                     8 . . . . . . . .
+                    X: up
+                ",
+            );
+
+            game.apply_action(Action::Move {
+                from: square(4, 3),
+                to: square(3, 2),
+            });
+
+            assert_eq!(game.opponent_pieces, 0);
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+
+        #[test]
+        fn should_always_finish_without_draws() {
+            let mut rng = DefaultRngFactory::default().create_seeded(7);
+
+            for _ in 0..10 {
+                let mut game = Breakthrough::new();
+
+                while game.outcome() == Outcome::InProgress {
+                    let action = *game
+                        .get_possible_actions()
+                        .choose(&mut rng)
+                        .expect("unfinished game has no actions");
+
+                    if game.apply_action(action) && game.outcome() == Outcome::InProgress {
+                        game.end_turn();
+                    }
+                }
+
+                assert_eq!(game.outcome(), Outcome::Win);
+            }
+        }
+    }
+
+    mod transform {
+        use super::*;
+
+        #[test]
+        fn should_mirror_pieces_and_actions() {
+            let game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 . . . . . . . .
+                     3 . . . . . . . .
+                     4 . O . . . . . .
+                     5 X . . . . . . .
+                     6 . . . . . . . .
+                     7 . . . . . . . .
+                     8 . . . . . . . .
+                    X: up
+                ",
+            );
+
+            let mirrored = game.transform(1);
+
+            assert_eq!(mirrored.player_pieces, 1u64 << square(4, 7));
+            assert_eq!(mirrored.opponent_pieces, 1u64 << square(3, 6));
+
+            let mut expected = mirrored
+                .get_possible_actions()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+
+            let mut transformed = game
+                .get_possible_actions()
+                .iter()
+                .map(|&action| game.transform_action(action, 1).to_string())
+                .collect::<Vec<_>>();
+
+            expected.sort();
+            transformed.sort();
+
+            assert_eq!(transformed, expected);
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = Breakthrough::new();
+
+            game.end_turn();
+
+            let parsed =
+                Breakthrough::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = Breakthrough::new();
+
+            game.end_turn();
+
+            let parsed: Breakthrough = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+    }
+}
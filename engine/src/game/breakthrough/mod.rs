@@ -0,0 +1,14 @@
+mod action;
+mod action_encoder;
+#[allow(clippy::module_inception)]
+mod breakthrough;
+mod portable;
+mod renderable;
+mod state_encoder;
+
+pub use action::Action as BreakthroughAction;
+pub use action_encoder::BreakthroughActionEncoder;
+pub use breakthrough::{
+    Breakthrough, Direction as BreakthroughDirection, Phase as BreakthroughPhase,
+};
+pub use state_encoder::BreakthroughStateEncoder;
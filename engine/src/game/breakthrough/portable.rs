@@ -0,0 +1,32 @@
+use crate::game::breakthrough::Breakthrough;
+use crate::game::breakthrough::action::Action;
+use crate::record::PortableGame;
+
+impl PortableGame for Breakthrough {
+    const NAME: &'static str = "breakthrough";
+
+    fn board_size() -> usize {
+        Self::BOARD_SIZE
+    }
+
+    fn format_action(action: Action) -> String {
+        match action {
+            Action::Move { from, to } => {
+                format!("{}{}", Self::format_point(from), Self::format_point(to))
+            }
+        }
+    }
+
+    fn parse_action(value: &str) -> Result<Action, String> {
+        let indices = value
+            .as_bytes()
+            .chunks(2)
+            .map(|point| Self::parse_point(str::from_utf8(point).unwrap_or_default()))
+            .collect::<Result<Vec<u8>, String>>()?;
+
+        match indices.as_slice() {
+            &[from, to] => Ok(Action::Move { from, to }),
+            _ => Err(format!("invalid action: {value}")),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::core::Turn;
+use crate::game::breakthrough::Breakthrough;
+use crate::game::breakthrough::action::Action;
+use crate::render::{PieceShape, RenderableGame, RenderedPiece};
+
+impl RenderableGame for Breakthrough {
+    fn grid_size(&self) -> (usize, usize) {
+        (Self::BOARD_SIZE, Self::BOARD_SIZE)
+    }
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece> {
+        let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+        let owner = if self.player_pieces & mask != 0 {
+            turn
+        } else if self.opponent_pieces & mask != 0 {
+            turn.advance()
+        } else {
+            return None;
+        };
+
+        Some(RenderedPiece {
+            owner,
+            shape: PieceShape::Mark,
+        })
+    }
+
+    fn action_squares(&self, action: Action) -> Vec<(usize, usize)> {
+        match action {
+            Action::Move { from, to } => [from, to]
+                .iter()
+                .map(|&square| {
+                    (
+                        square as usize / Self::BOARD_SIZE,
+                        square as usize % Self::BOARD_SIZE,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::render::weighted_squares;
+
+    mod piece_at {
+        use super::*;
+
+        #[test]
+        fn should_draw_the_starting_pieces() {
+            let game = Breakthrough::new();
+
+            assert_eq!(
+                game.piece_at(7, 0, Turn::Player1),
+                Some(RenderedPiece {
+                    owner: Turn::Player1,
+                    shape: PieceShape::Mark,
+                })
+            );
+            assert_eq!(
+                game.piece_at(0, 0, Turn::Player1),
+                Some(RenderedPiece {
+                    owner: Turn::Player2,
+                    shape: PieceShape::Mark,
+                })
+            );
+            assert_eq!(game.piece_at(4, 0, Turn::Player1), None);
+        }
+    }
+
+    mod square_weights {
+        use super::*;
+
+        #[test]
+        fn should_map_actions_onto_their_squares() {
+            let game = Breakthrough::new();
+
+            assert_eq!(game.grid_size(), (8, 8));
+            assert_eq!(
+                weighted_squares(&game, &[(Action::Move { from: 48, to: 40 }, 0.5)],),
+                vec![(40, 0.25), (48, 0.25)]
+            );
+        }
+    }
+}
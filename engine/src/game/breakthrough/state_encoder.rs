@@ -0,0 +1,57 @@
+use crate::game::breakthrough::breakthrough::{Breakthrough, Direction};
+use crate::neural_network::StateEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct BreakthroughStateEncoder;
+
+impl BreakthroughStateEncoder {
+    const PLANE_COUNT: usize = 3;
+
+    pub fn new() -> Self {
+        BreakthroughStateEncoder
+    }
+
+    fn plane_slice(planes: &mut [f32], plane_index: usize) -> &mut [f32] {
+        let plane_size = Breakthrough::BOARD_SIZE * Breakthrough::BOARD_SIZE;
+
+        let start = plane_index * plane_size;
+        let end = start + plane_size;
+
+        &mut planes[start..end]
+    }
+
+    fn bitboard_to_plane(bits: u64, plane: &mut [f32]) {
+        for (i, value) in plane.iter_mut().enumerate() {
+            *value = ((bits >> i) & 1) as f32;
+        }
+    }
+}
+
+impl StateEncoder<Breakthrough> for BreakthroughStateEncoder {
+    fn shape(&self) -> Vec<usize> {
+        vec![
+            1,
+            Self::PLANE_COUNT,
+            Breakthrough::BOARD_SIZE,
+            Breakthrough::BOARD_SIZE,
+        ]
+    }
+
+    fn encode(&self, state: &Breakthrough) -> Vec<f32> {
+        let plane_size = Breakthrough::BOARD_SIZE * Breakthrough::BOARD_SIZE;
+        let plane_count = Self::PLANE_COUNT;
+
+        let mut planes = vec![0.0; plane_size * plane_count];
+
+        Self::bitboard_to_plane(state.player_pieces, Self::plane_slice(&mut planes, 0));
+        Self::bitboard_to_plane(state.opponent_pieces, Self::plane_slice(&mut planes, 1));
+
+        // NOTE - The side to move's direction, since the board is not rotated between turns.
+
+        if state.player_direction == Direction::Up {
+            Self::plane_slice(&mut planes, 2).fill(1.0);
+        }
+
+        planes
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt;
+
+use crate::game::checkers::Checkers;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action {
+    Move { from: u8, to: u8 },
+    Jump { from: u8, to: u8 },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Move { from, to } => write!(
+                f,
+                "moves from {} to {}.",
+                Checkers::square_name(*from),
+                Checkers::square_name(*to)
+            ),
+            Action::Jump { from, to } => write!(
+                f,
+                "jumps from {} to {}.",
+                Checkers::square_name(*from),
+                Checkers::square_name(*to)
+            ),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::game::checkers::Checkers;
+use crate::game::checkers::action::Action;
+use crate::neural_network::ActionEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct CheckersActionEncoder;
+
+// NOTE - Actions are identified by the dark square they start from, the diagonal they
+// follow and whether they jump.
+
+impl CheckersActionEncoder {
+    const DARK_SQUARE_COUNT: usize = Checkers::BOARD_SIZE * Checkers::BOARD_SIZE / 2;
+    const DIAGONAL_COUNT: usize = 4;
+
+    pub fn new() -> Self {
+        CheckersActionEncoder
+    }
+}
+
+impl ActionEncoder<Checkers> for CheckersActionEncoder {
+    const ACTION_COUNT: usize = Self::DARK_SQUARE_COUNT * Self::DIAGONAL_COUNT * 2;
+
+    fn encode(&self, action: &Action) -> usize {
+        let (from, to, jump) = match *action {
+            Action::Move { from, to } => (from, to, false),
+            Action::Jump { from, to } => (from, to, true),
+        };
+
+        let diagonal = usize::from(to > from) * 2 + usize::from(to % 8 > from % 8);
+
+        (usize::from(jump) * Self::DARK_SQUARE_COUNT + from as usize / 2) * Self::DIAGONAL_COUNT
+            + diagonal
+    }
+
+    fn decode(&self, action_id: usize) -> Action {
+        debug_assert!(action_id < Self::ACTION_COUNT);
+
+        let diagonal = action_id % Self::DIAGONAL_COUNT;
+        let dark_square = action_id / Self::DIAGONAL_COUNT % Self::DARK_SQUARE_COUNT;
+        let jump = action_id / Self::DIAGONAL_COUNT >= Self::DARK_SQUARE_COUNT;
+
+        let row = dark_square / 4;
+        let col = 2 * (dark_square % 4) + usize::from(row.is_multiple_of(2));
+
+        let from = u8::try_from(row * Checkers::BOARD_SIZE + col).unwrap();
+
+        if jump {
+            Action::Jump {
+                from,
+                to: Checkers::step(from, diagonal, 2).unwrap_or(from),
+            }
+        } else {
+            Action::Move {
+                from,
+                to: Checkers::step(from, diagonal, 1).unwrap_or(from),
+            }
+        }
+    }
+}
@@ -0,0 +1,1023 @@
+use std::iter::from_fn;
+use std::mem::swap;
+use std::{fmt, str};
+
+use crate::core::{ActionList, Game, Outcome, Turn, format_rows, hash_position, parse_grid};
+use crate::game::checkers::action::Action;
+
+// NOTE - English draughts. Pieces sit on the dark squares of an 8x8 board, indexed row
+// major from the top left, and the side to move starts at the bottom when moving up.
+// Captures are forced, and each jump of a chain is its own action, with the turn left
+// unfinished while the jumping piece can continue.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Checkers {
+    pub phase: Phase,
+
+    pub player_men: u64,
+    pub player_kings: u64,
+
+    pub opponent_men: u64,
+    pub opponent_kings: u64,
+
+    pub player_direction: Direction,
+    pub quiet_plies: u8,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Move,
+    Jump { square: u8 },
+    Finished,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn other(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    phase: Phase,
+
+    player_men: u64,
+    player_kings: u64,
+
+    opponent_men: u64,
+    opponent_kings: u64,
+
+    player_direction: Direction,
+    quiet_plies: u8,
+}
+
+impl Checkers {
+    pub const BOARD_SIZE: usize = 8;
+
+    // NOTE - Forty moves each without a capture or a man moving.
+
+    pub const NO_PROGRESS_LIMIT: u8 = 80;
+
+    const SYMMETRY_COUNT: u8 = 1;
+
+    const DIAGONALS: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+    const DARK_SQUARES: u64 = Self::make_dark_squares();
+
+    pub(crate) fn step(square: u8, diagonal: usize, distance: isize) -> Option<u8> {
+        let (row_step, col_step) = Self::DIAGONALS[diagonal];
+
+        let row = (square as usize / Self::BOARD_SIZE).checked_add_signed(row_step * distance)?;
+        let col = (square as usize % Self::BOARD_SIZE).checked_add_signed(col_step * distance)?;
+
+        (row < Self::BOARD_SIZE && col < Self::BOARD_SIZE)
+            .then(|| u8::try_from(row * Self::BOARD_SIZE + col).unwrap())
+    }
+
+    pub(crate) fn square_name(square: u8) -> String {
+        let row = square as usize / Self::BOARD_SIZE;
+        let col = u8::try_from(square as usize % Self::BOARD_SIZE).unwrap();
+
+        format!("{}{}", char::from(b'a' + col), row + 1)
+    }
+
+    fn player_pieces(&self) -> u64 {
+        self.player_men | self.player_kings
+    }
+
+    fn opponent_pieces(&self) -> u64 {
+        self.opponent_men | self.opponent_kings
+    }
+
+    fn occupied(&self) -> u64 {
+        self.player_pieces() | self.opponent_pieces()
+    }
+
+    fn flip_perspective(&mut self) {
+        swap(&mut self.player_men, &mut self.opponent_men);
+        swap(&mut self.player_kings, &mut self.opponent_kings);
+
+        self.player_direction = self.player_direction.other();
+    }
+
+    fn piece_diagonals(&self, square: u8) -> &'static [usize] {
+        if self.player_kings & (1u64 << square) != 0 {
+            return &[0, 1, 2, 3];
+        }
+
+        match self.player_direction {
+            Direction::Up => &[0, 1],
+            Direction::Down => &[2, 3],
+        }
+    }
+
+    fn push_jumps(&self, square: u8, actions: &mut ActionList<Action>) {
+        for &diagonal in self.piece_diagonals(square) {
+            if let (Some(over), Some(to)) = (
+                Self::step(square, diagonal, 1),
+                Self::step(square, diagonal, 2),
+            ) && self.opponent_pieces() & (1u64 << over) != 0
+                && self.occupied() & (1u64 << to) == 0
+            {
+                actions.push(Action::Jump { from: square, to });
+            }
+        }
+    }
+
+    fn push_moves(&self, square: u8, actions: &mut ActionList<Action>) {
+        for &diagonal in self.piece_diagonals(square) {
+            if let Some(to) = Self::step(square, diagonal, 1)
+                && self.occupied() & (1u64 << to) == 0
+            {
+                actions.push(Action::Move { from: square, to });
+            }
+        }
+    }
+
+    // NOTE - Legal actions for the player, ignoring whether the game is over.
+
+    fn legal_actions(&self) -> ActionList<Action> {
+        let mut actions = ActionList::new();
+
+        match self.phase {
+            Phase::Move => {
+                for square in Self::into_indices(self.player_pieces()) {
+                    self.push_jumps(square, &mut actions);
+                }
+
+                if actions.is_empty() {
+                    for square in Self::into_indices(self.player_pieces()) {
+                        self.push_moves(square, &mut actions);
+                    }
+                }
+            }
+            Phase::Jump { square } => self.push_jumps(square, &mut actions),
+            Phase::Finished => {}
+        }
+
+        actions
+    }
+
+    // NOTE - Returns whether the moved piece is a king.
+
+    fn move_piece(&mut self, from: u8, to: u8) -> bool {
+        let mask = (1u64 << from) | (1u64 << to);
+
+        if self.player_kings & (1u64 << from) != 0 {
+            self.player_kings ^= mask;
+
+            true
+        } else {
+            self.player_men ^= mask;
+
+            false
+        }
+    }
+
+    // NOTE - Returns whether a man was crowned, which ends the turn.
+
+    fn crown(&mut self, square: u8) -> bool {
+        let last_row = match self.player_direction {
+            Direction::Up => 0,
+            Direction::Down => Self::BOARD_SIZE - 1,
+        };
+
+        let mask = 1u64 << square;
+
+        if self.player_men & mask == 0 || square as usize / Self::BOARD_SIZE != last_row {
+            return false;
+        }
+
+        self.player_men &= !mask;
+        self.player_kings |= mask;
+
+        true
+    }
+
+    fn into_indices(mut bitboard: u64) -> impl Iterator<Item = u8> {
+        from_fn(move || {
+            if bitboard == 0 {
+                None
+            } else {
+                let mask = bitboard & (!bitboard + 1);
+                bitboard ^= mask;
+
+                Some(u8::try_from(mask.trailing_zeros()).unwrap())
+            }
+        })
+    }
+
+    fn parse_square(value: &str) -> Result<u8, String> {
+        let (col, row) = value.split_at_checked(1).ok_or("empty square")?;
+
+        let col = col.as_bytes()[0].wrapping_sub(b'a') as usize;
+        let row = row.parse::<usize>().unwrap_or_default();
+
+        if col >= Self::BOARD_SIZE || !(1..=Self::BOARD_SIZE).contains(&row) {
+            return Err(format!("invalid square: {value}"));
+        }
+
+        Ok(u8::try_from((row - 1) * Self::BOARD_SIZE + col).unwrap())
+    }
+
+    const fn make_dark_squares() -> u64 {
+        let mut mask = 0u64;
+
+        let mut index = 0;
+
+        while index < Self::BOARD_SIZE * Self::BOARD_SIZE {
+            if (index / Self::BOARD_SIZE + index % Self::BOARD_SIZE) % 2 == 1 {
+                mask |= 1u64 << index;
+            }
+
+            index += 1;
+        }
+
+        mask
+    }
+
+    const fn make_rows_mask(first_row: usize, last_row: usize) -> u64 {
+        let row_mask = (1u64 << Self::BOARD_SIZE) - 1;
+
+        let mut mask = 0u64;
+
+        let mut row = first_row;
+
+        while row <= last_row {
+            mask |= row_mask << (row * Self::BOARD_SIZE);
+
+            row += 1;
+        }
+
+        mask & Self::DARK_SQUARES
+    }
+}
+
+impl Game for Checkers {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Checkpoint;
+    type Config = ();
+
+    fn new_with_config(_config: ()) -> Self {
+        Checkers {
+            phase: Phase::Move,
+
+            player_men: Self::make_rows_mask(5, 7),
+            player_kings: 0,
+
+            opponent_men: Self::make_rows_mask(0, 2),
+            opponent_kings: 0,
+
+            player_direction: Direction::Up,
+            quiet_plies: 0,
+        }
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        if self.outcome() != Outcome::InProgress {
+            return ActionList::new();
+        }
+
+        self.legal_actions()
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Move { from, to } => {
+                let king_moved = self.move_piece(from, to);
+
+                self.quiet_plies = if king_moved {
+                    self.quiet_plies.saturating_add(1)
+                } else {
+                    0
+                };
+
+                self.crown(to);
+            }
+            Action::Jump { from, to } => {
+                self.move_piece(from, to);
+
+                let over = 1u64 << u8::midpoint(from, to);
+
+                self.opponent_men &= !over;
+                self.opponent_kings &= !over;
+
+                self.quiet_plies = 0;
+
+                if !self.crown(to) {
+                    self.phase = Phase::Jump { square: to };
+
+                    if !self.legal_actions().is_empty() {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        self.phase = Phase::Finished;
+
+        true
+    }
+
+    fn end_turn(&mut self) {
+        self.phase = Phase::Move;
+
+        self.flip_perspective();
+    }
+
+    // NOTE - A side that cannot move loses. Once the player's turn is finished, that is the
+    // opponent, who moves next.
+
+    fn outcome(&self) -> Outcome {
+        match self.phase {
+            Phase::Move => {
+                if self.legal_actions().is_empty() {
+                    return Outcome::Loss;
+                }
+            }
+            Phase::Jump { .. } => {}
+            Phase::Finished => {
+                let mut game = self.clone();
+
+                game.end_turn();
+
+                if game.legal_actions().is_empty() {
+                    return Outcome::Win;
+                }
+            }
+        }
+
+        if self.quiet_plies >= Self::NO_PROGRESS_LIMIT {
+            return Outcome::Draw;
+        }
+
+        Outcome::InProgress
+    }
+
+    // NOTE - The quiet ply count is left out, since it grows with every king move and would
+    // keep a repeated position from ever matching.
+
+    fn repetition_key(&self) -> Option<u64> {
+        Some(hash_position(&(
+            self.player_men,
+            self.player_kings,
+            self.opponent_men,
+            self.opponent_kings,
+            self.player_direction,
+        )))
+    }
+
+    fn create_checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            phase: self.phase,
+
+            player_men: self.player_men,
+            player_kings: self.player_kings,
+
+            opponent_men: self.opponent_men,
+            opponent_kings: self.opponent_kings,
+
+            player_direction: self.player_direction,
+            quiet_plies: self.quiet_plies,
+        }
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Checkpoint) {
+        self.phase = checkpoint.phase;
+
+        self.player_men = checkpoint.player_men;
+        self.player_kings = checkpoint.player_kings;
+
+        self.opponent_men = checkpoint.opponent_men;
+        self.opponent_kings = checkpoint.opponent_kings;
+
+        self.player_direction = checkpoint.player_direction;
+        self.quiet_plies = checkpoint.quiet_plies;
+    }
+
+    // NOTE - Reflections move pieces onto light squares, and the half turn swaps each
+    // side's direction, so there are no symmetries besides the identity.
+
+    fn symmetries(&self) -> u8 {
+        Self::SYMMETRY_COUNT
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        debug_assert!(symmetry < Self::SYMMETRY_COUNT);
+
+        self.clone()
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        debug_assert!(symmetry < Self::SYMMETRY_COUNT);
+
+        action
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        let mut game = self.clone();
+
+        if turn == Turn::Player2 {
+            game.flip_perspective();
+        }
+
+        format!("{game}")
+    }
+
+    fn to_notation(&self) -> String {
+        let board = format_rows((0..Self::BOARD_SIZE).map(|row| {
+            (0..Self::BOARD_SIZE).map(move |col| {
+                let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+                if self.player_men & mask != 0 {
+                    Some('x')
+                } else if self.player_kings & mask != 0 {
+                    Some('X')
+                } else if self.opponent_men & mask != 0 {
+                    Some('o')
+                } else if self.opponent_kings & mask != 0 {
+                    Some('O')
+                } else {
+                    None
+                }
+            })
+        }));
+
+        let direction = match self.player_direction {
+            Direction::Up => "up",
+            Direction::Down => "down",
+        };
+
+        let phase = match self.phase {
+            Phase::Move => "move".to_string(),
+            Phase::Jump { square } => Self::square_name(square),
+            Phase::Finished => "finished".to_string(),
+        };
+
+        format!("{board} {direction} {phase} {}", self.quiet_plies)
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = notation.split_whitespace().collect();
+
+        if fields.len() != 4 {
+            return Err(format!("expected 4 fields, found {}", fields.len()));
+        }
+
+        let rows = parse_grid(fields[0], Self::BOARD_SIZE, Self::BOARD_SIZE)?;
+
+        let mut game = Checkers {
+            phase: Phase::Move,
+
+            player_men: 0,
+            player_kings: 0,
+
+            opponent_men: 0,
+            opponent_kings: 0,
+
+            player_direction: Direction::Up,
+            quiet_plies: 0,
+        };
+
+        for (row, cells) in rows.into_iter().enumerate() {
+            for (col, cell) in cells.into_iter().enumerate() {
+                let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+                let board = match cell {
+                    None => continue,
+                    Some('x') => &mut game.player_men,
+                    Some('X') => &mut game.player_kings,
+                    Some('o') => &mut game.opponent_men,
+                    Some('O') => &mut game.opponent_kings,
+                    Some(character) => {
+                        return Err(format!("invalid character at row {}: {character}", row + 1));
+                    }
+                };
+
+                if Self::DARK_SQUARES & mask == 0 {
+                    return Err(format!("piece on a light square at row {}", row + 1));
+                }
+
+                *board |= mask;
+            }
+        }
+
+        game.player_direction = match fields[1] {
+            "up" => Direction::Up,
+            "down" => Direction::Down,
+            field => return Err(format!("invalid direction: {field}")),
+        };
+
+        game.phase = match fields[2] {
+            "move" => Phase::Move,
+            "finished" => Phase::Finished,
+            field => Phase::Jump {
+                square: Self::parse_square(field)?,
+            },
+        };
+
+        game.quiet_plies = fields[3]
+            .parse()
+            .map_err(|_| format!("invalid quiet plies: {}", fields[3]))?;
+
+        Ok(game)
+    }
+}
+
+impl fmt::Display for Checkers {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // NOTE - Board
+
+        write!(formatter, "  ")?;
+
+        for col in 0..Self::BOARD_SIZE {
+            write!(
+                formatter,
+                " {}",
+                char::from(b'a' + u8::try_from(col).unwrap())
+            )?;
+        }
+
+        writeln!(formatter)?;
+
+        for row in 0..Self::BOARD_SIZE {
+            write!(formatter, "{:>2}", row + 1)?;
+
+            for col in 0..Self::BOARD_SIZE {
+                let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+                let character = if self.player_men & mask != 0 {
+                    'x'
+                } else if self.player_kings & mask != 0 {
+                    'X'
+                } else if self.opponent_men & mask != 0 {
+                    'o'
+                } else if self.opponent_kings & mask != 0 {
+                    'O'
+                } else {
+                    '.'
+                };
+
+                write!(formatter, " {character}")?;
+            }
+
+            writeln!(formatter)?;
+        }
+
+        // NOTE - Direction, phase and progress
+
+        let direction = match self.player_direction {
+            Direction::Up => "up",
+            Direction::Down => "down",
+        };
+
+        writeln!(formatter, "X: {direction}")?;
+
+        match self.phase {
+            Phase::Move => {}
+            Phase::Jump { square } => {
+                writeln!(formatter, "Phase: Jump {}", Self::square_name(square))?;
+            }
+            Phase::Finished => writeln!(formatter, "Phase: Finished")?,
+        }
+
+        if self.quiet_plies > 0 {
+            writeln!(formatter, "Quiet plies: {}", self.quiet_plies)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl str::FromStr for Checkers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        if lines.len() < Self::BOARD_SIZE + 2 {
+            return Err(format!(
+                "expected at least {} lines, found {}",
+                Self::BOARD_SIZE + 2,
+                lines.len()
+            ));
+        }
+
+        let mut game = Checkers {
+            phase: Phase::Move,
+
+            player_men: 0,
+            player_kings: 0,
+
+            opponent_men: 0,
+            opponent_kings: 0,
+
+            player_direction: Direction::Up,
+            quiet_plies: 0,
+        };
+
+        // NOTE - Board
+
+        for (row, line) in lines[1..=Self::BOARD_SIZE].iter().enumerate() {
+            let cells: Vec<&str> = line.split_whitespace().skip(1).collect();
+
+            if cells.len() != Self::BOARD_SIZE {
+                return Err(format!("malformed board row {}", row + 1));
+            }
+
+            for (col, cell) in cells.into_iter().enumerate() {
+                let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+                let board = match cell {
+                    "." => continue,
+                    "x" => &mut game.player_men,
+                    "X" => &mut game.player_kings,
+                    "o" => &mut game.opponent_men,
+                    "O" => &mut game.opponent_kings,
+                    _ => return Err(format!("invalid character at row {}: {cell}", row + 1)),
+                };
+
+                if Self::DARK_SQUARES & mask == 0 {
+                    return Err(format!("piece on a light square at row {}", row + 1));
+                }
+
+                *board |= mask;
+            }
+        }
+
+        // NOTE - Direction, phase and progress
+
+        game.player_direction = match lines[Self::BOARD_SIZE + 1] {
+            "X: up" => Direction::Up,
+            "X: down" => Direction::Down,
+            line => return Err(format!("invalid direction: {line}")),
+        };
+
+        for line in &lines[Self::BOARD_SIZE + 2..] {
+            if let Some(phase) = line.strip_prefix("Phase:") {
+                game.phase = match phase.trim() {
+                    "Move" => Phase::Move,
+                    "Finished" => Phase::Finished,
+                    phase => match phase.strip_prefix("Jump") {
+                        Some(square) => Phase::Jump {
+                            square: Self::parse_square(square.trim())?,
+                        },
+                        None => return Err(format!("invalid phase: {phase}")),
+                    },
+                };
+            } else if let Some(quiet_plies) = line.strip_prefix("Quiet plies:") {
+                game.quiet_plies = quiet_plies
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("invalid quiet plies: {quiet_plies}"))?;
+            } else {
+                return Err(format!("unexpected line: {line}"));
+            }
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::seq::IndexedRandom;
+
+    use crate::core::{DefaultRngFactory, RngFactory};
+
+    fn parse_game(value: &str) -> Checkers {
+        value.parse().expect("unable to parse game")
+    }
+
+    fn square(row: usize, col: usize) -> u8 {
+        u8::try_from(row * Checkers::BOARD_SIZE + col).unwrap()
+    }
+
+    mod get_possible_actions {
+        use super::*;
+
+        #[test]
+        fn should_move_men_forward_from_the_start() {
+            let game = Checkers::new();
+
+            let actions = game.get_possible_actions();
+
+            assert_eq!(actions.len(), 7);
+            assert!(
+                actions
+                    .iter()
+                    .all(|action| matches!(action, Action::Move { from, to } if to < from))
+            );
+        }
+
+        #[test]
+        fn should_force_captures() {
+            let game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 . . . . . . . .
+                     3 . . . . . . . .
+                     4 . . . . o . . .
+                     5 . . . x . . . .
+                     6 . . . . . . x .
+                     7 . . . . . . . .
+                     8 . . . . . . . .
+                    X: up
+                ",
+            );
+
+            assert_eq!(
+                game.get_possible_actions().as_slice(),
+                &[Action::Jump {
+                    from: square(4, 3),
+                    to: square(2, 5)
+                }]
+            );
+        }
+
+        #[test]
+        fn should_move_kings_backward() {
+            let game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 . . . . . . . .
+                     3 . . . . . . . .
+                     4 . . . . . . . .
+                     5 . . . X . . . .
+                     6 . . . . . . . .
+                     7 . . . . . . . .
+                     8 o . . . . . . .
+                    X: up
+                ",
+            );
+
+            assert_eq!(game.get_possible_actions().len(), 4);
+        }
+    }
+
+    mod apply_action {
+        use super::*;
+
+        #[test]
+        fn should_continue_jump_chains() {
+            let mut game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 . . . . . . . .
+                     3 . . . . . . . .
+                     4 . . . . o . . .
+                     5 . . . . . . . .
+                     6 . . o . . . . .
+                     7 . x . . . . . .
+                     8 . . . . . . . .
+                    X: up
+                ",
+            );
+
+            let turn_complete = game.apply_action(Action::Jump {
+                from: square(6, 1),
+                to: square(4, 3),
+            });
+
+            assert!(!turn_complete);
+            assert_eq!(
+                game.phase,
+                Phase::Jump {
+                    square: square(4, 3)
+                }
+            );
+            assert_eq!(game.outcome(), Outcome::InProgress);
+
+            let turn_complete = game.apply_action(Action::Jump {
+                from: square(4, 3),
+                to: square(2, 5),
+            });
+
+            assert!(turn_complete);
+            assert_eq!(game.opponent_men, 0);
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+
+        #[test]
+        fn should_end_the_turn_when_crowning() {
+            let mut game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 . . . . o . o .
+                     3 . . . x . . . .
+                     4 . . . . . . . .
+                     5 . . . . . . . .
+                     6 . . . . . . . .
+                     7 . . . . . . . .
+                     8 . . . . . . . .
+                    X: up
+                ",
+            );
+
+            let turn_complete = game.apply_action(Action::Jump {
+                from: square(2, 3),
+                to: square(0, 5),
+            });
+
+            assert!(turn_complete);
+            assert_eq!(game.player_men, 0);
+            assert_eq!(game.player_kings, 1u64 << square(0, 5));
+        }
+
+        #[test]
+        fn should_count_quiet_plies_for_king_moves() {
+            let mut game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 . . . . . . . .
+                     3 . . . . . . . .
+                     4 . . . . . . . .
+                     5 . . . X . . . .
+                     6 . . . . . . . .
+                     7 . . . . . x . .
+                     8 o . . . . . . .
+                    X: up
+                    Quiet plies: 5
+                ",
+            );
+
+            game.apply_action(Action::Move {
+                from: square(4, 3),
+                to: square(5, 2),
+            });
+
+            assert_eq!(game.quiet_plies, 6);
+
+            game.phase = Phase::Move;
+
+            game.apply_action(Action::Move {
+                from: square(6, 5),
+                to: square(5, 4),
+            });
+
+            assert_eq!(game.quiet_plies, 0);
+        }
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        fn should_lose_when_blocked() {
+            let game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 . . . . . . . .
+                     3 . . . . . . . .
+                     4 . . . . . . . .
+                     5 . . . . . . . .
+                     6 . . o . . . . .
+                     7 . o . . . . . .
+                     8 x . . . . . . .
+                    X: up
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+        }
+
+        #[test]
+        fn should_draw_without_progress() {
+            let game = parse_game(
+                "
+                       a b c d e f g h
+                     1 . . . . . . . .
+                     2 . . . . . . . .
+                     3 . . . . . . . .
+                     4 . . . . . . . .
+                     5 . . . X . . . .
+                     6 . . . . . . . .
+                     7 . . . . . . . .
+                     8 O . . . . . . .
+                    X: up
+                    Quiet plies: 80
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::Draw);
+        }
+
+        #[test]
+        fn should_always_finish() {
+            let mut rng = DefaultRngFactory::default().create_seeded(7);
+
+            for _ in 0..10 {
+                let mut game = Checkers::new();
+
+                while game.outcome() == Outcome::InProgress {
+                    let action = *game
+                        .get_possible_actions()
+                        .choose(&mut rng)
+                        .expect("unfinished game has no actions");
+
+                    if game.apply_action(action) && game.outcome() == Outcome::InProgress {
+                        game.end_turn();
+                    }
+                }
+
+                assert_ne!(game.outcome(), Outcome::Loss);
+            }
+        }
+    }
+
+    mod repetition_key {
+        use super::*;
+
+        #[test]
+        fn should_ignore_the_quiet_ply_count() {
+            let mut game = Checkers::new();
+
+            let key = game.repetition_key();
+
+            game.quiet_plies = 12;
+
+            assert_eq!(game.repetition_key(), key);
+
+            game.end_turn();
+
+            assert_ne!(game.repetition_key(), key);
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = Checkers::new();
+
+            game.phase = Phase::Jump {
+                square: square(5, 0),
+            };
+            game.quiet_plies = 3;
+
+            let parsed =
+                Checkers::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = Checkers::new();
+
+            game.phase = Phase::Jump {
+                square: square(5, 0),
+            };
+            game.quiet_plies = 3;
+
+            let parsed: Checkers = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+
+        #[test]
+        fn should_reject_pieces_on_light_squares() {
+            let result = "
+                   a b c d e f g h
+                 1 x . . . . . . .
+                 2 . . . . . . . .
+                 3 . . . . . . . .
+                 4 . . . . . . . .
+                 5 . . . . . . . .
+                 6 . . . . . . . .
+                 7 . . . . . . . .
+                 8 . . . . . . . .
+                X: up
+            "
+            .parse::<Checkers>();
+
+            assert!(result.is_err());
+        }
+    }
+}
@@ -0,0 +1,12 @@
+mod action;
+mod action_encoder;
+#[allow(clippy::module_inception)]
+mod checkers;
+mod portable;
+mod renderable;
+mod state_encoder;
+
+pub use action::Action as CheckersAction;
+pub use action_encoder::CheckersActionEncoder;
+pub use checkers::{Checkers, Direction as CheckersDirection, Phase as CheckersPhase};
+pub use state_encoder::CheckersStateEncoder;
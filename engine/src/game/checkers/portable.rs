@@ -0,0 +1,38 @@
+use crate::game::checkers::Checkers;
+use crate::game::checkers::action::Action;
+use crate::record::PortableGame;
+
+impl PortableGame for Checkers {
+    const NAME: &'static str = "checkers";
+
+    fn board_size() -> usize {
+        Self::BOARD_SIZE
+    }
+
+    fn format_action(action: Action) -> String {
+        match action {
+            Action::Move { from, to } => {
+                format!("m{}{}", Self::format_point(from), Self::format_point(to))
+            }
+            Action::Jump { from, to } => {
+                format!("j{}{}", Self::format_point(from), Self::format_point(to))
+            }
+        }
+    }
+
+    fn parse_action(value: &str) -> Result<Action, String> {
+        let (prefix, points) = value.split_at_checked(1).ok_or("empty action")?;
+
+        let indices = points
+            .as_bytes()
+            .chunks(2)
+            .map(|point| Self::parse_point(str::from_utf8(point).unwrap_or_default()))
+            .collect::<Result<Vec<u8>, String>>()?;
+
+        match (prefix, indices.as_slice()) {
+            ("m", &[from, to]) => Ok(Action::Move { from, to }),
+            ("j", &[from, to]) => Ok(Action::Jump { from, to }),
+            _ => Err(format!("invalid action: {value}")),
+        }
+    }
+}
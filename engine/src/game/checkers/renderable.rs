@@ -0,0 +1,90 @@
+use crate::core::Turn;
+use crate::game::checkers::Checkers;
+use crate::game::checkers::action::Action;
+use crate::render::{PieceShape, RenderableGame, RenderedPiece};
+
+impl RenderableGame for Checkers {
+    fn grid_size(&self) -> (usize, usize) {
+        (Self::BOARD_SIZE, Self::BOARD_SIZE)
+    }
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece> {
+        let mask = 1u64 << (row * Self::BOARD_SIZE + col);
+
+        let (owner, shape) = if self.player_men & mask != 0 {
+            (turn, PieceShape::Small)
+        } else if self.player_kings & mask != 0 {
+            (turn, PieceShape::Large)
+        } else if self.opponent_men & mask != 0 {
+            (turn.advance(), PieceShape::Small)
+        } else if self.opponent_kings & mask != 0 {
+            (turn.advance(), PieceShape::Large)
+        } else {
+            return None;
+        };
+
+        Some(RenderedPiece { owner, shape })
+    }
+
+    fn action_squares(&self, action: Action) -> Vec<(usize, usize)> {
+        match action {
+            Action::Move { from, to } | Action::Jump { from, to } => [from, to]
+                .iter()
+                .map(|&square| {
+                    (
+                        square as usize / Self::BOARD_SIZE,
+                        square as usize % Self::BOARD_SIZE,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::render::weighted_squares;
+
+    mod piece_at {
+        use super::*;
+
+        #[test]
+        fn should_draw_the_starting_pieces() {
+            let game = Checkers::new();
+
+            assert_eq!(
+                game.piece_at(7, 0, Turn::Player1),
+                Some(RenderedPiece {
+                    owner: Turn::Player1,
+                    shape: PieceShape::Small,
+                })
+            );
+            assert_eq!(
+                game.piece_at(0, 1, Turn::Player1),
+                Some(RenderedPiece {
+                    owner: Turn::Player2,
+                    shape: PieceShape::Small,
+                })
+            );
+            assert_eq!(game.piece_at(7, 1, Turn::Player1), None);
+        }
+    }
+
+    mod square_weights {
+        use super::*;
+
+        #[test]
+        fn should_map_actions_onto_their_squares() {
+            let game = Checkers::new();
+
+            assert_eq!(game.grid_size(), (8, 8));
+            assert_eq!(
+                weighted_squares(&game, &[(Action::Jump { from: 40, to: 26 }, 0.5)],),
+                vec![(26, 0.25), (40, 0.25)]
+            );
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::game::checkers::checkers::{Checkers, Direction, Phase};
+use crate::neural_network::StateEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct CheckersStateEncoder;
+
+impl CheckersStateEncoder {
+    const PLANE_COUNT: usize = 7;
+
+    pub fn new() -> Self {
+        CheckersStateEncoder
+    }
+
+    fn plane_slice(planes: &mut [f32], plane_index: usize) -> &mut [f32] {
+        let plane_size = Checkers::BOARD_SIZE * Checkers::BOARD_SIZE;
+
+        let start = plane_index * plane_size;
+        let end = start + plane_size;
+
+        &mut planes[start..end]
+    }
+
+    fn bitboard_to_plane(bits: u64, plane: &mut [f32]) {
+        for (i, value) in plane.iter_mut().enumerate() {
+            *value = ((bits >> i) & 1) as f32;
+        }
+    }
+}
+
+impl StateEncoder<Checkers> for CheckersStateEncoder {
+    fn shape(&self) -> Vec<usize> {
+        vec![
+            1,
+            Self::PLANE_COUNT,
+            Checkers::BOARD_SIZE,
+            Checkers::BOARD_SIZE,
+        ]
+    }
+
+    fn encode(&self, state: &Checkers) -> Vec<f32> {
+        let plane_size = Checkers::BOARD_SIZE * Checkers::BOARD_SIZE;
+        let plane_count = Self::PLANE_COUNT;
+
+        let mut planes = vec![0.0; plane_size * plane_count];
+
+        Self::bitboard_to_plane(state.player_men, Self::plane_slice(&mut planes, 0));
+        Self::bitboard_to_plane(state.player_kings, Self::plane_slice(&mut planes, 1));
+        Self::bitboard_to_plane(state.opponent_men, Self::plane_slice(&mut planes, 2));
+        Self::bitboard_to_plane(state.opponent_kings, Self::plane_slice(&mut planes, 3));
+
+        // NOTE - The piece that has to keep jumping, if the turn is part way through a chain.
+
+        if let Phase::Jump { square } = state.phase {
+            Self::plane_slice(&mut planes, 4)[square as usize] = 1.0;
+        }
+
+        // NOTE - The side to move's direction, since the board is not rotated between turns.
+
+        if state.player_direction == Direction::Up {
+            Self::plane_slice(&mut planes, 5).fill(1.0);
+        }
+
+        Self::plane_slice(&mut planes, 6)
+            .fill(f32::from(state.quiet_plies) / f32::from(Checkers::NO_PROGRESS_LIMIT));
+
+        planes
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt;
+
+use crate::game::chess::chess::{Chess, Underpromotion};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action {
+    Move {
+        from: u8,
+        to: u8,
+        underpromotion: Option<Underpromotion>,
+    },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Move {
+                from,
+                to,
+                underpromotion,
+            } => {
+                write!(
+                    f,
+                    "moves from {} to {}",
+                    Chess::square_name(*from),
+                    Chess::square_name(*to)
+                )?;
+
+                match underpromotion {
+                    Some(Underpromotion::Knight) => write!(f, ", promoting to a knight."),
+                    Some(Underpromotion::Bishop) => write!(f, ", promoting to a bishop."),
+                    Some(Underpromotion::Rook) => write!(f, ", promoting to a rook."),
+                    None => write!(f, "."),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::game::chess::action::Action;
+use crate::game::chess::chess::{Chess, Underpromotion};
+use crate::neural_network::ActionEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct ChessActionEncoder;
+
+// NOTE - The 8x8x73 layout from AlphaZero. Each square a piece moves from has 56 planes for
+// sliding up to seven squares in one of eight directions, 8 for knight jumps and 9 for
+// underpromotions, straight or capturing either way. Queen promotions are ordinary moves.
+
+impl ChessActionEncoder {
+    const SQUARE_COUNT: usize = Chess::BOARD_SIZE * Chess::BOARD_SIZE;
+    const PLANE_COUNT: usize = 73;
+
+    const SLIDE_DISTANCE: usize = 7;
+    const KNIGHT_PLANE: usize = 8 * Self::SLIDE_DISTANCE;
+    const UNDERPROMOTION_PLANE: usize = Self::KNIGHT_PLANE + 8;
+
+    const DIRECTIONS: [(i8, i8); 8] = [
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+    ];
+
+    const KNIGHT_JUMPS: [(i8, i8); 8] = [
+        (1, 2),
+        (2, 1),
+        (2, -1),
+        (1, -2),
+        (-1, -2),
+        (-2, -1),
+        (-2, 1),
+        (-1, 2),
+    ];
+
+    pub fn new() -> Self {
+        ChessActionEncoder
+    }
+
+    // NOTE - File and rank offsets from one square to another.
+
+    fn offset(from: u8, to: u8) -> (i8, i8) {
+        let file = i8::try_from(to % 8).unwrap() - i8::try_from(from % 8).unwrap();
+        let rank = i8::try_from(to / 8).unwrap() - i8::try_from(from / 8).unwrap();
+
+        (file, rank)
+    }
+
+    fn step(from: usize, (file, rank): (i8, i8)) -> Option<usize> {
+        let to_file = (from % 8).checked_add_signed(isize::from(file))?;
+        let to_rank = (from / 8).checked_add_signed(isize::from(rank))?;
+
+        (to_file < Chess::BOARD_SIZE && to_rank < Chess::BOARD_SIZE)
+            .then_some(to_rank * Chess::BOARD_SIZE + to_file)
+    }
+}
+
+impl ActionEncoder<Chess> for ChessActionEncoder {
+    const ACTION_COUNT: usize = Self::SQUARE_COUNT * Self::PLANE_COUNT;
+
+    fn encode(&self, action: &Action) -> usize {
+        let Action::Move {
+            from,
+            to,
+            underpromotion,
+        } = *action;
+
+        let (file, rank) = Self::offset(from, to);
+
+        let plane = if let Some(underpromotion) = underpromotion {
+            let piece = Underpromotion::ALL
+                .iter()
+                .position(|&other| other == underpromotion)
+                .unwrap();
+
+            Self::UNDERPROMOTION_PLANE + usize::try_from(file + 1).unwrap() * 3 + piece
+        } else if let Some(jump) = Self::KNIGHT_JUMPS
+            .iter()
+            .position(|&jump| jump == (file, rank))
+        {
+            Self::KNIGHT_PLANE + jump
+        } else {
+            let direction = Self::DIRECTIONS
+                .iter()
+                .position(|&direction| direction == (file.signum(), rank.signum()))
+                .unwrap();
+
+            let distance = usize::from(file.unsigned_abs().max(rank.unsigned_abs()));
+
+            direction * Self::SLIDE_DISTANCE + distance - 1
+        };
+
+        from as usize * Self::PLANE_COUNT + plane
+    }
+
+    fn decode(&self, action_id: usize) -> Action {
+        debug_assert!(action_id < Self::ACTION_COUNT);
+
+        let from = action_id / Self::PLANE_COUNT;
+        let plane = action_id % Self::PLANE_COUNT;
+
+        let (offset, underpromotion) = if plane < Self::KNIGHT_PLANE {
+            let (file, rank) = Self::DIRECTIONS[plane / Self::SLIDE_DISTANCE];
+            let distance = i8::try_from(plane % Self::SLIDE_DISTANCE + 1).unwrap();
+
+            ((file * distance, rank * distance), None)
+        } else if plane < Self::UNDERPROMOTION_PLANE {
+            (Self::KNIGHT_JUMPS[plane - Self::KNIGHT_PLANE], None)
+        } else {
+            let index = plane - Self::UNDERPROMOTION_PLANE;
+
+            // NOTE - Only pawns one step from promoting can underpromote, and which way
+            // they step follows from the rank they stand on.
+
+            let rank = if from / Chess::BOARD_SIZE == 1 { -1 } else { 1 };
+            let file = i8::try_from(index / 3).unwrap() - 1;
+
+            ((file, rank), Some(Underpromotion::ALL[index % 3]))
+        };
+
+        let to = Self::step(from, offset).unwrap_or(from);
+
+        Action::Move {
+            from: u8::try_from(from).unwrap(),
+            to: u8::try_from(to).unwrap(),
+            underpromotion,
+        }
+    }
+}
@@ -0,0 +1,619 @@
+use std::hash::{Hash, Hasher};
+use std::{fmt, str};
+
+use shakmaty::fen::Fen;
+use shakmaty::uci::UciMove;
+use shakmaty::zobrist::Zobrist64;
+use shakmaty::{CastlingMode, Color, EnPassantMode, Move, Piece, Position, Rank, Role, Square};
+
+use crate::core::{ActionList, Game, Outcome, Turn};
+use crate::game::chess::action::Action;
+
+// NOTE - Standard chess, with move generation and the rules themselves left to `shakmaty`.
+// Every position since the start is kept, so repetitions can be counted, the state encoder
+// can look back over earlier positions and a checkpoint is just the length of the history.
+// Squares are indexed from a1 = 0 to h8 = 63 and are never flipped for black, so the side
+// to move is carried separately.
+//
+// Checkmate wins. Stalemate, threefold repetition, the fifty move rule and insufficient
+// material are all draws.
+
+#[derive(Clone, Debug)]
+pub struct Chess {
+    pub phase: Phase,
+
+    positions: Vec<shakmaty::Chess>,
+    hashes: Vec<u64>,
+
+    pub player_color: Color,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Phase {
+    Move,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Underpromotion {
+    Knight,
+    Bishop,
+    Rook,
+}
+
+impl Underpromotion {
+    pub(crate) const ALL: [Underpromotion; 3] = [
+        Underpromotion::Knight,
+        Underpromotion::Bishop,
+        Underpromotion::Rook,
+    ];
+
+    fn role(self) -> Role {
+        match self {
+            Underpromotion::Knight => Role::Knight,
+            Underpromotion::Bishop => Role::Bishop,
+            Underpromotion::Rook => Role::Rook,
+        }
+    }
+
+    fn from_role(role: Role) -> Option<Self> {
+        match role {
+            Role::Knight => Some(Underpromotion::Knight),
+            Role::Bishop => Some(Underpromotion::Bishop),
+            Role::Rook => Some(Underpromotion::Rook),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    plies: usize,
+
+    player_color: Color,
+}
+
+impl Chess {
+    pub const BOARD_SIZE: usize = 8;
+
+    const FIFTY_MOVE_PLIES: u32 = 100;
+    const REPETITION_LIMIT: usize = 3;
+
+    pub fn position(&self) -> &shakmaty::Chess {
+        self.positions.last().expect("history is never empty")
+    }
+
+    // NOTE - Earlier positions, most recent first, starting with the current one.
+
+    pub(crate) fn history(&self) -> impl Iterator<Item = &shakmaty::Chess> {
+        self.positions.iter().rev()
+    }
+
+    // NOTE - How many times the position `plies` back from the current one had already
+    // occurred before it was reached. Only positions since the last capture or pawn move
+    // can repeat, and only every other one has the same side to move.
+
+    pub(crate) fn repetitions(&self, plies: usize) -> usize {
+        let Some(index) = self.hashes.len().checked_sub(plies + 1) else {
+            return 0;
+        };
+
+        let reversible = self.positions[index].halfmoves() as usize;
+
+        self.hashes[..index]
+            .iter()
+            .rev()
+            .take(reversible)
+            .skip(1)
+            .step_by(2)
+            .filter(|&&hash| hash == self.hashes[index])
+            .count()
+    }
+
+    pub(crate) fn square_name(square: u8) -> String {
+        Square::new(u32::from(square)).to_string()
+    }
+
+    fn hash(position: &shakmaty::Chess) -> u64 {
+        position.zobrist_hash::<Zobrist64>(EnPassantMode::Legal).0
+    }
+
+    fn to_action(chess_move: Move) -> Action {
+        match chess_move.to_uci(CastlingMode::Standard) {
+            UciMove::Normal {
+                from,
+                to,
+                promotion,
+            } => Action::Move {
+                from: u8::from(from),
+                to: u8::from(to),
+                underpromotion: promotion.and_then(Underpromotion::from_role),
+            },
+            _ => unreachable!("standard moves are always normal"),
+        }
+    }
+
+    fn to_move(&self, action: Action) -> Option<Move> {
+        let Action::Move {
+            from,
+            to,
+            underpromotion,
+        } = action;
+
+        let from = Square::new(u32::from(from));
+        let to = Square::new(u32::from(to));
+
+        let position = self.position();
+
+        // NOTE - A pawn reaching the last rank without an underpromotion becomes a queen.
+
+        let promotion = match underpromotion {
+            Some(underpromotion) => Some(underpromotion.role()),
+            None if position.board().role_at(from) == Some(Role::Pawn)
+                && to.rank() == position.turn().fold_wb(Rank::Eighth, Rank::First) =>
+            {
+                Some(Role::Queen)
+            }
+            None => None,
+        };
+
+        let chess_move = UciMove::Normal {
+            from,
+            to,
+            promotion,
+        }
+        .to_move(position)
+        .ok()?;
+
+        position.is_legal(chess_move).then_some(chess_move)
+    }
+
+    fn from_position(position: shakmaty::Chess) -> Self {
+        Chess {
+            phase: Phase::Move,
+
+            hashes: vec![Self::hash(&position)],
+            player_color: position.turn(),
+            positions: vec![position],
+        }
+    }
+
+    pub fn from_fen(fen: &str) -> Result<Self, String> {
+        let position = fen
+            .parse::<Fen>()
+            .map_err(|error| format!("invalid FEN: {error}"))?
+            .into_position::<shakmaty::Chess>(CastlingMode::Standard)
+            .map_err(|error| format!("invalid position: {error}"))?;
+
+        Ok(Self::from_position(position))
+    }
+
+    pub fn fen(&self) -> String {
+        Fen::from_position(self.position(), EnPassantMode::Legal).to_string()
+    }
+}
+
+impl Game for Chess {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Checkpoint;
+    type Config = ();
+
+    fn new_with_config(_config: ()) -> Self {
+        Self::from_position(shakmaty::Chess::default())
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        if self.outcome() != Outcome::InProgress {
+            return ActionList::new();
+        }
+
+        self.position()
+            .legal_moves()
+            .into_iter()
+            .map(Self::to_action)
+            .collect()
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        let chess_move = self.to_move(action).expect("action is not legal");
+
+        let mut position = self.position().clone();
+        position.play_unchecked(chess_move);
+
+        self.hashes.push(Self::hash(&position));
+        self.positions.push(position);
+
+        true
+    }
+
+    fn end_turn(&mut self) {
+        self.player_color = !self.player_color;
+    }
+
+    fn outcome(&self) -> Outcome {
+        let position = self.position();
+
+        if position.is_checkmate() {
+            return if position.turn() == self.player_color {
+                Outcome::Loss
+            } else {
+                Outcome::Win
+            };
+        }
+
+        if position.is_stalemate()
+            || position.is_insufficient_material()
+            || position.halfmoves() >= Self::FIFTY_MOVE_PLIES
+            || self.repetitions(0) + 1 >= Self::REPETITION_LIMIT
+        {
+            return Outcome::Draw;
+        }
+
+        Outcome::InProgress
+    }
+
+    fn create_checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            plies: self.positions.len(),
+
+            player_color: self.player_color,
+        }
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Checkpoint) {
+        self.positions.truncate(checkpoint.plies);
+        self.hashes.truncate(checkpoint.plies);
+
+        self.player_color = checkpoint.player_color;
+    }
+
+    // NOTE - Castling rights and pawn direction rule out both reflections, so there are no
+    // symmetries besides the identity.
+
+    fn symmetries(&self) -> u8 {
+        1
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        debug_assert!(symmetry == 0);
+
+        self.clone()
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        debug_assert!(symmetry == 0);
+
+        action
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        let mut game = self.clone();
+
+        if turn == Turn::Player2 {
+            game.player_color = !game.player_color;
+        }
+
+        format!("{game}")
+    }
+
+    fn to_notation(&self) -> String {
+        self.fen()
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        Self::from_fen(notation)
+    }
+}
+
+// NOTE - Only the current position and the side to move are compared, the same as the
+// position a player would see.
+
+impl PartialEq for Chess {
+    fn eq(&self, other: &Self) -> bool {
+        self.hashes.last() == other.hashes.last() && self.player_color == other.player_color
+    }
+}
+
+impl Eq for Chess {}
+
+impl Hash for Chess {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hashes.last().hash(state);
+        self.player_color.hash(state);
+    }
+}
+
+impl fmt::Display for Chess {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // NOTE - Board
+
+        write!(formatter, "  ")?;
+
+        for col in 0..Self::BOARD_SIZE {
+            write!(
+                formatter,
+                " {}",
+                char::from(b'a' + u8::try_from(col).unwrap())
+            )?;
+        }
+
+        writeln!(formatter)?;
+
+        for rank in (0..Self::BOARD_SIZE).rev() {
+            write!(formatter, "{:>2}", rank + 1)?;
+
+            for file in 0..Self::BOARD_SIZE {
+                let square = Square::new(u32::try_from(rank * Self::BOARD_SIZE + file).unwrap());
+
+                let character = self
+                    .position()
+                    .board()
+                    .piece_at(square)
+                    .map_or('.', Piece::char);
+
+                write!(formatter, " {character}")?;
+            }
+
+            writeln!(formatter)?;
+        }
+
+        // NOTE - Position
+
+        writeln!(formatter, "FEN: {}", self.fen())?;
+
+        // NOTE - Player
+
+        let color = match self.player_color {
+            Color::White => "white",
+            Color::Black => "black",
+        };
+
+        writeln!(formatter, "X: {color}")
+    }
+}
+
+impl str::FromStr for Chess {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        // NOTE - Position
+
+        let fen = lines
+            .iter()
+            .find_map(|line| line.strip_prefix("FEN:"))
+            .ok_or("missing FEN")?;
+
+        let mut game = Self::from_fen(fen.trim())?;
+
+        // NOTE - Player
+
+        game.player_color = match lines.last() {
+            Some(&"X: white") => Color::White,
+            Some(&"X: black") => Color::Black,
+            line => return Err(format!("invalid player: {}", line.unwrap_or(&""))),
+        };
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::seq::IndexedRandom;
+
+    use crate::core::{DefaultRngFactory, RngFactory};
+
+    fn square(name: &str) -> u8 {
+        let square: Square = name.parse().expect("invalid square");
+
+        u8::from(square)
+    }
+
+    fn action(value: &str) -> Action {
+        Action::Move {
+            from: square(&value[0..2]),
+            to: square(&value[2..4]),
+            underpromotion: match value.get(4..) {
+                Some("n") => Some(Underpromotion::Knight),
+                Some("b") => Some(Underpromotion::Bishop),
+                Some("r") => Some(Underpromotion::Rook),
+                _ => None,
+            },
+        }
+    }
+
+    fn play(game: &mut Chess, actions: &[&str]) {
+        for value in actions {
+            assert!(game.apply_action(action(value)));
+
+            if game.outcome() == Outcome::InProgress {
+                game.end_turn();
+            }
+        }
+    }
+
+    mod get_possible_actions {
+        use super::*;
+
+        #[test]
+        fn should_list_twenty_opening_moves() {
+            let game = Chess::new();
+
+            assert_eq!(game.get_possible_actions().len(), 20);
+        }
+
+        #[test]
+        fn should_list_queen_promotions_and_underpromotions() {
+            let game = Chess::from_fen("8/P6k/8/8/8/8/8/K7 w - - 0 1").unwrap();
+
+            let actions = game.get_possible_actions();
+
+            for value in ["a7a8", "a7a8n", "a7a8b", "a7a8r"] {
+                assert!(actions.contains(&action(value)), "missing {value}");
+            }
+        }
+
+        #[test]
+        fn should_castle_by_moving_the_king_two_squares() {
+            let mut game = Chess::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+
+            let actions = game.get_possible_actions();
+
+            assert!(actions.contains(&action("e1g1")));
+            assert!(actions.contains(&action("e1c1")));
+
+            game.apply_action(action("e1g1"));
+
+            let board = game.position().board();
+
+            assert_eq!(board.role_at(Square::G1), Some(Role::King));
+            assert_eq!(board.role_at(Square::F1), Some(Role::Rook));
+        }
+    }
+
+    mod apply_action {
+        use super::*;
+
+        #[test]
+        fn should_promote_to_a_queen_by_default() {
+            let mut game = Chess::from_fen("8/P6k/8/8/8/8/8/K7 w - - 0 1").unwrap();
+
+            game.apply_action(action("a7a8"));
+
+            assert_eq!(
+                game.position().board().role_at(Square::A8),
+                Some(Role::Queen)
+            );
+        }
+
+        #[test]
+        fn should_underpromote() {
+            let mut game = Chess::from_fen("8/P6k/8/8/8/8/8/K7 w - - 0 1").unwrap();
+
+            game.apply_action(action("a7a8n"));
+
+            assert_eq!(
+                game.position().board().role_at(Square::A8),
+                Some(Role::Knight)
+            );
+        }
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        fn should_win_by_checkmate() {
+            let mut game = Chess::new();
+
+            play(&mut game, &["f2f3", "e7e5", "g2g4", "d8h4"]);
+
+            assert_eq!(game.outcome(), Outcome::Win);
+
+            game.end_turn();
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+        }
+
+        #[test]
+        fn should_draw_by_stalemate() {
+            let game = Chess::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+
+            assert_eq!(game.outcome(), Outcome::Draw);
+            assert!(game.get_possible_actions().is_empty());
+        }
+
+        #[test]
+        fn should_draw_by_threefold_repetition() {
+            let mut game = Chess::new();
+
+            let shuffle = ["g1f3", "g8f6", "f3g1", "f6g8"];
+
+            play(&mut game, &shuffle);
+
+            assert_eq!(game.outcome(), Outcome::InProgress);
+
+            play(&mut game, &shuffle);
+
+            assert_eq!(game.outcome(), Outcome::Draw);
+        }
+
+        #[test]
+        fn should_always_finish() {
+            let mut rng = DefaultRngFactory::default().create_seeded(7);
+
+            for _ in 0..4 {
+                let mut game = Chess::new();
+
+                while game.outcome() == Outcome::InProgress {
+                    let action = *game
+                        .get_possible_actions()
+                        .choose(&mut rng)
+                        .expect("unfinished game has no actions");
+
+                    if game.apply_action(action) && game.outcome() == Outcome::InProgress {
+                        game.end_turn();
+                    }
+                }
+            }
+        }
+    }
+
+    mod restore_checkpoint {
+        use super::*;
+
+        #[test]
+        fn should_rewind_the_history() {
+            let mut game = Chess::new();
+
+            let checkpoint = game.create_checkpoint();
+
+            play(&mut game, &["e2e4", "e7e5"]);
+
+            game.restore_checkpoint(checkpoint);
+
+            assert_eq!(game, Chess::new());
+            assert_eq!(game.history().count(), 1);
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = Chess::new();
+
+            play(&mut game, &["e2e4", "c7c5"]);
+
+            let parsed =
+                Chess::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = Chess::new();
+
+            play(&mut game, &["e2e4", "c7c5"]);
+
+            let parsed: Chess = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+            assert_eq!(parsed.fen(), game.fen());
+        }
+    }
+}
@@ -0,0 +1,12 @@
+mod action;
+mod action_encoder;
+#[allow(clippy::module_inception)]
+mod chess;
+mod portable;
+mod renderable;
+mod state_encoder;
+
+pub use action::Action as ChessAction;
+pub use action_encoder::ChessActionEncoder;
+pub use chess::{Chess, Phase as ChessPhase, Underpromotion as ChessUnderpromotion};
+pub use state_encoder::ChessStateEncoder;
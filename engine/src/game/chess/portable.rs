@@ -0,0 +1,62 @@
+use shakmaty::Square;
+
+use crate::game::chess::Chess;
+use crate::game::chess::action::Action;
+use crate::game::chess::chess::Underpromotion;
+use crate::record::PortableGame;
+
+// NOTE - Actions are written in UCI, so castling is the king moving two squares. A trailing
+// `q` is accepted when parsing but never written, since promotions are to a queen unless
+// they say otherwise.
+
+impl PortableGame for Chess {
+    const NAME: &'static str = "chess";
+
+    fn board_size() -> usize {
+        Self::BOARD_SIZE
+    }
+
+    fn format_action(action: Action) -> String {
+        let Action::Move {
+            from,
+            to,
+            underpromotion,
+        } = action;
+
+        let suffix = match underpromotion {
+            Some(Underpromotion::Knight) => "n",
+            Some(Underpromotion::Bishop) => "b",
+            Some(Underpromotion::Rook) => "r",
+            None => "",
+        };
+
+        format!(
+            "{}{}{suffix}",
+            Self::square_name(from),
+            Self::square_name(to)
+        )
+    }
+
+    fn parse_action(value: &str) -> Result<Action, String> {
+        let invalid = || format!("invalid action: {value}");
+
+        let square = |name: &str| name.parse::<Square>().map(u8::from).map_err(|_| invalid());
+
+        let from = square(value.get(0..2).ok_or_else(invalid)?)?;
+        let to = square(value.get(2..4).ok_or_else(invalid)?)?;
+
+        let underpromotion = match value.get(4..) {
+            Some("" | "q") => None,
+            Some("n") => Some(Underpromotion::Knight),
+            Some("b") => Some(Underpromotion::Bishop),
+            Some("r") => Some(Underpromotion::Rook),
+            _ => return Err(invalid()),
+        };
+
+        Ok(Action::Move {
+            from,
+            to,
+            underpromotion,
+        })
+    }
+}
@@ -0,0 +1,107 @@
+use shakmaty::{Position, Role, Square};
+
+use crate::core::Turn;
+use crate::game::chess::Chess;
+use crate::game::chess::action::Action;
+use crate::render::{PieceShape, RenderableGame, RenderedPiece};
+
+// NOTE - Drawn with white at the bottom. There are only three shapes to go around, so pawns
+// are small and every other piece is large.
+
+impl RenderableGame for Chess {
+    fn grid_size(&self) -> (usize, usize) {
+        (Self::BOARD_SIZE, Self::BOARD_SIZE)
+    }
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece> {
+        let square = Square::new(u32::try_from((7 - row) * Self::BOARD_SIZE + col).unwrap());
+
+        let piece = self.position().board().piece_at(square)?;
+
+        let owner = if piece.color == self.player_color {
+            turn
+        } else {
+            turn.advance()
+        };
+
+        let shape = if piece.role == Role::Pawn {
+            PieceShape::Small
+        } else {
+            PieceShape::Large
+        };
+
+        Some(RenderedPiece { owner, shape })
+    }
+
+    fn action_squares(&self, action: Action) -> Vec<(usize, usize)> {
+        let Action::Move { from, to, .. } = action;
+
+        [from, to]
+            .iter()
+            .map(|&square| {
+                (
+                    7 - square as usize / Self::BOARD_SIZE,
+                    square as usize % Self::BOARD_SIZE,
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::render::weighted_squares;
+
+    mod piece_at {
+        use super::*;
+
+        #[test]
+        fn should_draw_the_starting_pieces() {
+            let game = Chess::new();
+
+            assert_eq!(
+                game.piece_at(7, 4, Turn::Player1),
+                Some(RenderedPiece {
+                    owner: Turn::Player1,
+                    shape: PieceShape::Large,
+                })
+            );
+            assert_eq!(
+                game.piece_at(1, 0, Turn::Player1),
+                Some(RenderedPiece {
+                    owner: Turn::Player2,
+                    shape: PieceShape::Small,
+                })
+            );
+            assert_eq!(game.piece_at(4, 4, Turn::Player1), None);
+        }
+    }
+
+    mod square_weights {
+        use super::*;
+
+        #[test]
+        fn should_map_actions_onto_their_squares() {
+            let game = Chess::new();
+
+            assert_eq!(game.grid_size(), (8, 8));
+            assert_eq!(
+                weighted_squares(
+                    &game,
+                    &[(
+                        Action::Move {
+                            from: 12,
+                            to: 28,
+                            underpromotion: None,
+                        },
+                        0.5,
+                    )],
+                ),
+                vec![(36, 0.25), (52, 0.25)]
+            );
+        }
+    }
+}
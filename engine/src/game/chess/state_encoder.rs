@@ -0,0 +1,122 @@
+use shakmaty::{CastlingSide, Position, Role};
+
+use crate::game::chess::chess::Chess;
+use crate::neural_network::StateEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct ChessStateEncoder;
+
+// NOTE - The AlphaZero input. Each of the last eight positions, most recent first, gets a
+// plane per piece type for the side to move and then the other side, and two planes for
+// whether it had occurred once or twice before. Positions from before the start are left
+// empty. These are followed by the side to move, the move number, the four castling rights
+// with the side to move's first and the halfmove clock. The board is not flipped for black.
+
+impl ChessStateEncoder {
+    const HISTORY_LENGTH: usize = 8;
+    const HISTORY_PLANE_COUNT: usize = 12 + 2;
+
+    const PLANE_COUNT: usize = Self::HISTORY_LENGTH * Self::HISTORY_PLANE_COUNT + 7;
+
+    const ROLES: [Role; 6] = [
+        Role::Pawn,
+        Role::Knight,
+        Role::Bishop,
+        Role::Rook,
+        Role::Queen,
+        Role::King,
+    ];
+
+    pub fn new() -> Self {
+        ChessStateEncoder
+    }
+
+    fn plane_slice(planes: &mut [f32], plane_index: usize) -> &mut [f32] {
+        let plane_size = Chess::BOARD_SIZE * Chess::BOARD_SIZE;
+
+        let start = plane_index * plane_size;
+        let end = start + plane_size;
+
+        &mut planes[start..end]
+    }
+
+    fn bitboard_to_plane(bits: u64, plane: &mut [f32]) {
+        for (i, value) in plane.iter_mut().enumerate() {
+            *value = ((bits >> i) & 1) as f32;
+        }
+    }
+}
+
+impl StateEncoder<Chess> for ChessStateEncoder {
+    fn shape(&self) -> Vec<usize> {
+        vec![1, Self::PLANE_COUNT, Chess::BOARD_SIZE, Chess::BOARD_SIZE]
+    }
+
+    fn encode(&self, state: &Chess) -> Vec<f32> {
+        let plane_size = Chess::BOARD_SIZE * Chess::BOARD_SIZE;
+        let plane_count = Self::PLANE_COUNT;
+
+        let mut planes = vec![0.0; plane_size * plane_count];
+
+        let position = state.position();
+        let us = position.turn();
+
+        // NOTE - History
+
+        for (plies, earlier) in state.history().take(Self::HISTORY_LENGTH).enumerate() {
+            let board = earlier.board();
+            let base = plies * Self::HISTORY_PLANE_COUNT;
+
+            for (side, color) in [us, !us].into_iter().enumerate() {
+                for (index, &role) in Self::ROLES.iter().enumerate() {
+                    let bits = (board.by_color(color) & board.by_role(role)).0;
+
+                    Self::bitboard_to_plane(
+                        bits,
+                        Self::plane_slice(&mut planes, base + side * 6 + index),
+                    );
+                }
+            }
+
+            let repetitions = state.repetitions(plies);
+
+            if repetitions >= 1 {
+                Self::plane_slice(&mut planes, base + 12).fill(1.0);
+            }
+
+            if repetitions >= 2 {
+                Self::plane_slice(&mut planes, base + 13).fill(1.0);
+            }
+        }
+
+        // NOTE - Side to move, move number, castling rights and halfmove clock
+
+        let base = Self::HISTORY_LENGTH * Self::HISTORY_PLANE_COUNT;
+
+        if us.is_white() {
+            Self::plane_slice(&mut planes, base).fill(1.0);
+        }
+
+        Self::plane_slice(&mut planes, base + 1).fill(position.fullmoves().get() as f32 / 200.0);
+
+        let castles = position.castles();
+
+        for (index, (color, side)) in [
+            (us, CastlingSide::KingSide),
+            (us, CastlingSide::QueenSide),
+            (!us, CastlingSide::KingSide),
+            (!us, CastlingSide::QueenSide),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            if castles.has(color, side) {
+                Self::plane_slice(&mut planes, base + 2 + index).fill(1.0);
+            }
+        }
+
+        Self::plane_slice(&mut planes, base + 6).fill(position.halfmoves() as f32 / 100.0);
+
+        planes
+    }
+}
@@ -0,0 +1,40 @@
+use crate::game::go::Go;
+use crate::game::go::action::Action;
+use crate::neural_network::ActionEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct GoActionEncoder<const SIZE: usize = 9>;
+
+// NOTE - One identifier per point, followed by one for passing.
+
+impl<const SIZE: usize> GoActionEncoder<SIZE> {
+    const PASS: usize = SIZE * SIZE;
+
+    pub fn new() -> Self {
+        GoActionEncoder
+    }
+}
+
+impl<const SIZE: usize> ActionEncoder<Go<SIZE>> for GoActionEncoder<SIZE> {
+    const ACTION_COUNT: usize = SIZE * SIZE + 1;
+
+    fn encode(&self, action: &Action) -> usize {
+        match *action {
+            Action::Place { row, col } => row as usize * SIZE + col as usize,
+            Action::Pass => Self::PASS,
+        }
+    }
+
+    fn decode(&self, action_id: usize) -> Action {
+        debug_assert!(action_id < Self::ACTION_COUNT);
+
+        if action_id == Self::PASS {
+            return Action::Pass;
+        }
+
+        Action::Place {
+            row: u8::try_from(action_id / SIZE).unwrap(),
+            col: u8::try_from(action_id % SIZE).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,961 @@
+use std::array;
+use std::mem::swap;
+use std::{fmt, str};
+
+use crate::core::{ActionList, Game, Outcome, Turn, format_rows, parse_grid};
+use crate::game::go::action::Action;
+
+// NOTE - Suicide is illegal, a single stone cannot be recaptured straight away (simple
+// ko), and the game ends when both sides pass in a row. It is then scored by area: each
+// side's stones plus the empty regions that touch only its stones, with komi going to
+// white. Groups, their liberties and empty regions are all found by flooding `u32` row
+// masks, which hold boards up to 19x19.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Go<const SIZE: usize = 9> {
+    pub phase: Phase,
+
+    pub player_stones: [u32; SIZE],
+    pub opponent_stones: [u32; SIZE],
+
+    pub player_color: Color,
+
+    pub ko: Option<(u8, u8)>,
+    pub passes: u8,
+
+    // NOTE - Komi is kept in half points so the game stays hashable.
+    pub komi_halves: i16,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Play,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Color {
+    Black,
+    White,
+}
+
+impl Color {
+    fn other(self) -> Self {
+        match self {
+            Color::Black => Color::White,
+            Color::White => Color::Black,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GoConfig {
+    pub komi: f32,
+}
+
+impl Default for GoConfig {
+    fn default() -> Self {
+        GoConfig { komi: 7.5 }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkpoint<const SIZE: usize> {
+    player_stones: [u32; SIZE],
+    opponent_stones: [u32; SIZE],
+
+    player_color: Color,
+
+    ko: Option<(u8, u8)>,
+    passes: u8,
+}
+
+impl<const SIZE: usize> Go<SIZE> {
+    pub const BOARD_SIZE: usize = SIZE;
+
+    const SYMMETRY_COUNT: u8 = 8;
+
+    // NOTE - Boards are limited to 19x19, so `tt` is never a point and can stand for a pass.
+
+    const VALID_SIZE: () = assert!(SIZE >= 2 && SIZE <= 19, "go boards are 2x2 to 19x19");
+
+    const ROW_MASK: u32 = (1u32 << SIZE) - 1;
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn with_komi(mut self, komi: f32) -> Self {
+        assert!(
+            (komi * 2.0).fract() == 0.0,
+            "komi must be a whole or half point"
+        );
+
+        self.komi_halves = (komi * 2.0) as i16;
+
+        self
+    }
+
+    pub fn komi(&self) -> f32 {
+        f32::from(self.komi_halves) / 2.0
+    }
+
+    // NOTE - Area score margin for the side holding `player_stones`, komi included.
+
+    pub fn score(&self) -> f32 {
+        self.score_halves() as f32 / 2.0
+    }
+
+    fn score_halves(&self) -> i32 {
+        let (player_area, opponent_area) = self.area();
+
+        let margin =
+            2 * (i32::try_from(player_area).unwrap() - i32::try_from(opponent_area).unwrap());
+
+        match self.player_color {
+            Color::Black => margin - i32::from(self.komi_halves),
+            Color::White => margin + i32::from(self.komi_halves),
+        }
+    }
+
+    fn area(&self) -> (u32, u32) {
+        let empty = self.empty();
+
+        let mut player_area = Self::count(&self.player_stones);
+        let mut opponent_area = Self::count(&self.opponent_stones);
+
+        let mut remaining = empty;
+
+        while let Some(row) = remaining.iter().position(|&bits| bits != 0) {
+            let col = remaining[row].trailing_zeros() as usize;
+
+            let region = Self::flood(Self::point(row, col), &empty);
+            let border = Self::dilate(&region);
+
+            let touches_player = Self::count(&Self::intersect(&border, &self.player_stones)) > 0;
+            let touches_opponent =
+                Self::count(&Self::intersect(&border, &self.opponent_stones)) > 0;
+
+            match (touches_player, touches_opponent) {
+                (true, false) => player_area += Self::count(&region),
+                (false, true) => opponent_area += Self::count(&region),
+                _ => {}
+            }
+
+            for (remaining, region) in remaining.iter_mut().zip(region) {
+                *remaining &= !region;
+            }
+        }
+
+        (player_area, opponent_area)
+    }
+
+    fn flip_perspective(&mut self) {
+        swap(&mut self.player_stones, &mut self.opponent_stones);
+
+        self.player_color = self.player_color.other();
+    }
+
+    fn empty(&self) -> [u32; SIZE] {
+        array::from_fn(|row| {
+            !(self.player_stones[row] | self.opponent_stones[row]) & Self::ROW_MASK
+        })
+    }
+
+    // NOTE - A point is legal unless it is the ko point or it would leave the new stone's
+    // group without liberties. Capturing an adjacent group always frees one.
+
+    fn is_legal(&self, row: usize, col: usize, empty: &[u32; SIZE]) -> bool {
+        if self.ko == Some((u8::try_from(row).unwrap(), u8::try_from(col).unwrap())) {
+            return false;
+        }
+
+        let mut empty_after = *empty;
+        empty_after[row] &= !(1u32 << col);
+
+        Self::neighbors(row, col).any(|(row, col)| {
+            if Self::contains(empty, row, col) {
+                return true;
+            }
+
+            if Self::contains(&self.player_stones, row, col) {
+                let group = Self::flood(Self::point(row, col), &self.player_stones);
+
+                Self::liberties(&group, &empty_after) > 0
+            } else {
+                let group = Self::flood(Self::point(row, col), &self.opponent_stones);
+
+                Self::liberties(&group, &empty_after) == 0
+            }
+        })
+    }
+
+    fn place(&mut self, row: usize, col: usize) {
+        self.player_stones[row] |= 1u32 << col;
+
+        let empty = self.empty();
+
+        let mut captured = [0u32; SIZE];
+
+        for (row, col) in Self::neighbors(row, col) {
+            if Self::contains(&self.opponent_stones, row, col)
+                && !Self::contains(&captured, row, col)
+            {
+                let group = Self::flood(Self::point(row, col), &self.opponent_stones);
+
+                if Self::liberties(&group, &empty) == 0 {
+                    for (captured, group) in captured.iter_mut().zip(group) {
+                        *captured |= group;
+                    }
+                }
+            }
+        }
+
+        for (stones, captured) in self.opponent_stones.iter_mut().zip(captured) {
+            *stones &= !captured;
+        }
+
+        // NOTE - Capturing one stone with a lone stone left in atari is a ko, so the
+        // opponent may not retake it straight away.
+
+        self.ko = None;
+
+        if Self::count(&captured) == 1 {
+            let group = Self::flood(Self::point(row, col), &self.player_stones);
+
+            if Self::count(&group) == 1 && Self::liberties(&group, &self.empty()) == 1 {
+                let ko_row = captured.iter().position(|&bits| bits != 0).unwrap();
+                let ko_col = captured[ko_row].trailing_zeros();
+
+                self.ko = Some((u8::try_from(ko_row).unwrap(), u8::try_from(ko_col).unwrap()));
+            }
+        }
+    }
+
+    fn neighbors(row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ]
+        .into_iter()
+        .filter(|&(row, col)| row < SIZE && col < SIZE)
+    }
+
+    fn point(row: usize, col: usize) -> [u32; SIZE] {
+        let mut rows = [0u32; SIZE];
+        rows[row] = 1u32 << col;
+
+        rows
+    }
+
+    fn contains(rows: &[u32; SIZE], row: usize, col: usize) -> bool {
+        (rows[row] >> col) & 1 == 1
+    }
+
+    fn count(rows: &[u32; SIZE]) -> u32 {
+        rows.iter().map(|bits| bits.count_ones()).sum()
+    }
+
+    fn intersect(x: &[u32; SIZE], y: &[u32; SIZE]) -> [u32; SIZE] {
+        array::from_fn(|row| x[row] & y[row])
+    }
+
+    fn liberties(group: &[u32; SIZE], empty: &[u32; SIZE]) -> u32 {
+        Self::count(&Self::intersect(&Self::dilate(group), empty))
+    }
+
+    // NOTE - Grows each point into its orthogonal neighbors, keeping the points themselves.
+
+    fn dilate(rows: &[u32; SIZE]) -> [u32; SIZE] {
+        array::from_fn(|row| {
+            let mut bits = rows[row] | (rows[row] << 1) | (rows[row] >> 1);
+
+            if row > 0 {
+                bits |= rows[row - 1];
+            }
+
+            if row + 1 < SIZE {
+                bits |= rows[row + 1];
+            }
+
+            bits & Self::ROW_MASK
+        })
+    }
+
+    fn flood(seed: [u32; SIZE], within: &[u32; SIZE]) -> [u32; SIZE] {
+        let mut reached = Self::intersect(&seed, within);
+
+        loop {
+            let next = Self::intersect(&Self::dilate(&reached), within);
+
+            if next == reached {
+                return reached;
+            }
+
+            reached = next;
+        }
+    }
+
+    fn transform_rows(rows: &[u32; SIZE], symmetry: u8) -> [u32; SIZE] {
+        let mut result = [0u32; SIZE];
+
+        for (row_old, bits) in rows.iter().enumerate() {
+            for col_old in 0..SIZE {
+                if (bits >> col_old) & 1 == 1 {
+                    let (row_new, col_new) = Self::transform_position(row_old, col_old, symmetry);
+
+                    result[row_new] |= 1u32 << col_new;
+                }
+            }
+        }
+
+        result
+    }
+
+    fn transform_position(row: usize, col: usize, symmetry: u8) -> (usize, usize) {
+        match symmetry {
+            0 => (row, col),
+            1 => (col, SIZE - row - 1),
+            2 => (SIZE - row - 1, SIZE - col - 1),
+            3 => (SIZE - col - 1, row),
+            4 => (row, SIZE - col - 1),
+            5 => (SIZE - row - 1, col),
+            6 => (col, row),
+            7 => (SIZE - col - 1, SIZE - row - 1),
+            _ => unreachable!(),
+        }
+    }
+
+    fn transform_point(point: (u8, u8), symmetry: u8) -> (u8, u8) {
+        let (row, col) = Self::transform_position(point.0 as usize, point.1 as usize, symmetry);
+
+        (u8::try_from(row).unwrap(), u8::try_from(col).unwrap())
+    }
+
+    fn color_label(color: Color) -> &'static str {
+        match color {
+            Color::Black => "black",
+            Color::White => "white",
+        }
+    }
+
+    fn parse_point(value: &str) -> Option<(u8, u8)> {
+        let col = value.bytes().next()?.wrapping_sub(b'a');
+        let row = value.get(1..)?.parse::<u8>().ok()?.checked_sub(1)?;
+
+        (usize::from(row) < SIZE && usize::from(col) < SIZE).then_some((row, col))
+    }
+}
+
+impl<const SIZE: usize> Game for Go<SIZE> {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Checkpoint<SIZE>;
+    type Config = GoConfig;
+
+    fn new_with_config(config: GoConfig) -> Self {
+        let () = Self::VALID_SIZE;
+
+        Go {
+            phase: Phase::Play,
+
+            player_stones: [0; SIZE],
+            opponent_stones: [0; SIZE],
+
+            player_color: Color::Black,
+
+            ko: None,
+            passes: 0,
+
+            komi_halves: 0,
+        }
+        .with_komi(config.komi)
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        if self.outcome() != Outcome::InProgress {
+            return ActionList::new();
+        }
+
+        let mut actions = ActionList::new();
+
+        let empty = self.empty();
+
+        for row in 0..SIZE {
+            for col in 0..SIZE {
+                if Self::contains(&empty, row, col) && self.is_legal(row, col, &empty) {
+                    actions.push(Action::Place {
+                        row: u8::try_from(row).unwrap(),
+                        col: u8::try_from(col).unwrap(),
+                    });
+                }
+            }
+        }
+
+        actions.push(Action::Pass);
+
+        actions
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Place { row, col } => {
+                self.place(row as usize, col as usize);
+
+                self.passes = 0;
+            }
+            Action::Pass => {
+                self.ko = None;
+                self.passes += 1;
+            }
+        }
+
+        true
+    }
+
+    fn end_turn(&mut self) {
+        self.flip_perspective();
+    }
+
+    fn outcome(&self) -> Outcome {
+        if self.passes < 2 {
+            return Outcome::InProgress;
+        }
+
+        match self.score_halves() {
+            score if score > 0 => Outcome::Win,
+            score if score < 0 => Outcome::Loss,
+            _ => Outcome::Draw,
+        }
+    }
+
+    fn create_checkpoint(&self) -> Checkpoint<SIZE> {
+        Checkpoint {
+            player_stones: self.player_stones,
+            opponent_stones: self.opponent_stones,
+
+            player_color: self.player_color,
+
+            ko: self.ko,
+            passes: self.passes,
+        }
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Checkpoint<SIZE>) {
+        self.player_stones = checkpoint.player_stones;
+        self.opponent_stones = checkpoint.opponent_stones;
+
+        self.player_color = checkpoint.player_color;
+
+        self.ko = checkpoint.ko;
+        self.passes = checkpoint.passes;
+    }
+
+    fn symmetries(&self) -> u8 {
+        Self::SYMMETRY_COUNT
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        let mut game = self.clone();
+
+        game.player_stones = Self::transform_rows(&self.player_stones, symmetry);
+        game.opponent_stones = Self::transform_rows(&self.opponent_stones, symmetry);
+
+        game.ko = self.ko.map(|point| Self::transform_point(point, symmetry));
+
+        game
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        match action {
+            Action::Place { row, col } => {
+                let (row, col) = Self::transform_point((row, col), symmetry);
+
+                Action::Place { row, col }
+            }
+            Action::Pass => Action::Pass,
+        }
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        let mut game = self.clone();
+
+        if turn == Turn::Player2 {
+            game.flip_perspective();
+        }
+
+        format!("{game}")
+    }
+
+    fn to_notation(&self) -> String {
+        let board = format_rows((0..SIZE).map(|row| {
+            (0..SIZE).map(move |col| {
+                if Self::contains(&self.player_stones, row, col) {
+                    Some('X')
+                } else if Self::contains(&self.opponent_stones, row, col) {
+                    Some('O')
+                } else {
+                    None
+                }
+            })
+        }));
+
+        let ko = match self.ko {
+            Some((row, col)) => format!("{}{}", char::from(b'a' + col), row + 1),
+            None => "-".to_string(),
+        };
+
+        format!(
+            "{board} {} {} {ko} {}",
+            Self::color_label(self.player_color),
+            self.komi(),
+            self.passes
+        )
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = notation.split_whitespace().collect();
+
+        if fields.len() != 5 {
+            return Err(format!("expected 5 fields, found {}", fields.len()));
+        }
+
+        let rows = parse_grid(fields[0], SIZE, SIZE)?;
+
+        let mut game = Self::new();
+
+        for (row, cells) in rows.into_iter().enumerate() {
+            for (col, cell) in cells.into_iter().enumerate() {
+                match cell {
+                    None => {}
+                    Some('X') => game.player_stones[row] |= 1u32 << col,
+                    Some('O') => game.opponent_stones[row] |= 1u32 << col,
+                    Some(character) => {
+                        return Err(format!("invalid character at row {}: {character}", row + 1));
+                    }
+                }
+            }
+        }
+
+        game.player_color = match fields[1] {
+            "black" => Color::Black,
+            "white" => Color::White,
+            field => return Err(format!("invalid color: {field}")),
+        };
+
+        let komi: f32 = fields[2]
+            .parse()
+            .ok()
+            .filter(|komi: &f32| (komi * 2.0).fract() == 0.0)
+            .ok_or_else(|| format!("invalid komi: {}", fields[2]))?;
+
+        game = game.with_komi(komi);
+
+        game.ko = match fields[3] {
+            "-" => None,
+            field => Some(Self::parse_point(field).ok_or(format!("invalid ko point: {field}"))?),
+        };
+
+        game.passes = match fields[4].parse() {
+            Ok(passes @ 0..=2) => passes,
+            _ => return Err(format!("invalid passes: {}", fields[4])),
+        };
+
+        Ok(game)
+    }
+}
+
+impl<const SIZE: usize> fmt::Display for Go<SIZE> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "  ")?;
+
+        for col in 0..SIZE {
+            write!(
+                formatter,
+                " {}",
+                char::from(b'a' + u8::try_from(col).unwrap())
+            )?;
+        }
+
+        writeln!(formatter)?;
+
+        for row in 0..SIZE {
+            write!(formatter, "{:>2}", row + 1)?;
+
+            for col in 0..SIZE {
+                let character = if Self::contains(&self.player_stones, row, col) {
+                    'X'
+                } else if Self::contains(&self.opponent_stones, row, col) {
+                    'O'
+                } else {
+                    '.'
+                };
+
+                write!(formatter, " {character}")?;
+            }
+
+            writeln!(formatter)?;
+        }
+
+        writeln!(formatter, "X: {}", Self::color_label(self.player_color))?;
+        writeln!(formatter, "Komi: {}", self.komi())?;
+
+        if let Some((row, col)) = self.ko {
+            writeln!(formatter, "Ko: {}{}", char::from(b'a' + col), row + 1)?;
+        }
+
+        if self.passes > 0 {
+            writeln!(formatter, "Passes: {}", self.passes)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const SIZE: usize> str::FromStr for Go<SIZE> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        if lines.len() < SIZE + 2 {
+            return Err(format!(
+                "expected at least {} lines, found {}",
+                SIZE + 2,
+                lines.len()
+            ));
+        }
+
+        let mut game = Self::new();
+
+        for (row, line) in lines[1..=SIZE].iter().enumerate() {
+            let cells: Vec<&str> = line.split_whitespace().skip(1).collect();
+
+            if cells.len() != SIZE {
+                return Err(format!("malformed board row {}", row + 1));
+            }
+
+            for (col, cell) in cells.into_iter().enumerate() {
+                match cell {
+                    "." => {}
+                    "X" => game.player_stones[row] |= 1u32 << col,
+                    "O" => game.opponent_stones[row] |= 1u32 << col,
+                    _ => return Err(format!("invalid character at row {}: {cell}", row + 1)),
+                }
+            }
+        }
+
+        game.player_color = match lines[SIZE + 1] {
+            "X: black" => Color::Black,
+            "X: white" => Color::White,
+            line => return Err(format!("invalid color: {line}")),
+        };
+
+        for &line in &lines[SIZE + 2..] {
+            if let Some(value) = line.strip_prefix("Komi:") {
+                let komi: f32 = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("invalid komi: {value}"))?;
+
+                if (komi * 2.0).fract() != 0.0 {
+                    return Err(format!("invalid komi: {value}"));
+                }
+
+                game = game.with_komi(komi);
+            } else if let Some(value) = line.strip_prefix("Ko:") {
+                game.ko = Some(
+                    Self::parse_point(value.trim()).ok_or(format!("invalid ko point: {value}"))?,
+                );
+            } else if let Some(value) = line.strip_prefix("Passes:") {
+                game.passes = match value.trim().parse() {
+                    Ok(passes @ 0..=2) => passes,
+                    _ => return Err(format!("invalid passes: {value}")),
+                };
+            } else {
+                return Err(format!("unexpected line: {line}"));
+            }
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::seq::IndexedRandom;
+
+    use crate::core::{DefaultRngFactory, RngFactory};
+
+    fn parse_game(value: &str) -> Go<5> {
+        value.parse().expect("unable to parse game")
+    }
+
+    fn place(row: u8, col: u8) -> Action {
+        Action::Place { row, col }
+    }
+
+    mod get_possible_actions {
+        use super::*;
+
+        #[test]
+        fn should_forbid_suicide() {
+            let game = parse_game(
+                "
+                   a b c d e
+                 1 . O . . .
+                 2 O . . . .
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: black
+                ",
+            );
+
+            let actions = game.get_possible_actions();
+
+            assert!(!actions.contains(&place(0, 0)));
+            assert!(actions.contains(&Action::Pass));
+        }
+
+        #[test]
+        fn should_allow_filling_a_point_that_captures() {
+            let game = parse_game(
+                "
+                   a b c d e
+                 1 . O X . .
+                 2 O X . . .
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: black
+                ",
+            );
+
+            assert!(game.get_possible_actions().contains(&place(0, 0)));
+        }
+    }
+
+    mod apply_action {
+        use super::*;
+
+        #[test]
+        fn should_capture_groups_without_liberties() {
+            let mut game = parse_game(
+                "
+                   a b c d e
+                 1 . O O X .
+                 2 O X X . .
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: black
+                ",
+            );
+
+            game.apply_action(place(0, 0));
+
+            assert_eq!(game.opponent_stones, [0b00000, 0b00001, 0, 0, 0]);
+            assert_eq!(game.ko, None);
+        }
+
+        #[test]
+        fn should_forbid_immediate_ko_recapture() {
+            let mut game = parse_game(
+                "
+                   a b c d e
+                 1 . X O . .
+                 2 X O . O .
+                 3 . X O . .
+                 4 . . . . .
+                 5 . . . . .
+                X: black
+                ",
+            );
+
+            game.apply_action(place(1, 2));
+            game.end_turn();
+
+            assert_eq!(game.ko, Some((1, 1)));
+            assert!(!game.get_possible_actions().contains(&place(1, 1)));
+
+            game.apply_action(place(4, 4));
+            game.end_turn();
+
+            game.apply_action(Action::Pass);
+            game.end_turn();
+
+            assert!(game.get_possible_actions().contains(&place(1, 1)));
+        }
+    }
+
+    mod new_with_config {
+        use super::*;
+
+        #[test]
+        fn should_set_komi() {
+            assert!((Go::<5>::new().komi() - 7.5).abs() < f32::EPSILON);
+            assert!(
+                (Go::<5>::new_with_config(GoConfig { komi: 0.5 }).komi() - 0.5).abs()
+                    < f32::EPSILON
+            );
+        }
+    }
+
+    mod outcome {
+        use super::*;
+
+        fn make_game(color: &str, komi: f32) -> Go<5> {
+            let game = parse_game(&format!(
+                "
+                   a b c d e
+                 1 . X . O .
+                 2 . X . O .
+                 3 . X . O .
+                 4 . X . O .
+                 5 . X . O .
+                X: {color}
+                "
+            ));
+
+            game.with_komi(komi)
+        }
+
+        fn pass_twice(mut game: Go<5>) -> Go<5> {
+            game.apply_action(Action::Pass);
+
+            assert_eq!(game.outcome(), Outcome::InProgress);
+
+            game.end_turn();
+            game.apply_action(Action::Pass);
+
+            game
+        }
+
+        #[test]
+        fn should_score_by_area_after_two_passes() {
+            let game = pass_twice(make_game("white", 0.5));
+
+            assert_eq!(game.player_color, Color::Black);
+            assert!((game.score() + 0.5).abs() < f32::EPSILON);
+            assert_eq!(game.outcome(), Outcome::Loss);
+            assert!(game.get_possible_actions().is_empty());
+        }
+
+        #[test]
+        fn should_draw_on_equal_area_without_komi() {
+            let game = pass_twice(make_game("black", 0.0));
+
+            assert_eq!(game.outcome(), Outcome::Draw);
+        }
+
+        #[test]
+        fn should_always_finish_random_games() {
+            let mut rng = DefaultRngFactory::default().create_seeded(7);
+
+            for _ in 0..20 {
+                let mut game = Go::<5>::new();
+
+                while game.outcome() == Outcome::InProgress {
+                    let action = *game
+                        .get_possible_actions()
+                        .choose(&mut rng)
+                        .expect("unfinished game has no actions");
+
+                    game.apply_action(action);
+
+                    if game.outcome() == Outcome::InProgress {
+                        game.end_turn();
+                    }
+                }
+
+                assert_eq!(game.passes, 2);
+            }
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = Go::<5>::new().with_komi(6.5);
+
+            for (row, col) in [(1, 2), (0, 0), (3, 1)] {
+                game.apply_action(place(row, col));
+                game.end_turn();
+            }
+
+            game.apply_action(Action::Pass);
+            game.ko = Some((2, 4));
+
+            let parsed =
+                Go::<5>::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = Go::<5>::new().with_komi(6.5);
+
+            for (row, col) in [(1, 2), (0, 0), (3, 1)] {
+                game.apply_action(place(row, col));
+                game.end_turn();
+            }
+
+            game.apply_action(Action::Pass);
+            game.ko = Some((2, 4));
+
+            let parsed: Go<5> = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod transform {
+        use super::*;
+
+        #[test]
+        fn should_rotate_stones_and_ko() {
+            let game = parse_game(
+                "
+                   a b c d e
+                 1 X O . . .
+                 2 . . . . .
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: black
+                Ko: a2
+                ",
+            );
+
+            let mut expected_game = parse_game(
+                "
+                   a b c d e
+                 1 . . . . X
+                 2 . . . . O
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: black
+                ",
+            );
+
+            expected_game.ko = Some((0, 3));
+
+            assert_eq!(game.transform(1), expected_game);
+            assert_eq!(game.transform_action(place(0, 1), 1), place(1, 4));
+            assert_eq!(game.transform_action(Action::Pass, 1), Action::Pass);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+mod action;
+mod action_encoder;
+#[allow(clippy::module_inception)]
+mod go;
+mod portable;
+mod renderable;
+mod state_encoder;
+
+pub use action::Action as GoAction;
+pub use action_encoder::GoActionEncoder;
+pub use go::{Color as GoColor, Go, GoConfig, Phase as GoPhase};
+pub use state_encoder::GoStateEncoder;
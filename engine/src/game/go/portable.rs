@@ -0,0 +1,43 @@
+use crate::game::go::Go;
+use crate::game::go::action::Action;
+use crate::record::PortableGame;
+
+impl<const SIZE: usize> PortableGame for Go<SIZE> {
+    const NAME: &'static str = "go";
+
+    fn board_size() -> usize {
+        SIZE
+    }
+
+    // NOTE - Points are written column then row, as in SGF, and a pass is written as an
+    // empty point. SGF's older `tt` pass is accepted too.
+
+    fn format_action(action: Action) -> String {
+        match action {
+            Action::Place { row, col } => [col, row]
+                .iter()
+                .map(|&coordinate| char::from(b'a' + coordinate))
+                .collect(),
+            Action::Pass => String::new(),
+        }
+    }
+
+    fn parse_action(value: &str) -> Result<Action, String> {
+        if value.is_empty() || value == "tt" {
+            return Ok(Action::Pass);
+        }
+
+        let coordinates: Vec<usize> = value
+            .bytes()
+            .map(|byte| byte.wrapping_sub(b'a') as usize)
+            .collect();
+
+        match coordinates[..] {
+            [col, row] if col < SIZE && row < SIZE => Ok(Action::Place {
+                row: u8::try_from(row).map_err(|error| error.to_string())?,
+                col: u8::try_from(col).map_err(|error| error.to_string())?,
+            }),
+            _ => Err(format!("invalid point: {value}")),
+        }
+    }
+}
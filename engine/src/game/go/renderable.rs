@@ -0,0 +1,60 @@
+use crate::core::Turn;
+use crate::game::go::Go;
+use crate::game::go::action::Action;
+use crate::render::{PieceShape, RenderableGame, RenderedPiece};
+
+impl<const SIZE: usize> RenderableGame for Go<SIZE> {
+    fn grid_size(&self) -> (usize, usize) {
+        (SIZE, SIZE)
+    }
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece> {
+        let mask = 1u32 << col;
+
+        let owner = if self.player_stones[row] & mask != 0 {
+            turn
+        } else if self.opponent_stones[row] & mask != 0 {
+            turn.advance()
+        } else {
+            return None;
+        };
+
+        Some(RenderedPiece {
+            owner,
+            shape: PieceShape::Mark,
+        })
+    }
+
+    fn action_squares(&self, action: Action) -> Vec<(usize, usize)> {
+        match action {
+            Action::Place { row, col } => vec![(row as usize, col as usize)],
+            Action::Pass => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::render::weighted_squares;
+
+    mod square_weights {
+        use super::*;
+
+        #[test]
+        fn should_map_actions_onto_their_squares() {
+            let game = Go::<5>::new();
+
+            assert_eq!(game.grid_size(), (5, 5));
+            assert_eq!(
+                weighted_squares(
+                    &game,
+                    &[(Action::Place { row: 1, col: 2 }, 0.5), (Action::Pass, 0.5)],
+                ),
+                vec![(7, 0.5)]
+            );
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use crate::game::go::go::{Color, Go};
+use crate::neural_network::StateEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct GoStateEncoder<const SIZE: usize = 9>;
+
+impl<const SIZE: usize> GoStateEncoder<SIZE> {
+    const PLANE_COUNT: usize = 5;
+
+    pub fn new() -> Self {
+        GoStateEncoder
+    }
+
+    fn plane_slice(planes: &mut [f32], plane_index: usize) -> &mut [f32] {
+        let plane_size = SIZE * SIZE;
+
+        let start = plane_index * plane_size;
+        let end = start + plane_size;
+
+        &mut planes[start..end]
+    }
+
+    fn rows_to_plane(rows: &[u32; SIZE], plane: &mut [f32]) {
+        for (row, bits) in rows.iter().enumerate() {
+            for col in 0..SIZE {
+                plane[row * SIZE + col] = f32::from(u8::from((bits >> col) & 1 == 1));
+            }
+        }
+    }
+}
+
+impl<const SIZE: usize> StateEncoder<Go<SIZE>> for GoStateEncoder<SIZE> {
+    fn shape(&self) -> Vec<usize> {
+        vec![1, Self::PLANE_COUNT, SIZE, SIZE]
+    }
+
+    fn encode(&self, state: &Go<SIZE>) -> Vec<f32> {
+        let plane_size = SIZE * SIZE;
+        let plane_count = Self::PLANE_COUNT;
+
+        let mut planes = vec![0.0; plane_size * plane_count];
+
+        Self::rows_to_plane(&state.player_stones, Self::plane_slice(&mut planes, 0));
+        Self::rows_to_plane(&state.opponent_stones, Self::plane_slice(&mut planes, 1));
+
+        if let Some((row, col)) = state.ko {
+            Self::plane_slice(&mut planes, 2)[row as usize * SIZE + col as usize] = 1.0;
+        }
+
+        // NOTE - The side to move's color, since komi favors white, and whether the
+        // opponent just passed, since passing back would end the game.
+
+        if state.player_color == Color::Black {
+            Self::plane_slice(&mut planes, 3).fill(1.0);
+        }
+
+        if state.passes > 0 {
+            Self::plane_slice(&mut planes, 4).fill(1.0);
+        }
+
+        planes
+    }
+}
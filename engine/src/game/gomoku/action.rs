@@ -0,0 +1,26 @@
+use std::fmt;
+
+use crate::game::gomoku::Gomoku;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action {
+    Place { index: u8 },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Place { index } => {
+                let row = *index as usize / Gomoku::BOARD_SIZE;
+                let col = u8::try_from(*index as usize % Gomoku::BOARD_SIZE).unwrap();
+
+                write!(
+                    f,
+                    "places a stone at {}{}.",
+                    char::from(b'a' + col),
+                    row + 1
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::game::gomoku::Gomoku;
+use crate::game::gomoku::action::Action;
+use crate::neural_network::ActionEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct GomokuActionEncoder;
+
+impl GomokuActionEncoder {
+    pub fn new() -> Self {
+        GomokuActionEncoder
+    }
+}
+
+impl ActionEncoder<Gomoku> for GomokuActionEncoder {
+    const ACTION_COUNT: usize = Gomoku::BOARD_SIZE * Gomoku::BOARD_SIZE;
+
+    fn encode(&self, action: &Action) -> usize {
+        match *action {
+            Action::Place { index } => index as usize,
+        }
+    }
+
+    fn decode(&self, action_id: usize) -> Action {
+        debug_assert!(action_id < Self::ACTION_COUNT);
+
+        Action::Place {
+            index: u8::try_from(action_id).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,539 @@
+use std::mem::swap;
+use std::{fmt, str};
+
+use crate::core::{ActionList, Game, Outcome, Turn, format_rows, hash_position, parse_grid};
+use crate::game::gomoku::action::Action;
+
+// NOTE - Freestyle rules: five or more stones in a line wins, so overlines count, and
+// neither side is restricted. The 15 columns fit a `u16` per row, which lets `has_five`
+// test every line on the board with shifts and ANDs.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Gomoku {
+    pub phase: Phase,
+
+    pub player_stones: [u16; Gomoku::BOARD_SIZE],
+    pub opponent_stones: [u16; Gomoku::BOARD_SIZE],
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Place,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    player_stones: [u16; Gomoku::BOARD_SIZE],
+    opponent_stones: [u16; Gomoku::BOARD_SIZE],
+}
+
+impl Gomoku {
+    pub const BOARD_SIZE: usize = 15;
+
+    const SYMMETRY_COUNT: u8 = 8;
+
+    const LINE_LENGTH: usize = 5;
+    const ROW_MASK: u16 = (1u16 << Self::BOARD_SIZE) - 1;
+
+    fn flip_perspective(&mut self) {
+        swap(&mut self.player_stones, &mut self.opponent_stones);
+    }
+
+    // NOTE - Each direction ANDs together five copies of the board, each shifted one step
+    // further along the line, so any bit left standing marks the start of a five.
+
+    fn has_five(stones: &[u16; Self::BOARD_SIZE]) -> bool {
+        let horizontal = stones.iter().any(|&row| {
+            (0..Self::LINE_LENGTH).fold(Self::ROW_MASK, |line, step| line & (row >> step)) != 0
+        });
+
+        if horizontal {
+            return true;
+        }
+
+        stones.windows(Self::LINE_LENGTH).any(|rows| {
+            let mut vertical = Self::ROW_MASK;
+            let mut diagonal = Self::ROW_MASK;
+            let mut anti_diagonal = Self::ROW_MASK;
+
+            for (step, &row) in rows.iter().enumerate() {
+                vertical &= row;
+                diagonal &= row >> step;
+                anti_diagonal &= (row << step) & Self::ROW_MASK;
+            }
+
+            vertical | diagonal | anti_diagonal != 0
+        })
+    }
+
+    fn is_full(&self) -> bool {
+        self.player_stones
+            .iter()
+            .zip(&self.opponent_stones)
+            .all(|(player, opponent)| player | opponent == Self::ROW_MASK)
+    }
+
+    fn transform_rows(rows: &[u16; Self::BOARD_SIZE], symmetry: u8) -> [u16; Self::BOARD_SIZE] {
+        let mut result = [0u16; Self::BOARD_SIZE];
+
+        for (row_old, bits) in rows.iter().enumerate() {
+            for col_old in 0..Self::BOARD_SIZE {
+                if (bits >> col_old) & 1 == 1 {
+                    let (row_new, col_new) = Self::transform_position(row_old, col_old, symmetry);
+
+                    result[row_new] |= 1u16 << col_new;
+                }
+            }
+        }
+
+        result
+    }
+
+    fn transform_position(row: usize, col: usize, symmetry: u8) -> (usize, usize) {
+        match symmetry {
+            0 => (row, col),
+            1 => (col, Self::BOARD_SIZE - row - 1),
+            2 => (Self::BOARD_SIZE - row - 1, Self::BOARD_SIZE - col - 1),
+            3 => (Self::BOARD_SIZE - col - 1, row),
+            4 => (row, Self::BOARD_SIZE - col - 1),
+            5 => (Self::BOARD_SIZE - row - 1, col),
+            6 => (col, row),
+            7 => (Self::BOARD_SIZE - col - 1, Self::BOARD_SIZE - row - 1),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Game for Gomoku {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Checkpoint;
+    type Config = ();
+
+    fn new_with_config(_config: ()) -> Self {
+        Gomoku {
+            phase: Phase::Place,
+
+            player_stones: [0; Self::BOARD_SIZE],
+            opponent_stones: [0; Self::BOARD_SIZE],
+        }
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        if self.outcome() != Outcome::InProgress {
+            return ActionList::new();
+        }
+
+        let mut actions = ActionList::new();
+
+        for row in 0..Self::BOARD_SIZE {
+            let empty = !(self.player_stones[row] | self.opponent_stones[row]) & Self::ROW_MASK;
+
+            for col in 0..Self::BOARD_SIZE {
+                if (empty >> col) & 1 == 1 {
+                    actions.push(Action::Place {
+                        index: u8::try_from(row * Self::BOARD_SIZE + col).unwrap(),
+                    });
+                }
+            }
+        }
+
+        actions
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Place { index } => {
+                let (row, col) = (
+                    index as usize / Self::BOARD_SIZE,
+                    index as usize % Self::BOARD_SIZE,
+                );
+
+                self.player_stones[row] |= 1u16 << col;
+            }
+        }
+
+        true
+    }
+
+    fn end_turn(&mut self) {
+        self.flip_perspective();
+    }
+
+    fn outcome(&self) -> Outcome {
+        if Self::has_five(&self.opponent_stones) {
+            return Outcome::Loss;
+        }
+
+        if Self::has_five(&self.player_stones) {
+            return Outcome::Win;
+        }
+
+        if self.is_full() {
+            return Outcome::Draw;
+        }
+
+        Outcome::InProgress
+    }
+
+    fn hash(&self) -> Option<u64> {
+        Some(hash_position(self))
+    }
+
+    fn create_checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            player_stones: self.player_stones,
+            opponent_stones: self.opponent_stones,
+        }
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Checkpoint) {
+        self.player_stones = checkpoint.player_stones;
+        self.opponent_stones = checkpoint.opponent_stones;
+    }
+
+    fn symmetries(&self) -> u8 {
+        Self::SYMMETRY_COUNT
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        let mut game = self.clone();
+
+        game.player_stones = Self::transform_rows(&self.player_stones, symmetry);
+        game.opponent_stones = Self::transform_rows(&self.opponent_stones, symmetry);
+
+        game
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        match action {
+            Action::Place { index } => {
+                let (row, col) = (
+                    index as usize / Self::BOARD_SIZE,
+                    index as usize % Self::BOARD_SIZE,
+                );
+
+                let (new_row, new_col) = Self::transform_position(row, col, symmetry);
+
+                Action::Place {
+                    index: u8::try_from(new_row * Self::BOARD_SIZE + new_col).unwrap(),
+                }
+            }
+        }
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        let mut game = self.clone();
+
+        if turn == Turn::Player2 {
+            game.flip_perspective();
+        }
+
+        format!("{game}")
+    }
+
+    fn to_notation(&self) -> String {
+        format_rows((0..Self::BOARD_SIZE).map(|row| {
+            (0..Self::BOARD_SIZE).map(move |col| {
+                let mask = 1u16 << col;
+
+                if self.player_stones[row] & mask != 0 {
+                    Some('X')
+                } else if self.opponent_stones[row] & mask != 0 {
+                    Some('O')
+                } else {
+                    None
+                }
+            })
+        }))
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let mut fields = notation.split_whitespace();
+
+        let rows = parse_grid(
+            fields.next().unwrap_or_default(),
+            Self::BOARD_SIZE,
+            Self::BOARD_SIZE,
+        )?;
+
+        if let Some(field) = fields.next() {
+            return Err(format!("unexpected field: {field}"));
+        }
+
+        let mut game = Self::new();
+
+        for (row, cells) in rows.into_iter().enumerate() {
+            for (col, cell) in cells.into_iter().enumerate() {
+                match cell {
+                    None => {}
+                    Some('X') => game.player_stones[row] |= 1u16 << col,
+                    Some('O') => game.opponent_stones[row] |= 1u16 << col,
+                    Some(character) => {
+                        return Err(format!("invalid character at row {}: {character}", row + 1));
+                    }
+                }
+            }
+        }
+
+        Ok(game)
+    }
+}
+
+impl fmt::Display for Gomoku {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "  ")?;
+
+        for col in 0..Self::BOARD_SIZE {
+            write!(
+                formatter,
+                " {}",
+                char::from(b'a' + u8::try_from(col).unwrap())
+            )?;
+        }
+
+        writeln!(formatter)?;
+
+        for row in 0..Self::BOARD_SIZE {
+            write!(formatter, "{:>2}", row + 1)?;
+
+            for col in 0..Self::BOARD_SIZE {
+                let mask = 1u16 << col;
+
+                let character = if self.player_stones[row] & mask != 0 {
+                    'X'
+                } else if self.opponent_stones[row] & mask != 0 {
+                    'O'
+                } else {
+                    '.'
+                };
+
+                write!(formatter, " {character}")?;
+            }
+
+            writeln!(formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl str::FromStr for Gomoku {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        if lines.len() != Self::BOARD_SIZE + 1 {
+            return Err(format!(
+                "expected {} lines, found {}",
+                Self::BOARD_SIZE + 1,
+                lines.len()
+            ));
+        }
+
+        let mut game = Self::new();
+
+        for (row, line) in lines[1..].iter().enumerate() {
+            let cells: Vec<&str> = line.split_whitespace().skip(1).collect();
+
+            if cells.len() != Self::BOARD_SIZE {
+                return Err(format!("malformed board row {}", row + 1));
+            }
+
+            for (col, cell) in cells.into_iter().enumerate() {
+                match cell {
+                    "." => {}
+                    "X" => game.player_stones[row] |= 1u16 << col,
+                    "O" => game.opponent_stones[row] |= 1u16 << col,
+                    _ => return Err(format!("invalid character at row {}: {cell}", row + 1)),
+                }
+            }
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::seq::IndexedRandom;
+
+    use crate::core::{DefaultRngFactory, RngFactory};
+
+    fn place(stones: &[(usize, usize)]) -> [u16; Gomoku::BOARD_SIZE] {
+        let mut rows = [0u16; Gomoku::BOARD_SIZE];
+
+        for &(row, col) in stones {
+            rows[row] |= 1u16 << col;
+        }
+
+        rows
+    }
+
+    fn make_game(player: &[(usize, usize)], opponent: &[(usize, usize)]) -> Gomoku {
+        let mut game = Gomoku::new();
+
+        game.player_stones = place(player);
+        game.opponent_stones = place(opponent);
+
+        game
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        fn should_detect_horizontal_win() {
+            let game = make_game(&[(7, 10), (7, 11), (7, 12), (7, 13), (7, 14)], &[]);
+
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+
+        #[test]
+        fn should_detect_vertical_loss() {
+            let game = make_game(&[], &[(10, 0), (11, 0), (12, 0), (13, 0), (14, 0)]);
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+        }
+
+        #[test]
+        fn should_detect_diagonal_win() {
+            let game = make_game(&[(3, 3), (4, 4), (5, 5), (6, 6), (7, 7)], &[]);
+
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+
+        #[test]
+        fn should_detect_anti_diagonal_win() {
+            let game = make_game(&[(0, 14), (1, 13), (2, 12), (3, 11), (4, 10)], &[]);
+
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+
+        #[test]
+        fn should_not_count_four_in_a_row() {
+            let game = make_game(
+                &[
+                    (7, 0),
+                    (7, 1),
+                    (7, 2),
+                    (7, 3),
+                    (0, 14),
+                    (1, 0),
+                    (2, 1),
+                    (3, 2),
+                ],
+                &[(7, 4)],
+            );
+
+            assert_eq!(game.outcome(), Outcome::InProgress);
+        }
+
+        #[test]
+        fn should_not_wrap_diagonals_around_the_edge() {
+            let game = make_game(&[(0, 12), (1, 13), (2, 14), (3, 0), (4, 1)], &[]);
+
+            assert_eq!(game.outcome(), Outcome::InProgress);
+        }
+
+        #[test]
+        fn should_always_finish() {
+            let mut rng = DefaultRngFactory::default().create_seeded(7);
+
+            for _ in 0..5 {
+                let mut game = Gomoku::new();
+
+                while game.outcome() == Outcome::InProgress {
+                    let action = *game
+                        .get_possible_actions()
+                        .choose(&mut rng)
+                        .expect("unfinished game has no actions");
+
+                    game.apply_action(action);
+
+                    if game.outcome() == Outcome::InProgress {
+                        game.end_turn();
+                    }
+                }
+
+                assert_ne!(game.outcome(), Outcome::Loss);
+            }
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = Gomoku::new();
+
+            for index in [112, 0, 224, 14] {
+                game.apply_action(Action::Place { index });
+                game.end_turn();
+            }
+
+            let parsed =
+                Gomoku::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = Gomoku::new();
+
+            for index in [112, 0, 224, 14] {
+                game.apply_action(Action::Place { index });
+                game.end_turn();
+            }
+
+            let parsed: Gomoku = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod transform {
+        use super::*;
+
+        #[test]
+        fn should_keep_transformed_actions_in_sync_with_board() {
+            let game = make_game(&[(0, 1), (3, 7)], &[(14, 2)]);
+
+            for symmetry in 0..game.symmetries() {
+                let mut expected_game = game.transform(symmetry);
+                let mut actual_game = game.clone();
+
+                let action = Action::Place { index: 5 * 15 + 9 };
+
+                actual_game.apply_action(action);
+                expected_game.apply_action(game.transform_action(action, symmetry));
+
+                assert_eq!(actual_game.transform(symmetry), expected_game);
+            }
+        }
+
+        #[test]
+        fn should_rotate_quarter_turn() {
+            let game = make_game(&[(0, 1)], &[]);
+
+            assert_eq!(game.transform(1), make_game(&[(1, 14)], &[]));
+            assert_eq!(
+                game.transform_action(Action::Place { index: 1 }, 1),
+                Action::Place { index: 29 }
+            );
+        }
+    }
+}
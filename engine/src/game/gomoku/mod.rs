@@ -0,0 +1,12 @@
+mod action;
+mod action_encoder;
+#[allow(clippy::module_inception)]
+mod gomoku;
+mod portable;
+mod renderable;
+mod state_encoder;
+
+pub use action::Action as GomokuAction;
+pub use action_encoder::GomokuActionEncoder;
+pub use gomoku::{Gomoku, Phase as GomokuPhase};
+pub use state_encoder::GomokuStateEncoder;
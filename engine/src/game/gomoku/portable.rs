@@ -0,0 +1,23 @@
+use crate::game::gomoku::Gomoku;
+use crate::game::gomoku::action::Action;
+use crate::record::PortableGame;
+
+impl PortableGame for Gomoku {
+    const NAME: &'static str = "gomoku";
+
+    fn board_size() -> usize {
+        Self::BOARD_SIZE
+    }
+
+    fn format_action(action: Action) -> String {
+        match action {
+            Action::Place { index } => Self::format_point(index),
+        }
+    }
+
+    fn parse_action(value: &str) -> Result<Action, String> {
+        Ok(Action::Place {
+            index: Self::parse_point(value)?,
+        })
+    }
+}
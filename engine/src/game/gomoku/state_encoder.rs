@@ -0,0 +1,48 @@
+use crate::game::gomoku::gomoku::Gomoku;
+use crate::neural_network::StateEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct GomokuStateEncoder;
+
+impl GomokuStateEncoder {
+    const PLANE_COUNT: usize = 2;
+
+    pub fn new() -> Self {
+        GomokuStateEncoder
+    }
+
+    fn plane_slice(planes: &mut [f32], plane_index: usize) -> &mut [f32] {
+        let plane_size = Gomoku::BOARD_SIZE * Gomoku::BOARD_SIZE;
+
+        let start = plane_index * plane_size;
+        let end = start + plane_size;
+
+        &mut planes[start..end]
+    }
+
+    fn rows_to_plane(rows: &[u16; Gomoku::BOARD_SIZE], plane: &mut [f32]) {
+        for (row, bits) in rows.iter().enumerate() {
+            for col in 0..Gomoku::BOARD_SIZE {
+                plane[row * Gomoku::BOARD_SIZE + col] = f32::from((bits >> col) & 1);
+            }
+        }
+    }
+}
+
+impl StateEncoder<Gomoku> for GomokuStateEncoder {
+    fn shape(&self) -> Vec<usize> {
+        vec![1, Self::PLANE_COUNT, Gomoku::BOARD_SIZE, Gomoku::BOARD_SIZE]
+    }
+
+    fn encode(&self, state: &Gomoku) -> Vec<f32> {
+        let plane_size = Gomoku::BOARD_SIZE * Gomoku::BOARD_SIZE;
+        let plane_count = Self::PLANE_COUNT;
+
+        let mut planes = vec![0.0; plane_size * plane_count];
+
+        Self::rows_to_plane(&state.player_stones, Self::plane_slice(&mut planes, 0));
+        Self::rows_to_plane(&state.opponent_stones, Self::plane_slice(&mut planes, 1));
+
+        planes
+    }
+}
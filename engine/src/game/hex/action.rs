@@ -0,0 +1,21 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action {
+    Place { row: u8, col: u8 },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Place { row, col } => {
+                write!(
+                    f,
+                    "places a stone at {}{}.",
+                    char::from(b'a' + col),
+                    row + 1
+                )
+            }
+        }
+    }
+}
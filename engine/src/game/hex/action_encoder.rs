@@ -0,0 +1,31 @@
+use crate::game::hex::Hex;
+use crate::game::hex::action::Action;
+use crate::neural_network::ActionEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct HexActionEncoder<const SIZE: usize = 11>;
+
+impl<const SIZE: usize> HexActionEncoder<SIZE> {
+    pub fn new() -> Self {
+        HexActionEncoder
+    }
+}
+
+impl<const SIZE: usize> ActionEncoder<Hex<SIZE>> for HexActionEncoder<SIZE> {
+    const ACTION_COUNT: usize = SIZE * SIZE;
+
+    fn encode(&self, action: &Action) -> usize {
+        match *action {
+            Action::Place { row, col } => row as usize * SIZE + col as usize,
+        }
+    }
+
+    fn decode(&self, action_id: usize) -> Action {
+        debug_assert!(action_id < Self::ACTION_COUNT);
+
+        Action::Place {
+            row: u8::try_from(action_id / SIZE).unwrap(),
+            col: u8::try_from(action_id % SIZE).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,589 @@
+use std::mem::swap;
+use std::{fmt, str};
+
+use crate::core::{ActionList, Game, Outcome, Turn, format_rows, hash_position, parse_grid};
+use crate::game::hex::action::Action;
+
+// NOTE - The rhombus is skewed so that cell (row, col) neighbors (row, col ± 1),
+// (row - 1, col), (row - 1, col + 1), (row + 1, col) and (row + 1, col - 1). That keeps
+// both diagonals within a shift of one, so each side's stones are a `u32` per row and a
+// connection is traced a whole row at a time.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Hex<const SIZE: usize = 11> {
+    pub phase: Phase,
+
+    pub player_stones: [u32; SIZE],
+    pub opponent_stones: [u32; SIZE],
+
+    pub player_connection: Connection,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Place,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Connection {
+    TopBottom,
+    LeftRight,
+}
+
+impl Connection {
+    fn other(self) -> Self {
+        match self {
+            Connection::TopBottom => Connection::LeftRight,
+            Connection::LeftRight => Connection::TopBottom,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkpoint<const SIZE: usize> {
+    player_stones: [u32; SIZE],
+    opponent_stones: [u32; SIZE],
+
+    player_connection: Connection,
+}
+
+impl<const SIZE: usize> Hex<SIZE> {
+    pub const BOARD_SIZE: usize = SIZE;
+
+    const SYMMETRY_COUNT: u8 = 2;
+
+    // NOTE - Coordinates are written with letters, so boards are limited to 26 columns.
+
+    const VALID_SIZE: () = assert!(SIZE >= 1 && SIZE <= 26, "hex boards are 1x1 to 26x26");
+
+    const ROW_MASK: u32 = (1u32 << SIZE) - 1;
+
+    fn flip_perspective(&mut self) {
+        swap(&mut self.player_stones, &mut self.opponent_stones);
+
+        self.player_connection = self.player_connection.other();
+    }
+
+    // NOTE - Flood fill from the first edge, one row at a time, until nothing changes.
+
+    fn is_connected(stones: &[u32; SIZE], connection: Connection) -> bool {
+        let mut reached = [0u32; SIZE];
+
+        match connection {
+            Connection::TopBottom => reached[0] = stones[0],
+            Connection::LeftRight => {
+                for (reached, row) in reached.iter_mut().zip(stones) {
+                    *reached = row & 1;
+                }
+            }
+        }
+
+        loop {
+            let mut changed = false;
+
+            for row in 0..SIZE {
+                let mut frontier = reached[row];
+
+                if row > 0 {
+                    frontier |= reached[row - 1] | (reached[row - 1] >> 1);
+                }
+
+                if row + 1 < SIZE {
+                    frontier |= reached[row + 1] | (reached[row + 1] << 1);
+                }
+
+                let mut next = frontier & stones[row];
+
+                loop {
+                    let spread = (next | (next << 1) | (next >> 1)) & stones[row];
+
+                    if spread == next {
+                        break;
+                    }
+
+                    next = spread;
+                }
+
+                if next != reached[row] {
+                    reached[row] = next;
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        match connection {
+            Connection::TopBottom => reached[SIZE - 1] != 0,
+            Connection::LeftRight => reached.iter().any(|row| (row >> (SIZE - 1)) & 1 == 1),
+        }
+    }
+
+    fn transform_rows(rows: &[u32; SIZE], symmetry: u8) -> [u32; SIZE] {
+        match symmetry {
+            0 => *rows,
+            1 => {
+                let mut result = [0u32; SIZE];
+
+                for (row, bits) in rows.iter().enumerate() {
+                    result[SIZE - row - 1] = bits.reverse_bits() >> (32 - SIZE);
+                }
+
+                result
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn transform_position(row: usize, col: usize, symmetry: u8) -> (usize, usize) {
+        match symmetry {
+            0 => (row, col),
+            1 => (SIZE - row - 1, SIZE - col - 1),
+            _ => unreachable!(),
+        }
+    }
+
+    fn connection_label(connection: Connection) -> &'static str {
+        match connection {
+            Connection::TopBottom => "top-bottom",
+            Connection::LeftRight => "left-right",
+        }
+    }
+}
+
+impl<const SIZE: usize> Game for Hex<SIZE> {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Checkpoint<SIZE>;
+    type Config = ();
+
+    fn new_with_config(_config: ()) -> Self {
+        let () = Self::VALID_SIZE;
+
+        Hex {
+            phase: Phase::Place,
+
+            player_stones: [0; SIZE],
+            opponent_stones: [0; SIZE],
+
+            player_connection: Connection::TopBottom,
+        }
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        if self.outcome() != Outcome::InProgress {
+            return ActionList::new();
+        }
+
+        let mut actions = ActionList::new();
+
+        for row in 0..SIZE {
+            let empty = !(self.player_stones[row] | self.opponent_stones[row]) & Self::ROW_MASK;
+
+            for col in 0..SIZE {
+                if (empty >> col) & 1 == 1 {
+                    actions.push(Action::Place {
+                        row: u8::try_from(row).unwrap(),
+                        col: u8::try_from(col).unwrap(),
+                    });
+                }
+            }
+        }
+
+        actions
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Place { row, col } => {
+                self.player_stones[row as usize] |= 1u32 << col;
+            }
+        }
+
+        true
+    }
+
+    fn end_turn(&mut self) {
+        self.flip_perspective();
+    }
+
+    // NOTE - The board can never fill without one side connecting, so there are no draws.
+
+    fn outcome(&self) -> Outcome {
+        if Self::is_connected(&self.opponent_stones, self.player_connection.other()) {
+            return Outcome::Loss;
+        }
+
+        if Self::is_connected(&self.player_stones, self.player_connection) {
+            return Outcome::Win;
+        }
+
+        Outcome::InProgress
+    }
+
+    fn hash(&self) -> Option<u64> {
+        Some(hash_position(self))
+    }
+
+    fn create_checkpoint(&self) -> Checkpoint<SIZE> {
+        Checkpoint {
+            player_stones: self.player_stones,
+            opponent_stones: self.opponent_stones,
+
+            player_connection: self.player_connection,
+        }
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Checkpoint<SIZE>) {
+        self.player_stones = checkpoint.player_stones;
+        self.opponent_stones = checkpoint.opponent_stones;
+
+        self.player_connection = checkpoint.player_connection;
+    }
+
+    // NOTE - Only the half turn keeps each side's edges, since reflections would swap them.
+
+    fn symmetries(&self) -> u8 {
+        Self::SYMMETRY_COUNT
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        let mut game = self.clone();
+
+        game.player_stones = Self::transform_rows(&self.player_stones, symmetry);
+        game.opponent_stones = Self::transform_rows(&self.opponent_stones, symmetry);
+
+        game
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        match action {
+            Action::Place { row, col } => {
+                let (new_row, new_col) =
+                    Self::transform_position(row as usize, col as usize, symmetry);
+
+                Action::Place {
+                    row: u8::try_from(new_row).unwrap(),
+                    col: u8::try_from(new_col).unwrap(),
+                }
+            }
+        }
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        let mut game = self.clone();
+
+        if turn == Turn::Player2 {
+            game.flip_perspective();
+        }
+
+        format!("{game}")
+    }
+
+    fn to_notation(&self) -> String {
+        let board = format_rows((0..SIZE).map(|row| {
+            (0..SIZE).map(move |col| {
+                let mask = 1u32 << col;
+
+                if self.player_stones[row] & mask != 0 {
+                    Some('X')
+                } else if self.opponent_stones[row] & mask != 0 {
+                    Some('O')
+                } else {
+                    None
+                }
+            })
+        }));
+
+        format!("{board} {}", Self::connection_label(self.player_connection))
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let mut fields = notation.split_whitespace();
+
+        let rows = parse_grid(fields.next().unwrap_or_default(), SIZE, SIZE)?;
+
+        let mut game = Self::new();
+
+        for (row, cells) in rows.into_iter().enumerate() {
+            for (col, cell) in cells.into_iter().enumerate() {
+                match cell {
+                    None => {}
+                    Some('X') => game.player_stones[row] |= 1u32 << col,
+                    Some('O') => game.opponent_stones[row] |= 1u32 << col,
+                    Some(character) => {
+                        return Err(format!("invalid character at row {}: {character}", row + 1));
+                    }
+                }
+            }
+        }
+
+        game.player_connection = match fields.next() {
+            Some("top-bottom") => Connection::TopBottom,
+            Some("left-right") => Connection::LeftRight,
+            field => return Err(format!("invalid connection: {}", field.unwrap_or_default())),
+        };
+
+        if let Some(field) = fields.next() {
+            return Err(format!("unexpected field: {field}"));
+        }
+
+        Ok(game)
+    }
+}
+
+impl<const SIZE: usize> fmt::Display for Hex<SIZE> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // NOTE - Board, with each row shifted right to draw the rhombus.
+
+        write!(formatter, "   ")?;
+
+        for col in 0..SIZE {
+            write!(
+                formatter,
+                " {}",
+                char::from(b'a' + u8::try_from(col).unwrap())
+            )?;
+        }
+
+        writeln!(formatter)?;
+
+        for row in 0..SIZE {
+            write!(formatter, "{:>2} {:row$}", row + 1, "")?;
+
+            for col in 0..SIZE {
+                let mask = 1u32 << col;
+
+                let character = if self.player_stones[row] & mask != 0 {
+                    'X'
+                } else if self.opponent_stones[row] & mask != 0 {
+                    'O'
+                } else {
+                    '.'
+                };
+
+                write!(formatter, " {character}")?;
+            }
+
+            writeln!(formatter)?;
+        }
+
+        writeln!(
+            formatter,
+            "X: {}",
+            Self::connection_label(self.player_connection)
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<const SIZE: usize> str::FromStr for Hex<SIZE> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        if lines.len() != SIZE + 2 {
+            return Err(format!(
+                "expected {} lines, found {}",
+                SIZE + 2,
+                lines.len()
+            ));
+        }
+
+        let mut game = Self::new();
+
+        for (row, line) in lines[1..=SIZE].iter().enumerate() {
+            let cells: Vec<&str> = line.split_whitespace().skip(1).collect();
+
+            if cells.len() != SIZE {
+                return Err(format!("malformed board row {}", row + 1));
+            }
+
+            for (col, cell) in cells.into_iter().enumerate() {
+                match cell {
+                    "." => {}
+                    "X" => game.player_stones[row] |= 1u32 << col,
+                    "O" => game.opponent_stones[row] |= 1u32 << col,
+                    _ => return Err(format!("invalid character at row {}: {cell}", row + 1)),
+                }
+            }
+        }
+
+        game.player_connection = match lines[SIZE + 1] {
+            "X: top-bottom" => Connection::TopBottom,
+            "X: left-right" => Connection::LeftRight,
+            line => return Err(format!("invalid connection: {line}")),
+        };
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::seq::IndexedRandom;
+
+    use crate::core::{DefaultRngFactory, RngFactory};
+
+    fn parse_game(value: &str) -> Hex<4> {
+        value.parse().expect("unable to parse game")
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        fn should_detect_top_bottom_win() {
+            let game = parse_game(
+                "
+                        a b c d
+                     1  . X . .
+                     2   . X O .
+                     3    X O . .
+                     4     X . O .
+                    X: top-bottom
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+
+        #[test]
+        fn should_detect_left_right_loss() {
+            let game = parse_game(
+                "
+                        a b c d
+                     1  X . . .
+                     2   . X . .
+                     3    O O O O
+                     4     . . X .
+                    X: top-bottom
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+        }
+
+        #[test]
+        fn should_not_connect_across_the_short_diagonal() {
+            let game = parse_game(
+                "
+                        a b c d
+                     1  X . . .
+                     2   . X . .
+                     3    . . X .
+                     4     . . . X
+                    X: top-bottom
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::InProgress);
+        }
+
+        #[test]
+        fn should_always_finish_with_a_winner() {
+            let mut rng = DefaultRngFactory::default().create_seeded(7);
+
+            for _ in 0..20 {
+                let mut game = Hex::<5>::new();
+
+                while game.outcome() == Outcome::InProgress {
+                    let action = *game
+                        .get_possible_actions()
+                        .choose(&mut rng)
+                        .expect("unfinished game has no actions");
+
+                    game.apply_action(action);
+
+                    if game.outcome() == Outcome::InProgress {
+                        game.end_turn();
+                    }
+                }
+
+                assert_eq!(game.outcome(), Outcome::Win);
+            }
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = Hex::<4>::new();
+
+            for (row, col) in [(1, 2), (0, 0), (3, 1)] {
+                game.apply_action(Action::Place { row, col });
+                game.end_turn();
+            }
+
+            let parsed =
+                Hex::<4>::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = Hex::<4>::new();
+
+            for (row, col) in [(1, 2), (0, 0), (3, 1)] {
+                game.apply_action(Action::Place { row, col });
+                game.end_turn();
+            }
+
+            let parsed: Hex<4> = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod transform {
+        use super::*;
+
+        #[test]
+        fn should_rotate_half_turn() {
+            let game = parse_game(
+                "
+                        a b c d
+                     1  X O . .
+                     2   . . . .
+                     3    . . . .
+                     4     . . . .
+                    X: left-right
+                ",
+            );
+
+            let expected_game = parse_game(
+                "
+                        a b c d
+                     1  . . . .
+                     2   . . . .
+                     3    . . . .
+                     4     . . O X
+                    X: left-right
+                ",
+            );
+
+            assert_eq!(game.transform(1), expected_game);
+            assert_eq!(
+                game.transform_action(Action::Place { row: 0, col: 1 }, 1),
+                Action::Place { row: 3, col: 2 }
+            );
+        }
+    }
+}
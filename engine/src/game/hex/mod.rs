@@ -0,0 +1,12 @@
+mod action;
+mod action_encoder;
+#[allow(clippy::module_inception)]
+mod hex;
+mod portable;
+mod renderable;
+mod state_encoder;
+
+pub use action::Action as HexAction;
+pub use action_encoder::HexActionEncoder;
+pub use hex::{Connection as HexConnection, Hex, Phase as HexPhase};
+pub use state_encoder::HexStateEncoder;
@@ -0,0 +1,38 @@
+use crate::game::hex::Hex;
+use crate::game::hex::action::Action;
+use crate::record::PortableGame;
+
+impl<const SIZE: usize> PortableGame for Hex<SIZE> {
+    const NAME: &'static str = "hex";
+
+    fn board_size() -> usize {
+        SIZE
+    }
+
+    // NOTE - Points are written column then row, as in SGF, but kept as coordinates since
+    // larger boards have more points than fit in a `u8` index.
+
+    fn format_action(action: Action) -> String {
+        match action {
+            Action::Place { row, col } => [col, row]
+                .iter()
+                .map(|&coordinate| char::from(b'a' + coordinate))
+                .collect(),
+        }
+    }
+
+    fn parse_action(value: &str) -> Result<Action, String> {
+        let coordinates: Vec<usize> = value
+            .bytes()
+            .map(|byte| byte.wrapping_sub(b'a') as usize)
+            .collect();
+
+        match coordinates[..] {
+            [col, row] if col < SIZE && row < SIZE => Ok(Action::Place {
+                row: u8::try_from(row).map_err(|error| error.to_string())?,
+                col: u8::try_from(col).map_err(|error| error.to_string())?,
+            }),
+            _ => Err(format!("invalid point: {value}")),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::core::Turn;
+use crate::game::hex::Hex;
+use crate::game::hex::action::Action;
+use crate::render::{PieceShape, RenderableGame, RenderedPiece};
+
+impl<const SIZE: usize> RenderableGame for Hex<SIZE> {
+    fn grid_size(&self) -> (usize, usize) {
+        (SIZE, SIZE)
+    }
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece> {
+        let mask = 1u32 << col;
+
+        let owner = if self.player_stones[row] & mask != 0 {
+            turn
+        } else if self.opponent_stones[row] & mask != 0 {
+            turn.advance()
+        } else {
+            return None;
+        };
+
+        Some(RenderedPiece {
+            owner,
+            shape: PieceShape::Mark,
+        })
+    }
+
+    fn action_squares(&self, action: Action) -> Vec<(usize, usize)> {
+        match action {
+            Action::Place { row, col } => vec![(row as usize, col as usize)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::render::weighted_squares;
+
+    mod square_weights {
+        use super::*;
+
+        #[test]
+        fn should_map_actions_onto_their_squares() {
+            let game = Hex::<5>::new();
+
+            assert_eq!(game.grid_size(), (5, 5));
+            assert_eq!(
+                weighted_squares(&game, &[(Action::Place { row: 1, col: 3 }, 0.5)],),
+                vec![(8, 0.5)]
+            );
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::game::hex::hex::{Connection, Hex};
+use crate::neural_network::StateEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct HexStateEncoder<const SIZE: usize = 11>;
+
+impl<const SIZE: usize> HexStateEncoder<SIZE> {
+    const PLANE_COUNT: usize = 3;
+
+    pub fn new() -> Self {
+        HexStateEncoder
+    }
+
+    fn plane_slice(planes: &mut [f32], plane_index: usize) -> &mut [f32] {
+        let plane_size = SIZE * SIZE;
+
+        let start = plane_index * plane_size;
+        let end = start + plane_size;
+
+        &mut planes[start..end]
+    }
+
+    fn rows_to_plane(rows: &[u32; SIZE], plane: &mut [f32]) {
+        for (row, bits) in rows.iter().enumerate() {
+            for col in 0..SIZE {
+                plane[row * SIZE + col] = f32::from(u8::from((bits >> col) & 1 == 1));
+            }
+        }
+    }
+}
+
+impl<const SIZE: usize> StateEncoder<Hex<SIZE>> for HexStateEncoder<SIZE> {
+    fn shape(&self) -> Vec<usize> {
+        vec![1, Self::PLANE_COUNT, SIZE, SIZE]
+    }
+
+    fn encode(&self, state: &Hex<SIZE>) -> Vec<f32> {
+        let plane_size = SIZE * SIZE;
+        let plane_count = Self::PLANE_COUNT;
+
+        let mut planes = vec![0.0; plane_size * plane_count];
+
+        Self::rows_to_plane(&state.player_stones, Self::plane_slice(&mut planes, 0));
+        Self::rows_to_plane(&state.opponent_stones, Self::plane_slice(&mut planes, 1));
+
+        // NOTE - The side to move's direction, since the board is not transposed between
+        // turns.
+
+        if state.player_connection == Connection::TopBottom {
+            Self::plane_slice(&mut planes, 2).fill(1.0);
+        }
+
+        planes
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+use crate::game::hive::hive::{Bug, Hive};
+
+// NOTE - Moves name the piece by its slot among the player's pieces, which is also how the
+// action encoder tells them apart.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action {
+    Place { bug: Bug, cell: u16 },
+    Move { piece: u8, to: u16 },
+    Pass,
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Place { bug, cell } => {
+                write!(f, "places a {} at {}.", bug.name(), Hive::cell_name(*cell))
+            }
+            Action::Move { piece, to } => {
+                let bug = Bug::of_slot(*piece as usize);
+
+                write!(
+                    f,
+                    "moves {} {} to {}.",
+                    bug.name(),
+                    *piece as usize - bug.slots().start + 1,
+                    Hive::cell_name(*to)
+                )
+            }
+            Action::Pass => write!(f, "passes."),
+        }
+    }
+}
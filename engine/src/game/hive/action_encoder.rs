@@ -0,0 +1,57 @@
+use crate::game::hive::action::Action;
+use crate::game::hive::hive::{Bug, Hive};
+use crate::neural_network::ActionEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct HiveActionEncoder;
+
+// NOTE - Placements are identified by the kind of piece and the cell, and moves by the
+// slot of the piece and the cell it ends on, all in the recentered area. Passing comes
+// last.
+
+impl HiveActionEncoder {
+    const PLACE_COUNT: usize = Bug::ALL.len() * Hive::CELL_COUNT;
+    const MOVE_COUNT: usize = Hive::PIECE_COUNT * Hive::CELL_COUNT;
+
+    pub fn new() -> Self {
+        HiveActionEncoder
+    }
+}
+
+impl ActionEncoder<Hive> for HiveActionEncoder {
+    const ACTION_COUNT: usize = Self::PLACE_COUNT + Self::MOVE_COUNT + 1;
+
+    fn encode(&self, action: &Action) -> usize {
+        match *action {
+            Action::Place { bug, cell } => {
+                let kind = Bug::ALL.iter().position(|&other| other == bug).unwrap();
+
+                kind * Hive::CELL_COUNT + cell as usize
+            }
+            Action::Move { piece, to } => {
+                Self::PLACE_COUNT + piece as usize * Hive::CELL_COUNT + to as usize
+            }
+            Action::Pass => Self::PLACE_COUNT + Self::MOVE_COUNT,
+        }
+    }
+
+    fn decode(&self, action_id: usize) -> Action {
+        debug_assert!(action_id < Self::ACTION_COUNT);
+
+        if action_id < Self::PLACE_COUNT {
+            Action::Place {
+                bug: Bug::ALL[action_id / Hive::CELL_COUNT],
+                cell: u16::try_from(action_id % Hive::CELL_COUNT).unwrap(),
+            }
+        } else if action_id < Self::PLACE_COUNT + Self::MOVE_COUNT {
+            let index = action_id - Self::PLACE_COUNT;
+
+            Action::Move {
+                piece: u8::try_from(index / Hive::CELL_COUNT).unwrap(),
+                to: u16::try_from(index % Hive::CELL_COUNT).unwrap(),
+            }
+        } else {
+            Action::Pass
+        }
+    }
+}
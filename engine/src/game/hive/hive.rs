@@ -0,0 +1,1325 @@
+use std::collections::BTreeMap;
+use std::mem::swap;
+use std::ops::Range;
+use std::{fmt, str};
+
+use crate::core::{ActionList, Game, Outcome, Turn};
+use crate::game::hive::action::Action;
+
+// NOTE - Hive without expansions. Each side has a queen, two beetles, three grasshoppers,
+// two spiders and three ants, played on hexagons indexed as in Hex, so cell (row, col)
+// neighbors (row, col ± 1), (row - 1, col), (row - 1, col + 1), (row + 1, col) and
+// (row + 1, col - 1). The hive has no fixed place of its own, so after every action it is
+// moved to the middle of a 24x24 area, which always holds all 22 pieces and the cells
+// around them. Pieces of the same kind are kept sorted by where they stand, so positions
+// differing only in which ant is which compare equal.
+//
+// A queen must be placed by its side's fourth piece, and no piece moves before it is.
+// Surrounding the opposing queen wins, and surrounding both at once draws. A side with no
+// legal action passes, and games still going after `PLY_LIMIT` plies are drawn.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Hive {
+    pub phase: Phase,
+
+    pub player_pieces: [Option<Placement>; 11],
+    pub opponent_pieces: [Option<Placement>; 11],
+
+    pub plies: u16,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Play,
+}
+
+// NOTE - Pieces on the ground have height 0, and a beetle climbing onto them height 1.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Placement {
+    pub cell: u16,
+    pub height: u8,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Bug {
+    Queen,
+    Beetle,
+    Grasshopper,
+    Spider,
+    Ant,
+}
+
+impl Bug {
+    pub const ALL: [Bug; 5] = [
+        Bug::Queen,
+        Bug::Beetle,
+        Bug::Grasshopper,
+        Bug::Spider,
+        Bug::Ant,
+    ];
+
+    // NOTE - Where each kind sits among a side's pieces.
+
+    pub fn slots(self) -> Range<usize> {
+        match self {
+            Bug::Queen => 0..1,
+            Bug::Beetle => 1..3,
+            Bug::Grasshopper => 3..6,
+            Bug::Spider => 6..8,
+            Bug::Ant => 8..11,
+        }
+    }
+
+    pub fn of_slot(slot: usize) -> Bug {
+        Self::ALL
+            .into_iter()
+            .find(|bug| bug.slots().contains(&slot))
+            .expect("slot out of range")
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Bug::Queen => "queen",
+            Bug::Beetle => "beetle",
+            Bug::Grasshopper => "grasshopper",
+            Bug::Spider => "spider",
+            Bug::Ant => "ant",
+        }
+    }
+
+    pub(crate) fn letter(self) -> char {
+        match self {
+            Bug::Queen => 'Q',
+            Bug::Beetle => 'B',
+            Bug::Grasshopper => 'G',
+            Bug::Spider => 'S',
+            Bug::Ant => 'A',
+        }
+    }
+
+    pub(crate) fn from_letter(letter: char) -> Option<Bug> {
+        Self::ALL
+            .into_iter()
+            .find(|bug| bug.letter() == letter.to_ascii_uppercase())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    player_pieces: [Option<Placement>; 11],
+    opponent_pieces: [Option<Placement>; 11],
+
+    plies: u16,
+}
+
+type Heights = [u8; Hive::CELL_COUNT];
+
+impl Hive {
+    pub const BOARD_SIZE: usize = 24;
+    pub const CELL_COUNT: usize = Self::BOARD_SIZE * Self::BOARD_SIZE;
+
+    pub const PIECE_COUNT: usize = 11;
+
+    pub const PLY_LIMIT: u16 = 300;
+
+    const SYMMETRY_COUNT: u8 = 12;
+
+    #[allow(clippy::cast_possible_truncation)]
+    const CENTER: u16 = (Self::BOARD_SIZE / 2 * (Self::BOARD_SIZE + 1)) as u16;
+
+    // NOTE - In order around a cell, so neighbors of a cell in consecutive directions are
+    // also neighbors of each other.
+
+    const DIRECTIONS: [(isize, isize); 6] = [(0, 1), (-1, 1), (-1, 0), (0, -1), (1, -1), (1, 0)];
+
+    pub(crate) fn cell_name(cell: u16) -> String {
+        let row = cell as usize / Self::BOARD_SIZE;
+        let col = u8::try_from(cell as usize % Self::BOARD_SIZE).unwrap();
+
+        format!("{}{}", char::from(b'a' + col), row + 1)
+    }
+
+    pub(crate) fn parse_cell(value: &str) -> Option<u16> {
+        let col = value.bytes().next()?.checked_sub(b'a')? as usize;
+        let row = value.get(1..)?.parse::<usize>().ok()?.checked_sub(1)?;
+
+        (row < Self::BOARD_SIZE && col < Self::BOARD_SIZE)
+            .then(|| u16::try_from(row * Self::BOARD_SIZE + col).unwrap())
+    }
+
+    pub(crate) fn neighbor(cell: u16, direction: usize) -> Option<u16> {
+        let (row_step, col_step) = Self::DIRECTIONS[direction];
+
+        let row = (cell as usize / Self::BOARD_SIZE).checked_add_signed(row_step)?;
+        let col = (cell as usize % Self::BOARD_SIZE).checked_add_signed(col_step)?;
+
+        (row < Self::BOARD_SIZE && col < Self::BOARD_SIZE)
+            .then(|| u16::try_from(row * Self::BOARD_SIZE + col).unwrap())
+    }
+
+    fn neighbors(cell: u16) -> impl Iterator<Item = u16> {
+        (0..6).filter_map(move |direction| Self::neighbor(cell, direction))
+    }
+
+    fn flip_perspective(&mut self) {
+        swap(&mut self.player_pieces, &mut self.opponent_pieces);
+    }
+
+    fn pieces(&self) -> impl Iterator<Item = Placement> + '_ {
+        self.player_pieces
+            .iter()
+            .chain(&self.opponent_pieces)
+            .flatten()
+            .copied()
+    }
+
+    pub(crate) fn heights(&self) -> Heights {
+        let mut heights = [0; Self::CELL_COUNT];
+
+        for placement in self.pieces() {
+            heights[placement.cell as usize] += 1;
+        }
+
+        heights
+    }
+
+    // NOTE - The piece on top of each cell, as whether it is the player's and its kind.
+
+    pub(crate) fn tops(&self, heights: &Heights) -> [Option<(bool, Bug)>; Self::CELL_COUNT] {
+        let mut tops = [None; Self::CELL_COUNT];
+
+        for (is_player, pieces) in [(true, &self.player_pieces), (false, &self.opponent_pieces)] {
+            for (slot, placement) in pieces.iter().enumerate() {
+                if let Some(placement) = placement
+                    && heights[placement.cell as usize] == placement.height + 1
+                {
+                    tops[placement.cell as usize] = Some((is_player, Bug::of_slot(slot)));
+                }
+            }
+        }
+
+        tops
+    }
+
+    fn is_surrounded(queen: Option<Placement>, heights: &Heights) -> bool {
+        queen.is_some_and(|queen| {
+            (0..6).all(|direction| {
+                Self::neighbor(queen.cell, direction).is_some_and(|cell| heights[cell as usize] > 0)
+            })
+        })
+    }
+
+    fn is_connected(heights: &Heights) -> bool {
+        let occupied = heights.iter().filter(|&&height| height > 0).count();
+
+        let Some(start) = heights.iter().position(|&height| height > 0) else {
+            return true;
+        };
+
+        let mut seen = [false; Self::CELL_COUNT];
+        let mut stack = vec![u16::try_from(start).unwrap()];
+
+        seen[start] = true;
+
+        let mut reached = 0;
+
+        while let Some(cell) = stack.pop() {
+            reached += 1;
+
+            for neighbor in Self::neighbors(cell) {
+                if heights[neighbor as usize] > 0 && !seen[neighbor as usize] {
+                    seen[neighbor as usize] = true;
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        reached == occupied
+    }
+
+    // NOTE - One step from `from`, with the moving piece already lifted off the board. A
+    // piece cannot squeeze between two neighbors higher than both ends of the step, and on
+    // the ground it must keep touching the hive as it slides.
+
+    fn step(heights: &Heights, from: u16, direction: usize, climb: bool) -> Option<u16> {
+        let to = Self::neighbor(from, direction)?;
+
+        let height = |cell: Option<u16>| cell.map_or(0, |cell| heights[cell as usize]);
+
+        let left = height(Self::neighbor(from, (direction + 5) % 6));
+        let right = height(Self::neighbor(from, (direction + 1) % 6));
+
+        let source = heights[from as usize];
+        let target = heights[to as usize];
+
+        if !climb && target > 0 {
+            return None;
+        }
+
+        if left.min(right) > source.max(target) {
+            return None;
+        }
+
+        if source.max(target).max(left).max(right) == 0 {
+            return None;
+        }
+
+        Some(to)
+    }
+
+    fn destinations(bug: Bug, heights: &Heights, from: u16) -> Vec<u16> {
+        match bug {
+            Bug::Queen | Bug::Beetle => (0..6)
+                .filter_map(|direction| Self::step(heights, from, direction, bug == Bug::Beetle))
+                .collect(),
+            Bug::Grasshopper => (0..6)
+                .filter_map(|direction| {
+                    let mut cell = Self::neighbor(from, direction)?;
+
+                    if heights[cell as usize] == 0 {
+                        return None;
+                    }
+
+                    while heights[cell as usize] > 0 {
+                        cell = Self::neighbor(cell, direction)?;
+                    }
+
+                    Some(cell)
+                })
+                .collect(),
+            Bug::Spider => {
+                let mut destinations = vec![];
+
+                Self::walk(heights, &mut vec![from], &mut destinations);
+
+                destinations
+            }
+            Bug::Ant => {
+                let mut seen = [false; Self::CELL_COUNT];
+                let mut stack = vec![from];
+
+                seen[from as usize] = true;
+
+                let mut destinations = vec![];
+
+                while let Some(cell) = stack.pop() {
+                    for direction in 0..6 {
+                        if let Some(next) = Self::step(heights, cell, direction, false)
+                            && !seen[next as usize]
+                        {
+                            seen[next as usize] = true;
+                            stack.push(next);
+                            destinations.push(next);
+                        }
+                    }
+                }
+
+                destinations
+            }
+        }
+    }
+
+    // NOTE - Spiders slide exactly three cells without passing through any cell twice.
+
+    fn walk(heights: &Heights, path: &mut Vec<u16>, destinations: &mut Vec<u16>) {
+        let cell = *path.last().unwrap();
+
+        if path.len() == 4 {
+            if !destinations.contains(&cell) {
+                destinations.push(cell);
+            }
+
+            return;
+        }
+
+        for direction in 0..6 {
+            if let Some(next) = Self::step(heights, cell, direction, false)
+                && !path.contains(&next)
+            {
+                path.push(next);
+                Self::walk(heights, path, destinations);
+                path.pop();
+            }
+        }
+    }
+
+    fn placement_cells(&self, heights: &Heights) -> Vec<u16> {
+        let mut pieces = self.pieces();
+
+        match (pieces.next(), pieces.next()) {
+            (None, _) => return vec![Self::CENTER],
+            (Some(only), None) => return Self::neighbors(only.cell).collect(),
+            _ => {}
+        }
+
+        let tops = self.tops(heights);
+
+        (0..Self::CELL_COUNT)
+            .filter(|&cell| heights[cell] == 0)
+            .map(|cell| u16::try_from(cell).unwrap())
+            .filter(|&cell| {
+                let mut touches_player = false;
+
+                for neighbor in Self::neighbors(cell) {
+                    match tops[neighbor as usize] {
+                        Some((true, _)) => touches_player = true,
+                        Some((false, _)) => return false,
+                        None => {}
+                    }
+                }
+
+                touches_player
+            })
+            .collect()
+    }
+
+    fn coordinates(cell: u16) -> (isize, isize) {
+        (
+            (cell as usize / Self::BOARD_SIZE).cast_signed(),
+            (cell as usize % Self::BOARD_SIZE).cast_signed(),
+        )
+    }
+
+    fn to_cell((row, col): (isize, isize)) -> u16 {
+        u16::try_from(row * Self::BOARD_SIZE.cast_signed() + col).expect("cell out of range")
+    }
+
+    // NOTE - The shift that centers the bounding box of the given cells.
+
+    fn recentering(coordinates: &[(isize, isize)]) -> (isize, isize) {
+        let size = Self::BOARD_SIZE.cast_signed();
+
+        let center = |values: &mut dyn Iterator<Item = isize>| {
+            let (min, max) = values.fold((isize::MAX, isize::MIN), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+
+            (size - (max - min + 1)) / 2 - min
+        };
+
+        if coordinates.is_empty() {
+            return (0, 0);
+        }
+
+        (
+            center(&mut coordinates.iter().map(|&(row, _)| row)),
+            center(&mut coordinates.iter().map(|&(_, col)| col)),
+        )
+    }
+
+    // NOTE - Reflects across the axis through the center and then rotates about it, one
+    // sixth of a turn for each step, without recentering.
+
+    fn rotate(symmetry: u8, cell: u16) -> (isize, isize) {
+        let center = (Self::BOARD_SIZE / 2).cast_signed();
+
+        let (row, col) = Self::coordinates(cell);
+        let (mut q, mut r) = (col - center, row - center);
+
+        if symmetry >= 6 {
+            swap(&mut q, &mut r);
+        }
+
+        for _ in 0..symmetry % 6 {
+            (q, r) = (-r, q + r);
+        }
+
+        (r + center, q + center)
+    }
+
+    fn symmetry_map(&self, symmetry: u8) -> impl Fn(u16) -> u16 {
+        let coordinates: Vec<_> = self
+            .pieces()
+            .map(|placement| Self::rotate(symmetry, placement.cell))
+            .collect();
+
+        let (row_shift, col_shift) = Self::recentering(&coordinates);
+
+        move |cell| {
+            let (row, col) = Self::rotate(symmetry, cell);
+
+            Self::to_cell((row + row_shift, col + col_shift))
+        }
+    }
+
+    fn sort_pieces(&mut self) {
+        for bug in Bug::ALL {
+            self.player_pieces[bug.slots()].sort_unstable();
+            self.opponent_pieces[bug.slots()].sort_unstable();
+        }
+    }
+
+    fn normalize(&mut self) {
+        let coordinates: Vec<_> = self
+            .pieces()
+            .map(|placement| Self::coordinates(placement.cell))
+            .collect();
+
+        let (row_shift, col_shift) = Self::recentering(&coordinates);
+
+        for placement in self
+            .player_pieces
+            .iter_mut()
+            .chain(&mut self.opponent_pieces)
+            .flatten()
+        {
+            let (row, col) = Self::coordinates(placement.cell);
+
+            placement.cell = Self::to_cell((row + row_shift, col + col_shift));
+        }
+
+        self.sort_pieces();
+    }
+
+    // NOTE - Builds a game from the pieces on each cell, listed from the bottom up, as
+    // parsed from either the board diagram or the notation.
+
+    fn from_stacks(stacks: BTreeMap<u16, Vec<(bool, Bug)>>, plies: u16) -> Result<Self, String> {
+        let mut game = Self::new();
+
+        for (cell, stack) in stacks {
+            for (height, (is_player, bug)) in stack.into_iter().enumerate() {
+                let pieces = if is_player {
+                    &mut game.player_pieces
+                } else {
+                    &mut game.opponent_pieces
+                };
+
+                let slot = bug
+                    .slots()
+                    .find(|&slot| pieces[slot].is_none())
+                    .ok_or_else(|| format!("too many pieces of kind {}", bug.name()))?;
+
+                pieces[slot] = Some(Placement {
+                    cell,
+                    height: u8::try_from(height).unwrap(),
+                });
+            }
+        }
+
+        game.plies = plies;
+        game.normalize();
+
+        Ok(game)
+    }
+}
+
+impl Game for Hive {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Checkpoint;
+    type Config = ();
+
+    fn new_with_config(_config: ()) -> Self {
+        Hive {
+            phase: Phase::Play,
+
+            player_pieces: [None; Self::PIECE_COUNT],
+            opponent_pieces: [None; Self::PIECE_COUNT],
+
+            plies: 0,
+        }
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        let mut actions = ActionList::new();
+
+        if self.outcome() != Outcome::InProgress {
+            return actions;
+        }
+
+        let heights = self.heights();
+
+        // NOTE - Placements
+
+        let queen = self.player_pieces[Bug::Queen.slots().start];
+        let placed = self.player_pieces.iter().flatten().count();
+
+        let bugs = Bug::ALL.into_iter().filter(|&bug| {
+            let in_hand = bug.slots().any(|slot| self.player_pieces[slot].is_none());
+
+            in_hand && (queen.is_some() || placed < 3 || bug == Bug::Queen)
+        });
+
+        let cells = self.placement_cells(&heights);
+
+        for bug in bugs {
+            for &cell in &cells {
+                actions.push(Action::Place { bug, cell });
+            }
+        }
+
+        // NOTE - Moves
+
+        if queen.is_some() {
+            for (slot, placement) in self.player_pieces.iter().enumerate() {
+                let Some(placement) = *placement else {
+                    continue;
+                };
+
+                let from = placement.cell;
+
+                if heights[from as usize] != placement.height + 1 {
+                    continue;
+                }
+
+                let mut remaining = heights;
+                remaining[from as usize] -= 1;
+
+                if remaining[from as usize] == 0 && !Self::is_connected(&remaining) {
+                    continue;
+                }
+
+                for to in Self::destinations(Bug::of_slot(slot), &remaining, from) {
+                    actions.push(Action::Move {
+                        piece: u8::try_from(slot).unwrap(),
+                        to,
+                    });
+                }
+            }
+        }
+
+        if actions.is_empty() {
+            actions.push(Action::Pass);
+        }
+
+        actions
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Place { bug, cell } => {
+                let slot = bug
+                    .slots()
+                    .find(|&slot| self.player_pieces[slot].is_none())
+                    .expect("no piece left to place");
+
+                self.player_pieces[slot] = Some(Placement { cell, height: 0 });
+            }
+            Action::Move { piece, to } => {
+                let height = self
+                    .pieces()
+                    .filter(|placement| placement.cell == to)
+                    .count();
+
+                self.player_pieces[piece as usize] = Some(Placement {
+                    cell: to,
+                    height: u8::try_from(height).unwrap(),
+                });
+            }
+            Action::Pass => {}
+        }
+
+        self.normalize();
+
+        true
+    }
+
+    fn end_turn(&mut self) {
+        self.flip_perspective();
+
+        self.plies += 1;
+    }
+
+    fn outcome(&self) -> Outcome {
+        let heights = self.heights();
+
+        let queen = Bug::Queen.slots().start;
+
+        let won = Self::is_surrounded(self.opponent_pieces[queen], &heights);
+        let lost = Self::is_surrounded(self.player_pieces[queen], &heights);
+
+        if won && lost {
+            Outcome::Draw
+        } else if won {
+            Outcome::Win
+        } else if lost {
+            Outcome::Loss
+        } else if self.plies >= Self::PLY_LIMIT {
+            Outcome::Draw
+        } else {
+            Outcome::InProgress
+        }
+    }
+
+    fn create_checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            player_pieces: self.player_pieces,
+            opponent_pieces: self.opponent_pieces,
+
+            plies: self.plies,
+        }
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Checkpoint) {
+        self.player_pieces = checkpoint.player_pieces;
+        self.opponent_pieces = checkpoint.opponent_pieces;
+
+        self.plies = checkpoint.plies;
+    }
+
+    // NOTE - The six rotations of the hexagonal grid, each with and without a reflection,
+    // recentered afterwards like any other position.
+
+    fn symmetries(&self) -> u8 {
+        Self::SYMMETRY_COUNT
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        debug_assert!(symmetry < Self::SYMMETRY_COUNT);
+
+        let map = self.symmetry_map(symmetry);
+
+        let mut game = self.clone();
+
+        for placement in game
+            .player_pieces
+            .iter_mut()
+            .chain(&mut game.opponent_pieces)
+            .flatten()
+        {
+            placement.cell = map(placement.cell);
+        }
+
+        game.sort_pieces();
+
+        game
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        debug_assert!(symmetry < Self::SYMMETRY_COUNT);
+
+        let map = self.symmetry_map(symmetry);
+
+        match action {
+            Action::Place { bug, cell } => Action::Place {
+                bug,
+                cell: map(cell),
+            },
+            Action::Move { piece, to } => {
+                let placement = self.player_pieces[piece as usize].expect("piece is not placed");
+
+                let moved = Some(Placement {
+                    cell: map(placement.cell),
+                    height: placement.height,
+                });
+
+                let game = self.transform(symmetry);
+
+                let slot = Bug::of_slot(piece as usize)
+                    .slots()
+                    .find(|&slot| game.player_pieces[slot] == moved)
+                    .expect("piece is missing after transform");
+
+                Action::Move {
+                    piece: u8::try_from(slot).unwrap(),
+                    to: map(to),
+                }
+            }
+            Action::Pass => Action::Pass,
+        }
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        let mut game = self.clone();
+
+        if turn == Turn::Player2 {
+            game.flip_perspective();
+        }
+
+        format!("{game}")
+    }
+
+    fn to_notation(&self) -> String {
+        let mut stacks: BTreeMap<u16, Vec<(u8, char)>> = BTreeMap::new();
+
+        for (is_player, pieces) in [(true, &self.player_pieces), (false, &self.opponent_pieces)] {
+            for (slot, placement) in pieces.iter().enumerate() {
+                if let Some(placement) = placement {
+                    let letter = Bug::of_slot(slot).letter();
+
+                    stacks.entry(placement.cell).or_default().push((
+                        placement.height,
+                        if is_player {
+                            letter
+                        } else {
+                            letter.to_ascii_lowercase()
+                        },
+                    ));
+                }
+            }
+        }
+
+        let stacks: Vec<String> = stacks
+            .into_iter()
+            .map(|(cell, mut pieces)| {
+                pieces.sort_unstable();
+
+                let pieces: String = pieces.into_iter().map(|(_, letter)| letter).collect();
+
+                format!("{}:{pieces}", Self::cell_name(cell))
+            })
+            .collect();
+
+        if stacks.is_empty() {
+            format!("- {}", self.plies)
+        } else {
+            format!("{} {}", stacks.join(","), self.plies)
+        }
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = notation.split_whitespace().collect();
+
+        if fields.len() != 2 {
+            return Err(format!("expected 2 fields, found {}", fields.len()));
+        }
+
+        let mut stacks: BTreeMap<u16, Vec<(bool, Bug)>> = BTreeMap::new();
+
+        if fields[0] != "-" {
+            for entry in fields[0].split(',') {
+                let (cell, pieces) = entry
+                    .split_once(':')
+                    .ok_or_else(|| format!("invalid stack: {entry}"))?;
+
+                let cell = Self::parse_cell(cell).ok_or_else(|| format!("invalid cell: {cell}"))?;
+
+                let pieces = pieces
+                    .chars()
+                    .map(|character| {
+                        Bug::from_letter(character)
+                            .map(|bug| (character.is_ascii_uppercase(), bug))
+                            .ok_or_else(|| format!("invalid piece: {character}"))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if pieces.is_empty() || stacks.insert(cell, pieces).is_some() {
+                    return Err(format!("invalid stack: {entry}"));
+                }
+            }
+        }
+
+        let plies = fields[1]
+            .parse()
+            .map_err(|_| format!("invalid plies: {}", fields[1]))?;
+
+        Self::from_stacks(stacks, plies)
+    }
+}
+
+impl fmt::Display for Hive {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let heights = self.heights();
+        let tops = self.tops(&heights);
+
+        let letter = |(is_player, bug): (bool, Bug)| {
+            if is_player {
+                bug.letter()
+            } else {
+                bug.letter().to_ascii_lowercase()
+            }
+        };
+
+        // NOTE - Board, limited to the hive and the cells around it, with each row shifted
+        // right to draw the rhombus.
+
+        let coordinates: Vec<_> = self
+            .pieces()
+            .map(|placement| Self::coordinates(placement.cell))
+            .collect();
+
+        let bounds = |values: &mut dyn Iterator<Item = isize>| {
+            let (min, max) = values.fold((isize::MAX, isize::MIN), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+
+            (min - 1).cast_unsigned()..=(max + 1).cast_unsigned()
+        };
+
+        let (rows, cols) = if coordinates.is_empty() {
+            let center = Self::BOARD_SIZE / 2;
+
+            (center..=center, center..=center)
+        } else {
+            (
+                bounds(&mut coordinates.iter().map(|&(row, _)| row)),
+                bounds(&mut coordinates.iter().map(|&(_, col)| col)),
+            )
+        };
+
+        write!(formatter, "   ")?;
+
+        for col in cols.clone() {
+            write!(
+                formatter,
+                " {}",
+                char::from(b'a' + u8::try_from(col).unwrap())
+            )?;
+        }
+
+        writeln!(formatter)?;
+
+        for row in rows.clone() {
+            write!(
+                formatter,
+                "{:>2} {:indent$}",
+                row + 1,
+                "",
+                indent = row - rows.start()
+            )?;
+
+            for col in cols.clone() {
+                let character = tops[row * Self::BOARD_SIZE + col].map_or('.', letter);
+
+                write!(formatter, " {character}")?;
+            }
+
+            writeln!(formatter)?;
+        }
+
+        // NOTE - Stacks, from the bottom up
+
+        let mut stacks: BTreeMap<u16, Vec<(u8, char)>> = BTreeMap::new();
+
+        for (is_player, pieces) in [(true, &self.player_pieces), (false, &self.opponent_pieces)] {
+            for (slot, placement) in pieces.iter().enumerate() {
+                if let Some(placement) = placement
+                    && heights[placement.cell as usize] > 1
+                {
+                    stacks
+                        .entry(placement.cell)
+                        .or_default()
+                        .push((placement.height, letter((is_player, Bug::of_slot(slot)))));
+                }
+            }
+        }
+
+        if !stacks.is_empty() {
+            write!(formatter, "Stacks:")?;
+
+            for (cell, mut pieces) in stacks {
+                pieces.sort_unstable();
+
+                let pieces: String = pieces.into_iter().map(|(_, letter)| letter).collect();
+
+                write!(formatter, " {}:{pieces}", Self::cell_name(cell))?;
+            }
+
+            writeln!(formatter)?;
+        }
+
+        // NOTE - Plies
+
+        writeln!(formatter, "Plies: {}", self.plies)
+    }
+}
+
+impl str::FromStr for Hive {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        let Some((header, lines)) = lines.split_first() else {
+            return Err("missing board".to_string());
+        };
+
+        let cols = header
+            .split_whitespace()
+            .map(|letter| {
+                Self::parse_cell(&format!("{letter}1"))
+                    .ok_or_else(|| format!("invalid column: {letter}"))
+            })
+            .collect::<Result<Vec<u16>, String>>()?;
+
+        let piece = |character: char| {
+            Bug::from_letter(character)
+                .map(|bug| (character.is_ascii_uppercase(), bug))
+                .ok_or_else(|| format!("invalid piece: {character}"))
+        };
+
+        let mut stacks: BTreeMap<u16, Vec<(bool, Bug)>> = BTreeMap::new();
+        let mut plies = 0;
+
+        for line in lines {
+            // NOTE - Stacks
+
+            if let Some(entries) = line.strip_prefix("Stacks:") {
+                for entry in entries.split_whitespace() {
+                    let (cell, pieces) = entry
+                        .split_once(':')
+                        .ok_or_else(|| format!("invalid stack: {entry}"))?;
+
+                    let cell =
+                        Self::parse_cell(cell).ok_or_else(|| format!("invalid cell: {cell}"))?;
+
+                    stacks.insert(cell, pieces.chars().map(piece).collect::<Result<_, _>>()?);
+                }
+
+                continue;
+            }
+
+            // NOTE - Plies
+
+            if let Some(value) = line.strip_prefix("Plies:") {
+                plies = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("invalid plies: {value}"))?;
+
+                continue;
+            }
+
+            // NOTE - Board
+
+            let mut cells = line.split_whitespace();
+
+            let row = cells
+                .next()
+                .and_then(|label| label.parse::<usize>().ok())
+                .and_then(|label| label.checked_sub(1))
+                .filter(|&row| row < Self::BOARD_SIZE)
+                .ok_or_else(|| format!("invalid board row: {line}"))?;
+
+            let cells: Vec<&str> = cells.collect();
+
+            if cells.len() != cols.len() {
+                return Err(format!("malformed board row {}", row + 1));
+            }
+
+            for (&col, cell) in cols.iter().zip(cells) {
+                let cell_index = u16::try_from(row * Self::BOARD_SIZE).unwrap() + col;
+
+                match cell.chars().collect::<Vec<_>>()[..] {
+                    ['.'] => {}
+                    [character] => {
+                        stacks.insert(cell_index, vec![piece(character)?]);
+                    }
+                    _ => return Err(format!("invalid character at row {}: {cell}", row + 1)),
+                }
+            }
+        }
+
+        Self::from_stacks(stacks, plies)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::seq::IndexedRandom;
+
+    use crate::core::{DefaultRngFactory, RngFactory};
+
+    fn parse_game(value: &str) -> Hive {
+        value.parse().expect("unable to parse game")
+    }
+
+    fn random_game(plies: usize, seed: u64) -> Hive {
+        let mut rng = DefaultRngFactory::default().create_seeded(seed);
+
+        let mut game = Hive::new();
+
+        for _ in 0..plies {
+            if game.outcome() != Outcome::InProgress {
+                break;
+            }
+
+            let action = *game
+                .get_possible_actions()
+                .choose(&mut rng)
+                .expect("unfinished game has no actions");
+
+            game.apply_action(action);
+            game.end_turn();
+        }
+
+        game
+    }
+
+    fn placed(game: &Hive, bug: Bug) -> (usize, Placement) {
+        bug.slots()
+            .find_map(|slot| Some((slot, game.player_pieces[slot]?)))
+            .expect("no piece of that kind is placed")
+    }
+
+    fn moves(game: &Hive, bug: Bug) -> Vec<u16> {
+        game.get_possible_actions()
+            .iter()
+            .filter_map(|action| match *action {
+                Action::Move { piece, to } if Bug::of_slot(piece as usize) == bug => Some(to),
+                _ => None,
+            })
+            .collect()
+    }
+
+    mod get_possible_actions {
+        use super::*;
+
+        #[test]
+        fn should_open_in_the_center_then_beside_it() {
+            let mut game = Hive::new();
+
+            let actions = game.get_possible_actions();
+
+            assert_eq!(actions.len(), Bug::ALL.len());
+            assert!(actions.iter().all(
+                |action| matches!(action, Action::Place { cell, .. } if *cell == Hive::CENTER)
+            ));
+
+            game.apply_action(Action::Place {
+                bug: Bug::Spider,
+                cell: Hive::CENTER,
+            });
+            game.end_turn();
+
+            assert_eq!(game.get_possible_actions().len(), Bug::ALL.len() * 6);
+        }
+
+        #[test]
+        fn should_require_the_queen_by_the_fourth_piece() {
+            let game = parse_game(
+                "
+                     a b c d e
+                   1  . . . . .
+                   2   A G A q a
+                ",
+            );
+
+            let actions = game.get_possible_actions();
+
+            assert!(!actions.is_empty());
+            assert!(actions.iter().all(|action| matches!(
+                action,
+                Action::Place {
+                    bug: Bug::Queen,
+                    ..
+                }
+            )));
+        }
+
+        #[test]
+        fn should_not_move_before_the_queen_is_placed() {
+            let game = parse_game(
+                "
+                     a b c
+                   1  . . .
+                   2   A q .
+                ",
+            );
+
+            assert!(
+                game.get_possible_actions()
+                    .iter()
+                    .all(|action| matches!(action, Action::Place { .. }))
+            );
+        }
+
+        #[test]
+        fn should_not_split_the_hive() {
+            let game = parse_game(
+                "
+                     a b c d
+                   1  . . . .
+                   2   . Q A q
+                ",
+            );
+
+            assert_eq!(moves(&game, Bug::Queen).len(), 2);
+            assert!(moves(&game, Bug::Ant).is_empty());
+        }
+
+        #[test]
+        fn should_not_slide_through_a_gate() {
+            let game = parse_game(
+                "
+                     a b c
+                   1  . g g
+                   2   a Q g
+                   3    a . .
+                ",
+            );
+
+            assert!(moves(&game, Bug::Queen).is_empty());
+        }
+
+        #[test]
+        fn should_jump_grasshoppers_over_a_line() {
+            let game = parse_game(
+                "
+                     a b c d e
+                   1  . . . . .
+                   2   G Q q a .
+                ",
+            );
+
+            let (_, grasshopper) = placed(&game, Bug::Grasshopper);
+
+            assert_eq!(moves(&game, Bug::Grasshopper), [grasshopper.cell + 4]);
+        }
+
+        #[test]
+        fn should_walk_spiders_three_cells_and_ants_anywhere() {
+            let game = parse_game(
+                "
+                     a b c d
+                   1  . . . .
+                   2   S Q q .
+                ",
+            );
+
+            assert_eq!(moves(&game, Bug::Spider).len(), 2);
+
+            let game = parse_game(
+                "
+                     a b c d
+                   1  . . . .
+                   2   A Q q .
+                ",
+            );
+
+            assert_eq!(moves(&game, Bug::Ant).len(), 7);
+        }
+
+        #[test]
+        fn should_climb_beetles_onto_the_hive() {
+            let mut game = parse_game(
+                "
+                     a b c d
+                   1  . . . .
+                   2   B Q q .
+                ",
+            );
+
+            let (_, queen) = placed(&game, Bug::Queen);
+            let (slot, _) = placed(&game, Bug::Beetle);
+
+            assert_eq!(moves(&game, Bug::Beetle).len(), 3);
+
+            let climb = Action::Move {
+                piece: u8::try_from(slot).unwrap(),
+                to: queen.cell,
+            };
+
+            assert!(game.get_possible_actions().contains(&climb));
+
+            game.apply_action(climb);
+
+            let (_, queen) = placed(&game, Bug::Queen);
+            let (_, beetle) = placed(&game, Bug::Beetle);
+
+            assert_eq!(beetle.cell, queen.cell);
+            assert_eq!(beetle.height, 1);
+            assert!(moves(&game, Bug::Queen).is_empty());
+        }
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        fn should_win_by_surrounding_the_opposing_queen() {
+            let mut game = parse_game(
+                "
+                     a b c d
+                   1  . A G .
+                   2   G q A Q
+                   3    A G . .
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::Win);
+
+            game.end_turn();
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+        }
+
+        #[test]
+        fn should_draw_when_both_queens_are_surrounded() {
+            let game = parse_game(
+                "
+                     a b c d
+                   1  . A G a
+                   2   G q Q s
+                   3    A G b .
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::Draw);
+        }
+
+        #[test]
+        fn should_always_finish() {
+            for seed in 0..3 {
+                let game = random_game(usize::MAX, seed);
+
+                assert_ne!(game.outcome(), Outcome::InProgress);
+            }
+        }
+    }
+
+    mod transform {
+        use super::*;
+
+        #[test]
+        fn should_transform_pieces_and_actions_together() {
+            let game = random_game(16, 3);
+
+            for symmetry in 0..game.symmetries() {
+                let transformed = game.transform(symmetry);
+
+                let mut expected = transformed
+                    .get_possible_actions()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>();
+
+                let mut actual = game
+                    .get_possible_actions()
+                    .iter()
+                    .map(|&action| game.transform_action(action, symmetry).to_string())
+                    .collect::<Vec<_>>();
+
+                expected.sort();
+                actual.sort();
+
+                assert_eq!(actual, expected, "symmetry {symmetry}");
+            }
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let game = random_game(24, 5);
+
+            let parsed =
+                Hive::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let game = random_game(24, 5);
+
+            let parsed: Hive = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+mod action;
+mod action_encoder;
+#[allow(clippy::module_inception)]
+mod hive;
+mod portable;
+mod renderable;
+mod state_encoder;
+
+pub use action::Action as HiveAction;
+pub use action_encoder::HiveActionEncoder;
+pub use hive::{Bug as HiveBug, Hive, Phase as HivePhase, Placement as HivePlacement};
+pub use state_encoder::HiveStateEncoder;
@@ -0,0 +1,69 @@
+use crate::game::hive::Hive;
+use crate::game::hive::action::Action;
+use crate::game::hive::hive::Bug;
+use crate::record::PortableGame;
+
+// NOTE - Placements are written as the piece's letter and the cell, such as `Ak12`, and
+// moves add which of the player's pieces of that kind moves, such as `A2k12`. Cells are in
+// the recentered area of the position the action is played from.
+
+impl PortableGame for Hive {
+    const NAME: &'static str = "hive";
+
+    fn board_size() -> usize {
+        Self::BOARD_SIZE
+    }
+
+    fn format_action(action: Action) -> String {
+        match action {
+            Action::Place { bug, cell } => format!("{}{}", bug.letter(), Self::cell_name(cell)),
+            Action::Move { piece, to } => {
+                let bug = Bug::of_slot(piece as usize);
+
+                format!(
+                    "{}{}{}",
+                    bug.letter(),
+                    piece as usize - bug.slots().start + 1,
+                    Self::cell_name(to)
+                )
+            }
+            Action::Pass => "pass".to_string(),
+        }
+    }
+
+    fn parse_action(value: &str) -> Result<Action, String> {
+        let invalid = || format!("invalid action: {value}");
+
+        if value == "pass" {
+            return Ok(Action::Pass);
+        }
+
+        let mut characters = value.chars();
+
+        let bug = characters
+            .next()
+            .filter(char::is_ascii_uppercase)
+            .and_then(Bug::from_letter)
+            .ok_or_else(invalid)?;
+
+        let rest = characters.as_str();
+
+        let Some(ordinal) = rest.chars().next().and_then(|digit| digit.to_digit(10)) else {
+            let cell = Self::parse_cell(rest).ok_or_else(invalid)?;
+
+            return Ok(Action::Place { bug, cell });
+        };
+
+        let slots = bug.slots();
+        let piece = slots.start + (ordinal as usize).checked_sub(1).ok_or_else(invalid)?;
+
+        if !slots.contains(&piece) {
+            return Err(invalid());
+        }
+
+        Ok(Action::Move {
+            piece: u8::try_from(piece).unwrap(),
+            to: Self::parse_cell(&rest[1..]).ok_or_else(invalid)?,
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use crate::core::Turn;
+use crate::game::hive::Hive;
+use crate::game::hive::action::Action;
+use crate::game::hive::hive::Bug;
+use crate::render::{PieceShape, RenderableGame, RenderedPiece};
+
+// NOTE - Only the piece on top of each cell is drawn, with queens large and every other
+// kind small.
+
+impl RenderableGame for Hive {
+    fn grid_size(&self) -> (usize, usize) {
+        (Self::BOARD_SIZE, Self::BOARD_SIZE)
+    }
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece> {
+        let heights = self.heights();
+
+        let (is_player, bug) = self.tops(&heights)[row * Self::BOARD_SIZE + col]?;
+
+        let owner = if is_player { turn } else { turn.advance() };
+
+        let shape = if bug == Bug::Queen {
+            PieceShape::Large
+        } else {
+            PieceShape::Small
+        };
+
+        Some(RenderedPiece { owner, shape })
+    }
+
+    fn action_squares(&self, action: Action) -> Vec<(usize, usize)> {
+        let cells = match action {
+            Action::Place { cell, .. } => vec![cell],
+            Action::Move { piece, to } => self.player_pieces[piece as usize]
+                .map(|placement| placement.cell)
+                .into_iter()
+                .chain([to])
+                .collect(),
+            Action::Pass => vec![],
+        };
+
+        cells
+            .into_iter()
+            .map(|cell| {
+                (
+                    cell as usize / Self::BOARD_SIZE,
+                    cell as usize % Self::BOARD_SIZE,
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::render::weighted_squares;
+
+    mod square_weights {
+        use super::*;
+
+        #[test]
+        fn should_map_actions_onto_their_squares() {
+            let game = Hive::new();
+
+            assert_eq!(game.grid_size(), (24, 24));
+            assert_eq!(
+                weighted_squares(
+                    &game,
+                    &[
+                        (
+                            Action::Place {
+                                bug: Bug::Queen,
+                                cell: 300,
+                            },
+                            0.5,
+                        ),
+                        (Action::Pass, 0.5),
+                    ],
+                ),
+                vec![(300, 0.5)]
+            );
+        }
+    }
+}
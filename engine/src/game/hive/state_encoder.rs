@@ -0,0 +1,89 @@
+use crate::game::hive::hive::{Bug, Hive, Placement};
+use crate::neural_network::StateEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct HiveStateEncoder;
+
+// NOTE - For each side, planes for the kind of piece on top of each cell, one for pieces
+// buried beneath the top and one per kind for the share still in hand. The last plane is
+// how far the game has run towards the ply limit.
+
+impl HiveStateEncoder {
+    const SIDE_PLANE_COUNT: usize = 2 * Bug::ALL.len() + 1;
+
+    const PLANE_COUNT: usize = 2 * Self::SIDE_PLANE_COUNT + 1;
+
+    pub fn new() -> Self {
+        HiveStateEncoder
+    }
+
+    fn plane_slice(planes: &mut [f32], plane_index: usize) -> &mut [f32] {
+        let plane_size = Hive::CELL_COUNT;
+
+        let start = plane_index * plane_size;
+        let end = start + plane_size;
+
+        &mut planes[start..end]
+    }
+
+    fn encode_side(
+        planes: &mut [f32],
+        base: usize,
+        pieces: &[Option<Placement>; Hive::PIECE_COUNT],
+        heights: &[u8; Hive::CELL_COUNT],
+    ) {
+        for (kind, bug) in Bug::ALL.into_iter().enumerate() {
+            let slots = bug.slots();
+            let slot_count = slots.len();
+
+            let mut in_hand = 0;
+
+            for placement in &pieces[slots] {
+                let Some(placement) = placement else {
+                    in_hand += 1;
+
+                    continue;
+                };
+
+                let cell = placement.cell as usize;
+
+                if heights[cell] == placement.height + 1 {
+                    Self::plane_slice(planes, base + kind)[cell] = 1.0;
+                } else {
+                    Self::plane_slice(planes, base + Bug::ALL.len())[cell] = 1.0;
+                }
+            }
+
+            Self::plane_slice(planes, base + Bug::ALL.len() + 1 + kind)
+                .fill(in_hand as f32 / slot_count as f32);
+        }
+    }
+}
+
+impl StateEncoder<Hive> for HiveStateEncoder {
+    fn shape(&self) -> Vec<usize> {
+        vec![1, Self::PLANE_COUNT, Hive::BOARD_SIZE, Hive::BOARD_SIZE]
+    }
+
+    fn encode(&self, state: &Hive) -> Vec<f32> {
+        let plane_size = Hive::CELL_COUNT;
+        let plane_count = Self::PLANE_COUNT;
+
+        let mut planes = vec![0.0; plane_size * plane_count];
+
+        let heights = state.heights();
+
+        Self::encode_side(&mut planes, 0, &state.player_pieces, &heights);
+        Self::encode_side(
+            &mut planes,
+            Self::SIDE_PLANE_COUNT,
+            &state.opponent_pieces,
+            &heights,
+        );
+
+        Self::plane_slice(&mut planes, 2 * Self::SIDE_PLANE_COUNT)
+            .fill(f32::from(state.plies) / f32::from(Hive::PLY_LIMIT));
+
+        planes
+    }
+}
@@ -0,0 +1,42 @@
+use crate::game::mnk::action::Action;
+use crate::game::mnk::mnk::{MnkConfig, MnkGame};
+use crate::neural_network::ActionEncoder;
+
+// NOTE - One action per cell of the configured board, so networks for different board
+// sizes have different policy sizes. `ACTION_COUNT` only bounds them.
+
+#[derive(Clone, Copy, Default)]
+pub struct MnkActionEncoder {
+    config: MnkConfig,
+}
+
+impl MnkActionEncoder {
+    pub fn new(config: MnkConfig) -> Self {
+        MnkActionEncoder { config }
+    }
+}
+
+impl ActionEncoder<MnkGame> for MnkActionEncoder {
+    const ACTION_COUNT: usize = MnkGame::MAX_SIZE * MnkGame::MAX_SIZE;
+
+    fn size(&self) -> usize {
+        self.config.width as usize * self.config.height as usize
+    }
+
+    fn encode(&self, action: &Action) -> usize {
+        match *action {
+            Action::Place { row, col } => row as usize * self.config.width as usize + col as usize,
+        }
+    }
+
+    fn decode(&self, action_id: usize) -> Action {
+        debug_assert!(action_id < self.size());
+
+        let width = self.config.width as usize;
+
+        Action::Place {
+            row: u8::try_from(action_id / width).unwrap(),
+            col: u8::try_from(action_id % width).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,716 @@
+use std::mem::swap;
+use std::{fmt, str};
+
+use crate::core::{ActionList, Game, Outcome, Turn, format_rows, hash_position, parse_rows};
+use crate::game::mnk::action::Action;
+
+// NOTE - The m,n,k-game: marks are placed on a `width` by `height` board and the first
+// line of `win_length` or more wins. The size and line length are chosen at runtime, with
+// the defaults giving Tic-Tac-Toe, and misère rules make completing a line lose. Marks
+// are stored as one bitmask per row, with bit `col` set for an occupied cell.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MnkGame {
+    pub phase: Phase,
+
+    pub player_marks: [u32; MnkGame::MAX_SIZE],
+    pub opponent_marks: [u32; MnkGame::MAX_SIZE],
+
+    pub config: MnkConfig,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Place,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MnkConfig {
+    pub width: u8,
+    pub height: u8,
+    pub win_length: u8,
+    pub misere: bool,
+}
+
+impl MnkConfig {
+    pub fn new(width: u8, height: u8, win_length: u8) -> Self {
+        let max_size = u8::try_from(MnkGame::MAX_SIZE).unwrap();
+
+        assert!(
+            (1..=max_size).contains(&width) && (1..=max_size).contains(&height),
+            "boards are 1x1 to {max_size}x{max_size}, found {width}x{height}"
+        );
+
+        assert!(
+            (1..=width.max(height)).contains(&win_length),
+            "win length must be from 1 to {}, found {win_length}",
+            width.max(height)
+        );
+
+        MnkConfig {
+            width,
+            height,
+            win_length,
+            misere: false,
+        }
+    }
+
+    pub fn with_misere(mut self, misere: bool) -> Self {
+        self.misere = misere;
+
+        self
+    }
+}
+
+impl Default for MnkConfig {
+    fn default() -> Self {
+        MnkConfig::new(3, 3, 3)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    player_marks: [u32; MnkGame::MAX_SIZE],
+    opponent_marks: [u32; MnkGame::MAX_SIZE],
+}
+
+impl MnkGame {
+    pub const MAX_SIZE: usize = 19;
+
+    pub fn with_config(mut self, config: MnkConfig) -> Self {
+        self.config = config;
+
+        self.player_marks = [0; Self::MAX_SIZE];
+        self.opponent_marks = [0; Self::MAX_SIZE];
+
+        self
+    }
+
+    fn width(&self) -> usize {
+        self.config.width as usize
+    }
+
+    fn height(&self) -> usize {
+        self.config.height as usize
+    }
+
+    fn row_mask(&self) -> u32 {
+        (1u32 << self.width()) - 1
+    }
+
+    fn flip_perspective(&mut self) {
+        swap(&mut self.player_marks, &mut self.opponent_marks);
+    }
+
+    // NOTE - Each direction ANDs together `win_length` copies of the board, each shifted
+    // one step further along the line, so any bit left standing marks the start of a line.
+
+    fn has_line(&self, marks: &[u32; Self::MAX_SIZE]) -> bool {
+        let length = self.config.win_length as usize;
+        let row_mask = self.row_mask();
+
+        let rows = &marks[..self.height()];
+
+        let horizontal = rows
+            .iter()
+            .any(|&row| (0..length).fold(row_mask, |line, step| line & (row >> step)) != 0);
+
+        if horizontal {
+            return true;
+        }
+
+        rows.windows(length).any(|rows| {
+            let mut vertical = row_mask;
+            let mut diagonal = row_mask;
+            let mut anti_diagonal = row_mask;
+
+            for (step, &row) in rows.iter().enumerate() {
+                vertical &= row;
+                diagonal &= row >> step;
+                anti_diagonal &= (row << step) & row_mask;
+            }
+
+            vertical | diagonal | anti_diagonal != 0
+        })
+    }
+
+    fn line_outcome(&self, outcome: Outcome) -> Outcome {
+        if self.config.misere {
+            outcome.flip()
+        } else {
+            outcome
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        let row_mask = self.row_mask();
+
+        (0..self.height()).all(|row| self.player_marks[row] | self.opponent_marks[row] == row_mask)
+    }
+
+    fn symmetry_count(&self) -> u8 {
+        if self.width() == self.height() { 8 } else { 4 }
+    }
+
+    // NOTE - Rectangular boards keep only the reflections and the half turn, which come
+    // first so that the quarter turns and diagonal reflections of square boards follow.
+
+    fn transform_position(&self, row: usize, col: usize, symmetry: u8) -> (usize, usize) {
+        let last_row = self.height() - 1;
+        let last_col = self.width() - 1;
+
+        match symmetry {
+            0 => (row, col),
+            1 => (last_row - row, last_col - col),
+            2 => (row, last_col - col),
+            3 => (last_row - row, col),
+            4 => (col, last_row - row),
+            5 => (last_col - col, row),
+            6 => (col, row),
+            7 => (last_col - col, last_row - row),
+            _ => unreachable!(),
+        }
+    }
+
+    fn transform_rows(&self, rows: &[u32; Self::MAX_SIZE], symmetry: u8) -> [u32; Self::MAX_SIZE] {
+        let mut result = [0u32; Self::MAX_SIZE];
+
+        for (row_old, bits) in rows.iter().enumerate().take(self.height()) {
+            for col_old in 0..self.width() {
+                if (bits >> col_old) & 1 == 1 {
+                    let (row_new, col_new) = self.transform_position(row_old, col_old, symmetry);
+
+                    result[row_new] |= 1u32 << col_new;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl Game for MnkGame {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Checkpoint;
+    type Config = MnkConfig;
+
+    fn new_with_config(config: MnkConfig) -> Self {
+        MnkGame {
+            phase: Phase::Place,
+
+            player_marks: [0; Self::MAX_SIZE],
+            opponent_marks: [0; Self::MAX_SIZE],
+
+            config,
+        }
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        let mut actions = ActionList::new();
+
+        if self.outcome() != Outcome::InProgress {
+            return actions;
+        }
+
+        for row in 0..self.height() {
+            let empty = !(self.player_marks[row] | self.opponent_marks[row]) & self.row_mask();
+
+            for col in 0..self.width() {
+                if (empty >> col) & 1 == 1 {
+                    actions.push(Action::Place {
+                        row: u8::try_from(row).unwrap(),
+                        col: u8::try_from(col).unwrap(),
+                    });
+                }
+            }
+        }
+
+        actions
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Place { row, col } => {
+                self.player_marks[row as usize] |= 1u32 << col;
+            }
+        }
+
+        true
+    }
+
+    fn end_turn(&mut self) {
+        self.flip_perspective();
+    }
+
+    fn outcome(&self) -> Outcome {
+        if self.has_line(&self.opponent_marks) {
+            return self.line_outcome(Outcome::Loss);
+        }
+
+        if self.has_line(&self.player_marks) {
+            return self.line_outcome(Outcome::Win);
+        }
+
+        if self.is_full() {
+            return Outcome::Draw;
+        }
+
+        Outcome::InProgress
+    }
+
+    fn hash(&self) -> Option<u64> {
+        Some(hash_position(self))
+    }
+
+    fn create_checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            player_marks: self.player_marks,
+            opponent_marks: self.opponent_marks,
+        }
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Checkpoint) {
+        self.player_marks = checkpoint.player_marks;
+        self.opponent_marks = checkpoint.opponent_marks;
+    }
+
+    fn symmetries(&self) -> u8 {
+        self.symmetry_count()
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        debug_assert!(symmetry < self.symmetry_count());
+
+        let mut game = self.clone();
+
+        game.player_marks = self.transform_rows(&self.player_marks, symmetry);
+        game.opponent_marks = self.transform_rows(&self.opponent_marks, symmetry);
+
+        game
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        debug_assert!(symmetry < self.symmetry_count());
+
+        match action {
+            Action::Place { row, col } => {
+                let (row, col) = self.transform_position(row as usize, col as usize, symmetry);
+
+                Action::Place {
+                    row: u8::try_from(row).unwrap(),
+                    col: u8::try_from(col).unwrap(),
+                }
+            }
+        }
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        let mut game = self.clone();
+
+        if turn == Turn::Player2 {
+            game.flip_perspective();
+        }
+
+        format!("{game}")
+    }
+
+    fn to_notation(&self) -> String {
+        let board = format_rows((0..self.height()).map(|row| {
+            (0..self.width()).map(move |col| {
+                let mask = 1u32 << col;
+
+                if self.player_marks[row] & mask != 0 {
+                    Some('X')
+                } else if self.opponent_marks[row] & mask != 0 {
+                    Some('O')
+                } else {
+                    None
+                }
+            })
+        }));
+
+        let win_length = self.config.win_length;
+
+        if self.config.misere {
+            format!("{board} {win_length} misere")
+        } else {
+            format!("{board} {win_length}")
+        }
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let mut fields = notation.split_whitespace();
+
+        let rows = parse_rows(fields.next().unwrap_or_default())?;
+
+        let width = rows[0].len();
+        let height = rows.len();
+
+        if !(1..=Self::MAX_SIZE).contains(&width) || !(1..=Self::MAX_SIZE).contains(&height) {
+            return Err(format!("invalid board size: {width}x{height}"));
+        }
+
+        if let Some(row) = rows.iter().position(|cells| cells.len() != width) {
+            return Err(format!("malformed board row {}", row + 1));
+        }
+
+        let win_length = fields.next().unwrap_or_default();
+
+        let win_length = win_length
+            .parse::<u8>()
+            .ok()
+            .filter(|&value| (1..=width.max(height)).contains(&(value as usize)))
+            .ok_or_else(|| format!("invalid win length: {win_length}"))?;
+
+        let misere = match fields.next() {
+            None => false,
+            Some("misere") => true,
+            Some(field) => return Err(format!("unexpected field: {field}")),
+        };
+
+        if let Some(field) = fields.next() {
+            return Err(format!("unexpected field: {field}"));
+        }
+
+        let config = MnkConfig::new(
+            u8::try_from(width).unwrap(),
+            u8::try_from(height).unwrap(),
+            win_length,
+        )
+        .with_misere(misere);
+
+        let mut game = Self::new().with_config(config);
+
+        for (row, cells) in rows.into_iter().enumerate() {
+            for (col, cell) in cells.into_iter().enumerate() {
+                match cell {
+                    None => {}
+                    Some('X') => game.player_marks[row] |= 1u32 << col,
+                    Some('O') => game.opponent_marks[row] |= 1u32 << col,
+                    Some(character) => {
+                        return Err(format!("invalid character at row {}: {character}", row + 1));
+                    }
+                }
+            }
+        }
+
+        Ok(game)
+    }
+}
+
+impl fmt::Display for MnkGame {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // NOTE - Board
+
+        write!(formatter, "  ")?;
+
+        for col in 0..self.width() {
+            write!(
+                formatter,
+                " {}",
+                char::from(b'a' + u8::try_from(col).unwrap())
+            )?;
+        }
+
+        writeln!(formatter)?;
+
+        for row in 0..self.height() {
+            write!(formatter, "{:>2}", row + 1)?;
+
+            for col in 0..self.width() {
+                let mask = 1u32 << col;
+
+                let character = if self.player_marks[row] & mask != 0 {
+                    'X'
+                } else if self.opponent_marks[row] & mask != 0 {
+                    'O'
+                } else {
+                    '.'
+                };
+
+                write!(formatter, " {character}")?;
+            }
+
+            writeln!(formatter)?;
+        }
+
+        // NOTE - Win length
+
+        writeln!(formatter, "Win length: {}", self.config.win_length)?;
+
+        if self.config.misere {
+            writeln!(formatter, "Misère")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl str::FromStr for MnkGame {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines: Vec<&str> = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        let misere = lines.last() == Some(&"Misère");
+
+        if misere {
+            lines.pop();
+        }
+
+        let [header, rows @ .., win_length] = &lines[..] else {
+            return Err(format!("expected at least 3 lines, found {}", lines.len()));
+        };
+
+        let width = header.split_whitespace().count();
+        let height = rows.len();
+
+        if !(1..=Self::MAX_SIZE).contains(&width) || !(1..=Self::MAX_SIZE).contains(&height) {
+            return Err(format!("invalid board size: {width}x{height}"));
+        }
+
+        let win_length = win_length
+            .strip_prefix("Win length:")
+            .and_then(|value| value.trim().parse::<u8>().ok())
+            .filter(|&value| (1..=width.max(height)).contains(&(value as usize)))
+            .ok_or_else(|| format!("invalid win length: {win_length}"))?;
+
+        let config = MnkConfig::new(
+            u8::try_from(width).unwrap(),
+            u8::try_from(height).unwrap(),
+            win_length,
+        )
+        .with_misere(misere);
+
+        let mut game = Self::new().with_config(config);
+
+        // NOTE - Board
+
+        for (row, line) in rows.iter().enumerate() {
+            let cells: Vec<&str> = line.split_whitespace().skip(1).collect();
+
+            if cells.len() != width {
+                return Err(format!("malformed board row {}", row + 1));
+            }
+
+            for (col, cell) in cells.into_iter().enumerate() {
+                match cell {
+                    "." => {}
+                    "X" => game.player_marks[row] |= 1u32 << col,
+                    "O" => game.opponent_marks[row] |= 1u32 << col,
+                    _ => return Err(format!("invalid character at row {}: {cell}", row + 1)),
+                }
+            }
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::seq::IndexedRandom;
+
+    use crate::core::{DefaultRngFactory, RngFactory};
+
+    fn parse_game(value: &str) -> MnkGame {
+        value.parse().expect("unable to parse game")
+    }
+
+    mod get_possible_actions {
+        use super::*;
+
+        #[test]
+        fn should_list_every_cell_of_the_configured_board() {
+            let game = MnkGame::new().with_config(MnkConfig::new(7, 4, 4));
+
+            let actions = game.get_possible_actions();
+
+            assert_eq!(actions.len(), 28);
+            assert!(actions.contains(&Action::Place { row: 3, col: 6 }));
+        }
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        fn should_detect_lines_of_the_configured_length() {
+            let game = parse_game(
+                "
+                       a b c d e
+                     1 . . . . .
+                     2 X X X . .
+                     3 . O O . .
+                     4 . . . . .
+                    Win length: 4
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::InProgress);
+
+            let mut game = game;
+            game.apply_action(Action::Place { row: 1, col: 3 });
+
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+
+        #[test]
+        fn should_detect_diagonals_on_rectangular_boards() {
+            let game = parse_game(
+                "
+                       a b c d e f
+                     1 . . . O . .
+                     2 . . O . . .
+                     3 . O . . . .
+                    Win length: 3
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+        }
+
+        #[test]
+        fn should_not_wrap_lines_around_the_edge() {
+            let game = parse_game(
+                "
+                       a b c d
+                     1 . . X X
+                     2 X X . .
+                     3 . . . .
+                    Win length: 4
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::InProgress);
+        }
+
+        #[test]
+        fn should_lose_by_completing_a_line_under_misere_rules() {
+            let mut game = parse_game(
+                "
+                       a b c d
+                     1 X X X .
+                     2 . O O .
+                     3 . . . .
+                    Win length: 3
+                    Misère
+                ",
+            );
+
+            assert_eq!(game.config, MnkConfig::new(4, 3, 3).with_misere(true));
+            assert_eq!(game.outcome(), Outcome::Loss);
+
+            game.end_turn();
+
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+
+        #[test]
+        fn should_always_finish() {
+            let mut rng = DefaultRngFactory::default().create_seeded(7);
+
+            for config in [
+                MnkConfig::default(),
+                MnkConfig::new(7, 6, 4),
+                MnkConfig::new(19, 19, 5),
+            ] {
+                let mut game = MnkGame::new().with_config(config);
+
+                while game.outcome() == Outcome::InProgress {
+                    let action = *game
+                        .get_possible_actions()
+                        .choose(&mut rng)
+                        .expect("unfinished game has no actions");
+
+                    game.apply_action(action);
+
+                    if game.outcome() == Outcome::InProgress {
+                        game.end_turn();
+                    }
+                }
+
+                assert_ne!(game.outcome(), Outcome::Loss);
+            }
+        }
+    }
+
+    mod transform {
+        use super::*;
+
+        #[test]
+        fn should_keep_transformed_actions_in_sync_with_board() {
+            for config in [MnkConfig::new(5, 5, 4), MnkConfig::new(6, 4, 3)] {
+                let mut game = MnkGame::new().with_config(config);
+
+                game.apply_action(Action::Place { row: 0, col: 1 });
+                game.end_turn();
+                game.apply_action(Action::Place { row: 3, col: 2 });
+
+                for symmetry in 0..game.symmetries() {
+                    let mut expected_game = game.transform(symmetry);
+                    let mut actual_game = game.clone();
+
+                    let action = Action::Place { row: 2, col: 3 };
+
+                    actual_game.apply_action(action);
+                    expected_game.apply_action(game.transform_action(action, symmetry));
+
+                    assert_eq!(actual_game.transform(symmetry), expected_game);
+                }
+            }
+        }
+
+        #[test]
+        fn should_only_reflect_rectangular_boards() {
+            let game = MnkGame::new().with_config(MnkConfig::new(6, 4, 3));
+
+            assert_eq!(game.symmetries(), 4);
+            assert_eq!(MnkGame::new().symmetries(), 8);
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = MnkGame::new().with_config(MnkConfig::new(8, 5, 4).with_misere(true));
+
+            for (row, col) in [(2, 3), (0, 0), (4, 7), (1, 6)] {
+                game.apply_action(Action::Place { row, col });
+                game.end_turn();
+            }
+
+            let parsed =
+                MnkGame::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = MnkGame::new().with_config(MnkConfig::new(8, 5, 4).with_misere(true));
+
+            for (row, col) in [(2, 3), (0, 0), (4, 7), (1, 6)] {
+                game.apply_action(Action::Place { row, col });
+                game.end_turn();
+            }
+
+            let parsed: MnkGame = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+    }
+}
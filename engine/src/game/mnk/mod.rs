@@ -0,0 +1,12 @@
+mod action;
+mod action_encoder;
+#[allow(clippy::module_inception)]
+mod mnk;
+mod portable;
+mod renderable;
+mod state_encoder;
+
+pub use action::Action as MnkAction;
+pub use action_encoder::MnkActionEncoder;
+pub use mnk::{MnkConfig, MnkGame, Phase as MnkPhase};
+pub use state_encoder::MnkStateEncoder;
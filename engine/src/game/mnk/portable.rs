@@ -0,0 +1,38 @@
+use crate::game::mnk::MnkGame;
+use crate::game::mnk::action::Action;
+use crate::record::PortableGame;
+
+impl PortableGame for MnkGame {
+    const NAME: &'static str = "mnk";
+
+    // NOTE - Records carry the largest board, since the configured size is only known
+    // once a game exists. Points are written column then row, as in SGF.
+
+    fn board_size() -> usize {
+        Self::MAX_SIZE
+    }
+
+    fn format_action(action: Action) -> String {
+        match action {
+            Action::Place { row, col } => [col, row]
+                .iter()
+                .map(|&coordinate| char::from(b'a' + coordinate))
+                .collect(),
+        }
+    }
+
+    fn parse_action(value: &str) -> Result<Action, String> {
+        let coordinates: Vec<usize> = value
+            .bytes()
+            .map(|byte| byte.wrapping_sub(b'a') as usize)
+            .collect();
+
+        match coordinates[..] {
+            [col, row] if col < Self::MAX_SIZE && row < Self::MAX_SIZE => Ok(Action::Place {
+                row: u8::try_from(row).map_err(|error| error.to_string())?,
+                col: u8::try_from(col).map_err(|error| error.to_string())?,
+            }),
+            _ => Err(format!("invalid point: {value}")),
+        }
+    }
+}
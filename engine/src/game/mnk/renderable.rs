@@ -0,0 +1,57 @@
+use crate::core::Turn;
+use crate::game::mnk::MnkGame;
+use crate::game::mnk::action::Action;
+use crate::render::{PieceShape, RenderableGame, RenderedPiece};
+
+impl RenderableGame for MnkGame {
+    fn grid_size(&self) -> (usize, usize) {
+        (self.config.height as usize, self.config.width as usize)
+    }
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece> {
+        let mask = 1u32 << col;
+
+        let owner = if self.player_marks[row] & mask != 0 {
+            turn
+        } else if self.opponent_marks[row] & mask != 0 {
+            turn.advance()
+        } else {
+            return None;
+        };
+
+        Some(RenderedPiece {
+            owner,
+            shape: PieceShape::Mark,
+        })
+    }
+
+    fn action_squares(&self, action: Action) -> Vec<(usize, usize)> {
+        match action {
+            Action::Place { row, col } => vec![(row as usize, col as usize)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::game::mnk::MnkConfig;
+    use crate::render::weighted_squares;
+
+    mod square_weights {
+        use super::*;
+
+        #[test]
+        fn should_map_actions_onto_their_squares() {
+            let game = MnkGame::new_with_config(MnkConfig::new(4, 3, 3));
+
+            assert_eq!(game.grid_size(), (3, 4));
+            assert_eq!(
+                weighted_squares(&game, &[(Action::Place { row: 2, col: 3 }, 0.5)],),
+                vec![(11, 0.5)]
+            );
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::game::mnk::mnk::{MnkConfig, MnkGame};
+use crate::neural_network::StateEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct MnkStateEncoder {
+    config: MnkConfig,
+}
+
+impl MnkStateEncoder {
+    const PLANE_COUNT: usize = 2;
+
+    pub fn new(config: MnkConfig) -> Self {
+        MnkStateEncoder { config }
+    }
+
+    fn plane_size(self) -> usize {
+        self.config.width as usize * self.config.height as usize
+    }
+
+    fn plane_slice(planes: &mut [f32], plane_size: usize, plane_index: usize) -> &mut [f32] {
+        let start = plane_index * plane_size;
+        let end = start + plane_size;
+
+        &mut planes[start..end]
+    }
+
+    fn rows_to_plane(self, rows: &[u32; MnkGame::MAX_SIZE], plane: &mut [f32]) {
+        let width = self.config.width as usize;
+
+        for (row, bits) in rows.iter().take(self.config.height as usize).enumerate() {
+            for col in 0..width {
+                plane[row * width + col] = ((bits >> col) & 1) as f32;
+            }
+        }
+    }
+}
+
+impl StateEncoder<MnkGame> for MnkStateEncoder {
+    fn shape(&self) -> Vec<usize> {
+        vec![
+            1,
+            Self::PLANE_COUNT,
+            self.config.height as usize,
+            self.config.width as usize,
+        ]
+    }
+
+    fn encode(&self, state: &MnkGame) -> Vec<f32> {
+        debug_assert!(state.config == self.config);
+
+        let plane_size = self.plane_size();
+        let plane_count = Self::PLANE_COUNT;
+
+        let mut planes = vec![0.0; plane_size * plane_count];
+
+        self.rows_to_plane(
+            &state.player_marks,
+            Self::plane_slice(&mut planes, plane_size, 0),
+        );
+        self.rows_to_plane(
+            &state.opponent_marks,
+            Self::plane_slice(&mut planes, plane_size, 1),
+        );
+
+        planes
+    }
+}
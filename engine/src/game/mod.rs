@@ -1,2 +1,13 @@
+pub mod backgammon;
 pub mod boop;
+pub mod breakthrough;
+pub mod checkers;
+pub mod chess;
+pub mod go;
+pub mod gomoku;
+pub mod hex;
+pub mod hive;
+pub mod mnk;
+pub mod nim;
+pub mod tak;
 pub mod tic_tac_toe;
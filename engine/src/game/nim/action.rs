@@ -0,0 +1,14 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action {
+    Take { heap: u8, count: u8 },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Take { heap, count } => write!(f, "takes {count} from heap {}.", heap + 1),
+        }
+    }
+}
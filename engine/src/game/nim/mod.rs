@@ -0,0 +1,6 @@
+mod action;
+#[allow(clippy::module_inception)]
+mod nim;
+
+pub use action::Action as NimAction;
+pub use nim::{Nim, NimConfig, Phase as NimPhase};
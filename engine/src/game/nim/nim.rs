@@ -0,0 +1,488 @@
+use std::{fmt, str};
+
+use crate::core::{ActionList, Game, Outcome, Turn};
+use crate::game::nim::action::Action;
+
+// NOTE - Normal play Nim, where the player who takes the last object wins. Capping the
+// number of objects taken per move turns it into a subtraction game. Both are solved, so
+// the winning moves are known for every position, which makes them useful for checking
+// the search players.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Nim {
+    pub phase: Phase,
+
+    pub heaps: [u8; Nim::MAX_HEAPS],
+    pub heap_count: u8,
+
+    pub max_take: Option<u8>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Take,
+    Finished,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NimConfig {
+    pub heaps: Vec<u8>,
+    pub max_take: Option<u8>,
+}
+
+impl Default for NimConfig {
+    fn default() -> Self {
+        NimConfig {
+            heaps: Nim::DEFAULT_HEAPS.to_vec(),
+            max_take: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    phase: Phase,
+
+    heaps: [u8; Nim::MAX_HEAPS],
+}
+
+impl Nim {
+    pub const MAX_HEAPS: usize = 8;
+
+    const DEFAULT_HEAPS: [u8; 3] = [3, 4, 5];
+
+    const SYMMETRY_COUNT: u8 = 1;
+
+    pub fn with_heaps(mut self, heaps: &[u8]) -> Self {
+        assert!(
+            (1..=Self::MAX_HEAPS).contains(&heaps.len()),
+            "expected 1 to {} heaps, found {}",
+            Self::MAX_HEAPS,
+            heaps.len()
+        );
+
+        self.heaps = [0; Self::MAX_HEAPS];
+        self.heaps[..heaps.len()].copy_from_slice(heaps);
+        self.heap_count = u8::try_from(heaps.len()).unwrap();
+
+        self
+    }
+
+    pub fn with_max_take(mut self, max_take: u8) -> Self {
+        assert!(max_take > 0, "max take must be positive");
+
+        self.max_take = Some(max_take);
+
+        self
+    }
+
+    pub fn heaps(&self) -> &[u8] {
+        &self.heaps[..self.heap_count as usize]
+    }
+
+    // NOTE - XOR of each heap's Grundy value, which is the heap size itself in Nim and the
+    // size modulo `max_take + 1` in the subtraction game. The side to move loses with
+    // perfect play exactly when it is zero.
+
+    pub fn nim_sum(&self) -> u8 {
+        self.heaps()
+            .iter()
+            .map(|&heap| match self.max_take {
+                Some(max_take) => heap % (max_take + 1),
+                None => heap,
+            })
+            .fold(0, |sum, grundy| sum ^ grundy)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.heaps().iter().all(|&heap| heap == 0)
+    }
+}
+
+impl Game for Nim {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Checkpoint;
+    type Config = NimConfig;
+
+    fn new_with_config(config: NimConfig) -> Self {
+        let game = Nim {
+            phase: Phase::Take,
+
+            heaps: [0; Self::MAX_HEAPS],
+            heap_count: 0,
+
+            max_take: None,
+        }
+        .with_heaps(&config.heaps);
+
+        match config.max_take {
+            Some(max_take) => game.with_max_take(max_take),
+            None => game,
+        }
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        let mut actions = ActionList::new();
+
+        if self.phase != Phase::Take {
+            return actions;
+        }
+
+        for (heap, &size) in self.heaps().iter().enumerate() {
+            let max_count = self.max_take.map_or(size, |max_take| size.min(max_take));
+
+            for count in 1..=max_count {
+                actions.push(Action::Take {
+                    heap: u8::try_from(heap).unwrap(),
+                    count,
+                });
+            }
+        }
+
+        actions
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Take { heap, count } => self.heaps[heap as usize] -= count,
+        }
+
+        self.phase = Phase::Finished;
+
+        true
+    }
+
+    fn end_turn(&mut self) {
+        self.phase = Phase::Take;
+    }
+
+    // NOTE - Taking the last object wins, so an empty table is a loss for whoever is left
+    // to move and a win for whoever just moved.
+
+    fn outcome(&self) -> Outcome {
+        if !self.is_empty() {
+            return Outcome::InProgress;
+        }
+
+        match self.phase {
+            Phase::Take => Outcome::Loss,
+            Phase::Finished => Outcome::Win,
+        }
+    }
+
+    fn create_checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            phase: self.phase,
+
+            heaps: self.heaps,
+        }
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Checkpoint) {
+        self.phase = checkpoint.phase;
+
+        self.heaps = checkpoint.heaps;
+    }
+
+    // NOTE - Permuting heaps preserves the game, but actions are tied to heap positions and
+    // the search players gain little from it, so only the identity is offered.
+
+    fn symmetries(&self) -> u8 {
+        Self::SYMMETRY_COUNT
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        debug_assert!(symmetry < Self::SYMMETRY_COUNT);
+
+        self.clone()
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        debug_assert!(symmetry < Self::SYMMETRY_COUNT);
+
+        action
+    }
+
+    fn display(&self, _turn: Turn) -> String {
+        format!("{self}")
+    }
+
+    fn to_notation(&self) -> String {
+        let heaps: Vec<String> = self.heaps().iter().map(u8::to_string).collect();
+
+        let max_take = self
+            .max_take
+            .map_or_else(|| "-".to_string(), |max_take| max_take.to_string());
+
+        let phase = match self.phase {
+            Phase::Take => "take",
+            Phase::Finished => "finished",
+        };
+
+        format!("{} {max_take} {phase}", heaps.join(","))
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = notation.split_whitespace().collect();
+
+        if fields.len() != 3 {
+            return Err(format!("expected 3 fields, found {}", fields.len()));
+        }
+
+        let heaps = fields[0]
+            .split(',')
+            .map(str::parse::<u8>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| format!("invalid heaps: {}", fields[0]))?;
+
+        if !(1..=Self::MAX_HEAPS).contains(&heaps.len()) {
+            return Err(format!(
+                "expected 1 to {} heaps, found {}",
+                Self::MAX_HEAPS,
+                heaps.len()
+            ));
+        }
+
+        let mut game = Nim::new().with_heaps(&heaps);
+
+        game.max_take = match fields[1] {
+            "-" => None,
+            field => match field.parse() {
+                Ok(0) | Err(_) => return Err(format!("invalid max take: {field}")),
+                Ok(max_take) => Some(max_take),
+            },
+        };
+
+        game.phase = match fields[2] {
+            "take" => Phase::Take,
+            "finished" => Phase::Finished,
+            field => return Err(format!("invalid phase: {field}")),
+        };
+
+        Ok(game)
+    }
+}
+
+impl fmt::Display for Nim {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let heaps: Vec<String> = self.heaps().iter().map(u8::to_string).collect();
+
+        writeln!(formatter, "Heaps: {}", heaps.join(" "))?;
+
+        if let Some(max_take) = self.max_take {
+            writeln!(formatter, "Max take: {max_take}")?;
+        }
+
+        if self.phase == Phase::Finished {
+            writeln!(formatter, "Phase: Finished")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl str::FromStr for Nim {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut game = Nim::new();
+
+        let mut heaps = None;
+
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if let Some(value) = line.strip_prefix("Heaps:") {
+                heaps = Some(
+                    value
+                        .split_whitespace()
+                        .map(str::parse::<u8>)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|_| format!("invalid heaps: {value}"))?,
+                );
+            } else if let Some(value) = line.strip_prefix("Max take:") {
+                game.max_take = match value.trim().parse() {
+                    Ok(0) | Err(_) => return Err(format!("invalid max take: {value}")),
+                    Ok(max_take) => Some(max_take),
+                };
+            } else if line == "Phase: Finished" {
+                game.phase = Phase::Finished;
+            } else {
+                return Err(format!("unexpected line: {line}"));
+            }
+        }
+
+        let heaps = heaps.ok_or("missing heaps")?;
+
+        if !(1..=Self::MAX_HEAPS).contains(&heaps.len()) {
+            return Err(format!(
+                "expected 1 to {} heaps, found {}",
+                Self::MAX_HEAPS,
+                heaps.len()
+            ));
+        }
+
+        Ok(game.with_heaps(&heaps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Player;
+    use crate::player::{ClassicMctsPlayer, MinimaxPlayer, TemperatureSchedule};
+
+    fn take(heap: u8, count: u8) -> Action {
+        Action::Take { heap, count }
+    }
+
+    mod get_possible_actions {
+        use super::*;
+
+        #[test]
+        fn should_take_any_amount_from_any_heap() {
+            let game = Nim::new().with_heaps(&[1, 0, 2]);
+
+            assert_eq!(
+                game.get_possible_actions().as_slice(),
+                &[take(0, 1), take(2, 1), take(2, 2)]
+            );
+        }
+
+        #[test]
+        fn should_cap_takes_in_subtraction_games() {
+            let game = Nim::new().with_heaps(&[5]).with_max_take(2);
+
+            assert_eq!(
+                game.get_possible_actions().as_slice(),
+                &[take(0, 1), take(0, 2)]
+            );
+        }
+    }
+
+    mod new_with_config {
+        use super::*;
+
+        #[test]
+        fn should_match_the_builders() {
+            let game = Nim::new_with_config(NimConfig {
+                heaps: vec![1, 6],
+                max_take: Some(2),
+            });
+
+            assert_eq!(game, Nim::new().with_heaps(&[1, 6]).with_max_take(2));
+            assert_eq!(Nim::new_with_config(NimConfig::default()), Nim::new());
+        }
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        fn should_win_by_taking_the_last_object() {
+            let mut game = Nim::new().with_heaps(&[0, 2]);
+
+            game.apply_action(take(1, 2));
+
+            assert_eq!(game.outcome(), Outcome::Win);
+
+            game.end_turn();
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+            assert!(game.get_possible_actions().is_empty());
+        }
+    }
+
+    mod nim_sum {
+        use super::*;
+
+        #[test]
+        fn should_xor_heap_sizes() {
+            assert_eq!(Nim::new().with_heaps(&[1, 2, 3]).nim_sum(), 0);
+            assert_eq!(Nim::new().nim_sum(), 2);
+        }
+
+        #[test]
+        fn should_reduce_heaps_in_subtraction_games() {
+            let game = Nim::new().with_heaps(&[4, 5]).with_max_take(3);
+
+            assert_eq!(game.nim_sum(), 1);
+        }
+    }
+
+    mod search {
+        use super::*;
+
+        fn assert_wins_with(game: &Nim, action: Action) {
+            let mut after = game.clone();
+
+            after.apply_action(action);
+            after.end_turn();
+
+            assert_eq!(after.nim_sum(), 0, "{action} leaves a winning position");
+        }
+
+        #[test]
+        fn should_find_the_winning_move_with_minimax() {
+            let game = Nim::new().with_heaps(&[1, 2, 4]);
+
+            let choice = MinimaxPlayer::new(7).choose_action(&game, 0);
+
+            assert_eq!(choice.action, take(2, 1));
+            assert_wins_with(&game, choice.action);
+        }
+
+        #[test]
+        fn should_find_the_winning_move_with_mcts() {
+            let game = Nim::new().with_heaps(&[1, 2, 4]);
+
+            let mut player = ClassicMctsPlayer::new(2000)
+                .with_seed(0)
+                .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+            let choice = player.choose_action(&game, 0);
+
+            assert_wins_with(&game, choice.action);
+        }
+
+        #[test]
+        fn should_find_the_winning_move_in_subtraction_games() {
+            let game = Nim::new().with_heaps(&[6]).with_max_take(3);
+
+            let choice = MinimaxPlayer::new(6).choose_action(&game, 0);
+
+            assert_eq!(choice.action, take(0, 2));
+            assert_wins_with(&game, choice.action);
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = Nim::new().with_heaps(&[2, 0, 7]).with_max_take(3);
+
+            game.phase = Phase::Finished;
+
+            let parsed = Nim::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = Nim::new().with_heaps(&[2, 0, 7]).with_max_take(3);
+
+            game.phase = Phase::Finished;
+
+            let parsed: Nim = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+    }
+}
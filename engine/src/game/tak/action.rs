@@ -0,0 +1,59 @@
+use std::fmt;
+
+use crate::game::tak::tak::{Direction, Stone, Tak};
+
+// NOTE - A move's `drops` word has one bit per carried piece, from the bottom of the
+// carried stack up, set when that piece is the last one dropped on its square. Its length
+// is the number of pieces carried and its count of set bits the number of squares crossed.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action {
+    Place {
+        square: u8,
+        stone: Stone,
+    },
+    Move {
+        from: u8,
+        direction: Direction,
+        drops: u8,
+    },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Place { square, stone } => {
+                let stone = match stone {
+                    Stone::Flat => "a flat stone",
+                    Stone::Standing => "a standing stone",
+                    Stone::Capstone => "a capstone",
+                };
+
+                write!(f, "places {stone} at {}.", Tak::square_name(*square))
+            }
+            Action::Move {
+                from,
+                direction,
+                drops,
+            } => {
+                let counts: Vec<String> =
+                    Tak::drop_counts(*drops).iter().map(u8::to_string).collect();
+
+                let direction = match direction {
+                    Direction::Up => "up",
+                    Direction::Down => "down",
+                    Direction::Left => "left",
+                    Direction::Right => "right",
+                };
+
+                write!(
+                    f,
+                    "moves {} from {} {direction}, dropping {}.",
+                    Tak::carried(*drops),
+                    Tak::square_name(*from),
+                    counts.join(" ")
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::game::tak::action::Action;
+use crate::game::tak::tak::{Direction, Stone, Tak};
+use crate::neural_network::ActionEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct TakActionEncoder;
+
+// NOTE - Placements come first, three stones per square. Moves follow, identified by the
+// square they start from, their direction and their `drops` word, which is one of 31
+// values for a carry limit of five.
+
+impl TakActionEncoder {
+    const STONES: [Stone; 3] = [Stone::Flat, Stone::Standing, Stone::Capstone];
+    const DIRECTIONS: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    const PLACE_COUNT: usize = Tak::SQUARE_COUNT * Self::STONES.len();
+    const DROPS_COUNT: usize = (1 << Tak::CARRY_LIMIT) - 1;
+
+    pub fn new() -> Self {
+        TakActionEncoder
+    }
+}
+
+impl ActionEncoder<Tak> for TakActionEncoder {
+    const ACTION_COUNT: usize =
+        Self::PLACE_COUNT + Tak::SQUARE_COUNT * Self::DIRECTIONS.len() * Self::DROPS_COUNT;
+
+    fn encode(&self, action: &Action) -> usize {
+        match *action {
+            Action::Place { square, stone } => {
+                square as usize * Self::STONES.len() + stone as usize
+            }
+            Action::Move {
+                from,
+                direction,
+                drops,
+            } => {
+                Self::PLACE_COUNT
+                    + (from as usize * Self::DIRECTIONS.len() + direction as usize)
+                        * Self::DROPS_COUNT
+                    + (drops as usize - 1)
+            }
+        }
+    }
+
+    fn decode(&self, action_id: usize) -> Action {
+        debug_assert!(action_id < Self::ACTION_COUNT);
+
+        if action_id < Self::PLACE_COUNT {
+            return Action::Place {
+                square: u8::try_from(action_id / Self::STONES.len()).unwrap(),
+                stone: Self::STONES[action_id % Self::STONES.len()],
+            };
+        }
+
+        let move_id = action_id - Self::PLACE_COUNT;
+
+        let drops = move_id % Self::DROPS_COUNT + 1;
+        let path = move_id / Self::DROPS_COUNT;
+
+        Action::Move {
+            from: u8::try_from(path / Self::DIRECTIONS.len()).unwrap(),
+            direction: Self::DIRECTIONS[path % Self::DIRECTIONS.len()],
+            drops: u8::try_from(drops).unwrap(),
+        }
+    }
+}
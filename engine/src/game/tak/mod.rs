@@ -0,0 +1,15 @@
+mod action;
+mod action_encoder;
+mod portable;
+mod renderable;
+mod state_encoder;
+#[allow(clippy::module_inception)]
+mod tak;
+
+pub use action::Action as TakAction;
+pub use action_encoder::TakActionEncoder;
+pub use state_encoder::TakStateEncoder;
+pub use tak::{
+    Direction as TakDirection, Phase as TakPhase, Reserve as TakReserve, Stack as TakStack,
+    Stone as TakStone, Tak,
+};
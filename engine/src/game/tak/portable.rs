@@ -0,0 +1,159 @@
+use crate::game::tak::Tak;
+use crate::game::tak::action::Action;
+use crate::game::tak::tak::{Direction, Stone};
+use crate::record::PortableGame;
+
+// NOTE - Actions are written in PTN, except that rows are numbered from the top as on the
+// displayed board, so `+` moves towards row 1. Moves always carry their count and drops,
+// though both may be left out when parsing a single piece moved one square.
+
+impl PortableGame for Tak {
+    const NAME: &'static str = "tak";
+
+    fn board_size() -> usize {
+        Self::BOARD_SIZE
+    }
+
+    fn format_action(action: Action) -> String {
+        match action {
+            Action::Place { square, stone } => {
+                let prefix = match stone {
+                    Stone::Flat => "",
+                    Stone::Standing => "S",
+                    Stone::Capstone => "C",
+                };
+
+                format!("{prefix}{}", Self::square_name(square))
+            }
+            Action::Move {
+                from,
+                direction,
+                drops,
+            } => {
+                let direction = match direction {
+                    Direction::Up => '+',
+                    Direction::Down => '-',
+                    Direction::Left => '<',
+                    Direction::Right => '>',
+                };
+
+                let counts: String = Self::drop_counts(drops).iter().map(u8::to_string).collect();
+
+                format!(
+                    "{}{}{direction}{counts}",
+                    Self::carried(drops),
+                    Self::square_name(from)
+                )
+            }
+        }
+    }
+
+    fn parse_action(value: &str) -> Result<Action, String> {
+        let invalid = || format!("invalid action: {value}");
+
+        let Some(index) = value.find(['+', '-', '<', '>']) else {
+            let (stone, square) = match value.strip_prefix('S') {
+                Some(square) => (Stone::Standing, square),
+                None => match value.strip_prefix('C') {
+                    Some(square) => (Stone::Capstone, square),
+                    None => (Stone::Flat, value.strip_prefix('F').unwrap_or(value)),
+                },
+            };
+
+            let square = Self::parse_square(square).ok_or_else(invalid)?;
+
+            return Ok(Action::Place { square, stone });
+        };
+
+        let (origin, rest) = value.split_at(index);
+
+        let direction = match rest.as_bytes()[0] {
+            b'+' => Direction::Up,
+            b'-' => Direction::Down,
+            b'<' => Direction::Left,
+            _ => Direction::Right,
+        };
+
+        let (carried, square) = match origin.find(|c: char| c.is_ascii_lowercase()) {
+            Some(0) => (1, origin),
+            Some(split) => (
+                origin[..split].parse::<u8>().map_err(|_| invalid())?,
+                &origin[split..],
+            ),
+            None => return Err(invalid()),
+        };
+
+        let from = Self::parse_square(square).ok_or_else(invalid)?;
+
+        let counts: Vec<u8> = if rest.len() > 1 {
+            rest[1..]
+                .chars()
+                .map(|c| c.to_digit(10).and_then(|count| u8::try_from(count).ok()))
+                .collect::<Option<_>>()
+                .ok_or_else(invalid)?
+        } else {
+            vec![carried]
+        };
+
+        if counts.contains(&0)
+            || counts.iter().sum::<u8>() != carried
+            || carried > Self::CARRY_LIMIT
+        {
+            return Err(invalid());
+        }
+
+        let mut drops = 0;
+        let mut bit = 0;
+
+        for count in counts {
+            bit += count;
+            drops |= 1 << (bit - 1);
+        }
+
+        Ok(Action::Move {
+            from,
+            direction,
+            drops,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod parse_action {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_format_action() {
+            let actions = [
+                Action::Place {
+                    square: 7,
+                    stone: Stone::Standing,
+                },
+                Action::Move {
+                    from: 12,
+                    direction: Direction::Left,
+                    drops: 0b1010,
+                },
+            ];
+
+            for action in actions {
+                assert_eq!(Tak::parse_action(&Tak::format_action(action)), Ok(action));
+            }
+        }
+
+        #[test]
+        fn should_default_to_moving_one_piece() {
+            assert_eq!(
+                Tak::parse_action("c3+"),
+                Ok(Action::Move {
+                    from: 12,
+                    direction: Direction::Up,
+                    drops: 0b1,
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::core::Turn;
+use crate::game::tak::Tak;
+use crate::game::tak::action::Action;
+use crate::game::tak::tak::Stone;
+use crate::render::{PieceShape, RenderableGame, RenderedPiece};
+
+// NOTE - Only the top piece of each stack is drawn: a flat stone as a large disc, a
+// standing stone as a small one, and a capstone as a mark.
+
+impl RenderableGame for Tak {
+    fn grid_size(&self) -> (usize, usize) {
+        (Self::BOARD_SIZE, Self::BOARD_SIZE)
+    }
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece> {
+        let stack = &self.stacks[row * Self::BOARD_SIZE + col];
+
+        if stack.is_empty() {
+            return None;
+        }
+
+        let owner = if (stack.owners >> (stack.height - 1)) & 1 == 1 {
+            turn
+        } else {
+            turn.advance()
+        };
+
+        let shape = match stack.top {
+            Stone::Flat => PieceShape::Large,
+            Stone::Standing => PieceShape::Small,
+            Stone::Capstone => PieceShape::Mark,
+        };
+
+        Some(RenderedPiece { owner, shape })
+    }
+
+    fn action_squares(&self, action: Action) -> Vec<(usize, usize)> {
+        let squares = match action {
+            Action::Place { square, .. } => vec![square],
+            Action::Move {
+                from,
+                direction,
+                drops,
+            } => {
+                let mut squares = vec![from];
+
+                for _ in Self::drop_counts(drops) {
+                    let square = *squares.last().unwrap();
+
+                    squares.extend(Self::step(square, direction));
+                }
+
+                squares
+            }
+        };
+
+        squares
+            .into_iter()
+            .map(|square| {
+                (
+                    square as usize / Self::BOARD_SIZE,
+                    square as usize % Self::BOARD_SIZE,
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::game::tak::TakDirection as Direction;
+    use crate::render::weighted_squares;
+
+    mod square_weights {
+        use super::*;
+
+        #[test]
+        fn should_map_actions_onto_their_squares() {
+            let game = Tak::new();
+
+            assert_eq!(game.grid_size(), (5, 5));
+            assert_eq!(
+                weighted_squares(
+                    &game,
+                    &[(
+                        Action::Move {
+                            from: 0,
+                            direction: Direction::Right,
+                            drops: 0b11,
+                        },
+                        0.75,
+                    )],
+                ),
+                vec![(0, 0.25), (1, 0.25), (2, 0.25)]
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::game::tak::tak::{Reserve, Stone, Tak};
+use crate::neural_network::StateEncoder;
+
+#[derive(Clone, Copy, Default)]
+pub struct TakStateEncoder;
+
+// NOTE - Planes for the top piece by owner and stone, then for the owners of the pieces
+// just beneath it, then each side's reserve and whether the opening is still running.
+
+impl TakStateEncoder {
+    const BURIED_DEPTH: usize = 4;
+
+    const PLANE_COUNT: usize = 6 + 2 * Self::BURIED_DEPTH + 5;
+
+    pub fn new() -> Self {
+        TakStateEncoder
+    }
+
+    fn plane_slice(planes: &mut [f32], plane_index: usize) -> &mut [f32] {
+        let plane_size = Tak::SQUARE_COUNT;
+
+        let start = plane_index * plane_size;
+        let end = start + plane_size;
+
+        &mut planes[start..end]
+    }
+
+    fn fill_reserve(planes: &mut [f32], plane_index: usize, reserve: Reserve) {
+        Self::plane_slice(planes, plane_index).fill(f32::from(reserve.stones) / 21.0);
+        Self::plane_slice(planes, plane_index + 1).fill(f32::from(reserve.capstones));
+    }
+}
+
+impl StateEncoder<Tak> for TakStateEncoder {
+    fn shape(&self) -> Vec<usize> {
+        vec![1, Self::PLANE_COUNT, Tak::BOARD_SIZE, Tak::BOARD_SIZE]
+    }
+
+    fn encode(&self, state: &Tak) -> Vec<f32> {
+        let plane_size = Tak::SQUARE_COUNT;
+        let plane_count = Self::PLANE_COUNT;
+
+        let mut planes = vec![0.0; plane_size * plane_count];
+
+        for (square, stack) in state.stacks.iter().enumerate() {
+            if stack.is_empty() {
+                continue;
+            }
+
+            for depth in 0..=Self::BURIED_DEPTH.min(stack.height as usize - 1) {
+                let index = stack.height as usize - 1 - depth;
+                let is_player = (stack.owners >> index) & 1 == 1;
+
+                let plane_index = if depth == 0 {
+                    let stone = match stack.top {
+                        Stone::Flat => 0,
+                        Stone::Standing => 1,
+                        Stone::Capstone => 2,
+                    };
+
+                    stone + if is_player { 0 } else { 3 }
+                } else {
+                    6 + 2 * (depth - 1) + usize::from(!is_player)
+                };
+
+                Self::plane_slice(&mut planes, plane_index)[square] = 1.0;
+            }
+        }
+
+        let reserve_plane = 6 + 2 * Self::BURIED_DEPTH;
+
+        Self::fill_reserve(&mut planes, reserve_plane, state.player_reserve);
+        Self::fill_reserve(&mut planes, reserve_plane + 2, state.opponent_reserve);
+
+        if state.opening_plies > 0 {
+            Self::plane_slice(&mut planes, reserve_plane + 4).fill(1.0);
+        }
+
+        planes
+    }
+}
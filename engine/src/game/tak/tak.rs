@@ -0,0 +1,1142 @@
+use std::cmp::Ordering;
+use std::mem::swap;
+use std::{fmt, str};
+
+use crate::core::{ActionList, Game, Outcome, Turn};
+use crate::game::tak::action::Action;
+
+// NOTE - Tak on a 5x5 board, indexed row major from the top left. Each side has 21 stones
+// and a capstone. On their first turn, each side places one of the opponent's flat stones.
+// After that, a turn either places a flat stone, standing stone or capstone on an empty
+// square, or picks up to five pieces off a controlled stack and carries them in a straight
+// line, dropping at least one on each square. Nothing moves onto a standing stone or a
+// capstone, except that a capstone dropped alone flattens a standing stone.
+//
+// A road of flat stones and capstones joining opposite edges wins, and the mover wins if
+// both sides complete one. Otherwise the game ends when the board fills or either side
+// runs out of pieces, and whoever has more flat stones on top wins.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Tak {
+    pub phase: Phase,
+
+    pub stacks: [Stack; Tak::SQUARE_COUNT],
+
+    pub player_reserve: Reserve,
+    pub opponent_reserve: Reserve,
+
+    pub opening_plies: u8,
+}
+
+// NOTE - The player has just moved in `Finished`, so a double road goes to them.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Move,
+    Finished,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Stone {
+    Flat,
+    Standing,
+    Capstone,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+// NOTE - Bit `i` of `owners` is set when the `i`th piece from the bottom is the player's.
+// Only the top piece can be standing or a capstone, since covering a standing stone
+// flattens it and nothing covers a capstone.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Stack {
+    pub height: u8,
+    pub owners: u64,
+    pub top: Stone,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Reserve {
+    pub stones: u8,
+    pub capstones: u8,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    phase: Phase,
+
+    stacks: [Stack; Tak::SQUARE_COUNT],
+
+    player_reserve: Reserve,
+    opponent_reserve: Reserve,
+
+    opening_plies: u8,
+}
+
+impl Stack {
+    const EMPTY: Stack = Stack {
+        height: 0,
+        owners: 0,
+        top: Stone::Flat,
+    };
+
+    pub fn is_empty(&self) -> bool {
+        self.height == 0
+    }
+
+    fn is_controlled(&self) -> bool {
+        self.height > 0 && (self.owners >> (self.height - 1)) & 1 == 1
+    }
+
+    fn is_open(&self) -> bool {
+        self.is_empty() || self.top == Stone::Flat
+    }
+
+    fn mask(height: u8) -> u64 {
+        (1u64 << height) - 1
+    }
+
+    fn take(&mut self, count: u8) -> (u64, Stone) {
+        let carried = self.owners >> (self.height - count);
+        let top = self.top;
+
+        self.height -= count;
+        self.owners &= Self::mask(self.height);
+        self.top = Stone::Flat;
+
+        (carried, top)
+    }
+
+    fn drop(&mut self, owners: u64, count: u8, top: Stone) {
+        self.owners |= (owners & Self::mask(count)) << self.height;
+        self.height += count;
+        self.top = top;
+    }
+}
+
+impl Reserve {
+    const INITIAL: Reserve = Reserve {
+        stones: 21,
+        capstones: 1,
+    };
+
+    fn is_empty(self) -> bool {
+        self.stones == 0 && self.capstones == 0
+    }
+}
+
+impl Tak {
+    pub const BOARD_SIZE: usize = 5;
+    pub const SQUARE_COUNT: usize = Self::BOARD_SIZE * Self::BOARD_SIZE;
+
+    pub const CARRY_LIMIT: u8 = 5;
+
+    const SYMMETRY_COUNT: u8 = 8;
+
+    const BOARD_MASK: u32 = (1u32 << Self::SQUARE_COUNT) - 1;
+
+    const TOP_ROW: u32 = 0b11111;
+    const BOTTOM_ROW: u32 = Self::TOP_ROW << 20;
+
+    const LEFT_COLUMN: u32 = 0x0010_8421;
+    const RIGHT_COLUMN: u32 = Self::LEFT_COLUMN << 4;
+
+    pub(crate) fn square_name(square: u8) -> String {
+        let row = square as usize / Self::BOARD_SIZE;
+        let col = u8::try_from(square as usize % Self::BOARD_SIZE).unwrap();
+
+        format!("{}{}", char::from(b'a' + col), row + 1)
+    }
+
+    pub(crate) fn parse_square(value: &str) -> Option<u8> {
+        let col = value.bytes().next()?.wrapping_sub(b'a');
+        let row = value.get(1..)?.parse::<u8>().ok()?.checked_sub(1)?;
+
+        (usize::from(row) < Self::BOARD_SIZE && usize::from(col) < Self::BOARD_SIZE)
+            .then(|| row * u8::try_from(Self::BOARD_SIZE).unwrap() + col)
+    }
+
+    pub(crate) fn carried(drops: u8) -> u8 {
+        u8::try_from(u8::BITS - drops.leading_zeros()).unwrap()
+    }
+
+    pub(crate) fn drop_counts(drops: u8) -> Vec<u8> {
+        let mut counts = vec![];
+        let mut count = 0;
+
+        for bit in 0..Self::carried(drops) {
+            count += 1;
+
+            if (drops >> bit) & 1 == 1 {
+                counts.push(count);
+                count = 0;
+            }
+        }
+
+        counts
+    }
+
+    pub(crate) fn step(square: u8, direction: Direction) -> Option<u8> {
+        let size = u8::try_from(Self::BOARD_SIZE).unwrap();
+
+        let (row, col) = (square / size, square % size);
+
+        let (row, col) = match direction {
+            Direction::Up => (row.checked_sub(1)?, col),
+            Direction::Down => (row + 1, col),
+            Direction::Left => (row, col.checked_sub(1)?),
+            Direction::Right => (row, col + 1),
+        };
+
+        (row < size && col < size).then_some(row * size + col)
+    }
+
+    fn flip_perspective(&mut self) {
+        for stack in &mut self.stacks {
+            stack.owners ^= Stack::mask(stack.height);
+        }
+
+        swap(&mut self.player_reserve, &mut self.opponent_reserve);
+    }
+
+    // NOTE - Squares whose top piece counts towards a road for the player, or for the
+    // opponent when `player` is false.
+
+    fn road_squares(&self, player: bool) -> u32 {
+        self.stacks
+            .iter()
+            .enumerate()
+            .filter(|(_, stack)| {
+                !stack.is_empty() && stack.top != Stone::Standing && stack.is_controlled() == player
+            })
+            .fold(0, |bits, (square, _)| bits | (1u32 << square))
+    }
+
+    fn has_road(road: u32) -> bool {
+        Self::connects(road, Self::TOP_ROW, Self::BOTTOM_ROW)
+            || Self::connects(road, Self::LEFT_COLUMN, Self::RIGHT_COLUMN)
+    }
+
+    fn connects(road: u32, start: u32, goal: u32) -> bool {
+        let mut reached = road & start;
+
+        loop {
+            let spread = reached
+                | ((reached & !Self::RIGHT_COLUMN) << 1)
+                | ((reached & !Self::LEFT_COLUMN) >> 1)
+                | (reached << Self::BOARD_SIZE)
+                | (reached >> Self::BOARD_SIZE);
+
+            let next = spread & road & Self::BOARD_MASK;
+
+            if next == reached {
+                return reached & goal != 0;
+            }
+
+            reached = next;
+        }
+    }
+
+    fn flat_count(&self, player: bool) -> usize {
+        self.stacks
+            .iter()
+            .filter(|stack| {
+                !stack.is_empty() && stack.top == Stone::Flat && stack.is_controlled() == player
+            })
+            .count()
+    }
+
+    fn is_legal_move(&self, from: u8, direction: Direction, drops: u8) -> bool {
+        let stack = &self.stacks[from as usize];
+
+        if Self::carried(drops) > stack.height.min(Self::CARRY_LIMIT) {
+            return false;
+        }
+
+        let counts = Self::drop_counts(drops);
+
+        let mut square = from;
+
+        for (index, &count) in counts.iter().enumerate() {
+            let Some(next) = Self::step(square, direction) else {
+                return false;
+            };
+
+            square = next;
+
+            let target = &self.stacks[square as usize];
+
+            if target.is_open() {
+                continue;
+            }
+
+            let is_last = index + 1 == counts.len();
+
+            let flattens = is_last && count == 1 && stack.top == Stone::Capstone;
+
+            if target.top != Stone::Standing || !flattens {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn transform_square(square: u8, symmetry: u8) -> u8 {
+        let size = Self::BOARD_SIZE;
+
+        let (row, col) = (square as usize / size, square as usize % size);
+
+        let (row, col) = match symmetry {
+            0 => (row, col),
+            1 => (col, size - row - 1),
+            2 => (size - row - 1, size - col - 1),
+            3 => (size - col - 1, row),
+            4 => (row, size - col - 1),
+            5 => (size - row - 1, col),
+            6 => (col, row),
+            7 => (size - col - 1, size - row - 1),
+            _ => unreachable!(),
+        };
+
+        u8::try_from(row * size + col).unwrap()
+    }
+
+    // NOTE - A direction is transformed by stepping from the center square, which every
+    // symmetry leaves in place.
+
+    fn transform_direction(direction: Direction, symmetry: u8) -> Direction {
+        let center = u8::try_from(Self::SQUARE_COUNT / 2).unwrap();
+
+        let neighbor = Self::step(center, direction).unwrap();
+
+        [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ]
+        .into_iter()
+        .find(|&direction| {
+            Self::step(center, direction) == Some(Self::transform_square(neighbor, symmetry))
+        })
+        .unwrap()
+    }
+
+    fn format_stack(stack: &Stack) -> String {
+        if stack.is_empty() {
+            return ".".to_string();
+        }
+
+        let mut value: String = (0..stack.height)
+            .map(|index| {
+                if (stack.owners >> index) & 1 == 1 {
+                    'X'
+                } else {
+                    'O'
+                }
+            })
+            .collect();
+
+        match stack.top {
+            Stone::Flat => {}
+            Stone::Standing => value.push('s'),
+            Stone::Capstone => value.push('c'),
+        }
+
+        value
+    }
+
+    fn parse_stack(value: &str) -> Option<Stack> {
+        if value == "." {
+            return Some(Stack::EMPTY);
+        }
+
+        let (pieces, top) = match value.strip_suffix('s') {
+            Some(pieces) => (pieces, Stone::Standing),
+            None => match value.strip_suffix('c') {
+                Some(pieces) => (pieces, Stone::Capstone),
+                None => (value, Stone::Flat),
+            },
+        };
+
+        if pieces.is_empty() || pieces.len() >= u64::BITS as usize {
+            return None;
+        }
+
+        let mut stack = Stack::EMPTY;
+
+        for piece in pieces.chars() {
+            match piece {
+                'X' => stack.drop(1, 1, Stone::Flat),
+                'O' => stack.drop(0, 1, Stone::Flat),
+                _ => return None,
+            }
+        }
+
+        stack.top = top;
+
+        Some(stack)
+    }
+
+    fn format_reserve(reserve: Reserve) -> String {
+        format!("stones {}, capstones {}", reserve.stones, reserve.capstones)
+    }
+
+    fn parse_reserve(value: &str) -> Option<Reserve> {
+        let (stones, capstones) = value.strip_prefix("stones ")?.split_once(", capstones ")?;
+
+        Some(Reserve {
+            stones: stones.parse().ok()?,
+            capstones: capstones.parse().ok()?,
+        })
+    }
+}
+
+impl Game for Tak {
+    type Phase = Phase;
+    type Action = Action;
+    type Checkpoint = Checkpoint;
+    type Config = ();
+
+    fn new_with_config(_config: ()) -> Self {
+        Tak {
+            phase: Phase::Move,
+
+            stacks: [Stack::EMPTY; Self::SQUARE_COUNT],
+
+            player_reserve: Reserve::INITIAL,
+            opponent_reserve: Reserve::INITIAL,
+
+            opening_plies: 2,
+        }
+    }
+
+    fn get_possible_actions(&self) -> ActionList<Action> {
+        let mut actions = ActionList::new();
+
+        if self.phase != Phase::Move || self.outcome() != Outcome::InProgress {
+            return actions;
+        }
+
+        for (square, stack) in self.stacks.iter().enumerate() {
+            let square = u8::try_from(square).unwrap();
+
+            if stack.is_empty() {
+                let stones: &[Stone] = match (self.opening_plies, self.player_reserve) {
+                    (1.., _) => &[Stone::Flat],
+                    (
+                        0,
+                        Reserve {
+                            stones: 1..,
+                            capstones: 1..,
+                        },
+                    ) => &[Stone::Flat, Stone::Standing, Stone::Capstone],
+                    (0, Reserve { stones: 1.., .. }) => &[Stone::Flat, Stone::Standing],
+                    (0, Reserve { capstones: 1.., .. }) => &[Stone::Capstone],
+                    _ => &[],
+                };
+
+                for &stone in stones {
+                    actions.push(Action::Place { square, stone });
+                }
+            } else if self.opening_plies == 0 && stack.is_controlled() {
+                for direction in [
+                    Direction::Up,
+                    Direction::Down,
+                    Direction::Left,
+                    Direction::Right,
+                ] {
+                    for drops in 1..1u8 << Self::CARRY_LIMIT {
+                        if self.is_legal_move(square, direction, drops) {
+                            actions.push(Action::Move {
+                                from: square,
+                                direction,
+                                drops,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        actions
+    }
+
+    fn apply_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Place { square, stone } => {
+                let (reserve, owner) = if self.opening_plies > 0 {
+                    self.opening_plies -= 1;
+
+                    (&mut self.opponent_reserve, 0)
+                } else {
+                    (&mut self.player_reserve, 1)
+                };
+
+                match stone {
+                    Stone::Capstone => reserve.capstones -= 1,
+                    Stone::Flat | Stone::Standing => reserve.stones -= 1,
+                }
+
+                self.stacks[square as usize].drop(owner, 1, stone);
+            }
+            Action::Move {
+                from,
+                direction,
+                drops,
+            } => {
+                let (mut carried, top) = self.stacks[from as usize].take(Self::carried(drops));
+
+                let counts = Self::drop_counts(drops);
+
+                let mut square = from;
+
+                for (index, &count) in counts.iter().enumerate() {
+                    square = Self::step(square, direction).unwrap();
+
+                    let stone = if index + 1 == counts.len() {
+                        top
+                    } else {
+                        Stone::Flat
+                    };
+
+                    self.stacks[square as usize].drop(carried, count, stone);
+
+                    carried >>= count;
+                }
+            }
+        }
+
+        self.phase = Phase::Finished;
+
+        true
+    }
+
+    fn end_turn(&mut self) {
+        self.flip_perspective();
+
+        self.phase = Phase::Move;
+    }
+
+    fn outcome(&self) -> Outcome {
+        let player_road = Self::has_road(self.road_squares(true));
+        let opponent_road = Self::has_road(self.road_squares(false));
+
+        if player_road && (!opponent_road || self.phase == Phase::Finished) {
+            return Outcome::Win;
+        }
+
+        if opponent_road {
+            return Outcome::Loss;
+        }
+
+        let is_full = self.stacks.iter().all(|stack| !stack.is_empty());
+
+        if !is_full && !self.player_reserve.is_empty() && !self.opponent_reserve.is_empty() {
+            return Outcome::InProgress;
+        }
+
+        match self.flat_count(true).cmp(&self.flat_count(false)) {
+            Ordering::Greater => Outcome::Win,
+            Ordering::Less => Outcome::Loss,
+            Ordering::Equal => Outcome::Draw,
+        }
+    }
+
+    fn create_checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            phase: self.phase,
+
+            stacks: self.stacks,
+
+            player_reserve: self.player_reserve,
+            opponent_reserve: self.opponent_reserve,
+
+            opening_plies: self.opening_plies,
+        }
+    }
+
+    fn restore_checkpoint(&mut self, checkpoint: Checkpoint) {
+        self.phase = checkpoint.phase;
+
+        self.stacks = checkpoint.stacks;
+
+        self.player_reserve = checkpoint.player_reserve;
+        self.opponent_reserve = checkpoint.opponent_reserve;
+
+        self.opening_plies = checkpoint.opening_plies;
+    }
+
+    fn symmetries(&self) -> u8 {
+        Self::SYMMETRY_COUNT
+    }
+
+    fn transform(&self, symmetry: u8) -> Self {
+        let mut game = self.clone();
+
+        for (square, stack) in self.stacks.iter().enumerate() {
+            let square = Self::transform_square(u8::try_from(square).unwrap(), symmetry);
+
+            game.stacks[square as usize] = *stack;
+        }
+
+        game
+    }
+
+    fn transform_action(&self, action: Self::Action, symmetry: u8) -> Self::Action {
+        match action {
+            Action::Place { square, stone } => Action::Place {
+                square: Self::transform_square(square, symmetry),
+                stone,
+            },
+            Action::Move {
+                from,
+                direction,
+                drops,
+            } => Action::Move {
+                from: Self::transform_square(from, symmetry),
+                direction: Self::transform_direction(direction, symmetry),
+                drops,
+            },
+        }
+    }
+
+    fn display(&self, turn: Turn) -> String {
+        let mut game = self.clone();
+
+        if turn == Turn::Player2 {
+            game.flip_perspective();
+        }
+
+        format!("{game}")
+    }
+
+    fn to_notation(&self) -> String {
+        let board: Vec<String> = self
+            .stacks
+            .chunks(Self::BOARD_SIZE)
+            .map(|stacks| {
+                let stacks: Vec<String> = stacks.iter().map(Self::format_stack).collect();
+
+                stacks.join(",")
+            })
+            .collect();
+
+        let phase = match self.phase {
+            Phase::Move => "move",
+            Phase::Finished => "finished",
+        };
+
+        format!(
+            "{} {},{} {},{} {} {phase}",
+            board.join("/"),
+            self.player_reserve.stones,
+            self.player_reserve.capstones,
+            self.opponent_reserve.stones,
+            self.opponent_reserve.capstones,
+            self.opening_plies
+        )
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = notation.split_whitespace().collect();
+
+        if fields.len() != 5 {
+            return Err(format!("expected 5 fields, found {}", fields.len()));
+        }
+
+        let rows: Vec<&str> = fields[0].split('/').collect();
+
+        if rows.len() != Self::BOARD_SIZE {
+            return Err(format!(
+                "expected {} board rows, found {}",
+                Self::BOARD_SIZE,
+                rows.len()
+            ));
+        }
+
+        let mut game = Self::new();
+
+        for (row, line) in rows.into_iter().enumerate() {
+            let cells: Vec<&str> = line.split(',').collect();
+
+            if cells.len() != Self::BOARD_SIZE {
+                return Err(format!("malformed board row {}", row + 1));
+            }
+
+            for (col, cell) in cells.into_iter().enumerate() {
+                game.stacks[row * Self::BOARD_SIZE + col] = Self::parse_stack(cell)
+                    .ok_or(format!("invalid stack at row {}: {cell}", row + 1))?;
+            }
+        }
+
+        let parse_reserve = |value: &str| {
+            let (stones, capstones) = value.split_once(',')?;
+
+            Some(Reserve {
+                stones: stones.parse().ok()?,
+                capstones: capstones.parse().ok()?,
+            })
+        };
+
+        game.player_reserve =
+            parse_reserve(fields[1]).ok_or(format!("invalid reserve: {}", fields[1]))?;
+        game.opponent_reserve =
+            parse_reserve(fields[2]).ok_or(format!("invalid reserve: {}", fields[2]))?;
+
+        game.opening_plies = match fields[3].parse() {
+            Ok(plies @ 0..=2) => plies,
+            _ => return Err(format!("invalid opening: {}", fields[3])),
+        };
+
+        game.phase = match fields[4] {
+            "move" => Phase::Move,
+            "finished" => Phase::Finished,
+            field => return Err(format!("invalid phase: {field}")),
+        };
+
+        Ok(game)
+    }
+}
+
+impl fmt::Display for Tak {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // NOTE - Stacks are written bottom to top, with `s` or `c` after a standing stone or
+        // capstone on top.
+
+        write!(formatter, "  ")?;
+
+        for col in 0..Self::BOARD_SIZE {
+            write!(
+                formatter,
+                " {}",
+                char::from(b'a' + u8::try_from(col).unwrap())
+            )?;
+        }
+
+        writeln!(formatter)?;
+
+        for (row, stacks) in self.stacks.chunks(Self::BOARD_SIZE).enumerate() {
+            write!(formatter, "{:>2}", row + 1)?;
+
+            for stack in stacks {
+                write!(formatter, " {}", Self::format_stack(stack))?;
+            }
+
+            writeln!(formatter)?;
+        }
+
+        writeln!(
+            formatter,
+            "X: {}",
+            Self::format_reserve(self.player_reserve)
+        )?;
+        writeln!(
+            formatter,
+            "O: {}",
+            Self::format_reserve(self.opponent_reserve)
+        )?;
+
+        if self.opening_plies > 0 {
+            writeln!(formatter, "Opening: {}", self.opening_plies)?;
+        }
+
+        if self.phase == Phase::Finished {
+            writeln!(formatter, "Phase: Finished")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl str::FromStr for Tak {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        if lines.len() < Self::BOARD_SIZE + 3 {
+            return Err(format!(
+                "expected at least {} lines, found {}",
+                Self::BOARD_SIZE + 3,
+                lines.len()
+            ));
+        }
+
+        let mut game = Self::new();
+
+        game.opening_plies = 0;
+
+        for (row, line) in lines[1..=Self::BOARD_SIZE].iter().enumerate() {
+            let cells: Vec<&str> = line.split_whitespace().skip(1).collect();
+
+            if cells.len() != Self::BOARD_SIZE {
+                return Err(format!("malformed board row {}", row + 1));
+            }
+
+            for (col, cell) in cells.into_iter().enumerate() {
+                game.stacks[row * Self::BOARD_SIZE + col] = Self::parse_stack(cell)
+                    .ok_or(format!("invalid stack at row {}: {cell}", row + 1))?;
+            }
+        }
+
+        for &line in &lines[Self::BOARD_SIZE + 1..] {
+            if let Some(value) = line.strip_prefix("X: ") {
+                game.player_reserve =
+                    Self::parse_reserve(value).ok_or(format!("invalid reserve: {value}"))?;
+            } else if let Some(value) = line.strip_prefix("O: ") {
+                game.opponent_reserve =
+                    Self::parse_reserve(value).ok_or(format!("invalid reserve: {value}"))?;
+            } else if let Some(value) = line.strip_prefix("Opening:") {
+                game.opening_plies = match value.trim().parse() {
+                    Ok(plies @ 0..=2) => plies,
+                    _ => return Err(format!("invalid opening: {value}")),
+                };
+            } else if line == "Phase: Finished" {
+                game.phase = Phase::Finished;
+            } else {
+                return Err(format!("unexpected line: {line}"));
+            }
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::seq::IndexedRandom;
+
+    use crate::core::{DefaultRngFactory, RngFactory};
+
+    fn parse_game(value: &str) -> Tak {
+        value.parse().expect("unable to parse game")
+    }
+
+    fn square(name: &str) -> u8 {
+        Tak::parse_square(name).expect("invalid square")
+    }
+
+    mod get_possible_actions {
+        use super::*;
+
+        #[test]
+        fn should_only_place_flat_stones_in_the_opening() {
+            let actions = Tak::new().get_possible_actions();
+
+            assert_eq!(actions.len(), Tak::SQUARE_COUNT);
+            assert!(actions.iter().all(|action| matches!(
+                action,
+                Action::Place {
+                    stone: Stone::Flat,
+                    ..
+                }
+            )));
+        }
+
+        #[test]
+        fn should_flatten_standing_stones_with_a_lone_capstone() {
+            let game = parse_game(
+                "
+                   a b c d e
+                 1 OOXc . Os . .
+                 2 . . . . .
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: stones 20, capstones 0
+                O: stones 18, capstones 1
+                ",
+            );
+
+            let moves = |drops| Action::Move {
+                from: square("a1"),
+                direction: Direction::Right,
+                drops,
+            };
+
+            let actions = game.get_possible_actions();
+
+            assert!(actions.contains(&moves(0b110)));
+            assert!(!actions.contains(&moves(0b101)));
+            assert!(!actions.contains(&moves(0b111)));
+        }
+
+        #[test]
+        fn should_not_move_onto_capstones() {
+            let game = parse_game(
+                "
+                   a b c d e
+                 1 XXs Oc . . .
+                 2 . . . . .
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: stones 19, capstones 1
+                O: stones 20, capstones 0
+                ",
+            );
+
+            assert!(!game.get_possible_actions().iter().any(|action| matches!(
+                action,
+                Action::Move {
+                    direction: Direction::Right,
+                    ..
+                }
+            )));
+        }
+    }
+
+    mod apply_action {
+        use super::*;
+
+        #[test]
+        fn should_place_opponent_stones_in_the_opening() {
+            let mut game = Tak::new();
+
+            game.apply_action(Action::Place {
+                square: square("c3"),
+                stone: Stone::Flat,
+            });
+
+            assert_eq!(Tak::format_stack(&game.stacks[12]), "O");
+            assert_eq!(game.opponent_reserve.stones, 20);
+            assert_eq!(game.opening_plies, 1);
+        }
+
+        #[test]
+        fn should_drop_carried_pieces_bottom_first() {
+            let mut game = parse_game(
+                "
+                   a b c d e
+                 1 OXOX . O . .
+                 2 . . . . .
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: stones 19, capstones 1
+                O: stones 19, capstones 1
+                ",
+            );
+
+            game.apply_action(Action::Move {
+                from: square("a1"),
+                direction: Direction::Right,
+                drops: 0b101,
+            });
+
+            let stacks: Vec<String> = game.stacks[..3].iter().map(Tak::format_stack).collect();
+
+            assert_eq!(stacks, ["O", "X", "OOX"]);
+        }
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        fn should_win_with_a_road() {
+            let game = parse_game(
+                "
+                   a b c d e
+                 1 . X . . .
+                 2 . X . . .
+                 3 . XXc . O .
+                 4 . X O . .
+                 5 O OX . . .
+                X: stones 15, capstones 0
+                O: stones 17, capstones 1
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+
+        #[test]
+        fn should_not_count_standing_stones_in_roads() {
+            let game = parse_game(
+                "
+                   a b c d e
+                 1 O O Os O O
+                 2 . . . . .
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: stones 21, capstones 1
+                O: stones 16, capstones 1
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::InProgress);
+        }
+
+        #[test]
+        fn should_give_a_double_road_to_the_mover() {
+            let mut game = parse_game(
+                "
+                   a b c d e
+                 1 X X X X X
+                 2 O O O O O
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: stones 16, capstones 1
+                O: stones 16, capstones 1
+                ",
+            );
+
+            game.phase = Phase::Finished;
+
+            assert_eq!(game.outcome(), Outcome::Win);
+
+            game.end_turn();
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+        }
+
+        #[test]
+        fn should_count_flats_when_a_reserve_runs_out() {
+            let game = parse_game(
+                "
+                   a b c d e
+                 1 X X . . .
+                 2 O Os . . .
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: stones 0, capstones 0
+                O: stones 5, capstones 1
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+
+        #[test]
+        fn should_always_finish_random_games() {
+            let mut rng = DefaultRngFactory::default().create_seeded(7);
+
+            for _ in 0..10 {
+                let mut game = Tak::new();
+
+                while game.outcome() == Outcome::InProgress {
+                    let action = *game
+                        .get_possible_actions()
+                        .choose(&mut rng)
+                        .expect("unfinished game has no actions");
+
+                    game.apply_action(action);
+
+                    if game.outcome() == Outcome::InProgress {
+                        game.end_turn();
+                    }
+                }
+            }
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = Tak::new();
+
+            for (name, stone) in [("a1", Stone::Flat), ("b2", Stone::Flat)] {
+                game.apply_action(Action::Place {
+                    square: square(name),
+                    stone,
+                });
+                game.end_turn();
+            }
+
+            game.apply_action(Action::Place {
+                square: square("c3"),
+                stone: Stone::Capstone,
+            });
+
+            let parsed = Tak::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = Tak::new();
+
+            for (name, stone) in [("a1", Stone::Flat), ("b2", Stone::Flat)] {
+                game.apply_action(Action::Place {
+                    square: square(name),
+                    stone,
+                });
+                game.end_turn();
+            }
+
+            game.apply_action(Action::Place {
+                square: square("c3"),
+                stone: Stone::Capstone,
+            });
+
+            let parsed: Tak = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+    }
+
+    mod transform {
+        use super::*;
+
+        #[test]
+        fn should_rotate_stacks_and_moves() {
+            let game = parse_game(
+                "
+                   a b c d e
+                 1 XO . . . .
+                 2 . . . . .
+                 3 . . . . .
+                 4 . . . . .
+                 5 . . . . .
+                X: stones 20, capstones 1
+                O: stones 20, capstones 1
+                ",
+            );
+
+            let rotated = game.transform(1);
+
+            assert_eq!(Tak::format_stack(&rotated.stacks[4]), "XO");
+            assert_eq!(
+                game.transform_action(
+                    Action::Move {
+                        from: square("a1"),
+                        direction: Direction::Right,
+                        drops: 0b1,
+                    },
+                    1
+                ),
+                Action::Move {
+                    from: square("e1"),
+                    direction: Direction::Down,
+                    drops: 0b1,
+                }
+            );
+        }
+    }
+}
@@ -1,6 +1,12 @@
-use std::fmt;
+use std::{fmt, str};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+use serde::{Deserialize, Serialize};
+
+use crate::core::parse_square;
+use crate::game::tic_tac_toe::TicTacToe;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Action {
     Place { index: u8 },
 }
@@ -25,3 +31,43 @@ impl fmt::Display for Action {
         write!(f, "marks the {square} square.")
     }
 }
+
+// NOTE - Typed actions name the square to mark, such as `b2` for the center.
+
+impl str::FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (row, col) = parse_square(s.trim(), TicTacToe::BOARD_SIZE)?;
+
+        Ok(Action::Place {
+            index: u8::try_from(row * TicTacToe::BOARD_SIZE + col).unwrap(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_parse_squares() {
+            assert_eq!("a1".parse(), Ok(Action::Place { index: 0 }));
+            assert_eq!("b2".parse(), Ok(Action::Place { index: 4 }));
+            assert_eq!("c1".parse(), Ok(Action::Place { index: 2 }));
+        }
+
+        #[test]
+        fn should_reject_squares_off_the_board() {
+            for input in ["d1", "a4", "a0", ""] {
+                assert_eq!(
+                    input.parse::<Action>(),
+                    Err(format!("invalid square: {input}"))
+                );
+            }
+        }
+    }
+}
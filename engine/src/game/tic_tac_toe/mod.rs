@@ -1,5 +1,7 @@
 mod action;
 mod action_encoder;
+mod portable;
+mod renderable;
 mod state_encoder;
 #[allow(clippy::module_inception)]
 mod tic_tac_toe;
@@ -7,4 +9,4 @@ mod tic_tac_toe;
 pub use action::Action as TicTacToeAction;
 pub use action_encoder::TicTacToeActionEncoder;
 pub use state_encoder::TicTacToeStateEncoder;
-pub use tic_tac_toe::{Phase as TicTacToePhase, TicTacToe};
+pub use tic_tac_toe::{Phase as TicTacToePhase, TicTacToe, TicTacToeConfig};
@@ -0,0 +1,59 @@
+use crate::core::Turn;
+use crate::game::tic_tac_toe::TicTacToe;
+use crate::game::tic_tac_toe::action::Action;
+use crate::render::{PieceShape, RenderableGame, RenderedPiece};
+
+impl RenderableGame for TicTacToe {
+    fn grid_size(&self) -> (usize, usize) {
+        (Self::BOARD_SIZE, Self::BOARD_SIZE)
+    }
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece> {
+        let mask = 1u16 << (row * Self::BOARD_SIZE + col);
+
+        let owner = if self.player_marks & mask != 0 {
+            turn
+        } else if self.opponent_marks & mask != 0 {
+            turn.advance()
+        } else {
+            return None;
+        };
+
+        Some(RenderedPiece {
+            owner,
+            shape: PieceShape::Mark,
+        })
+    }
+
+    fn action_squares(&self, action: Action) -> Vec<(usize, usize)> {
+        match action {
+            Action::Place { index } => vec![(
+                index as usize / Self::BOARD_SIZE,
+                index as usize % Self::BOARD_SIZE,
+            )],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::render::weighted_squares;
+
+    mod square_weights {
+        use super::*;
+
+        #[test]
+        fn should_map_actions_onto_their_squares() {
+            let game = TicTacToe::new();
+
+            assert_eq!(game.grid_size(), (3, 3));
+            assert_eq!(
+                weighted_squares(&game, &[(Action::Place { index: 5 }, 0.5)],),
+                vec![(5, 0.5)]
+            );
+        }
+    }
+}
@@ -2,22 +2,32 @@ use std::iter::from_fn;
 use std::mem::swap;
 use std::{fmt, str};
 
-use crate::core::{Game, Outcome, Turn};
+use serde::{Deserialize, Serialize};
+
+use crate::core::{ActionList, Game, Outcome, Turn, format_rows, hash_position, parse_grid};
 use crate::game::tic_tac_toe::action::Action;
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct TicTacToe {
     pub phase: Phase,
 
     pub player_marks: u16,
     pub opponent_marks: u16,
+
+    pub misere: bool,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Phase {
     Place,
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TicTacToeConfig {
+    pub misere: bool,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Checkpoint {
     player_marks: u16,
@@ -32,6 +42,18 @@ impl TicTacToe {
     const BOARD_MASK: u16 = (1u16 << 9) - 1;
     const THREE_IN_A_ROW_MASKS: [u16; 8] = Self::make_three_in_a_row_masks();
 
+    // NOTE - Under misère rules the side that completes three in a row loses instead.
+
+    pub fn with_misere(mut self, misere: bool) -> Self {
+        self.misere = misere;
+
+        self
+    }
+
+    fn line_outcome(&self, outcome: Outcome) -> Outcome {
+        if self.misere { outcome.flip() } else { outcome }
+    }
+
     fn transform_bitboard(bitboard: u16, symmetry: u8) -> u16 {
         let mut result = 0u16;
 
@@ -145,6 +167,19 @@ impl TicTacToe {
         masks
     }
 
+    fn parse_row(line: &str) -> Option<Vec<char>> {
+        let cells = line.trim().strip_prefix('║')?.strip_suffix('║')?;
+
+        cells
+            .split('│')
+            .map(|cell| match cell.chars().collect::<Vec<_>>()[..] {
+                [' ', character, ' '] => Some(character),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .filter(|cells| cells.len() == Self::BOARD_SIZE)
+    }
+
     const fn xy_to_mask(x: usize, y: usize) -> u16 {
         1u16 << (x * Self::BOARD_SIZE + y)
     }
@@ -154,25 +189,28 @@ impl Game for TicTacToe {
     type Phase = Phase;
     type Action = Action;
     type Checkpoint = Checkpoint;
+    type Config = TicTacToeConfig;
 
-    fn new() -> Self {
+    fn new_with_config(config: TicTacToeConfig) -> Self {
         TicTacToe {
             phase: Phase::Place,
 
             player_marks: 0,
             opponent_marks: 0,
+
+            misere: config.misere,
         }
     }
 
-    fn get_possible_actions(&self) -> Vec<Action> {
+    fn get_possible_actions(&self) -> ActionList<Action> {
         if self.outcome() != Outcome::InProgress {
-            return vec![];
+            return ActionList::new();
         }
 
         let empty_squares = !(self.player_marks | self.opponent_marks) & Self::BOARD_MASK;
         let empty_square_count = empty_squares.count_ones() as usize;
 
-        let mut actions = Vec::with_capacity(empty_square_count);
+        let mut actions = ActionList::with_capacity(empty_square_count);
 
         for index in Self::into_indices(empty_squares) {
             actions.push(Action::Place { index });
@@ -202,7 +240,7 @@ impl Game for TicTacToe {
 
         for &mask in &Self::THREE_IN_A_ROW_MASKS {
             if (self.opponent_marks & mask) == mask {
-                return Outcome::Loss;
+                return self.line_outcome(Outcome::Loss);
             }
         }
 
@@ -210,7 +248,7 @@ impl Game for TicTacToe {
 
         for &mask in &Self::THREE_IN_A_ROW_MASKS {
             if (self.player_marks & mask) == mask {
-                return Outcome::Win;
+                return self.line_outcome(Outcome::Win);
             }
         }
 
@@ -223,6 +261,10 @@ impl Game for TicTacToe {
         Outcome::InProgress
     }
 
+    fn hash(&self) -> Option<u64> {
+        Some(hash_position(self))
+    }
+
     fn create_checkpoint(&self) -> Checkpoint {
         Checkpoint {
             player_marks: self.player_marks,
@@ -274,6 +316,67 @@ impl Game for TicTacToe {
 
         format!("{game}")
     }
+
+    fn to_notation(&self) -> String {
+        let board = format_rows((0..Self::BOARD_SIZE).map(|x| {
+            (0..Self::BOARD_SIZE).map(move |y| {
+                let mask = Self::xy_to_mask(x, y);
+
+                if self.player_marks & mask != 0 {
+                    Some('X')
+                } else if self.opponent_marks & mask != 0 {
+                    Some('O')
+                } else {
+                    None
+                }
+            })
+        }));
+
+        if self.misere {
+            format!("{board} misere")
+        } else {
+            board
+        }
+    }
+
+    fn from_notation(notation: &str) -> Result<Self, String> {
+        let mut fields = notation.split_whitespace();
+
+        let rows = parse_grid(
+            fields.next().unwrap_or_default(),
+            Self::BOARD_SIZE,
+            Self::BOARD_SIZE,
+        )?;
+
+        let misere = match fields.next() {
+            None => false,
+            Some("misere") => true,
+            Some(field) => return Err(format!("unexpected field: {field}")),
+        };
+
+        if let Some(field) = fields.next() {
+            return Err(format!("unexpected field: {field}"));
+        }
+
+        let mut game = Self::new().with_misere(misere);
+
+        for (x, cells) in rows.into_iter().enumerate() {
+            for (y, cell) in cells.into_iter().enumerate() {
+                let mask = Self::xy_to_mask(x, y);
+
+                match cell {
+                    None => {}
+                    Some('X') => game.player_marks |= mask,
+                    Some('O') => game.opponent_marks |= mask,
+                    Some(character) => {
+                        return Err(format!("invalid character at row {}: {character}", x + 1));
+                    }
+                }
+            }
+        }
+
+        Ok(game)
+    }
 }
 
 impl fmt::Display for TicTacToe {
@@ -312,6 +415,12 @@ impl fmt::Display for TicTacToe {
 
         writeln!(formatter, "╚═══╧═══╧═══╝")?;
 
+        // NOTE - Rules
+
+        if self.misere {
+            writeln!(formatter, "Misère")?;
+        }
+
         Ok(())
     }
 }
@@ -320,40 +429,41 @@ impl str::FromStr for TicTacToe {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut player_marks = 0u16;
-        let mut opponent_marks = 0u16;
+        let mut board_lines: Vec<&str> = s
+            .lines()
+            .map(str::trim_end)
+            .filter(|line| !line.is_empty())
+            .collect();
 
-        let lines: Vec<&str> = s.lines().collect();
+        let misere = board_lines.last() == Some(&"Misère");
 
-        if lines.len() != 2 * Self::BOARD_SIZE + 1 {
-            return Err("unexpected number of lines".to_string());
+        if misere {
+            board_lines.pop();
         }
 
-        let board_lines: Vec<&str> = lines
-            .iter()
-            .filter(|line| !line.contains('═') && !line.contains('─'))
-            .copied()
-            .collect();
-
-        for (x, line) in board_lines.iter().enumerate() {
-            let characters: Vec<char> = line
-                .chars()
-                .filter(|&character| character != '║' && character != '│')
-                .collect();
+        if board_lines.len() != 2 * Self::BOARD_SIZE + 1 {
+            return Err(format!(
+                "expected {} board lines, found {}",
+                2 * Self::BOARD_SIZE + 1,
+                board_lines.len()
+            ));
+        }
 
-            for y in 0..Self::BOARD_SIZE {
-                let character = characters.get(y * 3 + 1).copied().unwrap_or(' ');
+        let mut player_marks = 0u16;
+        let mut opponent_marks = 0u16;
 
-                if character == ' ' {
-                    continue;
-                }
+        for (x, line) in board_lines.iter().skip(1).step_by(2).enumerate() {
+            let cells =
+                Self::parse_row(line).ok_or_else(|| format!("malformed board row {}", x + 1))?;
 
+            for (y, character) in cells.into_iter().enumerate() {
                 let mask = Self::xy_to_mask(x, y);
 
                 match character {
+                    ' ' => {}
                     'X' => player_marks |= mask,
                     'O' => opponent_marks |= mask,
-                    _ => return Err(format!("invalid character: {character}")),
+                    _ => return Err(format!("invalid character at row {}: {character}", x + 1)),
                 }
             }
         }
@@ -363,6 +473,8 @@ impl str::FromStr for TicTacToe {
 
             player_marks,
             opponent_marks,
+
+            misere,
         })
     }
 }
@@ -394,7 +506,7 @@ mod tests {
             let mut game = parse_game(
                 "
                         ╔═══╤═══╤═══╗
-                        ║   │   │   │
+                        ║   │   │   ║
                         ╟───┼───┼───╢
                         ║   │   │   ║
                         ╟───┼───┼───╢
@@ -423,6 +535,128 @@ mod tests {
         }
     }
 
+    mod canonicalize {
+        use super::*;
+
+        #[test]
+        fn should_share_canonical_form_across_symmetries() {
+            let game = parse_game(
+                "
+                    ╔═══╤═══╤═══╗
+                    ║ X │   │   ║
+                    ╟───┼───┼───╢
+                    ║   │ O │ X ║
+                    ╟───┼───┼───╢
+                    ║   │   │   ║
+                    ╚═══╧═══╧═══╝
+                ",
+            );
+
+            let (canonical_game, symmetry) = game.canonicalize();
+
+            assert_eq!(game.transform(symmetry), canonical_game);
+
+            for symmetry in 0..game.symmetries() {
+                assert_eq!(game.transform(symmetry).canonicalize().0, canonical_game);
+            }
+        }
+    }
+
+    mod from_notation {
+        use super::*;
+
+        #[test]
+        fn should_write_the_board_from_the_side_to_move() {
+            let mut game = TicTacToe::new();
+
+            for index in [4, 0, 8] {
+                game.apply_action(Action::Place { index });
+                game.end_turn();
+            }
+
+            assert_eq!(game.to_notation(), "X2/1O1/2O");
+            assert_eq!(
+                TicTacToe::new().with_misere(true).to_notation(),
+                "3/3/3 misere"
+            );
+        }
+
+        #[test]
+        fn should_round_trip_notation() {
+            let mut game = TicTacToe::new();
+
+            for index in [4, 0, 8] {
+                game.apply_action(Action::Place { index });
+                game.end_turn();
+            }
+
+            let parsed =
+                TicTacToe::from_notation(&game.to_notation()).expect("unable to parse notation");
+
+            assert_eq!(parsed, game);
+        }
+
+        #[test]
+        fn should_reject_malformed_notation() {
+            for (input, error) in [
+                ("3/3", "expected 3 board rows, found 2"),
+                ("3/2/3", "malformed board row 2"),
+                ("3/1?1/3", "invalid character at row 2: ?"),
+                ("3/3/3 normal", "unexpected field: normal"),
+            ] {
+                assert_eq!(TicTacToe::from_notation(input), Err(error.to_string()));
+            }
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_display() {
+            let mut game = TicTacToe::new();
+
+            for index in [4, 0, 8] {
+                game.apply_action(Action::Place { index });
+                game.end_turn();
+            }
+
+            let parsed: TicTacToe = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+
+        #[test]
+        fn should_round_trip_misere_display() {
+            let mut game = TicTacToe::new().with_misere(true);
+
+            game.apply_action(Action::Place { index: 4 });
+
+            let parsed: TicTacToe = format!("{game}").parse().expect("unable to parse game");
+
+            assert_eq!(parsed, game);
+        }
+
+        #[test]
+        fn should_reject_malformed_input() {
+            let game = TicTacToe::new().to_string();
+
+            for (input, error) in [
+                (game.replacen("║   │", "║   ", 1), "malformed board row 1"),
+                (
+                    game.replacen("║   │", "║ ? │", 1),
+                    "invalid character at row 1: ?",
+                ),
+                (
+                    game.lines().skip(1).collect::<Vec<_>>().join("\n"),
+                    "expected 7 board lines, found 6",
+                ),
+            ] {
+                assert_eq!(input.parse::<TicTacToe>(), Err(error.to_string()));
+            }
+        }
+    }
+
     mod outcome {
         use super::*;
 
@@ -520,6 +754,58 @@ mod tests {
 
             assert_eq!(outcome, Outcome::Win);
         }
+
+        #[test]
+        fn should_lose_by_completing_a_line_under_misere_rules() {
+            let game = parse_game(
+                "
+                    ╔═══╤═══╤═══╗
+                    ║ X │ X │ X ║
+                    ╟───┼───┼───╢
+                    ║   │   │   ║
+                    ╟───┼───┼───╢
+                    ║ O │   │ O ║
+                    ╚═══╧═══╧═══╝
+                    Misère
+                ",
+            );
+
+            assert_eq!(game.outcome(), Outcome::Loss);
+
+            let mut game = game;
+            game.end_turn();
+
+            assert_eq!(game.outcome(), Outcome::Win);
+        }
+    }
+
+    mod serialize {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_through_json() {
+            let mut game = TicTacToe::new();
+
+            game.apply_action(Action::Place { index: 4 });
+            game.end_turn();
+
+            let json = serde_json::to_string(&game).expect("unable to serialize");
+            let parsed: TicTacToe = serde_json::from_str(&json).expect("unable to deserialize");
+
+            assert_eq!(parsed, game);
+        }
+
+        #[test]
+        fn should_name_variants_in_snake_case() {
+            let json = serde_json::to_string(&(
+                Action::Place { index: 4 },
+                Outcome::InProgress,
+                Turn::Player2,
+            ))
+            .expect("unable to serialize");
+
+            assert_eq!(json, r#"[{"place":{"index":4}},"in_progress","player2"]"#);
+        }
     }
 
     mod transform {
@@ -1,20 +1,67 @@
+mod analysis;
+mod builder;
+mod config;
 mod core;
+mod fixtures;
 mod game;
 mod neural_network;
 mod player;
+pub mod prelude;
+mod record;
+mod render;
 mod self_play;
 
+pub use analysis::{
+    AccuracyReport, Blunder, BlunderReport, CalibrationBin, CalibrationReport,
+    DiversityRunnerEventSink, LadderRung, OpeningTree, OpeningTreeMove, OpeningTreeNode,
+    PairingResult, perft,
+};
+pub use builder::{AnalysisBuilder, MatchBuilder, SelfPlayBuilder};
+pub use config::HermesPaths;
 pub use core::{
-    Choice, EventSink, Game, NullEventSink, Outcome, Player, Runner, RunnerEvent,
-    StatisticsRunnerEventSink, StdoutRunnerEventSink, Turn,
+    ActionList, Choice, DefaultRngFactory, EventSink, Game, NullEventSink, Outcome, PieRule,
+    PieRuleAction, PieRulePhase, Player, RepetitionCounter, ResultCounts, RngFactory, Runner,
+    RunnerEvent, Scoring, ShutdownSignal, SmallRngFactory, StatisticsRunnerEventSink,
+    StdRngFactory, StdoutRunnerEventSink, TeeEventSink, TimeControl, Turn,
 };
+pub use fixtures::{BOOP_FIXTURES, Fixture, TIC_TAC_TOE_FIXTURES};
+pub use game::backgammon;
 pub use game::boop;
+pub use game::breakthrough;
+pub use game::checkers;
+pub use game::chess;
+pub use game::go;
+pub use game::gomoku;
+pub use game::hex;
+pub use game::hive;
+pub use game::mnk;
+pub use game::nim;
+pub use game::tak;
 pub use game::tic_tac_toe;
+#[cfg(feature = "candle")]
+pub use neural_network::CandleNeuralNetwork;
 pub use neural_network::{
-    ActionEncoder, NeuralNetwork, OnnxNeuralNetwork, RandomNeuralNetwork, StateEncoder,
+    ActionEncoder, ActionEncoderValidation, CachedNeuralNetwork, ConstantNeuralNetwork, ModelEntry,
+    ModelShapeError, ModelZoo, NeuralNetwork, OnnxNeuralNetwork, PieRuleActionEncoder,
+    PieRuleStateEncoder, Prediction, RandomNeuralNetwork, ReloadableNeuralNetwork, StateEncoder,
+    validate_action_encoder,
 };
+#[cfg(not(target_arch = "wasm32"))]
+pub use neural_network::{InferenceClient, InferenceServer};
 pub use player::{
-    ClassicMctsPlayer, DirichletNoise, ManualPlayer, MinimaxPlayer, NeuralNetworkMctsPlayer,
-    RandomPlayer, TemperatureSchedule,
+    AdaptiveBudget, AnalyzingPlayer, CallbackHandle, CallbackPlayer, ClassicMctsPlayer,
+    DirichletNoise, EnsembleMode, EnsemblePlayer, EpsilonGreedyPlayer, ForcedPlayouts,
+    GreedyPlayer, GreedyValuePlayer, Heuristic, HumanPlayer, HybridPlayer, ManualPlayer,
+    MemoryUsage, MinimaxPlayer, MoveAnalysis, NeuralNetworkMctsPlayer, NullHeuristic, OpeningBook,
+    OpeningBookPlayer, PolicyPlayer, RandomPlayer, RemotePlayer, SearchStatistics,
+    SkillLimitedPlayer, StateStorage, SymmetryMode, TemperatureSchedule, TimedPlayer,
+    TreeExportNode, TreeExportOptions,
+};
+pub use record::{
+    GameHistory, GameRecord, GameRecordRunnerEventSink, JsonGameRecordSink, PortableGame,
+    RecordedMove,
+};
+pub use render::{PieceShape, PolicyOverlay, RenderableGame, RenderedPiece, SvgRenderer};
+pub use self_play::{
+    BinarySampleSink, InterleavedRunner, JsonSampleSink, Sample, SampleBatch, SampleRunnerEventSink,
 };
-pub use self_play::{JsonSampleSink, Sample, SampleRunnerEventSink};
@@ -1,5 +1,8 @@
 use crate::core::Game;
 
+// NOTE - Identifiers need not all be reachable. Board games that encode actions by their
+// start and direction leave some that lead off the board, which are never legal.
+
 pub trait ActionEncoder<G: Game>: Copy {
     const ACTION_COUNT: usize;
 
@@ -9,4 +12,40 @@ pub trait ActionEncoder<G: Game>: Copy {
 
     fn encode(&self, action: &G::Action) -> usize;
     fn decode(&self, action_id: usize) -> G::Action;
+
+    // NOTE - One entry per encoded action, 1.0 where the action is legal in `game` and 0.0
+    // elsewhere, so logits can be masked before normalization.
+
+    fn legal_mask(&self, game: &G) -> Vec<f32> {
+        let mut mask = vec![0.0; self.size()];
+
+        for action in game.get_possible_actions() {
+            mask[self.encode(&action)] = 1.0;
+        }
+
+        mask
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction, TicTacToeActionEncoder};
+
+    mod legal_mask {
+        use super::*;
+
+        #[test]
+        fn should_mark_only_legal_actions() {
+            let mut game = TicTacToe::new();
+
+            game.apply_action(TicTacToeAction::Place { index: 4 });
+            game.end_turn();
+
+            let mask = TicTacToeActionEncoder.legal_mask(&game);
+
+            assert_eq!(mask, [1.0, 1.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0]);
+        }
+    }
 }
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use rand::seq::IndexedRandom;
+
+use crate::core::{DefaultRngFactory, Game, Outcome, RngFactory, sample_chance_outcome};
+use crate::neural_network::action_encoder::ActionEncoder;
+
+#[derive(Clone, Debug, Default)]
+pub struct ActionEncoderValidation {
+    pub games: u32,
+    pub positions: usize,
+    pub actions: usize,
+
+    // NOTE - Counts cover every occurrence, examples only the first few.
+    pub out_of_range: usize,
+    pub round_trip_failures: usize,
+    pub collisions: usize,
+
+    pub examples: Vec<String>,
+}
+
+impl ActionEncoderValidation {
+    pub const MAX_EXAMPLES: usize = 10;
+
+    pub fn is_valid(&self) -> bool {
+        self.out_of_range == 0 && self.round_trip_failures == 0 && self.collisions == 0
+    }
+
+    fn add_example(&mut self, example: String) {
+        if self.examples.len() < Self::MAX_EXAMPLES {
+            self.examples.push(example);
+        }
+    }
+}
+
+// NOTE - Plays random games and checks every legal action along the way: it must encode to
+// an index below the encoder's size, decode back to itself, and not share its index with
+// any other action seen. Chance outcomes are drawn rather than checked, since they are
+// never encoded.
+
+pub fn validate_action_encoder<G: Game, AE: ActionEncoder<G>>(
+    action_encoder: AE,
+    games: u32,
+    seed: u64,
+) -> ActionEncoderValidation {
+    let mut rng = DefaultRngFactory::default().create_seeded(seed);
+
+    let mut validation = ActionEncoderValidation {
+        games,
+        ..ActionEncoderValidation::default()
+    };
+
+    let mut seen: HashMap<usize, G::Action> = HashMap::new();
+
+    for _ in 0..games {
+        let mut game = G::new();
+
+        while game.outcome() == Outcome::InProgress {
+            if let Some(action) = sample_chance_outcome(&game, &mut rng) {
+                game.apply_action(action);
+
+                continue;
+            }
+
+            let actions = game.get_possible_actions();
+
+            validation.positions += 1;
+
+            for &action in &actions {
+                validation.actions += 1;
+
+                let action_id = action_encoder.encode(&action);
+
+                if action_id >= action_encoder.size() {
+                    validation.out_of_range += 1;
+                    validation.add_example(format!(
+                        "{action} encodes to {action_id}, outside of {}",
+                        action_encoder.size()
+                    ));
+
+                    continue;
+                }
+
+                let decoded = action_encoder.decode(action_id);
+
+                if decoded != action {
+                    validation.round_trip_failures += 1;
+                    validation.add_example(format!(
+                        "{action} encodes to {action_id}, which decodes to {decoded}"
+                    ));
+                }
+
+                match seen.get(&action_id) {
+                    Some(&other) if other != action => {
+                        validation.collisions += 1;
+                        validation.add_example(format!(
+                            "{action} and {other} both encode to {action_id}"
+                        ));
+                    }
+                    Some(_) => {}
+                    None => {
+                        seen.insert(action_id, action);
+                    }
+                }
+            }
+
+            let Some(&action) = actions.choose(&mut rng) else {
+                break;
+            };
+
+            if game.apply_action(action) && game.outcome() == Outcome::InProgress {
+                game.end_turn();
+            }
+        }
+    }
+
+    validation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::backgammon::{Backgammon, BackgammonActionEncoder};
+    use crate::game::boop::{Boop, BoopActionEncoder};
+    use crate::game::breakthrough::{Breakthrough, BreakthroughActionEncoder};
+    use crate::game::checkers::{Checkers, CheckersActionEncoder};
+    use crate::game::chess::{Chess, ChessActionEncoder};
+    use crate::game::go::{Go, GoActionEncoder};
+    use crate::game::gomoku::{Gomoku, GomokuActionEncoder};
+    use crate::game::hex::{Hex, HexActionEncoder};
+    use crate::game::hive::{Hive, HiveActionEncoder};
+    use crate::game::mnk::{MnkActionEncoder, MnkConfig, MnkGame};
+    use crate::game::tak::{Tak, TakActionEncoder};
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction, TicTacToeActionEncoder};
+
+    #[derive(Clone, Copy)]
+    struct HalvingActionEncoder;
+
+    impl ActionEncoder<TicTacToe> for HalvingActionEncoder {
+        const ACTION_COUNT: usize = 5;
+
+        fn encode(&self, action: &TicTacToeAction) -> usize {
+            let TicTacToeAction::Place { index } = *action;
+
+            usize::from(index / 2)
+        }
+
+        fn decode(&self, action_id: usize) -> TicTacToeAction {
+            TicTacToeAction::Place {
+                index: u8::try_from(action_id * 2).unwrap(),
+            }
+        }
+    }
+
+    mod validate_action_encoder {
+        use super::*;
+
+        #[test]
+        fn should_accept_tic_tac_toe_encoder() {
+            let validation = validate_action_encoder::<TicTacToe, _>(TicTacToeActionEncoder, 20, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_backgammon_encoder() {
+            let validation =
+                validate_action_encoder::<Backgammon, _>(BackgammonActionEncoder::new(), 5, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_boop_encoder() {
+            let validation = validate_action_encoder::<Boop, _>(BoopActionEncoder::new(), 20, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_hex_encoder() {
+            let validation = validate_action_encoder::<Hex, _>(HexActionEncoder::new(), 5, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_hive_encoder() {
+            let validation = validate_action_encoder::<Hive, _>(HiveActionEncoder::new(), 2, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_mnk_encoder() {
+            let validation = validate_action_encoder::<MnkGame, _>(
+                MnkActionEncoder::new(MnkConfig::default()),
+                10,
+                0,
+            );
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_breakthrough_encoder() {
+            let validation =
+                validate_action_encoder::<Breakthrough, _>(BreakthroughActionEncoder::new(), 20, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_checkers_encoder() {
+            let validation =
+                validate_action_encoder::<Checkers, _>(CheckersActionEncoder::new(), 20, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_chess_encoder() {
+            let validation = validate_action_encoder::<Chess, _>(ChessActionEncoder::new(), 2, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_gomoku_encoder() {
+            let validation = validate_action_encoder::<Gomoku, _>(GomokuActionEncoder::new(), 5, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_go_encoder() {
+            let validation = validate_action_encoder::<Go, _>(GoActionEncoder::new(), 5, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_accept_tak_encoder() {
+            let validation = validate_action_encoder::<Tak, _>(TakActionEncoder::new(), 5, 0);
+
+            assert!(validation.is_valid(), "{:?}", validation.examples);
+            assert!(validation.actions > 0);
+        }
+
+        #[test]
+        fn should_report_collisions() {
+            let validation = validate_action_encoder::<TicTacToe, _>(HalvingActionEncoder, 5, 0);
+
+            assert!(!validation.is_valid());
+            assert!(validation.collisions > 0);
+            assert!(validation.round_trip_failures > 0);
+            assert!(!validation.examples.is_empty());
+        }
+    }
+}
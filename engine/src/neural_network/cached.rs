@@ -0,0 +1,497 @@
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::mem::size_of;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::neural_network::neural_network::{NeuralNetwork, Prediction};
+use crate::player::MemoryUsage;
+
+// NOTE - Clones share one cache, so games played in parallel warm it for each other and
+// it can be saved from any handle once they finish.
+
+#[derive(Clone)]
+pub struct CachedNeuralNetwork<NN: NeuralNetwork> {
+    neural_network: NN,
+
+    cache: Arc<Mutex<PredictionCache>>,
+    max_entries: Option<usize>,
+    model_hash: Option<String>,
+}
+
+#[derive(Default)]
+struct PredictionCache {
+    predictions: HashMap<u64, Prediction>,
+
+    hits: u64,
+    misses: u64,
+}
+
+impl<NN: NeuralNetwork> CachedNeuralNetwork<NN> {
+    pub const MAGIC: &'static [u8; 4] = b"HPC3";
+
+    pub fn new(neural_network: NN) -> Self {
+        Self {
+            neural_network,
+
+            cache: Arc::new(Mutex::new(PredictionCache::default())),
+            max_entries: None,
+            model_hash: None,
+        }
+    }
+
+    // NOTE - Once full, new predictions are still returned but no longer cached.
+
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+
+        self
+    }
+
+    // NOTE - Saved caches record the hash of the model that filled them, and only load
+    // into a network with the same hash.
+
+    pub fn with_model_hash(mut self, model_hash: impl Into<String>) -> Self {
+        self.model_hash = Some(model_hash.into());
+
+        self
+    }
+
+    pub fn model_hash(&self) -> Option<&str> {
+        self.model_hash.as_deref()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().predictions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().predictions.is_empty()
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.lock().hits
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.lock().misses
+    }
+
+    pub fn clear(&mut self) {
+        self.lock().predictions.clear();
+    }
+
+    // NOTE - Covers the cache shared by every clone, counting reserved capacity.
+
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let cache = self.lock();
+
+        let policy_bytes: usize = cache
+            .predictions
+            .values()
+            .map(|prediction| prediction.policy_logits.capacity() * size_of::<f32>())
+            .sum();
+
+        MemoryUsage {
+            nodes: cache.predictions.len(),
+            bytes: cache.predictions.capacity() * size_of::<(u64, Prediction)>() + policy_bytes,
+        }
+    }
+
+    // NOTE - Layout is the magic, the model hash as a little-endian u32 length and UTF-8
+    // bytes (empty if unknown), then the entry count as a u32, then for each entry its key
+    // as a u64, its policy length as a u32, the policy logits and the value as f32s, and a
+    // flag byte followed by the draw probability as an f32 when the flag is set. Keys only
+    // depend on the encoded state, so a cache is only valid for the model that filled it.
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let cache = self.lock();
+
+        writer.write_all(Self::MAGIC)?;
+
+        let model_hash = self.model_hash.as_deref().unwrap_or_default().as_bytes();
+
+        writer.write_all(&to_u32(model_hash.len())?.to_le_bytes())?;
+        writer.write_all(model_hash)?;
+
+        writer.write_all(&to_u32(cache.predictions.len())?.to_le_bytes())?;
+
+        for (key, prediction) in &cache.predictions {
+            let policy_size = to_u32(prediction.policy_logits.len())?;
+
+            let mut bytes = Vec::with_capacity(21 + 4 * prediction.policy_logits.len());
+
+            bytes.extend(key.to_le_bytes());
+            bytes.extend(policy_size.to_le_bytes());
+            bytes.extend(
+                prediction
+                    .policy_logits
+                    .iter()
+                    .flat_map(|logit| logit.to_le_bytes()),
+            );
+            bytes.extend(prediction.value.to_le_bytes());
+
+            match prediction.draw_probability {
+                Some(draw_probability) => {
+                    bytes.push(1);
+                    bytes.extend(draw_probability.to_le_bytes());
+                }
+                None => bytes.push(0),
+            }
+
+            writer.write_all(&bytes)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn read_from<R: Read>(&mut self, reader: &mut R) -> io::Result<()> {
+        let mut magic = [0; 4];
+
+        reader.read_exact(&mut magic)?;
+
+        if &magic != Self::MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not a prediction cache",
+            ));
+        }
+
+        let length = read_u32(reader)? as usize;
+
+        let mut bytes = vec![0; length];
+
+        reader.read_exact(&mut bytes)?;
+
+        let saved_hash = String::from_utf8(bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        if let Some(model_hash) = &self.model_hash
+            && !saved_hash.eq_ignore_ascii_case(model_hash)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "prediction cache was filled by a different model: expected {model_hash}, found {saved_hash}"
+                ),
+            ));
+        }
+
+        let count = read_u32(reader)?;
+
+        let mut cache = self.lock();
+
+        for _ in 0..count {
+            let mut key = [0; 8];
+
+            reader.read_exact(&mut key)?;
+
+            let policy_size = read_u32(reader)?;
+
+            let policy_logits = (0..policy_size)
+                .map(|_| read_f32(reader))
+                .collect::<io::Result<Vec<_>>>()?;
+
+            let value = read_f32(reader)?;
+
+            let draw_probability = match read_u8(reader)? {
+                0 => None,
+                1 => Some(read_f32(reader)?),
+                flag => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid draw probability flag: {flag}"),
+                    ));
+                }
+            };
+
+            cache.predictions.insert(
+                u64::from_le_bytes(key),
+                Prediction {
+                    policy_logits,
+                    value,
+                    draw_probability,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PredictionCache> {
+        self.cache.lock().expect("prediction cache lock poisoned")
+    }
+
+    // NOTE - FNV-1a over the encoded state, which is stable across builds so that saved
+    // caches stay valid.
+
+    fn key(input: &[f32]) -> u64 {
+        let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+
+        for byte in input.iter().flat_map(|value| value.to_bits().to_le_bytes()) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01B3);
+        }
+
+        hash
+    }
+}
+
+impl<NN: NeuralNetwork> NeuralNetwork for CachedNeuralNetwork<NN> {
+    fn reseed(&mut self, seed: u64) {
+        self.neural_network.reseed(seed);
+    }
+
+    fn new_game(&mut self) -> bool {
+        let changed = self.neural_network.new_game();
+
+        if changed {
+            self.clear();
+        }
+
+        changed
+    }
+
+    fn predict(&mut self, input: &[f32]) -> Prediction {
+        let key = Self::key(input);
+
+        {
+            let mut cache = self.lock();
+
+            if let Some(prediction) = cache.predictions.get(&key).cloned() {
+                cache.hits += 1;
+
+                return prediction;
+            }
+
+            cache.misses += 1;
+        }
+
+        // NOTE - Inference runs without the lock, so two games may both miss on the same
+        // state and predict it twice.
+
+        let prediction = self.neural_network.predict(input);
+
+        let mut cache = self.lock();
+
+        if self
+            .max_entries
+            .is_none_or(|max_entries| cache.predictions.len() < max_entries)
+        {
+            cache.predictions.insert(key, prediction.clone());
+        }
+
+        prediction
+    }
+
+    // NOTE - Only the states missing from the cache are passed on, as a single batch.
+
+    fn predict_batch(&mut self, inputs: &[Vec<f32>]) -> Vec<Prediction> {
+        let keys: Vec<u64> = inputs.iter().map(|input| Self::key(input)).collect();
+
+        let mut predictions: Vec<Option<Prediction>> = {
+            let mut cache = self.lock();
+
+            let predictions: Vec<_> = keys
+                .iter()
+                .map(|key| cache.predictions.get(key).cloned())
+                .collect();
+
+            let hits = predictions
+                .iter()
+                .filter(|prediction| prediction.is_some())
+                .count();
+
+            cache.hits += hits as u64;
+            cache.misses += (predictions.len() - hits) as u64;
+
+            predictions
+        };
+
+        let missing: Vec<usize> = (0..inputs.len())
+            .filter(|&i| predictions[i].is_none())
+            .collect();
+
+        let missing_inputs: Vec<Vec<f32>> = missing.iter().map(|&i| inputs[i].clone()).collect();
+
+        let missing_predictions = self.neural_network.predict_batch(&missing_inputs);
+
+        let mut cache = self.lock();
+
+        for (i, prediction) in missing.into_iter().zip(missing_predictions) {
+            if self
+                .max_entries
+                .is_none_or(|max_entries| cache.predictions.len() < max_entries)
+            {
+                cache.predictions.insert(keys[i], prediction.clone());
+            }
+
+            predictions[i] = Some(prediction);
+        }
+
+        predictions
+            .into_iter()
+            .map(|prediction| prediction.expect("missing prediction"))
+            .collect()
+    }
+}
+
+fn to_u32(value: usize) -> io::Result<u32> {
+    u32::try_from(value).map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))
+}
+
+fn read_u8<R: Read>(reader: &mut R) -> io::Result<u8> {
+    let mut bytes = [0; 1];
+
+    reader.read_exact(&mut bytes)?;
+
+    Ok(bytes[0])
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+
+    reader.read_exact(&mut bytes)?;
+
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_f32<R: Read>(reader: &mut R) -> io::Result<f32> {
+    let mut bytes = [0; 4];
+
+    reader.read_exact(&mut bytes)?;
+
+    Ok(f32::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::neural_network::{ConstantNeuralNetwork, RandomNeuralNetwork};
+
+    mod predict {
+        use super::*;
+
+        #[test]
+        fn should_reuse_predictions_for_repeated_states() {
+            let mut neural_network = CachedNeuralNetwork::new(RandomNeuralNetwork::new(4));
+
+            let first = neural_network.predict(&[1.0, 0.0]);
+            let second = neural_network.predict(&[1.0, 0.0]);
+
+            neural_network.predict(&[0.0, 1.0]);
+
+            assert_eq!(first.policy_logits, second.policy_logits);
+            assert_eq!(first.value.to_bits(), second.value.to_bits());
+
+            assert_eq!(neural_network.hits(), 1);
+            assert_eq!(neural_network.misses(), 2);
+            assert_eq!(neural_network.len(), 2);
+        }
+
+        #[test]
+        fn should_share_predictions_between_clones() {
+            let mut neural_network = CachedNeuralNetwork::new(ConstantNeuralNetwork::new(4));
+            let mut clone = neural_network.clone();
+
+            neural_network.predict(&[1.0, 0.0]);
+            clone.predict(&[1.0, 0.0]);
+
+            assert_eq!(neural_network.hits(), 1);
+            assert_eq!(clone.len(), 1);
+        }
+    }
+
+    mod memory_usage {
+        use super::*;
+
+        #[test]
+        fn should_count_the_policy_of_each_prediction() {
+            let mut neural_network = CachedNeuralNetwork::new(ConstantNeuralNetwork::new(100));
+
+            neural_network.predict(&[1.0, 0.0]);
+
+            let MemoryUsage { nodes, bytes } = neural_network.memory_usage();
+
+            assert_eq!(nodes, 1);
+            assert!(bytes >= size_of::<(u64, Prediction)>() + 100 * size_of::<f32>());
+        }
+    }
+
+    mod read_from {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_saved_predictions() {
+            let mut neural_network = CachedNeuralNetwork::new(RandomNeuralNetwork::new(4));
+
+            let expected = neural_network.predict(&[1.0, 0.0]);
+
+            let mut bytes = vec![];
+
+            neural_network
+                .write_to(&mut bytes)
+                .expect("unable to write cache");
+
+            let mut loaded = CachedNeuralNetwork::new(RandomNeuralNetwork::new(4));
+
+            loaded
+                .read_from(&mut bytes.as_slice())
+                .expect("unable to read cache");
+
+            let actual = loaded.predict(&[1.0, 0.0]);
+
+            assert_eq!(actual.policy_logits, expected.policy_logits);
+            assert_eq!(actual.value.to_bits(), expected.value.to_bits());
+            assert_eq!(loaded.hits(), 1);
+        }
+
+        #[test]
+        fn should_round_trip_draw_probabilities() {
+            let mut neural_network =
+                CachedNeuralNetwork::new(ConstantNeuralNetwork::new(4).with_draw_probability(0.25));
+
+            neural_network.predict(&[1.0, 0.0]);
+
+            let mut bytes = vec![];
+
+            neural_network
+                .write_to(&mut bytes)
+                .expect("unable to write cache");
+
+            let mut loaded = CachedNeuralNetwork::new(ConstantNeuralNetwork::new(4));
+
+            loaded
+                .read_from(&mut bytes.as_slice())
+                .expect("unable to read cache");
+
+            let prediction = loaded.predict(&[1.0, 0.0]);
+
+            assert_eq!(prediction.draw_probability, Some(0.25));
+            assert_eq!(loaded.hits(), 1);
+        }
+
+        #[test]
+        fn should_reject_caches_from_other_models() {
+            let mut neural_network =
+                CachedNeuralNetwork::new(RandomNeuralNetwork::new(4)).with_model_hash("aaaa");
+
+            neural_network.predict(&[1.0, 0.0]);
+
+            let mut bytes = vec![];
+
+            neural_network
+                .write_to(&mut bytes)
+                .expect("unable to write cache");
+
+            let mut same_model =
+                CachedNeuralNetwork::new(RandomNeuralNetwork::new(4)).with_model_hash("AAAA");
+            let mut other_model =
+                CachedNeuralNetwork::new(RandomNeuralNetwork::new(4)).with_model_hash("bbbb");
+
+            assert!(same_model.read_from(&mut bytes.as_slice()).is_ok());
+            assert!(other_model.read_from(&mut bytes.as_slice()).is_err());
+            assert!(other_model.is_empty());
+        }
+    }
+}
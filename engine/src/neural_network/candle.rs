@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::marker::PhantomData;
+use std::path::Path;
+
+use candle_core::{DType, Device, Module, ModuleT, Tensor};
+use candle_nn::{BatchNorm, Conv2d, Conv2dConfig, Linear, VarBuilder};
+
+use crate::core::Game;
+use crate::neural_network::neural_network::{NeuralNetwork, Prediction};
+use crate::neural_network::state_encoder::StateEncoder;
+
+// NOTE - Runs the residual network from training without ONNX, from the `state_dict` of a
+// `BoopNetwork` saved as safetensors. The tower's width and depth, and the sizes of both
+// heads, are read from the weights. A value head with a single output has `tanh` applied,
+// as in training, and one with three is read as win, draw and loss logits.
+
+#[derive(Clone)]
+pub struct CandleNeuralNetwork<G: Game, SE: StateEncoder<G>> {
+    model: ResidualNetwork,
+
+    state_encoder: SE,
+
+    _phantom: PhantomData<G>,
+}
+
+impl<G: Game, SE: StateEncoder<G>> CandleNeuralNetwork<G, SE> {
+    pub fn new(path: impl AsRef<Path>, state_encoder: SE) -> Result<Self, Box<dyn Error>> {
+        let tensors = candle_core::safetensors::load(path, &Device::Cpu)?;
+
+        Self::from_tensors(tensors, state_encoder)
+    }
+
+    pub fn new_from_bytes(bytes: &[u8], state_encoder: SE) -> Result<Self, Box<dyn Error>> {
+        let tensors = candle_core::safetensors::load_buffer(bytes, &Device::Cpu)?;
+
+        Self::from_tensors(tensors, state_encoder)
+    }
+
+    fn from_tensors(
+        tensors: HashMap<String, Tensor>,
+        state_encoder: SE,
+    ) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            model: ResidualNetwork::load(tensors)?,
+            state_encoder,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn run(&self, inputs: &[Vec<f32>]) -> candle_core::Result<Vec<Prediction>> {
+        let mut shape = self.state_encoder.shape();
+        shape[0] = inputs.len();
+
+        let input = Tensor::from_vec(inputs.concat(), shape, &Device::Cpu)?;
+
+        let (policy_logits, values) = self.model.forward(&input)?;
+
+        Ok(policy_logits
+            .to_vec2::<f32>()?
+            .into_iter()
+            .zip(values.to_vec2::<f32>()?)
+            .map(|(policy_logits, values)| Prediction::from_value_outputs(policy_logits, &values))
+            .collect())
+    }
+}
+
+impl<G: Game, SE: StateEncoder<G>> NeuralNetwork for CandleNeuralNetwork<G, SE> {
+    fn reseed(&mut self, _seed: u64) {}
+
+    fn predict(&mut self, input: &[f32]) -> Prediction {
+        self.predict_batch(&[input.to_vec()])
+            .pop()
+            .expect("model returned no prediction")
+    }
+
+    fn predict_batch(&mut self, inputs: &[Vec<f32>]) -> Vec<Prediction> {
+        if inputs.is_empty() {
+            return vec![];
+        }
+
+        self.run(inputs).expect("failed to run model")
+    }
+}
+
+#[derive(Clone)]
+struct ResidualNetwork {
+    input: (Conv2d, BatchNorm),
+    blocks: Vec<[(Conv2d, BatchNorm); 2]>,
+
+    policy_head: (Conv2d, BatchNorm),
+    policy_output: Linear,
+
+    value_head: (Conv2d, BatchNorm),
+    value_hidden: Linear,
+    value_output: Linear,
+}
+
+impl ResidualNetwork {
+    const BATCH_NORM_EPSILON: f64 = 1e-5;
+
+    fn load(tensors: HashMap<String, Tensor>) -> Result<Self, Box<dyn Error>> {
+        let dims = |name: &str| {
+            tensors
+                .get(name)
+                .map(|tensor| tensor.dims().to_vec())
+                .ok_or_else(|| format!("missing weights: {name}"))
+        };
+
+        let input_dims = dims("conv_input.weight")?;
+        let policy_dims = dims("policy_conv.weight")?;
+        let policy_output_dims = dims("policy_fc.weight")?;
+        let value_dims = dims("value_conv.weight")?;
+        let value_hidden_dims = dims("value_fc1.weight")?;
+        let value_output_dims = dims("value_fc2.weight")?;
+
+        let block_count = (0..tensors.len())
+            .take_while(|i| tensors.contains_key(&format!("residual_blocks.{i}.conv1.weight")))
+            .count();
+
+        let channels = input_dims[0];
+
+        let vb = VarBuilder::from_tensors(tensors, DType::F32, &Device::Cpu);
+
+        let convolution = |name: &str, size: (usize, usize), kernel_size: usize| {
+            let config = Conv2dConfig {
+                padding: kernel_size / 2,
+                ..Conv2dConfig::default()
+            };
+
+            candle_nn::conv2d_no_bias(size.0, size.1, kernel_size, config, vb.pp(name))
+        };
+
+        let batch_norm = |name: &str, size: usize| {
+            candle_nn::batch_norm(size, Self::BATCH_NORM_EPSILON, vb.pp(name))
+        };
+
+        let linear = |name: &str, dims: &[usize]| candle_nn::linear(dims[1], dims[0], vb.pp(name));
+
+        let blocks = (0..block_count)
+            .map(|i| -> candle_core::Result<[(Conv2d, BatchNorm); 2]> {
+                let block = format!("residual_blocks.{i}");
+
+                Ok([
+                    (
+                        convolution(&format!("{block}.conv1"), (channels, channels), 3)?,
+                        batch_norm(&format!("{block}.bn1"), channels)?,
+                    ),
+                    (
+                        convolution(&format!("{block}.conv2"), (channels, channels), 3)?,
+                        batch_norm(&format!("{block}.bn2"), channels)?,
+                    ),
+                ])
+            })
+            .collect::<candle_core::Result<_>>()?;
+
+        Ok(Self {
+            input: (
+                convolution("conv_input", (input_dims[1], channels), input_dims[2])?,
+                batch_norm("bn_input", channels)?,
+            ),
+            blocks,
+
+            policy_head: (
+                convolution("policy_conv", (channels, policy_dims[0]), 1)?,
+                batch_norm("policy_bn", policy_dims[0])?,
+            ),
+            policy_output: linear("policy_fc", &policy_output_dims)?,
+
+            value_head: (
+                convolution("value_conv", (channels, value_dims[0]), 1)?,
+                batch_norm("value_bn", value_dims[0])?,
+            ),
+            value_hidden: linear("value_fc1", &value_hidden_dims)?,
+            value_output: linear("value_fc2", &value_output_dims)?,
+        })
+    }
+
+    fn forward(&self, input: &Tensor) -> candle_core::Result<(Tensor, Tensor)> {
+        let layer = |(convolution, batch_norm): &(Conv2d, BatchNorm), x: &Tensor| {
+            batch_norm.forward_t(&convolution.forward(x)?, false)
+        };
+
+        let mut x = layer(&self.input, input)?.relu()?;
+
+        for [first, second] in &self.blocks {
+            let hidden = layer(first, &x)?.relu()?;
+
+            x = (layer(second, &hidden)? + x)?.relu()?;
+        }
+
+        let policy = layer(&self.policy_head, &x)?.relu()?.flatten_from(1)?;
+        let policy_logits = self.policy_output.forward(&policy)?;
+
+        let value = layer(&self.value_head, &x)?.relu()?.flatten_from(1)?;
+        let value = self.value_hidden.forward(&value)?.relu()?;
+        let mut values = self.value_output.forward(&value)?;
+
+        if values.dim(1)? == 1 {
+            values = values.tanh()?;
+        }
+
+        Ok((policy_logits, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::boop::{Boop, BoopStateEncoder};
+
+    const CHANNELS: usize = 4;
+    const HEAD_CHANNELS: usize = 2;
+    const ACTION_COUNT: usize = 188;
+
+    fn add(tensors: &mut HashMap<String, Tensor>, name: &str, shape: &[usize], value: f32) {
+        let tensor = Tensor::full(value, shape, &Device::Cpu).unwrap();
+
+        tensors.insert(name.to_string(), tensor);
+    }
+
+    fn add_layer(tensors: &mut HashMap<String, Tensor>, name: &str, shape: &[usize], value: f32) {
+        let [convolution, batch_norm] = name.split_once(',').map(|(x, y)| [x, y]).unwrap();
+
+        add(tensors, &format!("{convolution}.weight"), shape, value);
+
+        add(tensors, &format!("{batch_norm}.weight"), &shape[..1], 1.0);
+        add(tensors, &format!("{batch_norm}.bias"), &shape[..1], 0.0);
+        add(
+            tensors,
+            &format!("{batch_norm}.running_mean"),
+            &shape[..1],
+            0.0,
+        );
+        add(
+            tensors,
+            &format!("{batch_norm}.running_var"),
+            &shape[..1],
+            1.0,
+        );
+    }
+
+    fn tensors(value_size: usize) -> HashMap<String, Tensor> {
+        let mut tensors = HashMap::new();
+
+        let board_size = HEAD_CHANNELS * 36;
+
+        add_layer(
+            &mut tensors,
+            "conv_input,bn_input",
+            &[CHANNELS, 10, 3, 3],
+            0.01,
+        );
+
+        for i in 1..=2 {
+            let name = format!("residual_blocks.0.conv{i},residual_blocks.0.bn{i}");
+
+            add_layer(&mut tensors, &name, &[CHANNELS, CHANNELS, 3, 3], 0.01);
+        }
+
+        add_layer(
+            &mut tensors,
+            "policy_conv,policy_bn",
+            &[HEAD_CHANNELS, CHANNELS, 1, 1],
+            0.1,
+        );
+        add(
+            &mut tensors,
+            "policy_fc.weight",
+            &[ACTION_COUNT, board_size],
+            0.1,
+        );
+        add(&mut tensors, "policy_fc.bias", &[ACTION_COUNT], 0.0);
+
+        add_layer(
+            &mut tensors,
+            "value_conv,value_bn",
+            &[HEAD_CHANNELS, CHANNELS, 1, 1],
+            0.1,
+        );
+        add(&mut tensors, "value_fc1.weight", &[8, board_size], 0.1);
+        add(&mut tensors, "value_fc1.bias", &[8], 0.0);
+        add(&mut tensors, "value_fc2.weight", &[value_size, 8], 0.1);
+        add(&mut tensors, "value_fc2.bias", &[value_size], 0.0);
+
+        tensors
+    }
+
+    fn network(value_size: usize) -> CandleNeuralNetwork<Boop, BoopStateEncoder> {
+        CandleNeuralNetwork::from_tensors(tensors(value_size), BoopStateEncoder::new()).unwrap()
+    }
+
+    mod predict {
+        use super::*;
+
+        #[test]
+        fn should_predict_a_policy_and_a_bounded_value() {
+            let input = BoopStateEncoder::new().encode(&Boop::new());
+
+            let prediction = network(1).predict(&input);
+
+            assert_eq!(prediction.policy_logits.len(), ACTION_COUNT);
+            assert!(prediction.value > 0.0 && prediction.value < 1.0);
+            assert!(prediction.draw_probability.is_none());
+        }
+
+        #[test]
+        fn should_read_a_three_output_value_head_as_wdl_logits() {
+            let input = BoopStateEncoder::new().encode(&Boop::new());
+
+            let prediction = network(3).predict(&input);
+
+            assert!(prediction.value.abs() < 1e-6);
+            assert!((prediction.draw_probability.unwrap() - 1.0 / 3.0).abs() < 1e-6);
+        }
+    }
+
+    mod predict_batch {
+        use super::*;
+
+        #[test]
+        fn should_match_single_predictions() {
+            let mut network = network(1);
+
+            let mut game = Boop::new();
+
+            let first = BoopStateEncoder::new().encode(&game);
+
+            let action = game.get_possible_actions()[0];
+
+            if game.apply_action(action) {
+                game.end_turn();
+            }
+
+            let second = BoopStateEncoder::new().encode(&game);
+
+            let predictions = network.predict_batch(&[first.clone(), second.clone()]);
+
+            for (prediction, input) in predictions.iter().zip([first, second]) {
+                let expected = network.predict(&input);
+
+                assert!((prediction.value - expected.value).abs() < 1e-6);
+                assert!(
+                    prediction
+                        .policy_logits
+                        .iter()
+                        .zip(&expected.policy_logits)
+                        .all(|(x, y)| (x - y).abs() < 1e-5)
+                );
+            }
+        }
+    }
+}
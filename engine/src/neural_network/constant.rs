@@ -0,0 +1,48 @@
+use crate::neural_network::neural_network::{NeuralNetwork, Prediction};
+
+#[derive(Clone, Debug)]
+pub struct ConstantNeuralNetwork {
+    policy_logits: Vec<f32>,
+    value: f32,
+    draw_probability: Option<f32>,
+}
+
+impl ConstantNeuralNetwork {
+    pub fn new(policy_size: usize) -> Self {
+        Self {
+            policy_logits: vec![0.0; policy_size],
+            value: 0.0,
+            draw_probability: None,
+        }
+    }
+
+    pub fn with_policy_logits(mut self, policy_logits: Vec<f32>) -> Self {
+        self.policy_logits = policy_logits;
+
+        self
+    }
+
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.value = value;
+
+        self
+    }
+
+    pub fn with_draw_probability(mut self, draw_probability: f32) -> Self {
+        self.draw_probability = Some(draw_probability);
+
+        self
+    }
+}
+
+impl NeuralNetwork for ConstantNeuralNetwork {
+    fn reseed(&mut self, _seed: u64) {}
+
+    fn predict(&mut self, _input: &[f32]) -> Prediction {
+        Prediction {
+            policy_logits: self.policy_logits.clone(),
+            value: self.value,
+            draw_probability: self.draw_probability,
+        }
+    }
+}
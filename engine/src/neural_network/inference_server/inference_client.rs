@@ -0,0 +1,37 @@
+use std::sync::mpsc::{self, Sender};
+
+use crate::neural_network::inference_server::inference_server::InferenceRequest;
+use crate::neural_network::neural_network::{NeuralNetwork, Prediction};
+
+// NOTE - A handle to an `InferenceServer`, usable anywhere a network is. Each call blocks
+// until the server has run the batch it joined. Reseeding is ignored, since the server's
+// network is shared by every client.
+
+#[derive(Clone)]
+pub struct InferenceClient {
+    pub(super) requests: Sender<InferenceRequest>,
+}
+
+impl NeuralNetwork for InferenceClient {
+    fn reseed(&mut self, _seed: u64) {}
+
+    fn predict(&mut self, input: &[f32]) -> Prediction {
+        self.predict_batch(&[input.to_vec()])
+            .pop()
+            .expect("inference server returned no prediction")
+    }
+
+    fn predict_batch(&mut self, inputs: &[Vec<f32>]) -> Vec<Prediction> {
+        if inputs.is_empty() {
+            return vec![];
+        }
+
+        let (sender, receiver) = mpsc::channel();
+
+        self.requests
+            .send((inputs.to_vec(), sender))
+            .expect("inference server stopped");
+
+        receiver.recv().expect("inference server stopped")
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::neural_network::inference_server::inference_client::InferenceClient;
+use crate::neural_network::neural_network::{NeuralNetwork, Prediction};
+
+// NOTE - Owns one network on a thread of its own, and serves every client through it.
+// Requests are gathered until `max_batch_size` states are waiting or `max_wait` has passed
+// since the first, then run as one batch, so self-play threads that each evaluate a few
+// states at a time still reach the network in large batches. A request is never split, so
+// a batch can exceed the limit by one request. The thread stops once the server and all
+// of its clients are dropped.
+
+pub struct InferenceServer {
+    requests: Sender<InferenceRequest>,
+}
+
+pub(super) type InferenceRequest = (Vec<Vec<f32>>, Sender<Vec<Prediction>>);
+
+impl InferenceServer {
+    pub fn new<NN: NeuralNetwork + Send + 'static>(
+        neural_network: NN,
+        max_batch_size: usize,
+        max_wait: Duration,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            Self::serve(neural_network, &receiver, max_batch_size.max(1), max_wait);
+        });
+
+        Self { requests: sender }
+    }
+
+    pub fn client(&self) -> InferenceClient {
+        InferenceClient {
+            requests: self.requests.clone(),
+        }
+    }
+
+    fn serve<NN: NeuralNetwork>(
+        mut neural_network: NN,
+        receiver: &Receiver<InferenceRequest>,
+        max_batch_size: usize,
+        max_wait: Duration,
+    ) {
+        while let Ok(request) = receiver.recv() {
+            let deadline = Instant::now() + max_wait;
+
+            let mut batch = vec![request];
+            let mut size = batch[0].0.len();
+
+            while size < max_batch_size {
+                match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(request) => {
+                        size += request.0.len();
+
+                        batch.push(request);
+                    }
+                    Err(_) => break,
+                }
+            }
+
+            let inputs: Vec<Vec<f32>> = batch
+                .iter()
+                .flat_map(|(inputs, _)| inputs.iter().cloned())
+                .collect();
+
+            let mut predictions = neural_network.predict_batch(&inputs).into_iter();
+
+            for (inputs, sender) in batch {
+                let _ = sender.send(predictions.by_ref().take(inputs.len()).collect());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct EchoNeuralNetwork {
+        batch_sizes: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl NeuralNetwork for EchoNeuralNetwork {
+        fn reseed(&mut self, _seed: u64) {}
+
+        fn predict(&mut self, input: &[f32]) -> Prediction {
+            Prediction {
+                policy_logits: vec![],
+                value: input[0],
+                draw_probability: None,
+            }
+        }
+
+        fn predict_batch(&mut self, inputs: &[Vec<f32>]) -> Vec<Prediction> {
+            self.batch_sizes.lock().unwrap().push(inputs.len());
+
+            inputs.iter().map(|input| self.predict(input)).collect()
+        }
+    }
+
+    mod client {
+        use super::*;
+
+        #[test]
+        fn should_batch_requests_from_many_threads() {
+            let neural_network = EchoNeuralNetwork::default();
+            let batch_sizes = Arc::clone(&neural_network.batch_sizes);
+
+            let server = InferenceServer::new(neural_network, 4, Duration::from_secs(5));
+
+            let values: Vec<f32> = thread::scope(|scope| {
+                let workers: Vec<_> = (0..4)
+                    .map(|i| {
+                        let mut client = server.client();
+
+                        scope.spawn(move || client.predict(&[i as f32]).value)
+                    })
+                    .collect();
+
+                workers
+                    .into_iter()
+                    .map(|worker| worker.join().unwrap())
+                    .collect()
+            });
+
+            assert_eq!(values, [0.0, 1.0, 2.0, 3.0]);
+            assert_eq!(*batch_sizes.lock().unwrap(), [4]);
+        }
+    }
+}
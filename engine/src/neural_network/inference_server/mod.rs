@@ -0,0 +1,6 @@
+mod inference_client;
+#[allow(clippy::module_inception)]
+mod inference_server;
+
+pub use inference_client::InferenceClient;
+pub use inference_server::InferenceServer;
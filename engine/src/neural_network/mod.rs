@@ -1,12 +1,34 @@
 mod action_encoder;
+mod action_encoder_validation;
+mod cached;
+#[cfg(feature = "candle")]
+mod candle;
+mod constant;
+#[cfg(not(target_arch = "wasm32"))]
+mod inference_server;
+mod model_shape_error;
+mod model_zoo;
 #[allow(clippy::module_inception)]
 mod neural_network;
 mod onnx;
+mod pie_rule;
 mod random;
+mod reloadable;
 mod state_encoder;
 
 pub use action_encoder::ActionEncoder;
+pub use action_encoder_validation::{ActionEncoderValidation, validate_action_encoder};
+pub use cached::CachedNeuralNetwork;
+#[cfg(feature = "candle")]
+pub use candle::CandleNeuralNetwork;
+pub use constant::ConstantNeuralNetwork;
+#[cfg(not(target_arch = "wasm32"))]
+pub use inference_server::{InferenceClient, InferenceServer};
+pub use model_shape_error::ModelShapeError;
+pub use model_zoo::{ModelEntry, ModelZoo};
 pub use neural_network::{NeuralNetwork, Prediction};
 pub use onnx::OnnxNeuralNetwork;
+pub use pie_rule::{PieRuleActionEncoder, PieRuleStateEncoder};
 pub use random::RandomNeuralNetwork;
+pub use reloadable::ReloadableNeuralNetwork;
 pub use state_encoder::StateEncoder;
@@ -0,0 +1,50 @@
+use std::error::Error;
+use std::fmt;
+
+// NOTE - Raised when a model is loaded for encoders it was not exported for. Dimensions
+// that the model leaves symbolic, such as its batch size, are shown as `None`.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ModelShapeError {
+    Input {
+        expected: Vec<usize>,
+        actual: Vec<Option<usize>>,
+    },
+    OutputCount {
+        actual: usize,
+    },
+    Policy {
+        expected: usize,
+        actual: Vec<Option<usize>>,
+    },
+    Value {
+        actual: Vec<Option<usize>>,
+    },
+}
+
+impl fmt::Display for ModelShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Input { expected, actual } => write!(
+                f,
+                "model input has shape {actual:?}, but the state encoder produces {expected:?}"
+            ),
+            Self::OutputCount { actual } => write!(
+                f,
+                "model has {actual} outputs, but policy and value outputs are required"
+            ),
+            Self::Policy { expected, actual } => write!(
+                f,
+                "model policy output has shape {actual:?}, but the action encoder has {expected} \
+                 actions"
+            ),
+            Self::Value { actual } => write!(
+                f,
+                "model value output has shape {actual:?}, but one value or three win, draw and \
+                 loss logits are required"
+            ),
+        }
+    }
+}
+
+impl Error for ModelShapeError {}
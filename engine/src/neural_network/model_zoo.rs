@@ -0,0 +1,434 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModelEntry {
+    pub url: String,
+    pub sha256: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ModelZoo {
+    cache_dir: PathBuf,
+
+    registry: HashMap<String, ModelEntry>,
+}
+
+impl ModelZoo {
+    const MODEL_EXTENSION: &'static str = "onnx";
+
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+
+            registry: HashMap::new(),
+        }
+    }
+
+    pub fn with_registry_file(mut self, path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let json = fs::read_to_string(path)?;
+
+        let registry: HashMap<String, ModelEntry> = serde_json::from_str(&json)?;
+
+        self.registry.extend(registry);
+
+        Ok(self)
+    }
+
+    pub fn register(&mut self, name: impl Into<String>, entry: ModelEntry) {
+        self.registry.insert(name.into(), entry);
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn resolve(&self, reference: &str) -> Result<PathBuf, Box<dyn Error>> {
+        let path = Path::new(reference);
+
+        if path.is_file() {
+            return Ok(path.to_path_buf());
+        }
+
+        if let Some(entry) = self.registry.get(reference) {
+            // NOTE - Names may nest, as in `boop/v1.5`, but must stay inside the cache.
+
+            if !path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+            {
+                return Err(format!("invalid model name: {reference}").into());
+            }
+
+            let cached_path = self
+                .cache_dir
+                .join(format!("{reference}.{}", Self::MODEL_EXTENSION));
+
+            return Self::fetch(entry, &cached_path);
+        }
+
+        if Self::is_url(reference) {
+            let entry = ModelEntry {
+                url: reference.to_string(),
+                sha256: None,
+            };
+
+            let cached_path = self
+                .cache_dir
+                .join("urls")
+                .join(Self::digest(reference.as_bytes()))
+                .with_extension(Self::MODEL_EXTENSION);
+
+            return Self::fetch(&entry, &cached_path);
+        }
+
+        Err(format!("unknown model reference: {reference}").into())
+    }
+
+    fn fetch(entry: &ModelEntry, cached_path: &Path) -> Result<PathBuf, Box<dyn Error>> {
+        if cached_path.is_file() {
+            let bytes = fs::read(cached_path)?;
+
+            if Self::verify(entry, &bytes).is_ok() {
+                return Ok(cached_path.to_path_buf());
+            }
+        }
+
+        let bytes = Self::download(&entry.url)?;
+
+        Self::verify(entry, &bytes)?;
+
+        if let Some(parent) = cached_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // NOTE - Write to a temporary file first so an interrupted download never
+        // leaves a truncated model in the cache.
+
+        let partial_path = cached_path.with_extension("partial");
+
+        fs::write(&partial_path, &bytes)?;
+        fs::rename(&partial_path, cached_path)?;
+
+        Ok(cached_path.to_path_buf())
+    }
+
+    // NOTE - SHA-256 of a model file, as a lowercase hex string. Identifies a model
+    // version, e.g. for prediction caches.
+
+    pub fn checksum(path: impl AsRef<Path>) -> Result<String, Box<dyn Error>> {
+        Ok(Self::digest(&fs::read(path)?))
+    }
+
+    fn verify(entry: &ModelEntry, bytes: &[u8]) -> Result<(), Box<dyn Error>> {
+        let Some(expected) = &entry.sha256 else {
+            return Ok(());
+        };
+
+        let actual = Self::digest(bytes);
+
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(format!(
+                "checksum mismatch for {}: expected {expected}, found {actual}",
+                entry.url
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn download(url: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+        if let Some(path) = url.strip_prefix("file://") {
+            return Ok(fs::read(path)?);
+        }
+
+        Self::download_http(url)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn download_http(url: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+        use std::io::{self, Read};
+
+        let mut response = ureq::get(url).call()?;
+
+        let mut bytes = vec![];
+
+        response
+            .body_mut()
+            .as_reader()
+            .read_to_end(&mut bytes)
+            .map_err(|error| io::Error::new(error.kind(), format!("{url}: {error}")))?;
+
+        Ok(bytes)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn download_http(url: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+        Err(format!("downloading models is not supported on this target: {url}").into())
+    }
+
+    fn is_url(reference: &str) -> bool {
+        ["http://", "https://", "file://"]
+            .iter()
+            .any(|scheme| reference.starts_with(scheme))
+    }
+
+    fn digest(bytes: &[u8]) -> String {
+        Sha256::digest(bytes)
+            .iter()
+            .fold(String::new(), |mut digest, byte| {
+                let _ = write!(digest, "{byte:02x}");
+
+                digest
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+
+    const MODEL: &[u8] = b"model";
+
+    fn setup(name: &str) -> (PathBuf, String) {
+        let directory =
+            std::env::temp_dir().join(format!("hermes-model-zoo-{}-{name}", process::id()));
+
+        let _ = fs::remove_dir_all(&directory);
+
+        fs::create_dir_all(&directory).unwrap();
+
+        let source = directory.join("source.onnx");
+
+        fs::write(&source, MODEL).unwrap();
+
+        (directory, format!("file://{}", source.display()))
+    }
+
+    fn make_zoo(directory: &Path, url: &str, sha256: Option<String>) -> ModelZoo {
+        let mut zoo = ModelZoo::new(directory.join("cache"));
+
+        zoo.register(
+            "tiny",
+            ModelEntry {
+                url: url.to_string(),
+                sha256,
+            },
+        );
+
+        zoo
+    }
+
+    mod with_registry_file {
+        use super::*;
+
+        #[test]
+        fn should_register_every_model_in_the_file() {
+            let (directory, url) = setup("registry");
+
+            let registry = directory.join("registry.json");
+
+            fs::write(&registry, format!(r#"{{"tiny": {{"url": "{url}"}}}}"#)).unwrap();
+
+            let zoo = ModelZoo::new(directory.join("cache"))
+                .with_registry_file(&registry)
+                .unwrap();
+
+            assert_eq!(
+                zoo.resolve("tiny").unwrap(),
+                directory.join("cache").join("tiny.onnx")
+            );
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+    }
+
+    mod resolve {
+        use super::*;
+
+        #[test]
+        fn should_return_existing_paths() {
+            let (directory, _) = setup("path");
+
+            let source = directory.join("source.onnx");
+
+            let zoo = ModelZoo::new(directory.join("cache"));
+
+            assert_eq!(zoo.resolve(source.to_str().unwrap()).unwrap(), source);
+            assert!(!directory.join("cache").exists());
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+
+        #[test]
+        fn should_fetch_registered_names_into_the_cache() {
+            let (directory, url) = setup("name");
+
+            let zoo = make_zoo(&directory, &url, Some(ModelZoo::digest(MODEL)));
+
+            let path = zoo.resolve("tiny").unwrap();
+
+            assert_eq!(path, directory.join("cache").join("tiny.onnx"));
+            assert_eq!(fs::read(&path).unwrap(), MODEL);
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+
+        #[test]
+        fn should_keep_dotted_names_apart() {
+            let (directory, url) = setup("dotted");
+
+            let other_source = directory.join("other.onnx");
+
+            fs::write(&other_source, b"other").unwrap();
+
+            let mut zoo = make_zoo(&directory, &url, None);
+
+            for (name, url) in [
+                ("boop/v1.5", url),
+                ("boop/v1.6", format!("file://{}", other_source.display())),
+            ] {
+                zoo.register(name, ModelEntry { url, sha256: None });
+            }
+
+            let path = zoo.resolve("boop/v1.5").unwrap();
+            let other_path = zoo.resolve("boop/v1.6").unwrap();
+
+            assert_eq!(path, directory.join("cache").join("boop").join("v1.5.onnx"));
+            assert_eq!(fs::read(&path).unwrap(), MODEL);
+            assert_eq!(fs::read(&other_path).unwrap(), b"other");
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+
+        #[test]
+        fn should_reject_names_outside_the_cache() {
+            let (directory, url) = setup("escape");
+
+            let mut zoo = ModelZoo::new(directory.join("cache"));
+
+            for name in ["../escaped", "/tmp/escaped"] {
+                zoo.register(
+                    name,
+                    ModelEntry {
+                        url: url.clone(),
+                        sha256: None,
+                    },
+                );
+
+                let error = zoo.resolve(name).unwrap_err();
+
+                assert!(error.to_string().contains("invalid model name"));
+            }
+
+            assert!(!directory.join("escaped").exists());
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+
+        #[test]
+        fn should_fetch_urls_into_the_cache() {
+            let (directory, url) = setup("url");
+
+            let zoo = ModelZoo::new(directory.join("cache"));
+
+            let path = zoo.resolve(&url).unwrap();
+
+            assert_eq!(
+                path,
+                directory
+                    .join("cache")
+                    .join("urls")
+                    .join(ModelZoo::digest(url.as_bytes()))
+                    .with_extension("onnx")
+            );
+            assert_eq!(fs::read(&path).unwrap(), MODEL);
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+
+        #[test]
+        fn should_reject_unknown_references() {
+            let (directory, _) = setup("unknown");
+
+            let zoo = ModelZoo::new(directory.join("cache"));
+
+            assert!(zoo.resolve("missing").is_err());
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+
+        #[test]
+        fn should_reuse_cached_models() {
+            let (directory, url) = setup("cache-hit");
+
+            let zoo = make_zoo(&directory, &url, Some(ModelZoo::digest(MODEL)));
+
+            let path = zoo.resolve("tiny").unwrap();
+
+            fs::remove_file(directory.join("source.onnx")).unwrap();
+
+            assert_eq!(zoo.resolve("tiny").unwrap(), path);
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+
+        #[test]
+        fn should_refetch_cached_models_that_fail_verification() {
+            let (directory, url) = setup("cache-miss");
+
+            let zoo = make_zoo(&directory, &url, Some(ModelZoo::digest(MODEL)));
+
+            let path = zoo.resolve("tiny").unwrap();
+
+            fs::write(&path, b"corrupt").unwrap();
+
+            assert_eq!(zoo.resolve("tiny").unwrap(), path);
+            assert_eq!(fs::read(&path).unwrap(), MODEL);
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+
+        #[test]
+        fn should_reject_checksum_mismatches() {
+            let (directory, url) = setup("mismatch");
+
+            let zoo = make_zoo(&directory, &url, Some("0".repeat(64)));
+
+            let error = zoo.resolve("tiny").unwrap_err();
+
+            assert!(error.to_string().contains("checksum mismatch"));
+            assert!(!directory.join("cache").join("tiny.onnx").exists());
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+
+        #[test]
+        fn should_rename_the_partial_download_into_place() {
+            let (directory, url) = setup("partial");
+
+            let zoo = make_zoo(&directory, &url, None);
+
+            let partial_path = directory.join("cache").join("tiny.partial");
+
+            fs::create_dir_all(directory.join("cache")).unwrap();
+            fs::write(&partial_path, b"interrupted").unwrap();
+
+            let path = zoo.resolve("tiny").unwrap();
+
+            assert_eq!(fs::read(&path).unwrap(), MODEL);
+            assert!(!partial_path.exists());
+
+            fs::remove_dir_all(&directory).unwrap();
+        }
+    }
+}
@@ -1,10 +1,86 @@
 pub trait NeuralNetwork {
-    fn with_seed(self, seed: u64) -> Self;
+    fn reseed(&mut self, seed: u64);
+
+    fn with_seed(mut self, seed: u64) -> Self
+    where
+        Self: Sized,
+    {
+        self.reseed(seed);
+
+        self
+    }
+
+    // NOTE - Called once before each game, for networks that change their model between
+    // games rather than mid-search. Returns whether the model changed, so that caches in
+    // front of it can drop stale predictions.
+
+    fn new_game(&mut self) -> bool {
+        false
+    }
 
     fn predict(&mut self, input: &[f32]) -> Prediction;
+
+    // NOTE - Networks that can run several states in one inference call should override
+    // this. Predictions are returned in input order.
+
+    fn predict_batch(&mut self, inputs: &[Vec<f32>]) -> Vec<Prediction> {
+        inputs.iter().map(|input| self.predict(input)).collect()
+    }
 }
 
+#[derive(Clone)]
 pub struct Prediction {
     pub policy_logits: Vec<f32>,
     pub value: f32,
+    pub draw_probability: Option<f32>,
+}
+
+impl Prediction {
+    // NOTE - For models whose value head outputs win, draw and loss logits. Search backs up
+    // the expected value, and the draw probability is kept for contempt and analysis.
+
+    pub fn from_wdl_logits(policy_logits: Vec<f32>, wdl_logits: [f32; 3]) -> Self {
+        let max = wdl_logits.into_iter().fold(f32::NEG_INFINITY, f32::max);
+
+        let [win, draw, loss] = wdl_logits.map(|logit| (logit - max).exp());
+
+        let total = win + draw + loss;
+
+        Prediction {
+            policy_logits,
+            value: (win - loss) / total,
+            draw_probability: Some(draw / total),
+        }
+    }
+
+    // NOTE - A value head with three outputs is read as win, draw and loss logits, and any
+    // other as a single value.
+
+    pub(crate) fn from_value_outputs(policy_logits: Vec<f32>, values: &[f32]) -> Self {
+        match *values {
+            [win, draw, loss] => Self::from_wdl_logits(policy_logits, [win, draw, loss]),
+            _ => Prediction {
+                policy_logits,
+                value: *values.first().expect("value output is empty"),
+                draw_probability: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod from_wdl_logits {
+        use super::*;
+
+        #[test]
+        fn should_convert_logits_to_an_expected_value() {
+            let prediction = Prediction::from_wdl_logits(vec![], [2.0_f32.ln(), 0.0, 0.0]);
+
+            assert!((prediction.value - 0.25).abs() < 1e-6);
+            assert!((prediction.draw_probability.unwrap() - 0.25).abs() < 1e-6);
+        }
+    }
 }
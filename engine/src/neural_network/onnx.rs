@@ -5,6 +5,8 @@ use std::sync::Arc;
 use tract_onnx::prelude::*;
 
 use crate::core::Game;
+use crate::neural_network::action_encoder::ActionEncoder;
+use crate::neural_network::model_shape_error::ModelShapeError;
 use crate::neural_network::neural_network::{NeuralNetwork, Prediction};
 use crate::neural_network::state_encoder::StateEncoder;
 
@@ -20,37 +22,134 @@ pub struct OnnxNeuralNetwork<G: Game, SE: StateEncoder<G>> {
 }
 
 impl<G: Game, SE: StateEncoder<G>> OnnxNeuralNetwork<G, SE> {
-    pub fn new(path: impl AsRef<std::path::Path>, state_encoder: SE) -> Result<Self, Box<dyn Error>> {
-        let model = tract_onnx::onnx()
-            .model_for_path(path)?
-            .into_optimized()?
-            .into_runnable()?;
+    // NOTE - The action encoder is only used to check the size of the policy output, and
+    // is not kept.
 
-        Ok(Self {
-            model: Arc::new(model),
-            state_encoder,
-            _phantom: PhantomData,
-        })
+    pub fn new(
+        path: impl AsRef<std::path::Path>,
+        state_encoder: SE,
+        action_encoder: impl ActionEncoder<G>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let model = tract_onnx::onnx().model_for_path(path)?.into_optimized()?;
+
+        Self::from_model(model, state_encoder, action_encoder.size())
     }
 
-    pub fn new_from_bytes(bytes: &[u8], state_encoder: SE) -> Result<Self, Box<dyn Error>> {
+    pub fn new_from_bytes(
+        bytes: &[u8],
+        state_encoder: SE,
+        action_encoder: impl ActionEncoder<G>,
+    ) -> Result<Self, Box<dyn Error>> {
         let model = tract_onnx::onnx()
             .model_for_read(&mut std::io::Cursor::new(bytes))?
-            .into_optimized()?
-            .into_runnable()?;
+            .into_optimized()?;
+
+        Self::from_model(model, state_encoder, action_encoder.size())
+    }
+
+    // NOTE - Shapes are checked up front so that a model exported for other encoders fails
+    // to load, rather than on its first inference. The batch dimension is not checked, nor
+    // are sizes the model leaves symbolic.
+
+    fn from_model(
+        model: TypedModel,
+        state_encoder: SE,
+        action_count: usize,
+    ) -> Result<Self, Box<dyn Error>> {
+        let shape_of = |fact: &TypedFact| -> Vec<Option<usize>> {
+            fact.shape
+                .iter()
+                .map(|dim| dim.as_i64().and_then(|dim| usize::try_from(dim).ok()))
+                .collect()
+        };
+
+        let expected = state_encoder.shape();
+        let input_shape = shape_of(model.input_fact(0)?);
+
+        if input_shape.len() != expected.len()
+            || input_shape
+                .iter()
+                .zip(&expected)
+                .skip(1)
+                .any(|(actual, expected)| actual.is_some_and(|actual| actual != *expected))
+        {
+            return Err(ModelShapeError::Input {
+                expected,
+                actual: input_shape,
+            }
+            .into());
+        }
+
+        if model.output_outlets()?.len() < 2 {
+            return Err(ModelShapeError::OutputCount {
+                actual: model.output_outlets()?.len(),
+            }
+            .into());
+        }
+
+        let policy_shape = shape_of(model.output_fact(0)?);
+        let value_shape = shape_of(model.output_fact(1)?);
+
+        if matches!(policy_shape.last(), Some(Some(size)) if *size != action_count) {
+            return Err(ModelShapeError::Policy {
+                expected: action_count,
+                actual: policy_shape,
+            }
+            .into());
+        }
+
+        if value_shape.len() > 1 && !matches!(value_shape.last(), Some(None | Some(1 | 3))) {
+            return Err(ModelShapeError::Value {
+                actual: value_shape,
+            }
+            .into());
+        }
 
         Ok(Self {
-            model: Arc::new(model),
+            model: Arc::new(model.into_runnable()?),
             state_encoder,
             _phantom: PhantomData,
         })
     }
+
+    fn run_batch(&self, inputs: &[Vec<f32>]) -> Option<Vec<Prediction>> {
+        let mut shape = self.state_encoder.shape();
+        shape[0] = inputs.len();
+
+        let tensor: Tensor =
+            tract_ndarray::Array::from_shape_vec(tract_ndarray::IxDyn(&shape), inputs.concat())
+                .expect("failed to create input tensor")
+                .into();
+
+        let result = self.model.run(tvec!(tensor.into())).ok()?;
+
+        let policy_logits = result[0].to_array_view::<f32>().ok()?;
+        let values = result[1].to_array_view::<f32>().ok()?;
+
+        if values.len() % inputs.len() != 0 || policy_logits.len() % inputs.len() != 0 {
+            return None;
+        }
+
+        let policy_size = policy_logits.len() / inputs.len();
+        let value_size = values.len() / inputs.len();
+
+        let policy_logits: Vec<f32> = policy_logits.iter().copied().collect();
+        let values: Vec<f32> = values.iter().copied().collect();
+
+        Some(
+            policy_logits
+                .chunks(policy_size)
+                .zip(values.chunks(value_size))
+                .map(|(policy_logits, values)| {
+                    Prediction::from_value_outputs(policy_logits.to_vec(), values)
+                })
+                .collect(),
+        )
+    }
 }
 
 impl<G: Game, SE: StateEncoder<G>> NeuralNetwork for OnnxNeuralNetwork<G, SE> {
-    fn with_seed(self, _seed: u64) -> Self {
-        self
-    }
+    fn reseed(&mut self, _seed: u64) {}
 
     fn predict(&mut self, input: &[f32]) -> Prediction {
         let shape = self.state_encoder.shape();
@@ -60,7 +159,10 @@ impl<G: Game, SE: StateEncoder<G>> NeuralNetwork for OnnxNeuralNetwork<G, SE> {
                 .expect("failed to create input tensor")
                 .into();
 
-        let result = self.model.run(tvec!(tensor.into())).expect("failed to run model");
+        let result = self
+            .model
+            .run(tvec!(tensor.into()))
+            .expect("failed to run model");
 
         let policy_logits: Vec<f32> = result[0]
             .to_array_view::<f32>()
@@ -69,16 +171,26 @@ impl<G: Game, SE: StateEncoder<G>> NeuralNetwork for OnnxNeuralNetwork<G, SE> {
             .copied()
             .collect();
 
-        let value = *result[1]
+        let values: Vec<f32> = result[1]
             .to_array_view::<f32>()
             .expect("failed to extract value")
             .iter()
-            .next()
-            .expect("value output is empty");
+            .copied()
+            .collect();
 
-        Prediction {
-            policy_logits,
-            value,
+        Prediction::from_value_outputs(policy_logits, &values)
+    }
+
+    // NOTE - The states are stacked along the batch dimension. Models exported with a fixed
+    // batch size of one cannot run that, so they fall back to one inference call per state.
+
+    fn predict_batch(&mut self, inputs: &[Vec<f32>]) -> Vec<Prediction> {
+        if inputs.len() > 1
+            && let Some(predictions) = self.run_batch(inputs)
+        {
+            return predictions;
         }
+
+        inputs.iter().map(|input| self.predict(input)).collect()
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,99 @@
+use crate::core::{Game, PieRule, PieRuleAction};
+use crate::neural_network::{ActionEncoder, StateEncoder};
+
+// NOTE - The swap takes the id after the wrapped game's actions, so a policy head for the
+// pie rule is one wider than the plain game's.
+
+#[derive(Clone, Copy, Default)]
+pub struct PieRuleActionEncoder<AE> {
+    inner: AE,
+}
+
+impl<AE> PieRuleActionEncoder<AE> {
+    pub fn new(inner: AE) -> Self {
+        Self { inner }
+    }
+}
+
+impl<G: Game, AE: ActionEncoder<G>> ActionEncoder<PieRule<G>> for PieRuleActionEncoder<AE> {
+    const ACTION_COUNT: usize = AE::ACTION_COUNT + 1;
+
+    fn size(&self) -> usize {
+        self.inner.size() + 1
+    }
+
+    fn encode(&self, action: &PieRuleAction<G::Action>) -> usize {
+        match action {
+            PieRuleAction::Play(action) => self.inner.encode(action),
+            PieRuleAction::Swap => self.inner.size(),
+        }
+    }
+
+    fn decode(&self, action_id: usize) -> PieRuleAction<G::Action> {
+        if action_id == self.inner.size() {
+            PieRuleAction::Swap
+        } else {
+            PieRuleAction::Play(self.inner.decode(action_id))
+        }
+    }
+}
+
+// NOTE - The wrapped game's planes are passed through unchanged. The offer only stands
+// while a single first move is on the board, which the planes already show.
+
+#[derive(Clone, Copy, Default)]
+pub struct PieRuleStateEncoder<SE> {
+    inner: SE,
+}
+
+impl<SE> PieRuleStateEncoder<SE> {
+    pub fn new(inner: SE) -> Self {
+        Self { inner }
+    }
+}
+
+impl<G: Game, SE: StateEncoder<G>> StateEncoder<PieRule<G>> for PieRuleStateEncoder<SE> {
+    fn shape(&self) -> Vec<usize> {
+        self.inner.shape()
+    }
+
+    fn encode(&self, state: &PieRule<G>) -> Vec<f32> {
+        self.inner.encode(&state.game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::hex::{Hex, HexActionEncoder};
+
+    type PieHex = PieRule<Hex<3>>;
+
+    mod encode {
+        use super::*;
+
+        #[test]
+        fn should_put_the_swap_after_the_wrapped_actions() {
+            let encoder = PieRuleActionEncoder::new(HexActionEncoder::<3>::new());
+
+            let game = PieHex::new();
+
+            let action = game.get_possible_actions()[0];
+
+            assert_eq!(ActionEncoder::<PieHex>::size(&encoder), 10);
+            assert_eq!(
+                ActionEncoder::<PieHex>::encode(&encoder, &PieRuleAction::Swap),
+                9
+            );
+            assert_eq!(
+                ActionEncoder::<PieHex>::decode(&encoder, 9),
+                PieRuleAction::Swap
+            );
+            assert_eq!(
+                ActionEncoder::<PieHex>::decode(&encoder, encoder.encode(&action)),
+                action
+            );
+        }
+    }
+}
@@ -1,30 +1,36 @@
-use rand::rngs::StdRng;
-use rand::{RngExt, SeedableRng, rng};
+use rand::RngExt;
 use rand_distr::{Distribution, Normal};
 
+use crate::core::{DefaultRngFactory, RngFactory};
 use crate::neural_network::neural_network::{NeuralNetwork, Prediction};
 
-pub struct RandomNeuralNetwork {
-    rng: StdRng,
+pub struct RandomNeuralNetwork<F: RngFactory = DefaultRngFactory> {
+    rng_factory: F,
+    rng: F::Rng,
 
     policy_size: usize,
 }
 
 impl RandomNeuralNetwork {
     pub fn new(policy_size: usize) -> Self {
+        Self::with_rng_factory(policy_size, DefaultRngFactory::default())
+    }
+}
+
+impl<F: RngFactory> RandomNeuralNetwork<F> {
+    pub fn with_rng_factory(policy_size: usize, rng_factory: F) -> Self {
         Self {
-            rng: StdRng::from_rng(&mut rng()),
+            rng: rng_factory.create(),
+            rng_factory,
 
             policy_size,
         }
     }
 }
 
-impl NeuralNetwork for RandomNeuralNetwork {
-    fn with_seed(mut self, seed: u64) -> Self {
-        self.rng = StdRng::seed_from_u64(seed);
-
-        self
+impl<F: RngFactory> NeuralNetwork for RandomNeuralNetwork<F> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = self.rng_factory.create_seeded(seed);
     }
 
     fn predict(&mut self, _input: &[f32]) -> Prediction {
@@ -39,6 +45,7 @@ impl NeuralNetwork for RandomNeuralNetwork {
         Prediction {
             policy_logits,
             value,
+            draw_probability: None,
         }
     }
 }
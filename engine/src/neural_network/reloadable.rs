@@ -0,0 +1,185 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::neural_network::neural_network::{NeuralNetwork, Prediction};
+
+// NOTE - Follows a model file that is replaced during a long run, such as a `latest.onnx`
+// symlink that training repoints at each new checkpoint. The file is checked in `new_game`,
+// which runners call before every game, and reloaded with `load` if it has been modified
+// or the symlink now points elsewhere, so a model never changes mid-game. A new model only
+// replaces the old one once it has loaded, so a file caught mid-write keeps the old weights
+// and leaves the error in `last_error` until the next check. Writing checkpoints elsewhere
+// and renaming them into place avoids that.
+
+pub struct ReloadableNeuralNetwork<NN: NeuralNetwork> {
+    neural_network: NN,
+
+    path: PathBuf,
+    load: ModelLoader<NN>,
+    version: Option<ModelVersion>,
+    last_error: Option<String>,
+}
+
+type ModelLoader<NN> = fn(&Path) -> Result<NN, Box<dyn Error>>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ModelVersion {
+    target: PathBuf,
+    modified: SystemTime,
+    len: u64,
+}
+
+impl<NN: NeuralNetwork + Clone> Clone for ReloadableNeuralNetwork<NN> {
+    fn clone(&self) -> Self {
+        Self {
+            neural_network: self.neural_network.clone(),
+
+            path: self.path.clone(),
+            load: self.load,
+            version: self.version.clone(),
+            last_error: self.last_error.clone(),
+        }
+    }
+}
+
+impl<NN: NeuralNetwork> ReloadableNeuralNetwork<NN> {
+    pub fn new(path: impl Into<PathBuf>, load: ModelLoader<NN>) -> Result<Self, Box<dyn Error>> {
+        let path = path.into();
+
+        let version = Self::version_of(&path);
+        let neural_network = load(&path)?;
+
+        Ok(Self {
+            neural_network,
+
+            path,
+            load,
+            version,
+            last_error: None,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn inner(&self) -> &NN {
+        &self.neural_network
+    }
+
+    // NOTE - The error from the last check in `new_game`, if the file could not be loaded.
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    // NOTE - Returns whether a new model was swapped in. The old model is kept when the
+    // file cannot be loaded.
+
+    pub fn reload_if_changed(&mut self) -> Result<bool, Box<dyn Error>> {
+        let version = Self::version_of(&self.path);
+
+        if version.is_none() || version == self.version {
+            return Ok(false);
+        }
+
+        self.neural_network = (self.load)(&self.path)?;
+        self.version = version;
+
+        Ok(true)
+    }
+
+    fn version_of(path: &Path) -> Option<ModelVersion> {
+        let target = fs::canonicalize(path).ok()?;
+        let metadata = fs::metadata(&target).ok()?;
+
+        Some(ModelVersion {
+            target,
+            modified: metadata.modified().ok()?,
+            len: metadata.len(),
+        })
+    }
+}
+
+impl<NN: NeuralNetwork> NeuralNetwork for ReloadableNeuralNetwork<NN> {
+    fn reseed(&mut self, seed: u64) {
+        self.neural_network.reseed(seed);
+    }
+
+    fn new_game(&mut self) -> bool {
+        let reloaded = match self.reload_if_changed() {
+            Ok(reloaded) => {
+                self.last_error = None;
+
+                reloaded
+            }
+            Err(error) => {
+                self.last_error =
+                    Some(format!("failed to reload {}: {error}", self.path.display()));
+
+                false
+            }
+        };
+
+        self.neural_network.new_game() || reloaded
+    }
+
+    fn predict(&mut self, input: &[f32]) -> Prediction {
+        self.neural_network.predict(input)
+    }
+
+    fn predict_batch(&mut self, inputs: &[Vec<f32>]) -> Vec<Prediction> {
+        self.neural_network.predict_batch(inputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+
+    use crate::neural_network::ConstantNeuralNetwork;
+
+    fn load(path: &Path) -> Result<ConstantNeuralNetwork, Box<dyn Error>> {
+        let value: f32 = fs::read_to_string(path)?.trim().parse()?;
+
+        Ok(ConstantNeuralNetwork::new(1).with_value(value))
+    }
+
+    mod new_game {
+        use super::*;
+
+        #[test]
+        fn should_swap_in_a_changed_model() {
+            let path = std::env::temp_dir().join(format!("hermes-reload-{}", process::id()));
+
+            fs::write(&path, "0.5").unwrap();
+
+            let mut neural_network = ReloadableNeuralNetwork::new(&path, load).unwrap();
+
+            assert!(!neural_network.new_game());
+            assert!((neural_network.predict(&[]).value - 0.5).abs() < f32::EPSILON);
+
+            fs::write(&path, "not a model").unwrap();
+
+            assert!(!neural_network.new_game());
+            assert!((neural_network.predict(&[]).value - 0.5).abs() < f32::EPSILON);
+            assert!(neural_network.last_error().is_some());
+
+            fs::write(&path, "-0.25").unwrap();
+
+            neural_network.reseed(2);
+
+            assert!((neural_network.predict(&[]).value - 0.5).abs() < f32::EPSILON);
+
+            assert!(neural_network.new_game());
+            assert!((neural_network.predict(&[]).value + 0.25).abs() < f32::EPSILON);
+            assert_eq!(neural_network.last_error(), None);
+
+            fs::remove_file(&path).unwrap();
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use std::time::Duration;
+
+use crate::core::{Choice, EventSink, Game, Player, Policy};
+use crate::player::analyzing::move_analysis::MoveAnalysis;
+
+// NOTE - Passes every choice of an inner player through unchanged, and emits a
+// `MoveAnalysis` of it to the sink, so a game's evaluations can be reviewed afterwards.
+
+pub struct AnalyzingPlayer<P, S> {
+    player: P,
+    sink: S,
+
+    last_move: Option<(u32, f32)>,
+}
+
+impl<P: Clone, S: Clone> Clone for AnalyzingPlayer<P, S> {
+    fn clone(&self) -> Self {
+        Self {
+            player: self.player.clone(),
+            sink: self.sink.clone(),
+
+            last_move: None,
+        }
+    }
+}
+
+impl<P, S> AnalyzingPlayer<P, S> {
+    pub fn new(player: P, sink: S) -> Self {
+        Self {
+            player,
+            sink,
+
+            last_move: None,
+        }
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.player
+    }
+
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    fn analyze<G: Game>(
+        &mut self,
+        game: &G,
+        turn_number: u32,
+        choice: &Choice<G>,
+    ) -> MoveAnalysis<G> {
+        // NOTE - A turn number no later than the last move's means a new game.
+
+        if self
+            .last_move
+            .is_some_and(|(last_turn_number, _)| turn_number <= last_turn_number)
+        {
+            self.last_move = None;
+        }
+
+        let value = choice
+            .evaluation
+            .as_ref()
+            .map(|evaluation| evaluation.value);
+
+        let value_change = value
+            .zip(self.last_move)
+            .map(|(value, (_, last_value))| value - last_value);
+
+        if let Some(value) = value {
+            self.last_move = Some((turn_number, value));
+        }
+
+        let mut policy: Policy<G> = choice
+            .evaluation
+            .as_ref()
+            .map(|evaluation| evaluation.policy.clone())
+            .unwrap_or_default();
+
+        policy.sort_by(|x, y| y.prior.total_cmp(&x.prior));
+
+        let chosen_rank = policy
+            .iter()
+            .position(|policy_item| policy_item.action == choice.action);
+
+        MoveAnalysis {
+            turn_number,
+            position: game.clone(),
+            action: choice.action,
+            resign: choice.resign,
+
+            value,
+            value_change,
+
+            policy,
+            chosen_rank,
+        }
+    }
+}
+
+impl<G, P, S> Player<G> for AnalyzingPlayer<P, S>
+where
+    G: Game,
+    P: Player<G>,
+    S: EventSink<MoveAnalysis<G>>,
+{
+    fn name(&self) -> &str {
+        self.player.name()
+    }
+
+    fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
+        let choice = self.player.choose_action(game, turn_number);
+
+        let analysis = self.analyze(game, turn_number, &choice);
+
+        self.sink.emit(analysis);
+
+        choice
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.player.reseed(seed);
+    }
+
+    fn new_game(&mut self) {
+        self.player.new_game();
+    }
+
+    fn set_clock(&mut self, remaining: Duration, increment: Duration) {
+        self.player.set_clock(remaining, increment);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{Evaluation, PolicyItem};
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    struct ScriptedPlayer {
+        values: Vec<f32>,
+    }
+
+    impl Player<TicTacToe> for ScriptedPlayer {
+        fn name(&self) -> &'static str {
+            "Scripted"
+        }
+
+        fn choose_action(&mut self, _game: &TicTacToe, _turn_number: u32) -> Choice<TicTacToe> {
+            let place = |index| TicTacToeAction::Place { index };
+
+            Choice {
+                evaluation: Some(Evaluation {
+                    policy: [(0, 0.2), (4, 0.7), (8, 0.1)]
+                        .iter()
+                        .map(|&(index, prior)| PolicyItem {
+                            action: place(index),
+                            prior,
+                        })
+                        .collect(),
+                    value: self.values.remove(0),
+                }),
+                action: place(0),
+                resign: false,
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct AnalysisRecorder {
+        analyses: Vec<MoveAnalysis<TicTacToe>>,
+    }
+
+    impl EventSink<MoveAnalysis<TicTacToe>> for AnalysisRecorder {
+        fn emit(&mut self, analysis: MoveAnalysis<TicTacToe>) {
+            self.analyses.push(analysis);
+        }
+    }
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_emit_the_value_trend_and_rank_of_each_move() {
+            let mut player = AnalyzingPlayer::new(
+                ScriptedPlayer {
+                    values: vec![0.1, 0.4, 0.2],
+                },
+                AnalysisRecorder::default(),
+            );
+
+            let game = TicTacToe::new();
+
+            player.choose_action(&game, 0);
+            player.choose_action(&game, 2);
+            player.choose_action(&game, 0);
+
+            let analyses = &player.sink().analyses;
+
+            let changes: Vec<Option<f32>> = analyses
+                .iter()
+                .map(|analysis| analysis.value_change)
+                .collect();
+
+            assert_eq!(changes[0], None);
+            assert!((changes[1].unwrap() - 0.3).abs() < 1e-6);
+            assert_eq!(changes[2], None);
+
+            assert_eq!(analyses[0].chosen_rank, Some(1));
+            assert_eq!(
+                analyses[0].policy[0].action,
+                TicTacToeAction::Place { index: 4 }
+            );
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod analyzing_player;
+mod move_analysis;
+
+pub use analyzing_player::AnalyzingPlayer;
+pub use move_analysis::MoveAnalysis;
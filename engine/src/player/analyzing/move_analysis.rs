@@ -0,0 +1,20 @@
+use crate::core::{Game, Policy};
+
+// NOTE - What a player saw when it moved. Values are from the mover's side, and the change
+// is against the same player's previous move of the game. The policy is sorted most likely
+// first, and `chosen_rank` is where the played action falls in it. Players that return no
+// evaluation leave the value and policy empty.
+
+#[derive(Clone)]
+pub struct MoveAnalysis<G: Game> {
+    pub turn_number: u32,
+    pub position: G,
+    pub action: G::Action,
+    pub resign: bool,
+
+    pub value: Option<f32>,
+    pub value_change: Option<f32>,
+
+    pub policy: Policy<G>,
+    pub chosen_rank: Option<usize>,
+}
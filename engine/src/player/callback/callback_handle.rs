@@ -0,0 +1,34 @@
+use std::sync::mpsc::{Receiver, Sender, TryRecvError};
+
+use crate::core::Game;
+
+// NOTE - The frontend's side of a `CallbackPlayer`. Turns arrive as the position and turn
+// number to move in, and are answered with `play` or `resign`. Every method reports whether
+// the player is still there to talk to.
+
+pub struct CallbackHandle<G: Game> {
+    pub(super) turn_receiver: Receiver<(G, u32)>,
+    pub(super) action_sender: Sender<Option<G::Action>>,
+}
+
+impl<G: Game> CallbackHandle<G> {
+    pub fn next_turn(&self) -> Option<(G, u32)> {
+        self.turn_receiver.recv().ok()
+    }
+
+    pub fn try_next_turn(&self) -> Result<Option<(G, u32)>, String> {
+        match self.turn_receiver.try_recv() {
+            Ok(turn) => Ok(Some(turn)),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err("callback player disconnected".to_string()),
+        }
+    }
+
+    pub fn play(&self, action: G::Action) -> bool {
+        self.action_sender.send(Some(action)).is_ok()
+    }
+
+    pub fn resign(&self) -> bool {
+        self.action_sender.send(None).is_ok()
+    }
+}
@@ -0,0 +1,169 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use crate::core::{Choice, Game, Player};
+use crate::player::callback::callback_handle::CallbackHandle;
+
+// NOTE - Plays whatever action is injected through its `CallbackHandle`, so that a GUI or a
+// web server can drive human input from another thread. Each turn is announced on the
+// handle, and the player blocks until it is answered, so the game must run on a different
+// thread than the frontend. Single-threaded targets such as wasm32 cannot block like this,
+// and step the game themselves instead, as the WASM bindings do with `queue_action` and
+// `step`. Illegal actions are ignored, and a dropped handle is taken as resigning. Anything
+// sent before a turn is announced is stale, e.g. a click during the opponent's move, and
+// is dropped.
+
+pub struct CallbackPlayer<G: Game> {
+    turn_sender: Sender<(G, u32)>,
+    action_receiver: Receiver<Option<G::Action>>,
+}
+
+impl<G: Game> CallbackPlayer<G> {
+    pub fn new() -> (Self, CallbackHandle<G>) {
+        let (turn_sender, turn_receiver) = mpsc::channel();
+        let (action_sender, action_receiver) = mpsc::channel();
+
+        let player = Self {
+            turn_sender,
+            action_receiver,
+        };
+
+        let handle = CallbackHandle {
+            turn_receiver,
+            action_sender,
+        };
+
+        (player, handle)
+    }
+
+    fn wait_for_action(&self, game: &G, turn_number: u32) -> Option<G::Action> {
+        while self.action_receiver.try_recv().is_ok() {}
+
+        self.turn_sender.send((game.clone(), turn_number)).ok()?;
+
+        let actions = game.get_possible_actions();
+
+        loop {
+            let action = self.action_receiver.recv().ok()??;
+
+            if actions.contains(&action) {
+                return Some(action);
+            }
+        }
+    }
+}
+
+impl<G: Game> Player<G> for CallbackPlayer<G> {
+    fn name(&self) -> &'static str {
+        "Callback"
+    }
+
+    fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
+        match self.wait_for_action(game, turn_number) {
+            Some(action) => Choice {
+                evaluation: None,
+                action,
+                resign: false,
+            },
+            None => Choice {
+                evaluation: None,
+                action: game.get_possible_actions()[0],
+                resign: true,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_play_the_first_legal_action_injected() {
+            let (mut player, handle) = CallbackPlayer::<TicTacToe>::new();
+
+            let frontend = thread::spawn(move || {
+                let (_, turn_number) = handle.next_turn().unwrap();
+
+                handle.play(TicTacToeAction::Place { index: 9 });
+                handle.play(TicTacToeAction::Place { index: 4 });
+
+                turn_number
+            });
+
+            let choice = player.choose_action(&TicTacToe::new(), 3);
+
+            assert_eq!(choice.action, TicTacToeAction::Place { index: 4 });
+            assert!(!choice.resign);
+            assert_eq!(frontend.join().unwrap(), 3);
+        }
+
+        #[test]
+        fn should_drop_actions_sent_before_the_turn() {
+            let (mut player, handle) = CallbackPlayer::<TicTacToe>::new();
+
+            handle.play(TicTacToeAction::Place { index: 0 });
+            handle.resign();
+
+            let frontend = thread::spawn(move || {
+                handle.next_turn().unwrap();
+
+                handle.play(TicTacToeAction::Place { index: 4 });
+            });
+
+            let choice = player.choose_action(&TicTacToe::new(), 0);
+
+            assert_eq!(choice.action, TicTacToeAction::Place { index: 4 });
+            assert!(!choice.resign);
+
+            frontend.join().unwrap();
+        }
+
+        #[test]
+        fn should_resign_when_the_handle_is_dropped() {
+            let (mut player, handle) = CallbackPlayer::<TicTacToe>::new();
+
+            drop(handle);
+
+            assert!(player.choose_action(&TicTacToe::new(), 0).resign);
+        }
+    }
+
+    mod run {
+        use super::*;
+
+        use crate::core::{Runner, StatisticsRunnerEventSink};
+        use crate::player::RandomPlayer;
+
+        #[test]
+        fn should_play_a_full_game_through_the_runner() {
+            let (player, handle) = CallbackPlayer::<TicTacToe>::new();
+
+            let game = thread::spawn(move || {
+                let mut runner = Runner::new(
+                    1,
+                    player,
+                    RandomPlayer::new(),
+                    StatisticsRunnerEventSink::new(),
+                )
+                .with_seed(2);
+
+                runner.run();
+
+                runner.sink().total_games
+            });
+
+            while let Some((position, _)) = handle.next_turn() {
+                handle.play(position.get_possible_actions()[0]);
+            }
+
+            assert_eq!(game.join().unwrap(), 1);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod callback_handle;
+mod callback_player;
+
+pub use callback_handle::CallbackHandle;
+pub use callback_player::CallbackPlayer;
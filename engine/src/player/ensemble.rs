@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::core::{Choice, Evaluation, Game, Player, Policy, PolicyItem, derive_seed};
+
+// NOTE - How the ensemble combines its players' choices. `MajorityVote` plays the action
+// with the most weight behind it. `PolicyMixing` mixes the players' policies, such as the
+// visit distributions of searches, by weight and plays the most likely action, counting a
+// player that returns no evaluation as all-in on its choice. Ties go to the action chosen
+// by the earlier player.
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum EnsembleMode {
+    #[default]
+    MajorityVote,
+    PolicyMixing,
+}
+
+pub struct EnsemblePlayer<G: Game> {
+    mode: EnsembleMode,
+    players: Vec<(Box<dyn Player<G>>, f32)>,
+}
+
+impl<G: Game> EnsemblePlayer<G> {
+    pub fn new(mode: EnsembleMode) -> Self {
+        Self {
+            mode,
+            players: vec![],
+        }
+    }
+
+    pub fn with_player(mut self, player: impl Player<G> + 'static, weight: f32) -> Self {
+        self.players.push((Box::new(player), weight));
+
+        self
+    }
+
+    fn combine(&self, choices: &[Choice<G>]) -> (G::Action, Option<Evaluation<G>>) {
+        let mut order: Vec<G::Action> = vec![];
+        let mut scores: HashMap<G::Action, f32> = HashMap::new();
+
+        let mut add = |action: G::Action, score: f32| {
+            if !scores.contains_key(&action) {
+                order.push(action);
+            }
+
+            *scores.entry(action).or_insert(0.0) += score;
+        };
+
+        let total_weight: f32 = self.players.iter().map(|(_, weight)| weight).sum();
+
+        for (choice, (_, weight)) in choices.iter().zip(&self.players) {
+            add(choice.action, 0.0);
+
+            match (&choice.evaluation, self.mode) {
+                (Some(evaluation), EnsembleMode::PolicyMixing) => {
+                    for policy_item in &evaluation.policy {
+                        add(policy_item.action, weight * policy_item.prior);
+                    }
+                }
+                _ => add(choice.action, *weight),
+            }
+        }
+
+        let action = order
+            .iter()
+            .rev()
+            .max_by(|x, y| scores[x].total_cmp(&scores[y]))
+            .copied()
+            .expect("ensemble has no players");
+
+        if self.mode == EnsembleMode::MajorityVote {
+            return (action, None);
+        }
+
+        let policy: Policy<G> = order
+            .iter()
+            .map(|action| PolicyItem {
+                action: *action,
+                prior: scores[action] / total_weight.max(f32::EPSILON),
+            })
+            .collect();
+
+        let (value_total, value_weight) = choices
+            .iter()
+            .zip(&self.players)
+            .filter_map(|(choice, (_, weight))| {
+                choice
+                    .evaluation
+                    .as_ref()
+                    .map(|evaluation| (weight * evaluation.value, *weight))
+            })
+            .fold((0.0, 0.0), |(x, y), (value, weight)| {
+                (x + value, y + weight)
+            });
+
+        let value = if value_weight > 0.0 {
+            value_total / value_weight
+        } else {
+            0.0
+        };
+
+        (action, Some(Evaluation { policy, value }))
+    }
+}
+
+impl<G: Game> Player<G> for EnsemblePlayer<G> {
+    fn name(&self) -> &'static str {
+        "Ensemble"
+    }
+
+    // NOTE - The ensemble only resigns when every player would.
+
+    fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
+        let choices: Vec<Choice<G>> = self
+            .players
+            .iter_mut()
+            .map(|(player, _)| player.choose_action(game, turn_number))
+            .collect();
+
+        let resign = choices.iter().all(|choice| choice.resign);
+
+        let (action, evaluation) = self.combine(&choices);
+
+        Choice {
+            evaluation,
+            action,
+            resign,
+        }
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        for (i, (player, _)) in self.players.iter_mut().enumerate() {
+            player.reseed(derive_seed(seed, i as u64));
+        }
+    }
+
+    fn new_game(&mut self) {
+        for (player, _) in &mut self.players {
+            player.new_game();
+        }
+    }
+
+    fn set_clock(&mut self, remaining: Duration, increment: Duration) {
+        for (player, _) in &mut self.players {
+            player.set_clock(remaining, increment);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    struct FixedPlayer {
+        action: TicTacToeAction,
+        policy: Option<Vec<(u8, f32)>>,
+    }
+
+    impl Player<TicTacToe> for FixedPlayer {
+        fn name(&self) -> &'static str {
+            "Fixed"
+        }
+
+        fn choose_action(&mut self, _game: &TicTacToe, _turn_number: u32) -> Choice<TicTacToe> {
+            Choice {
+                evaluation: self.policy.as_ref().map(|policy| Evaluation {
+                    policy: policy
+                        .iter()
+                        .map(|&(index, prior)| PolicyItem {
+                            action: TicTacToeAction::Place { index },
+                            prior,
+                        })
+                        .collect(),
+                    value: 0.5,
+                }),
+                action: self.action,
+                resign: false,
+            }
+        }
+    }
+
+    fn fixed(index: u8, policy: Option<Vec<(u8, f32)>>) -> FixedPlayer {
+        FixedPlayer {
+            action: TicTacToeAction::Place { index },
+            policy,
+        }
+    }
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_play_the_majority_choice() {
+            let mut player = EnsemblePlayer::new(EnsembleMode::MajorityVote)
+                .with_player(fixed(0, None), 1.0)
+                .with_player(fixed(4, None), 1.0)
+                .with_player(fixed(4, None), 1.0);
+
+            let choice = player.choose_action(&TicTacToe::new(), 0);
+
+            assert_eq!(choice.action, TicTacToeAction::Place { index: 4 });
+            assert!(choice.evaluation.is_none());
+        }
+
+        #[test]
+        fn should_play_the_most_likely_action_of_the_mixed_policy() {
+            let mut player = EnsemblePlayer::new(EnsembleMode::PolicyMixing)
+                .with_player(fixed(0, Some(vec![(0, 0.6), (4, 0.4)])), 1.0)
+                .with_player(fixed(8, Some(vec![(4, 0.5), (8, 0.5)])), 1.0);
+
+            let choice = player.choose_action(&TicTacToe::new(), 0);
+
+            assert_eq!(choice.action, TicTacToeAction::Place { index: 4 });
+
+            let evaluation = choice.evaluation.unwrap();
+
+            assert!((evaluation.value - 0.5).abs() < f32::EPSILON);
+            assert!(
+                (evaluation.policy.iter().map(|item| item.prior).sum::<f32>() - 1.0).abs() < 1e-6
+            );
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use rand::RngExt;
+use rand::seq::IndexedRandom;
+
+use crate::core::{Choice, DefaultRngFactory, Game, Player, RngFactory, derive_seed};
+
+// NOTE - With probability `epsilon`, plays a uniformly random legal action instead of
+// asking the inner player, which also skips its search. Useful as an exploration baseline,
+// or to diversify the openings of self-play.
+
+pub struct EpsilonGreedyPlayer<P, F: RngFactory = DefaultRngFactory> {
+    player: P,
+
+    epsilon: f32,
+
+    rng_factory: F,
+    rng: F::Rng,
+}
+
+impl<P: Clone, F: RngFactory> Clone for EpsilonGreedyPlayer<P, F> {
+    fn clone(&self) -> Self {
+        Self {
+            player: self.player.clone(),
+
+            epsilon: self.epsilon,
+
+            rng_factory: self.rng_factory.clone(),
+            rng: self.rng_factory.create(),
+        }
+    }
+}
+
+impl<P> EpsilonGreedyPlayer<P> {
+    pub fn new(player: P, epsilon: f32) -> Self {
+        Self::with_rng_factory(player, epsilon, DefaultRngFactory::default())
+    }
+}
+
+impl<P, F: RngFactory> EpsilonGreedyPlayer<P, F> {
+    pub fn with_rng_factory(player: P, epsilon: f32, rng_factory: F) -> Self {
+        Self {
+            player,
+
+            epsilon,
+
+            rng: rng_factory.create(),
+            rng_factory,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = self.rng_factory.create_seeded(seed);
+
+        self
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.player
+    }
+}
+
+impl<G: Game, P: Player<G>, F: RngFactory> Player<G> for EpsilonGreedyPlayer<P, F> {
+    fn name(&self) -> &str {
+        self.player.name()
+    }
+
+    fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
+        if self.epsilon > 0.0
+            && self.rng.random::<f32>() < self.epsilon
+            && let Some(&action) = game.get_possible_actions().choose(&mut self.rng)
+        {
+            return Choice {
+                evaluation: None,
+                action,
+                resign: false,
+            };
+        }
+
+        self.player.choose_action(game, turn_number)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = self.rng_factory.create_seeded(seed);
+
+        self.player.reseed(derive_seed(seed, 0));
+    }
+
+    fn new_game(&mut self) {
+        self.player.new_game();
+    }
+
+    fn set_clock(&mut self, remaining: Duration, increment: Duration) {
+        self.player.set_clock(remaining, increment);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+    use crate::player::ManualPlayer;
+
+    fn actions(epsilon: f32) -> Vec<TicTacToeAction> {
+        let mut player = EpsilonGreedyPlayer::new(ManualPlayer::new(), epsilon).with_seed(3);
+
+        (0..50)
+            .map(|_| {
+                player
+                    .player
+                    .queue_action(TicTacToeAction::Place { index: 4 });
+
+                player.choose_action(&TicTacToe::new(), 0).action
+            })
+            .collect()
+    }
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_delegate_without_exploration() {
+            assert!(
+                actions(0.0)
+                    .iter()
+                    .all(|&action| action == TicTacToeAction::Place { index: 4 })
+            );
+        }
+
+        #[test]
+        fn should_play_random_actions_while_exploring() {
+            assert!(
+                actions(1.0)
+                    .iter()
+                    .any(|&action| action != TicTacToeAction::Place { index: 4 })
+            );
+        }
+    }
+    mod reseed {
+        use super::*;
+
+        use crate::player::RandomPlayer;
+
+        #[test]
+        fn should_reseed_the_inner_player_from_a_derived_seed() {
+            let game = TicTacToe::new();
+
+            let mut player = EpsilonGreedyPlayer::new(RandomPlayer::new(), 0.0);
+            let mut inner = RandomPlayer::new();
+
+            Player::<TicTacToe>::reseed(&mut player, 5);
+            Player::<TicTacToe>::reseed(&mut inner, derive_seed(5, 0));
+
+            for _ in 0..20 {
+                assert_eq!(
+                    player.choose_action(&game, 0).action,
+                    inner.choose_action(&game, 0).action
+                );
+            }
+        }
+    }
+}
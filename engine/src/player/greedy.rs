@@ -0,0 +1,121 @@
+use rand::seq::IndexedRandom;
+
+use crate::core::{Choice, DefaultRngFactory, Game, Outcome, Player, RngFactory};
+
+// NOTE - Looks a single action ahead, taking an immediate win when there is one and
+// otherwise playing randomly, which makes it a cheap anchor just above Random.
+
+pub struct GreedyPlayer<F: RngFactory = DefaultRngFactory> {
+    rng_factory: F,
+    rng: F::Rng,
+}
+
+impl<F: RngFactory> Clone for GreedyPlayer<F> {
+    fn clone(&self) -> Self {
+        Self {
+            rng_factory: self.rng_factory.clone(),
+            rng: self.rng_factory.create(),
+        }
+    }
+}
+
+impl GreedyPlayer {
+    pub fn new() -> Self {
+        Self::with_rng_factory(DefaultRngFactory::default())
+    }
+}
+
+impl<F: RngFactory> GreedyPlayer<F> {
+    pub fn with_rng_factory(rng_factory: F) -> Self {
+        Self {
+            rng: rng_factory.create(),
+            rng_factory,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = self.rng_factory.create_seeded(seed);
+
+        self
+    }
+}
+
+impl Default for GreedyPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Game, F: RngFactory> Player<G> for GreedyPlayer<F> {
+    fn name(&self) -> &'static str {
+        "Greedy"
+    }
+
+    fn choose_action(&mut self, game: &G, _turn_number: u32) -> Choice<G> {
+        let actions = game.get_possible_actions();
+
+        let mut game = game.clone();
+        let checkpoint = game.create_checkpoint();
+
+        // NOTE - The turn is never ended here, so a win is relative to the mover.
+
+        let winning_action = actions.iter().copied().find(|&action| {
+            game.apply_action(action);
+
+            let outcome = game.outcome();
+
+            game.restore_checkpoint(checkpoint);
+
+            outcome == Outcome::Win
+        });
+
+        let action = winning_action
+            .or_else(|| actions.choose(&mut self.rng).copied())
+            .expect("no legal actions available");
+
+        Choice {
+            evaluation: None,
+            action,
+            resign: false,
+        }
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = self.rng_factory.create_seeded(seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_take_an_immediate_win() {
+            let game: TicTacToe = [
+                "╔═══╤═══╤═══╗",
+                "║ O │ O │   ║",
+                "╟───┼───┼───╢",
+                "║ X │ X │   ║",
+                "╟───┼───┼───╢",
+                "║   │   │   ║",
+                "╚═══╧═══╧═══╝",
+            ]
+            .join("\n")
+            .parse()
+            .expect("unable to parse game");
+
+            let mut player = GreedyPlayer::new().with_seed(0);
+
+            for turn_number in 0..10 {
+                let Choice { action, .. } = player.choose_action(&game, turn_number);
+
+                assert!(action == TicTacToeAction::Place { index: 5 });
+            }
+        }
+    }
+}
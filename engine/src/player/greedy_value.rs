@@ -0,0 +1,145 @@
+use crate::core::{Choice, Game, Outcome, Player};
+use crate::neural_network::{ActionEncoder, NeuralNetwork, StateEncoder};
+use crate::player::mcts::{Evaluator, NeuralNetworkEvaluator, SymmetryMode};
+
+// NOTE - Looks a single action ahead like Greedy, but scores every resulting position with
+// the network's value instead of only spotting wins, and plays the best. Positions are
+// valued from the mover's side, so a finished turn negates the opponent's value.
+
+#[derive(Clone)]
+pub struct GreedyValuePlayer<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork>
+{
+    evaluator: NeuralNetworkEvaluator<G, SE, AE, NN>,
+}
+
+impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork>
+    GreedyValuePlayer<G, SE, AE, NN>
+{
+    pub fn new(state_encoder: SE, action_encoder: AE, neural_network: NN) -> Self {
+        Self {
+            evaluator: NeuralNetworkEvaluator::new(state_encoder, action_encoder, neural_network),
+        }
+    }
+
+    pub fn with_symmetry_mode(mut self, symmetry_mode: SymmetryMode) -> Self {
+        self.evaluator = self.evaluator.with_symmetry_mode(symmetry_mode);
+
+        self
+    }
+}
+
+impl<G, SE, AE, NN> Player<G> for GreedyValuePlayer<G, SE, AE, NN>
+where
+    G: Game,
+    SE: StateEncoder<G>,
+    AE: ActionEncoder<G>,
+    NN: NeuralNetwork,
+{
+    fn name(&self) -> &'static str {
+        "Greedy Value"
+    }
+
+    fn choose_action(&mut self, game: &G, _turn_number: u32) -> Choice<G> {
+        let actions = game.get_possible_actions();
+
+        let mut values = vec![0.0; actions.len()];
+
+        let mut pending = vec![];
+
+        for (index, &action) in actions.iter().enumerate() {
+            let mut game = game.clone();
+
+            let turn_complete = game.apply_action(action);
+
+            match game.outcome() {
+                Outcome::Win => values[index] = 1.0,
+                Outcome::Loss => values[index] = -1.0,
+                Outcome::Draw => values[index] = 0.0,
+                Outcome::InProgress => {
+                    if turn_complete {
+                        game.end_turn();
+                    }
+
+                    pending.push((index, turn_complete, game));
+                }
+            }
+        }
+
+        let games: Vec<&G> = pending.iter().map(|(_, _, game)| game).collect();
+
+        // NOTE - Skipped when every action ends the game, as not every network accepts
+        // an empty batch.
+
+        let evaluations = if games.is_empty() {
+            vec![]
+        } else {
+            self.evaluator.evaluate_batch(&games)
+        };
+
+        for ((index, turn_complete, _), evaluation) in pending.iter().zip(evaluations) {
+            values[*index] = if *turn_complete {
+                -evaluation.value
+            } else {
+                evaluation.value
+            };
+        }
+
+        let action = actions
+            .iter()
+            .zip(values)
+            .max_by(|(_, x), (_, y)| x.total_cmp(y))
+            .map(|(&action, _)| action)
+            .expect("no legal actions available");
+
+        Choice {
+            evaluation: None,
+            action,
+            resign: false,
+        }
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.evaluator.reseed(seed);
+    }
+
+    fn new_game(&mut self) {
+        self.evaluator.new_game();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::{
+        TicTacToe, TicTacToeAction, TicTacToeActionEncoder, TicTacToeStateEncoder,
+    };
+    use crate::neural_network::ConstantNeuralNetwork;
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_take_an_immediate_win_over_positions_the_opponent_favours() {
+            let mut game = TicTacToe::new();
+
+            for index in [0, 3, 1, 4] {
+                if game.apply_action(TicTacToeAction::Place { index }) {
+                    game.end_turn();
+                }
+            }
+
+            let neural_network = ConstantNeuralNetwork::new(9).with_value(0.9);
+
+            let mut player = GreedyValuePlayer::new(
+                TicTacToeStateEncoder,
+                TicTacToeActionEncoder,
+                neural_network,
+            );
+
+            let Choice { action, .. } = player.choose_action(&game, 4);
+
+            assert_eq!(action, TicTacToeAction::Place { index: 2 });
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::core::Game;
+
+// NOTE - Scores a position that is still in progress, relative to the side to move. Values
+// should stay strictly between -1 and 1, so a proven win or loss always outranks them.
+
+pub trait Heuristic<G: Game>: Clone {
+    fn evaluate(&self, game: &G) -> f32;
+
+    // NOTE - Marks positions whose score is about to swing, such as those partway
+    // through a turn, so searches look one ply past them before scoring.
+
+    fn is_unstable(&self, _game: &G) -> bool {
+        false
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod heuristic;
+mod null_heuristic;
+
+pub use heuristic::Heuristic;
+pub use null_heuristic::NullHeuristic;
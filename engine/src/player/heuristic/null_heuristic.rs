@@ -0,0 +1,17 @@
+use crate::core::Game;
+use crate::player::heuristic::Heuristic;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullHeuristic;
+
+impl NullHeuristic {
+    pub fn new() -> Self {
+        NullHeuristic
+    }
+}
+
+impl<G: Game> Heuristic<G> for NullHeuristic {
+    fn evaluate(&self, _game: &G) -> f32 {
+        0.0
+    }
+}
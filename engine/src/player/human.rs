@@ -0,0 +1,199 @@
+use std::io::{self, BufRead, StdinLock, Stdout, Write};
+
+use crate::core::{Choice, Game, Player, Turn};
+
+// NOTE - Plays from a console: prints the position from this player's seat, lists the
+// legal actions, and reads a choice as an action's number in the list, as typed notation
+// when a parser is given, or as `resign`. The end of input is also taken as resigning.
+
+pub struct HumanPlayer<G: Game, R = StdinLock<'static>, W = Stdout> {
+    turn: Turn,
+
+    reader: R,
+    writer: W,
+
+    parser: Option<ActionParser<G>>,
+}
+
+type ActionParser<G> = fn(&str) -> Result<<G as Game>::Action, String>;
+
+impl<G: Game> HumanPlayer<G> {
+    pub fn new(turn: Turn) -> Self {
+        Self::with_io(turn, io::stdin().lock(), io::stdout())
+    }
+}
+
+impl<G: Game, R: BufRead, W: Write> HumanPlayer<G, R, W> {
+    pub fn with_io(turn: Turn, reader: R, writer: W) -> Self {
+        Self {
+            turn,
+
+            reader,
+            writer,
+
+            parser: None,
+        }
+    }
+
+    pub fn with_action_parser(mut self, parser: ActionParser<G>) -> Self {
+        self.parser = Some(parser);
+
+        self
+    }
+
+    fn prompt(&mut self, game: &G) -> io::Result<Option<G::Action>> {
+        let actions = game.get_possible_actions();
+
+        writeln!(self.writer, "{}", game.display(self.turn))?;
+
+        for (i, action) in actions.iter().enumerate() {
+            writeln!(self.writer, "{:>3}. {action}", i + 1)?;
+        }
+
+        loop {
+            write!(self.writer, "> ")?;
+
+            self.writer.flush()?;
+
+            let mut line = String::new();
+
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+
+            let line = line.trim();
+
+            if line == "resign" {
+                return Ok(None);
+            }
+
+            let action = match line.parse::<usize>() {
+                Ok(number) => number
+                    .checked_sub(1)
+                    .and_then(|index| actions.get(index).copied())
+                    .ok_or_else(|| format!("no action numbered {number}")),
+                Err(_) => match self.parser {
+                    Some(parser) => parser(line).and_then(|action| {
+                        if actions.contains(&action) {
+                            Ok(action)
+                        } else {
+                            Err(format!("illegal action: {line}"))
+                        }
+                    }),
+                    None => Err(format!("invalid choice: {line}")),
+                },
+            };
+
+            match action {
+                Ok(action) => return Ok(Some(action)),
+                Err(error) => writeln!(self.writer, "{error}")?,
+            }
+        }
+    }
+}
+
+impl<G: Game, R: BufRead, W: Write> Player<G> for HumanPlayer<G, R, W> {
+    fn name(&self) -> &'static str {
+        "Human"
+    }
+
+    fn choose_action(&mut self, game: &G, _turn_number: u32) -> Choice<G> {
+        let action = self.prompt(game).expect("unable to use the console");
+
+        match action {
+            Some(action) => Choice {
+                evaluation: None,
+                action,
+                resign: false,
+            },
+            None => Choice {
+                evaluation: None,
+                action: game.get_possible_actions()[0],
+                resign: true,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{Runner, StatisticsRunnerEventSink};
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+    use crate::player::RandomPlayer;
+
+    fn play(input: &str) -> (Choice<TicTacToe>, String) {
+        let mut output = vec![];
+
+        let choice = HumanPlayer::with_io(Turn::Player1, input.as_bytes(), &mut output)
+            .with_action_parser(str::parse)
+            .choose_action(&TicTacToe::new(), 0);
+
+        (choice, String::from_utf8(output).unwrap())
+    }
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_read_actions_by_number_or_notation() {
+            let (choice, output) = play("10\n5\n");
+
+            assert_eq!(choice.action, TicTacToeAction::Place { index: 4 });
+            assert!(output.contains("no action numbered 10"));
+
+            let (choice, _) = play("b2\n");
+
+            assert_eq!(choice.action, TicTacToeAction::Place { index: 4 });
+            assert!(!choice.resign);
+        }
+
+        #[test]
+        fn should_resign_at_the_end_of_input() {
+            let (choice, output) = play("nonsense\n");
+
+            assert!(choice.resign);
+            assert!(output.contains("invalid square"));
+        }
+
+        #[test]
+        fn should_resign_when_asked() {
+            let (choice, _) = play("resign\n");
+
+            assert!(choice.resign);
+        }
+    }
+
+    mod run {
+        use super::*;
+
+        #[test]
+        fn should_play_a_full_game_through_the_runner() {
+            let input = "1\n".repeat(9);
+            let mut output = vec![];
+
+            let human = HumanPlayer::<TicTacToe, _, _>::with_io(
+                Turn::Player1,
+                input.as_bytes(),
+                &mut output,
+            );
+
+            let mut runner = Runner::new(
+                1,
+                human,
+                RandomPlayer::new(),
+                StatisticsRunnerEventSink::new(),
+            )
+            .with_seed(2);
+
+            runner.run();
+
+            assert_eq!(runner.sink().total_games, 1);
+
+            drop(runner);
+
+            assert!(String::from_utf8(output).unwrap().contains("  1. "));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use crate::core::{Choice, Game, Player};
+
+// NOTE - Hands positions over from one player to another, typically from a search such as
+// MCTS to an exact solver, once `is_endgame` says the rest of the game is small enough to
+// solve. A `MinimaxPlayer` without a depth limit or a heuristic plays such endgames
+// perfectly, since it deepens until every line ends.
+
+pub struct HybridPlayer<G: Game, P, S> {
+    player: P,
+    solver: S,
+
+    is_endgame: fn(&G) -> bool,
+}
+
+impl<G: Game, P: Clone, S: Clone> Clone for HybridPlayer<G, P, S> {
+    fn clone(&self) -> Self {
+        Self {
+            player: self.player.clone(),
+            solver: self.solver.clone(),
+
+            is_endgame: self.is_endgame,
+        }
+    }
+}
+
+impl<G: Game, P: Player<G>, S: Player<G>> HybridPlayer<G, P, S> {
+    pub fn new(player: P, solver: S, is_endgame: fn(&G) -> bool) -> Self {
+        Self {
+            player,
+            solver,
+
+            is_endgame,
+        }
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.player
+    }
+
+    pub fn solver(&self) -> &S {
+        &self.solver
+    }
+}
+
+impl<G: Game, P: Player<G>, S: Player<G>> Player<G> for HybridPlayer<G, P, S> {
+    fn name(&self) -> &'static str {
+        "Hybrid"
+    }
+
+    fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
+        if (self.is_endgame)(game) {
+            self.solver.choose_action(game, turn_number)
+        } else {
+            self.player.choose_action(game, turn_number)
+        }
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.player.reseed(seed);
+        self.solver.reseed(seed);
+    }
+
+    fn new_game(&mut self) {
+        self.player.new_game();
+        self.solver.new_game();
+    }
+
+    fn set_clock(&mut self, remaining: Duration, increment: Duration) {
+        self.player.set_clock(remaining, increment);
+        self.solver.set_clock(remaining, increment);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+    use crate::player::{ManualPlayer, MinimaxPlayer};
+
+    fn is_endgame(game: &TicTacToe) -> bool {
+        game.get_possible_actions().len() <= 5
+    }
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_switch_to_the_solver_in_the_endgame() {
+            let mut player = HybridPlayer::new(
+                ManualPlayer::new(),
+                MinimaxPlayer::new(usize::MAX),
+                is_endgame,
+            );
+
+            let mut game = TicTacToe::new();
+
+            player
+                .player
+                .queue_action(TicTacToeAction::Place { index: 0 });
+
+            let Choice { action, .. } = player.choose_action(&game, 0);
+
+            assert_eq!(action, TicTacToeAction::Place { index: 0 });
+
+            for index in [0, 3, 1, 4] {
+                if game.apply_action(TicTacToeAction::Place { index }) {
+                    game.end_turn();
+                }
+            }
+
+            let Choice { action, .. } = player.choose_action(&game, 4);
+
+            assert_eq!(action, TicTacToeAction::Place { index: 2 });
+        }
+    }
+}
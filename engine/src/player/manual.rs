@@ -36,6 +36,7 @@ impl<G: Game> Player<G> for ManualPlayer<G> {
         Choice {
             evaluation: None,
             action,
+            resign: false,
         }
     }
 }
@@ -1,26 +1,58 @@
-use crate::core::{Choice, Game, Player};
+use std::time::Duration;
+
+use crate::core::{Choice, DefaultRngFactory, Game, Player, RngFactory};
+use crate::player::heuristic::{Heuristic, NullHeuristic};
 use crate::player::mcts::evaluator::RolloutEvaluator;
 use crate::player::mcts::expander::RandomExpander;
 use crate::player::mcts::mcts::{Mcts, MtcsOptions, SearchResult};
+use crate::player::mcts::memory_usage::MemoryUsage;
 use crate::player::mcts::noise::DirichletNoise;
 use crate::player::mcts::scorer::Ucb1Scorer;
+use crate::player::mcts::search_budget::AdaptiveBudget;
+use crate::player::mcts::search_statistics::SearchStatistics;
+use crate::player::mcts::state_storage::StateStorage;
 use crate::player::mcts::temperature::TemperatureSchedule;
+use crate::player::mcts::tree_export::{TreeExportNode, TreeExportOptions};
 
-pub struct ClassicMctsPlayer<G: Game> {
-    mcts: Mcts<G, RolloutEvaluator, Ucb1Scorer, RandomExpander>,
+#[derive(Clone)]
+pub struct ClassicMctsPlayer<G: Game, F: RngFactory = DefaultRngFactory, H = NullHeuristic>
+where
+    H: Heuristic<G>,
+{
+    mcts: Mcts<G, RolloutEvaluator<F, H>, Ucb1Scorer, RandomExpander<F>, F>,
 }
 
 impl<G: Game> ClassicMctsPlayer<G> {
     pub fn new(simulations: u32) -> Self {
+        Self::with_rng_factory(simulations, DefaultRngFactory::default())
+    }
+}
+
+impl<G: Game, F: RngFactory> ClassicMctsPlayer<G, F> {
+    pub fn with_rng_factory(simulations: u32, rng_factory: F) -> Self {
         let options = MtcsOptions::new(
             simulations,
-            RolloutEvaluator::new(),
+            RolloutEvaluator::with_rng_factory(rng_factory.clone()),
             Ucb1Scorer::new(),
-            RandomExpander::new(),
+            RandomExpander::with_rng_factory(rng_factory.clone()),
         );
 
         Self {
-            mcts: Mcts::new(options),
+            mcts: Mcts::new(options, rng_factory),
+        }
+    }
+}
+
+impl<G: Game, F: RngFactory, H: Heuristic<G>> ClassicMctsPlayer<G, F, H> {
+    pub fn with_rollout_cutoff<H2: Heuristic<G>>(
+        self,
+        steps: u32,
+        heuristic: H2,
+    ) -> ClassicMctsPlayer<G, F, H2> {
+        ClassicMctsPlayer {
+            mcts: self
+                .mcts
+                .map_evaluator(|evaluator| evaluator.with_heuristic_cutoff(steps, heuristic)),
         }
     }
 
@@ -30,6 +62,14 @@ impl<G: Game> ClassicMctsPlayer<G> {
         self
     }
 
+    pub fn with_exploration(mut self, c: f32) -> Self {
+        let scorer = self.mcts.scorer().clone().with_exploration(c);
+
+        self.mcts = self.mcts.with_scorer(scorer);
+
+        self
+    }
+
     pub fn with_dirichlet_noise(mut self, dirichlet_noise: DirichletNoise) -> Self {
         self.mcts = self.mcts.with_dirichlet_noise(dirichlet_noise);
 
@@ -41,19 +81,141 @@ impl<G: Game> ClassicMctsPlayer<G> {
 
         self
     }
+
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.mcts = self.mcts.with_max_nodes(max_nodes);
+
+        self
+    }
+
+    pub fn with_draw_value(mut self, draw_value: f32) -> Self {
+        self.mcts = self.mcts.with_draw_value(draw_value);
+
+        self
+    }
+
+    pub fn with_resign_threshold(mut self, resign_threshold: f32) -> Self {
+        self.mcts = self.mcts.with_resign_threshold(resign_threshold);
+
+        self
+    }
+
+    pub fn with_state_storage(mut self, state_storage: StateStorage) -> Self {
+        self.mcts = self.mcts.with_state_storage(state_storage);
+
+        self
+    }
+
+    pub fn with_adaptive_budget(mut self, adaptive_budget: AdaptiveBudget) -> Self {
+        self.mcts = self.mcts.with_adaptive_budget(adaptive_budget);
+
+        self
+    }
+
+    pub fn with_early_termination(mut self, early_termination: bool) -> Self {
+        self.mcts = self.mcts.with_early_termination(early_termination);
+
+        self
+    }
+
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.set_time_limit(time_limit);
+
+        self
+    }
+
+    pub fn set_time_limit(&mut self, time_limit: Duration) {
+        self.mcts.set_time_limit(time_limit);
+    }
+
+    pub fn with_determinizations(mut self, determinizations: u32) -> Self {
+        self.mcts = self.mcts.with_determinizations(determinizations);
+
+        self
+    }
+
+    pub fn with_deterministic(mut self, seed: u64) -> Self {
+        self.mcts = self.mcts.with_deterministic(seed);
+
+        self
+    }
+
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.mcts.memory_usage()
+    }
+
+    pub fn search_statistics(&self) -> SearchStatistics {
+        self.mcts.statistics()
+    }
+
+    pub fn choose_action_among(
+        &mut self,
+        game: &G,
+        turn_number: u32,
+        actions: &[G::Action],
+    ) -> Choice<G> {
+        let SearchResult {
+            action,
+            evaluation,
+            resign,
+        } = self.mcts.search_among(game, turn_number, actions);
+
+        Choice {
+            evaluation: Some(evaluation),
+            action,
+            resign,
+        }
+    }
+
+    pub fn choose_action_with_tree(
+        &mut self,
+        game: &G,
+        turn_number: u32,
+        options: &TreeExportOptions,
+    ) -> (Choice<G>, TreeExportNode) {
+        let (
+            SearchResult {
+                action,
+                evaluation,
+                resign,
+            },
+            tree,
+        ) = self.mcts.search_with_tree(game, turn_number, options);
+
+        let choice = Choice {
+            evaluation: Some(evaluation),
+            action,
+            resign,
+        };
+
+        (choice, tree)
+    }
 }
 
-impl<G: Game> Player<G> for ClassicMctsPlayer<G> {
+impl<G: Game, F: RngFactory, H: Heuristic<G>> Player<G> for ClassicMctsPlayer<G, F, H> {
     fn name(&self) -> &'static str {
         "MCTS - Classic"
     }
 
     fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
-        let SearchResult { action, evaluation } = self.mcts.search(game, turn_number);
+        let SearchResult {
+            action,
+            evaluation,
+            resign,
+        } = self.mcts.search(game, turn_number);
 
         Choice {
             evaluation: Some(evaluation),
             action,
+            resign,
         }
     }
+
+    fn reseed(&mut self, seed: u64) {
+        self.mcts.reseed(seed);
+    }
+
+    fn new_game(&mut self) {
+        self.mcts.new_game();
+    }
 }
@@ -0,0 +1,476 @@
+use std::collections::{HashMap, VecDeque};
+use std::mem::size_of;
+
+use crate::core::{Evaluation, Game, PolicyItem, canonical_position_key};
+use crate::player::mcts::evaluator::Evaluator;
+use crate::player::mcts::memory_usage::MemoryUsage;
+
+// NOTE - Memoizes evaluations by the position key of the position's canonical symmetry,
+// so transpositions and symmetric positions share one evaluation. Entries are stored in
+// the canonical frame and mapped onto the position's own actions when reused. Positions
+// without a key are always passed through, as is everything when the capacity is zero.
+
+pub struct CachedEvaluator<G: Game, E> {
+    evaluator: E,
+
+    capacity: usize,
+    reuse_across_moves: bool,
+
+    entries: HashMap<u64, CacheEntry<G>>,
+    recency: VecDeque<(u64, u64)>,
+    clock: u64,
+
+    hits: u64,
+    misses: u64,
+}
+
+struct CacheEntry<G: Game> {
+    evaluation: Evaluation<G>,
+    last_used: u64,
+}
+
+impl<G: Game, E: Clone> Clone for CachedEvaluator<G, E> {
+    fn clone(&self) -> Self {
+        Self::new(self.evaluator.clone(), self.capacity)
+            .with_reuse_across_moves(self.reuse_across_moves)
+    }
+}
+
+impl<G: Game, E> CachedEvaluator<G, E> {
+    pub fn new(evaluator: E, capacity: usize) -> Self {
+        Self {
+            evaluator,
+
+            capacity,
+            reuse_across_moves: false,
+
+            entries: HashMap::new(),
+            recency: VecDeque::new(),
+            clock: 0,
+
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    // NOTE - By default the cache is cleared when a search starts, so it only spans one
+    // search. Keeping it lets the next move's search start from the evaluations of this
+    // one.
+
+    pub fn with_reuse_across_moves(mut self, reuse_across_moves: bool) -> Self {
+        self.reuse_across_moves = reuse_across_moves;
+
+        self
+    }
+
+    pub fn map_inner(mut self, f: impl FnOnce(E) -> E) -> Self {
+        self.evaluator = f(self.evaluator);
+
+        self
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.evaluator
+    }
+
+    pub fn into_inner(self) -> E {
+        self.evaluator
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    // NOTE - Counts reserved capacity, and policies only once they outgrow their inline
+    // storage.
+
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let policy_bytes: usize = self
+            .entries
+            .values()
+            .map(|entry| &entry.evaluation.policy)
+            .filter(|policy| policy.spilled())
+            .map(|policy| policy.capacity() * size_of::<PolicyItem<G>>())
+            .sum();
+
+        MemoryUsage {
+            nodes: self.entries.len(),
+            bytes: self.entries.capacity() * size_of::<(u64, CacheEntry<G>)>()
+                + self.recency.capacity() * size_of::<(u64, u64)>()
+                + policy_bytes,
+        }
+    }
+
+    // NOTE - Evaluates the positions missing from the cache in one call to `evaluate`, in
+    // input order, and fills in the rest from the cache.
+
+    pub fn evaluate_batch_with(
+        &mut self,
+        games: &[&G],
+        evaluate: impl FnOnce(&mut E, &[&G]) -> Vec<Evaluation<G>>,
+    ) -> Vec<Evaluation<G>> {
+        let keys: Vec<Option<(u64, u8)>> =
+            games.iter().map(|game| self.canonical_key(game)).collect();
+
+        let mut evaluations: Vec<Option<Evaluation<G>>> = games
+            .iter()
+            .zip(&keys)
+            .map(|(game, key)| key.and_then(|key| self.lookup(game, key)))
+            .collect();
+
+        let missing: Vec<usize> = (0..games.len())
+            .filter(|&index| evaluations[index].is_none())
+            .collect();
+
+        let missing_games: Vec<&G> = missing.iter().map(|&index| games[index]).collect();
+
+        let missing_evaluations = evaluate(&mut self.evaluator, &missing_games);
+
+        for (index, evaluation) in missing.into_iter().zip(missing_evaluations) {
+            if let Some(key) = keys[index] {
+                self.insert(games[index], key, &evaluation);
+            }
+
+            evaluations[index] = Some(evaluation);
+        }
+
+        evaluations
+            .into_iter()
+            .map(|evaluation| evaluation.expect("missing evaluation"))
+            .collect()
+    }
+
+    fn canonical_key(&self, game: &G) -> Option<(u64, u8)> {
+        if self.capacity == 0 {
+            return None;
+        }
+
+        canonical_position_key(game)
+    }
+
+    fn lookup(&mut self, game: &G, (key, symmetry): (u64, u8)) -> Option<Evaluation<G>> {
+        let Some(entry) = self.entries.get_mut(&key) else {
+            self.misses += 1;
+
+            return None;
+        };
+
+        self.hits += 1;
+        self.clock += 1;
+
+        entry.last_used = self.clock;
+        self.recency.push_back((key, self.clock));
+
+        let policy = game
+            .get_possible_actions()
+            .into_iter()
+            .map(|action| {
+                let canonical_action = Self::transform_action(game, action, symmetry);
+
+                let prior = entry
+                    .evaluation
+                    .policy
+                    .iter()
+                    .find(|policy_item| policy_item.action == canonical_action)
+                    .map_or(0.0, |policy_item| policy_item.prior);
+
+                PolicyItem { action, prior }
+            })
+            .collect();
+
+        let value = entry.evaluation.value;
+
+        self.compact();
+
+        Some(Evaluation { policy, value })
+    }
+
+    fn insert(&mut self, game: &G, (key, symmetry): (u64, u8), evaluation: &Evaluation<G>) {
+        if self.entries.len() >= self.capacity {
+            self.evict();
+        }
+
+        let policy = evaluation
+            .policy
+            .iter()
+            .map(|&PolicyItem { action, prior }| PolicyItem {
+                action: Self::transform_action(game, action, symmetry),
+                prior,
+            })
+            .collect();
+
+        self.clock += 1;
+
+        self.entries.insert(
+            key,
+            CacheEntry {
+                evaluation: Evaluation {
+                    policy,
+                    value: evaluation.value,
+                },
+                last_used: self.clock,
+            },
+        );
+
+        self.recency.push_back((key, self.clock));
+
+        self.compact();
+    }
+
+    // NOTE - Every use queues the entry again rather than moving it, so entries further
+    // up the queue are stale once their entry has been used since.
+
+    fn evict(&mut self) {
+        while let Some((key, used)) = self.recency.pop_front() {
+            if self
+                .entries
+                .get(&key)
+                .is_some_and(|entry| entry.last_used == used)
+            {
+                self.entries.remove(&key);
+
+                return;
+            }
+        }
+    }
+
+    fn compact(&mut self) {
+        if self.recency.len() > 2 * self.capacity {
+            let entries = &self.entries;
+
+            self.recency.retain(|(key, used)| {
+                entries
+                    .get(key)
+                    .is_some_and(|entry| entry.last_used == *used)
+            });
+        }
+    }
+
+    fn transform_action(game: &G, action: G::Action, symmetry: u8) -> G::Action {
+        if symmetry == 0 {
+            action
+        } else {
+            game.transform_action(action, symmetry)
+        }
+    }
+}
+
+impl<G: Game, E: Evaluator<G>> Evaluator<G> for CachedEvaluator<G, E> {
+    fn reseed(&mut self, seed: u64) {
+        self.evaluator.reseed(seed);
+    }
+
+    fn new_game(&mut self) -> bool {
+        let changed = self.evaluator.new_game();
+
+        if changed {
+            self.clear();
+        }
+
+        changed
+    }
+
+    fn start_search(&mut self) {
+        if !self.reuse_across_moves {
+            self.clear();
+        }
+
+        self.evaluator.start_search();
+    }
+
+    fn evaluate(&mut self, game: &G) -> Evaluation<G> {
+        let Some(key) = self.canonical_key(game) else {
+            return self.evaluator.evaluate(game);
+        };
+
+        if let Some(evaluation) = self.lookup(game, key) {
+            return evaluation;
+        }
+
+        let evaluation = self.evaluator.evaluate(game);
+
+        self.insert(game, key, &evaluation);
+
+        evaluation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::boop::{Boop, BoopAction, BoopPiece};
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    // NOTE - Favours the cell opposite the opponent's first mark, so a reused evaluation
+    // shows whether its policy was mapped onto the right actions.
+
+    #[derive(Clone, Default)]
+    struct CountingEvaluator {
+        evaluations: usize,
+    }
+
+    impl Evaluator<TicTacToe> for CountingEvaluator {
+        fn reseed(&mut self, _seed: u64) {}
+
+        fn evaluate(&mut self, game: &TicTacToe) -> Evaluation<TicTacToe> {
+            self.evaluations += 1;
+
+            let favoured = 8 - game.opponent_marks.trailing_zeros();
+
+            let policy = game
+                .get_possible_actions()
+                .into_iter()
+                .map(|action| PolicyItem {
+                    action,
+                    prior: match action {
+                        TicTacToeAction::Place { index } if u32::from(index) == favoured => 1.0,
+                        TicTacToeAction::Place { .. } => 0.0,
+                    },
+                })
+                .collect();
+
+            Evaluation { policy, value: 0.5 }
+        }
+    }
+
+    impl Evaluator<Boop> for CountingEvaluator {
+        fn reseed(&mut self, _seed: u64) {}
+
+        fn evaluate(&mut self, game: &Boop) -> Evaluation<Boop> {
+            self.evaluations += 1;
+
+            let policy = game
+                .get_possible_actions()
+                .into_iter()
+                .map(|action| PolicyItem { action, prior: 1.0 })
+                .collect();
+
+            Evaluation { policy, value: 0.5 }
+        }
+    }
+
+    fn after(index: u8) -> TicTacToe {
+        let mut game = TicTacToe::new();
+
+        if game.apply_action(TicTacToeAction::Place { index }) {
+            game.end_turn();
+        }
+
+        game
+    }
+
+    fn favoured(evaluation: &Evaluation<TicTacToe>) -> TicTacToeAction {
+        evaluation
+            .policy
+            .iter()
+            .max_by(|a, b| a.prior.total_cmp(&b.prior))
+            .expect("missing actions")
+            .action
+    }
+
+    mod evaluate {
+        use super::*;
+
+        #[test]
+        fn should_reuse_evaluations_of_symmetric_positions() {
+            let mut evaluator = CachedEvaluator::new(CountingEvaluator::default(), 16);
+
+            let first = evaluator.evaluate(&after(0));
+            let second = evaluator.evaluate(&after(2));
+
+            assert_eq!(evaluator.inner().evaluations, 1);
+            assert_eq!((evaluator.hits(), evaluator.misses()), (1, 1));
+
+            assert_eq!(favoured(&first), TicTacToeAction::Place { index: 8 });
+            assert_eq!(favoured(&second), TicTacToeAction::Place { index: 6 });
+            assert!((second.value - 0.5).abs() < f32::EPSILON);
+        }
+
+        #[test]
+        fn should_reuse_evaluations_of_games_keyed_by_position() {
+            let mut evaluator = CachedEvaluator::new(CountingEvaluator::default(), 16);
+
+            for index in [0, 5] {
+                let mut game = Boop::new();
+
+                assert!(game.apply_action(BoopAction::Place {
+                    piece: BoopPiece::Kitten,
+                    index,
+                }));
+
+                game.end_turn();
+
+                evaluator.evaluate(&game);
+            }
+
+            assert_eq!(evaluator.inner().evaluations, 1);
+            assert_eq!((evaluator.hits(), evaluator.misses()), (1, 1));
+        }
+
+        #[test]
+        fn should_evict_the_least_recently_used_entry() {
+            let mut evaluator = CachedEvaluator::new(CountingEvaluator::default(), 2);
+
+            evaluator.evaluate(&after(0));
+            evaluator.evaluate(&after(1));
+            evaluator.evaluate(&after(0));
+            evaluator.evaluate(&after(4));
+            evaluator.evaluate(&after(0));
+
+            assert_eq!(evaluator.inner().evaluations, 3);
+
+            evaluator.evaluate(&after(1));
+
+            assert_eq!(evaluator.inner().evaluations, 4);
+        }
+    }
+
+    mod memory_usage {
+        use super::*;
+
+        #[test]
+        fn should_grow_with_the_entries_held() {
+            let mut evaluator = CachedEvaluator::new(CountingEvaluator::default(), 16);
+
+            assert_eq!(evaluator.memory_usage().nodes, 0);
+
+            evaluator.evaluate(&after(0));
+
+            let MemoryUsage { nodes, bytes } = evaluator.memory_usage();
+
+            evaluator.evaluate(&after(4));
+
+            assert_eq!(nodes, 1);
+            assert!(bytes >= size_of::<(u64, CacheEntry<TicTacToe>)>());
+            assert_eq!(evaluator.memory_usage().nodes, 2);
+        }
+    }
+
+    mod start_search {
+        use super::*;
+
+        #[test]
+        fn should_only_keep_evaluations_across_moves_when_asked() {
+            for (reuse_across_moves, expected_evaluations) in [(false, 2), (true, 1)] {
+                let mut evaluator = CachedEvaluator::new(CountingEvaluator::default(), 16)
+                    .with_reuse_across_moves(reuse_across_moves);
+
+                evaluator.evaluate(&after(4));
+                evaluator.start_search();
+                evaluator.evaluate(&after(4));
+
+                assert_eq!(evaluator.inner().evaluations, expected_evaluations);
+            }
+        }
+    }
+}
@@ -1,7 +1,19 @@
 use crate::core::{Evaluation, Game};
 
 pub trait Evaluator<G: Game> {
-    fn with_seed(self, seed: u64) -> Self;
+    fn reseed(&mut self, seed: u64);
+
+    // NOTE - Called once before each game. Returns whether the evaluator's model changed,
+    // as `NeuralNetwork::new_game` does.
+
+    fn new_game(&mut self) -> bool {
+        false
+    }
+
+    // NOTE - Called as each search starts, for evaluators that keep state between
+    // evaluations.
+
+    fn start_search(&mut self) {}
 
     fn evaluate(&mut self, game: &G) -> Evaluation<G>;
 }
@@ -1,8 +1,10 @@
+mod cached;
 #[allow(clippy::module_inception)]
 mod evaluator;
 mod neural_network;
 mod rollout;
 
+pub use cached::CachedEvaluator;
 pub use evaluator::Evaluator;
-pub use neural_network::NeuralNetworkEvaluator;
+pub use neural_network::{NeuralNetworkEvaluator, SymmetryMode};
 pub use rollout::RolloutEvaluator;
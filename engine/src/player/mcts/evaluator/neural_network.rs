@@ -1,10 +1,25 @@
 use std::f32;
 use std::marker::PhantomData;
 
-use crate::core::{Evaluation, Game, PolicyItem};
+use rand::RngExt;
+
+use crate::core::{DefaultRngFactory, Evaluation, Game, Policy, PolicyItem, RngFactory};
 use crate::neural_network::{ActionEncoder, NeuralNetwork, Prediction, StateEncoder};
 use crate::player::mcts::evaluator::Evaluator;
 
+// NOTE - Which symmetries of a position the network is shown. `Random` evaluates one at
+// random and `Average` averages the evaluations of all of them, with the policy mapped back
+// onto the position's own actions either way, which evens out biases the network has
+// towards particular orientations.
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SymmetryMode {
+    #[default]
+    Identity,
+    Random,
+    Average,
+}
+
 pub struct NeuralNetworkEvaluator<
     G: Game,
     SE: StateEncoder<G>,
@@ -15,9 +30,35 @@ pub struct NeuralNetworkEvaluator<
     action_encoder: AE,
     neural_network: NN,
 
+    symmetry_mode: SymmetryMode,
+    draw_value: f32,
+    rng: <DefaultRngFactory as RngFactory>::Rng,
+
     _phantom: PhantomData<G>,
 }
 
+impl<G, SE, AE, NN> Clone for NeuralNetworkEvaluator<G, SE, AE, NN>
+where
+    G: Game,
+    SE: StateEncoder<G>,
+    AE: ActionEncoder<G>,
+    NN: NeuralNetwork + Clone,
+{
+    fn clone(&self) -> Self {
+        NeuralNetworkEvaluator {
+            state_encoder: self.state_encoder,
+            action_encoder: self.action_encoder,
+            neural_network: self.neural_network.clone(),
+
+            symmetry_mode: self.symmetry_mode,
+            draw_value: self.draw_value,
+            rng: DefaultRngFactory::default().create(),
+
+            _phantom: PhantomData,
+        }
+    }
+}
+
 impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork>
     NeuralNetworkEvaluator<G, SE, AE, NN>
 {
@@ -27,9 +68,117 @@ impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork>
             action_encoder,
             neural_network,
 
+            symmetry_mode: SymmetryMode::default(),
+            draw_value: 0.0,
+            rng: DefaultRngFactory::default().create(),
+
             _phantom: PhantomData,
         }
     }
+
+    pub fn with_symmetry_mode(mut self, symmetry_mode: SymmetryMode) -> Self {
+        self.symmetry_mode = symmetry_mode;
+
+        self
+    }
+
+    // NOTE - Only networks that predict a draw probability are affected. Their value
+    // counts a draw as zero, and this credits it with `draw_value` instead.
+
+    pub fn with_draw_value(mut self, draw_value: f32) -> Self {
+        self.draw_value = draw_value;
+
+        self
+    }
+
+    pub fn neural_network(&self) -> &NN {
+        &self.neural_network
+    }
+
+    pub fn evaluate_batch(&mut self, games: &[&G]) -> Vec<Evaluation<G>> {
+        let symmetries: Vec<Vec<u8>> = games.iter().map(|game| self.symmetries(game)).collect();
+
+        let states: Vec<Vec<f32>> = games
+            .iter()
+            .zip(&symmetries)
+            .flat_map(|(game, symmetries)| {
+                symmetries
+                    .iter()
+                    .map(|&symmetry| self.encode(game, symmetry))
+            })
+            .collect();
+
+        let mut predictions = self.neural_network.predict_batch(&states).into_iter();
+
+        games
+            .iter()
+            .zip(symmetries)
+            .map(|(game, symmetries)| {
+                let predictions = symmetries.into_iter().zip(predictions.by_ref()).collect();
+
+                self.to_evaluation(game, predictions)
+            })
+            .collect()
+    }
+
+    fn symmetries(&mut self, game: &G) -> Vec<u8> {
+        match self.symmetry_mode {
+            SymmetryMode::Identity => vec![0],
+            SymmetryMode::Random => vec![self.rng.random_range(0..game.symmetries())],
+            SymmetryMode::Average => (0..game.symmetries()).collect(),
+        }
+    }
+
+    fn encode(&self, game: &G, symmetry: u8) -> Vec<f32> {
+        if symmetry == 0 {
+            self.state_encoder.encode(game)
+        } else {
+            self.state_encoder.encode(&game.transform(symmetry))
+        }
+    }
+
+    // NOTE - A prediction for a transformed position scores each action by where the
+    // symmetry moves it, so the policy comes back in the position's own frame.
+
+    fn to_evaluation(&self, game: &G, predictions: Vec<(u8, Prediction)>) -> Evaluation<G> {
+        let actions = game.get_possible_actions();
+
+        let weight = 1.0 / predictions.len() as f32;
+
+        let mut policy: Policy<G> = actions
+            .iter()
+            .map(|&action| PolicyItem { action, prior: 0.0 })
+            .collect();
+
+        let mut value = 0.0;
+
+        for (symmetry, prediction) in predictions {
+            let priors: Vec<f32> = actions
+                .iter()
+                .map(|&action| {
+                    let action = if symmetry == 0 {
+                        action
+                    } else {
+                        game.transform_action(action, symmetry)
+                    };
+
+                    prediction.policy_logits[self.action_encoder.encode(&action)].exp()
+                })
+                .collect();
+
+            let total: f32 = priors.iter().sum();
+
+            for (policy_item, prior) in policy.iter_mut().zip(priors) {
+                policy_item.prior += weight * prior / total.max(f32::EPSILON);
+            }
+
+            let draw_value = self.draw_value * prediction.draw_probability.unwrap_or(0.0);
+
+            value += weight * (prediction.value + draw_value);
+        }
+
+        Evaluation { policy, value }
+    }
 }
 
 impl<G, SE, AE, NN> Evaluator<G> for NeuralNetworkEvaluator<G, SE, AE, NN>
@@ -39,39 +188,134 @@ where
     AE: ActionEncoder<G>,
     NN: NeuralNetwork,
 {
-    fn with_seed(self, _seed: u64) -> Self {
-        self
+    fn reseed(&mut self, seed: u64) {
+        self.neural_network.reseed(seed);
+
+        self.rng = DefaultRngFactory::default().create_seeded(seed);
+    }
+
+    fn new_game(&mut self) -> bool {
+        self.neural_network.new_game()
     }
 
     fn evaluate(&mut self, game: &G) -> Evaluation<G> {
-        let state = self.state_encoder.encode(game);
+        if self.symmetry_mode == SymmetryMode::Identity {
+            let state = self.state_encoder.encode(game);
 
-        let Prediction {
-            policy_logits,
-            value,
-        } = self.neural_network.predict(&state);
+            let prediction = self.neural_network.predict(&state);
 
-        let actions = game.get_possible_actions();
+            return self.to_evaluation(game, vec![(0, prediction)]);
+        }
+
+        self.evaluate_batch(&[game])
+            .pop()
+            .expect("missing evaluation")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let mut policy = Vec::with_capacity(actions.len());
-        let mut total = 0.0;
+    use crate::game::tic_tac_toe::{
+        TicTacToe, TicTacToeAction, TicTacToeActionEncoder, TicTacToeStateEncoder,
+    };
+    use crate::neural_network::ConstantNeuralNetwork;
 
-        for action in actions {
-            let action_id = self.action_encoder.encode(&action);
+    fn make_evaluator(
+        symmetry_mode: SymmetryMode,
+    ) -> NeuralNetworkEvaluator<
+        TicTacToe,
+        TicTacToeStateEncoder,
+        TicTacToeActionEncoder,
+        ConstantNeuralNetwork,
+    > {
+        let mut policy_logits = vec![0.0; 9];
+        policy_logits[0] = 2.0;
 
-            let value = policy_logits[action_id].exp();
-            total += value;
+        let neural_network = ConstantNeuralNetwork::new(9).with_policy_logits(policy_logits);
 
-            policy.push(PolicyItem {
-                action,
-                prior: value,
-            });
+        NeuralNetworkEvaluator::new(
+            TicTacToeStateEncoder,
+            TicTacToeActionEncoder,
+            neural_network,
+        )
+        .with_symmetry_mode(symmetry_mode)
+    }
+
+    fn prior(evaluation: &Evaluation<TicTacToe>, index: u8) -> f32 {
+        evaluation
+            .policy
+            .iter()
+            .find(|policy_item| policy_item.action == TicTacToeAction::Place { index })
+            .expect("missing action")
+            .prior
+    }
+
+    mod evaluate {
+        use super::*;
+
+        #[test]
+        fn should_average_the_policy_over_symmetries() {
+            let game = TicTacToe::new();
+
+            let identity = make_evaluator(SymmetryMode::Identity).evaluate(&game);
+
+            assert!(prior(&identity, 0) > prior(&identity, 8));
+
+            let average = make_evaluator(SymmetryMode::Average).evaluate(&game);
+
+            for index in [2, 6, 8] {
+                assert!((prior(&average, 0) - prior(&average, index)).abs() < 1e-6);
+            }
+
+            let total: f32 = average
+                .policy
+                .iter()
+                .map(|policy_item| policy_item.prior)
+                .sum();
+
+            assert!((total - 1.0).abs() < 1e-5);
         }
 
-        for PolicyItem { prior: value, .. } in &mut policy {
-            *value /= total.max(f32::EPSILON);
+        #[test]
+        fn should_credit_predicted_draws_with_the_draw_value() {
+            let neural_network = ConstantNeuralNetwork::new(9)
+                .with_value(0.2)
+                .with_draw_probability(0.5);
+
+            let mut evaluator = NeuralNetworkEvaluator::new(
+                TicTacToeStateEncoder,
+                TicTacToeActionEncoder,
+                neural_network,
+            )
+            .with_draw_value(-0.4);
+
+            let evaluation = evaluator.evaluate(&TicTacToe::new());
+
+            assert!(evaluation.value.abs() < 1e-6);
         }
 
-        Evaluation { policy, value }
+        #[test]
+        fn should_map_a_random_symmetry_back_to_the_position() {
+            let game = TicTacToe::new();
+
+            let mut evaluator = make_evaluator(SymmetryMode::Random);
+
+            let best: Vec<u8> = (0..8)
+                .map(|seed| {
+                    evaluator.reseed(seed);
+
+                    let evaluation = evaluator.evaluate(&game);
+
+                    (0..9)
+                        .max_by(|&a, &b| prior(&evaluation, a).total_cmp(&prior(&evaluation, b)))
+                        .expect("missing actions")
+                })
+                .collect();
+
+            assert!(best.iter().all(|index| [0, 2, 6, 8].contains(index)));
+            assert!(best.iter().any(|&index| index != best[0]));
+        }
     }
 }
@@ -1,42 +1,116 @@
-use rand::rngs::StdRng;
 use rand::seq::IndexedRandom;
-use rand::{SeedableRng, rng};
 
-use crate::core::{Evaluation, Game, Outcome, PolicyItem};
+use crate::core::{
+    DefaultRngFactory, Evaluation, Game, Outcome, Policy, PolicyItem, RngFactory,
+    sample_chance_outcome,
+};
+use crate::player::heuristic::{Heuristic, NullHeuristic};
 use crate::player::mcts::evaluator::Evaluator;
 
 #[derive(Debug)]
-pub struct RolloutEvaluator {
-    rng: StdRng,
+pub struct RolloutEvaluator<F: RngFactory = DefaultRngFactory, H = NullHeuristic> {
+    rng_factory: F,
+    rng: F::Rng,
+
+    cutoff: Option<u32>,
+    heuristic: H,
+}
+
+impl<F: RngFactory, H: Clone> Clone for RolloutEvaluator<F, H> {
+    fn clone(&self) -> Self {
+        Self {
+            rng_factory: self.rng_factory.clone(),
+            rng: self.rng_factory.create(),
+
+            cutoff: self.cutoff,
+            heuristic: self.heuristic.clone(),
+        }
+    }
 }
 
 impl RolloutEvaluator {
     pub fn new() -> Self {
+        Self::with_rng_factory(DefaultRngFactory::default())
+    }
+}
+
+impl<F: RngFactory> RolloutEvaluator<F> {
+    pub fn with_rng_factory(rng_factory: F) -> Self {
         Self {
-            rng: StdRng::from_rng(&mut rng()),
+            rng: rng_factory.create(),
+            rng_factory,
+
+            cutoff: None,
+            heuristic: NullHeuristic,
         }
     }
+}
 
-    fn rollout<G: Game>(&mut self, game: &G) -> f32 {
+impl<F: RngFactory, H> RolloutEvaluator<F, H> {
+    // NOTE - Stops each rollout after `steps` actions and scores the position reached with
+    // the heuristic, instead of playing it out to the end.
+
+    pub fn with_heuristic_cutoff<H2>(self, steps: u32, heuristic: H2) -> RolloutEvaluator<F, H2> {
+        RolloutEvaluator {
+            rng_factory: self.rng_factory,
+            rng: self.rng,
+
+            cutoff: Some(steps),
+            heuristic,
+        }
+    }
+
+    fn rollout<G: Game>(&mut self, game: &G) -> f32
+    where
+        H: Heuristic<G>,
+    {
         let mut game = game.clone();
 
+        // NOTE - The outcome is relative to the side to move, so track whether that is
+        // still the player the rollout started from. Only a completed turn passes the move,
+        // so games with several actions per turn keep the sign until the turn ends.
+
+        let mut sign = 1.0;
+        let mut steps = 0;
+
         loop {
-            let actions = game.get_possible_actions();
-
-            if actions.is_empty() {
-                return match game.outcome() {
-                    Outcome::Win => 1.0,
-                    Outcome::Loss => -1.0,
-                    Outcome::Draw => 0.0,
-                    Outcome::InProgress => unreachable!(),
-                };
+            if self.cutoff.is_some_and(|cutoff| steps >= cutoff)
+                && game.outcome() == Outcome::InProgress
+            {
+                return sign * self.heuristic.evaluate(&game);
             }
 
-            let Some(&action) = actions.choose(&mut self.rng) else {
-                panic!("no legal actions available")
+            steps += 1;
+
+            let action = if let Some(action) = sample_chance_outcome(&game, &mut self.rng) {
+                action
+            } else {
+                let actions = game.get_possible_actions();
+
+                if actions.is_empty() {
+                    return sign
+                        * match game.outcome() {
+                            Outcome::Win => 1.0,
+                            Outcome::Loss => -1.0,
+                            Outcome::Draw => 0.0,
+                            Outcome::InProgress => unreachable!(),
+                        };
+                }
+
+                let Some(&action) = actions.choose(&mut self.rng) else {
+                    panic!("no legal actions available")
+                };
+
+                action
             };
 
-            game.apply_action(action);
+            let turn_complete = game.apply_action(action);
+
+            if turn_complete && game.outcome() == Outcome::InProgress {
+                game.end_turn();
+
+                sign = -sign;
+            }
         }
     }
 }
@@ -47,11 +121,9 @@ impl Default for RolloutEvaluator {
     }
 }
 
-impl<G: Game> Evaluator<G> for RolloutEvaluator {
-    fn with_seed(mut self, seed: u64) -> Self {
-        self.rng = StdRng::seed_from_u64(seed);
-
-        self
+impl<G: Game, F: RngFactory, H: Heuristic<G>> Evaluator<G> for RolloutEvaluator<F, H> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = self.rng_factory.create_seeded(seed);
     }
 
     fn evaluate(&mut self, game: &G) -> Evaluation<G> {
@@ -64,7 +136,7 @@ impl<G: Game> Evaluator<G> for RolloutEvaluator {
             };
 
             return Evaluation {
-                policy: vec![],
+                policy: Policy::new(),
                 value,
             };
         }
@@ -86,3 +158,85 @@ impl<G: Game> Evaluator<G> for RolloutEvaluator {
         Evaluation { policy, value }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::backgammon::Backgammon;
+    use crate::game::tic_tac_toe::TicTacToe;
+
+    #[derive(Clone)]
+    struct ConstantHeuristic(f32);
+
+    impl Heuristic<TicTacToe> for ConstantHeuristic {
+        fn evaluate(&self, _game: &TicTacToe) -> f32 {
+            self.0
+        }
+    }
+
+    mod evaluate {
+        use super::*;
+
+        #[test]
+        fn should_score_from_the_side_to_move() {
+            let game: TicTacToe = [
+                "╔═══╤═══╤═══╗",
+                "║ O │ O │   ║",
+                "╟───┼───┼───╢",
+                "║ O │ X │ X ║",
+                "╟───┼───┼───╢",
+                "║   │ X │ O ║",
+                "╚═══╧═══╧═══╝",
+            ]
+            .join("\n")
+            .parse()
+            .expect("unable to parse game");
+
+            let mut evaluator = RolloutEvaluator::new();
+
+            Evaluator::<TicTacToe>::reseed(&mut evaluator, 0);
+
+            for _ in 0..10 {
+                let evaluation = Evaluator::<TicTacToe>::evaluate(&mut evaluator, &game);
+
+                assert!((evaluation.value + 1.0).abs() < f32::EPSILON);
+            }
+        }
+
+        #[test]
+        fn should_keep_the_sign_until_the_turn_ends() {
+            let game = Backgammon::from_notation("1:1,off:14 12:15 -").unwrap();
+
+            let mut evaluator = RolloutEvaluator::new();
+
+            Evaluator::<Backgammon>::reseed(&mut evaluator, 0);
+
+            for _ in 0..10 {
+                let evaluation = Evaluator::<Backgammon>::evaluate(&mut evaluator, &game);
+
+                assert!((evaluation.value - 1.0).abs() < f32::EPSILON);
+            }
+        }
+
+        #[test]
+        fn should_score_the_cutoff_position_with_the_heuristic() {
+            let mut evaluator =
+                RolloutEvaluator::new().with_heuristic_cutoff(0, ConstantHeuristic(0.25));
+
+            let evaluation = evaluator.evaluate(&TicTacToe::new());
+
+            assert!((evaluation.value - 0.25).abs() < f32::EPSILON);
+        }
+
+        #[test]
+        fn should_score_from_the_side_that_started_the_rollout() {
+            let mut evaluator =
+                RolloutEvaluator::new().with_heuristic_cutoff(1, ConstantHeuristic(0.25));
+
+            let evaluation = evaluator.evaluate(&TicTacToe::new());
+
+            assert!((evaluation.value + 0.25).abs() < f32::EPSILON);
+        }
+    }
+}
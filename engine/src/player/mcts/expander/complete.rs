@@ -1,4 +1,4 @@
-use crate::core::{Evaluation, Game, PolicyItem};
+use crate::core::{Evaluation, Game, Policy};
 use crate::player::mcts::expander::Expander;
 use crate::player::mcts::tree::Node;
 
@@ -12,11 +12,9 @@ impl CompleteExpander {
 }
 
 impl<G: Game> Expander<G> for CompleteExpander {
-    fn with_seed(self, _seed: u64) -> Self {
-        self
-    }
+    fn reseed(&mut self, _seed: u64) {}
 
-    fn expand(&mut self, node: &mut Node<G>, evaluation: &Evaluation<G>) -> Vec<PolicyItem<G>> {
+    fn expand(&mut self, node: &mut Node<G>, evaluation: &Evaluation<G>) -> Policy<G> {
         node.unexplored_actions.clear();
 
         evaluation.policy.clone()
@@ -1,8 +1,8 @@
-use crate::core::{Evaluation, Game, PolicyItem};
+use crate::core::{Evaluation, Game, Policy};
 use crate::player::mcts::tree::Node;
 
 pub trait Expander<G: Game> {
-    fn with_seed(self, seed: u64) -> Self;
+    fn reseed(&mut self, seed: u64);
 
-    fn expand(&mut self, node: &mut Node<G>, evaluation: &Evaluation<G>) -> Vec<PolicyItem<G>>;
+    fn expand(&mut self, node: &mut Node<G>, evaluation: &Evaluation<G>) -> Policy<G>;
 }
@@ -1,19 +1,36 @@
-use rand::rngs::StdRng;
-use rand::{RngExt, SeedableRng, rng};
+use rand::RngExt;
+use smallvec::smallvec;
 
-use crate::core::{Evaluation, Game, PolicyItem};
+use crate::core::{DefaultRngFactory, Evaluation, Game, Policy, PolicyItem, RngFactory};
 use crate::player::mcts::expander::Expander;
 use crate::player::mcts::tree::Node;
 
 #[derive(Debug)]
-pub struct RandomExpander {
-    rng: StdRng,
+pub struct RandomExpander<F: RngFactory = DefaultRngFactory> {
+    rng_factory: F,
+    rng: F::Rng,
+}
+
+impl<F: RngFactory> Clone for RandomExpander<F> {
+    fn clone(&self) -> Self {
+        Self {
+            rng_factory: self.rng_factory.clone(),
+            rng: self.rng_factory.create(),
+        }
+    }
 }
 
 impl RandomExpander {
     pub fn new() -> Self {
-        RandomExpander {
-            rng: StdRng::from_rng(&mut rng()),
+        Self::with_rng_factory(DefaultRngFactory::default())
+    }
+}
+
+impl<F: RngFactory> RandomExpander<F> {
+    pub fn with_rng_factory(rng_factory: F) -> Self {
+        Self {
+            rng: rng_factory.create(),
+            rng_factory,
         }
     }
 }
@@ -24,16 +41,14 @@ impl Default for RandomExpander {
     }
 }
 
-impl<G: Game> Expander<G> for RandomExpander {
-    fn with_seed(mut self, seed: u64) -> Self {
-        self.rng = StdRng::seed_from_u64(seed);
-
-        self
+impl<G: Game, F: RngFactory> Expander<G> for RandomExpander<F> {
+    fn reseed(&mut self, seed: u64) {
+        self.rng = self.rng_factory.create_seeded(seed);
     }
 
-    fn expand(&mut self, node: &mut Node<G>, _evaluation: &Evaluation<G>) -> Vec<PolicyItem<G>> {
+    fn expand(&mut self, node: &mut Node<G>, _evaluation: &Evaluation<G>) -> Policy<G> {
         if node.unexplored_actions.is_empty() {
-            return vec![];
+            return Policy::new();
         }
 
         let action_index = self.rng.random_range(0..node.unexplored_actions.len());
@@ -41,6 +56,6 @@ impl<G: Game> Expander<G> for RandomExpander {
 
         node.unexplored_actions.swap_remove(action_index);
 
-        vec![PolicyItem { action, prior: 1.0 }]
+        smallvec![PolicyItem { action, prior: 1.0 }]
     }
 }
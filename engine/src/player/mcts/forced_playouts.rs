@@ -0,0 +1,14 @@
+// NOTE - KataGo-style forced playouts: each root child with prior `p` is visited at least
+// `sqrt(k * p * N)` times, for `N` root visits, and those visits are pruned back out of
+// the policy target unless the child earned them.
+
+#[derive(Clone, Copy)]
+pub struct ForcedPlayouts {
+    pub k: f32,
+}
+
+impl ForcedPlayouts {
+    pub(crate) fn forced_visits(self, prior: f32, parent_visits: u32) -> f32 {
+        (self.k * prior * parent_visits as f32).sqrt()
+    }
+}
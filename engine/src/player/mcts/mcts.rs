@@ -1,78 +1,190 @@
 use std::marker::PhantomData;
+use std::mem;
+use std::time::{Duration, Instant};
 
 use rand::distr::weighted::WeightedIndex;
-use rand::rngs::StdRng;
-use rand::{SeedableRng, rng};
 use rand_distr::Distribution;
 use rand_distr::multi::Dirichlet;
 
-use crate::core::{Evaluation, Game, PolicyItem};
+use crate::core::{
+    ActionList, DefaultRngFactory, Evaluation, Game, Outcome, Policy, PolicyItem, RngFactory,
+    derive_seed, hash_position,
+};
 use crate::player::mcts::evaluator::Evaluator;
 use crate::player::mcts::expander::Expander;
+use crate::player::mcts::forced_playouts::ForcedPlayouts;
+use crate::player::mcts::memory_usage::MemoryUsage;
+use crate::player::mcts::node_arena::{NodeArena, NodeIndex};
 use crate::player::mcts::noise::DirichletNoise;
 use crate::player::mcts::scorer::Scorer;
+use crate::player::mcts::search_budget::{AdaptiveBudget, is_decided};
+use crate::player::mcts::search_statistics::SearchStatistics;
+use crate::player::mcts::state_storage::StateStorage;
 use crate::player::mcts::temperature::TemperatureSchedule;
-use crate::player::mcts::tree::{Node, Tree};
-
-pub struct Mcts<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> {
-    rng: StdRng,
+use crate::player::mcts::tree::{Edge, Node, Tree};
+use crate::player::mcts::tree_export::{TreeExportNode, TreeExportOptions};
+
+pub struct Mcts<
+    G: Game,
+    E: Evaluator<G>,
+    S: Scorer<G>,
+    X: Expander<G>,
+    F: RngFactory = DefaultRngFactory,
+> {
+    rng_factory: F,
+    rng: F::Rng,
 
     simulations: u32,
+    adaptive_budget: Option<AdaptiveBudget>,
+    early_termination: bool,
+    time_limit: Option<Duration>,
+    max_nodes: Option<usize>,
+    determinizations: u32,
+    deterministic_seed: Option<u64>,
+    root_actions: Vec<G::Action>,
 
     evaluator: E,
     scorer: S,
     expander: X,
 
     dirichlet_noise: Option<DirichletNoise>,
+    forced_playouts: Option<ForcedPlayouts>,
     temperature_schedule: Option<TemperatureSchedule>,
+    draw_value: f32,
+    resign_threshold: Option<f32>,
+
+    store_states: bool,
+    node_buffer: NodeArena<G>,
+    statistics: SearchStatistics,
 
     _phantom: PhantomData<G>,
 }
 
-impl<G: Game, E: Evaluator<G> + Clone, S: Scorer<G> + Clone, X: Expander<G> + Clone> Clone
-    for Mcts<G, E, S, X>
+impl<G, E, S, X, F> Clone for Mcts<G, E, S, X, F>
+where
+    G: Game,
+    E: Evaluator<G> + Clone,
+    S: Scorer<G> + Clone,
+    X: Expander<G> + Clone,
+    F: RngFactory,
 {
     fn clone(&self) -> Self {
         Self {
-            rng: StdRng::from_rng(&mut rng()),
+            rng_factory: self.rng_factory.clone(),
+            rng: self.rng_factory.create(),
 
             simulations: self.simulations,
+            adaptive_budget: self.adaptive_budget,
+            early_termination: self.early_termination,
+            time_limit: self.time_limit,
+            max_nodes: self.max_nodes,
+            determinizations: self.determinizations,
+            deterministic_seed: self.deterministic_seed,
+            root_actions: vec![],
 
             evaluator: self.evaluator.clone(),
             scorer: self.scorer.clone(),
             expander: self.expander.clone(),
 
             dirichlet_noise: self.dirichlet_noise,
+            forced_playouts: self.forced_playouts,
             temperature_schedule: self.temperature_schedule.clone(),
+            draw_value: self.draw_value,
+            resign_threshold: self.resign_threshold,
+
+            store_states: self.store_states,
+            node_buffer: NodeArena::new(),
+            statistics: SearchStatistics::default(),
 
             _phantom: PhantomData,
         }
     }
 }
 
-impl<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> Mcts<G, E, S, X> {
-    pub fn new(options: MtcsOptions<G, E, S, X>) -> Self {
+impl<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>, F: RngFactory> Mcts<G, E, S, X, F> {
+    pub fn new(options: MtcsOptions<G, E, S, X>, rng_factory: F) -> Self {
         Self {
-            rng: StdRng::from_rng(&mut rng()),
+            rng: rng_factory.create(),
+            rng_factory,
 
             simulations: options.simulations,
+            adaptive_budget: None,
+            early_termination: false,
+            time_limit: None,
+            max_nodes: None,
+            determinizations: 1,
+            deterministic_seed: None,
+            root_actions: vec![],
 
             evaluator: options.evaluator,
             scorer: options.scorer,
             expander: options.expander,
 
             dirichlet_noise: options.dirichlet_noise,
+            forced_playouts: options.forced_playouts,
             temperature_schedule: options.temperature_schedule,
+            draw_value: 0.0,
+            resign_threshold: None,
+
+            store_states: StateStorage::default().stores_states::<G>(),
+            node_buffer: NodeArena::new(),
+            statistics: SearchStatistics::default(),
 
             _phantom: PhantomData,
         }
     }
 
     pub fn with_seed(mut self, seed: u64) -> Self {
-        self.rng = StdRng::seed_from_u64(seed);
+        self.reseed(seed);
+
+        self
+    }
+
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = self.rng_factory.create_seeded(seed);
+
+        self.evaluator.reseed(seed);
+        self.expander.reseed(seed);
+    }
+
+    pub fn new_game(&mut self) {
+        self.evaluator.new_game();
+    }
+
+    pub fn map_evaluator<E2: Evaluator<G>>(self, f: impl FnOnce(E) -> E2) -> Mcts<G, E2, S, X, F> {
+        Mcts {
+            rng_factory: self.rng_factory,
+            rng: self.rng,
+
+            simulations: self.simulations,
+            adaptive_budget: self.adaptive_budget,
+            early_termination: self.early_termination,
+            time_limit: self.time_limit,
+            max_nodes: self.max_nodes,
+            determinizations: self.determinizations,
+            deterministic_seed: self.deterministic_seed,
+            root_actions: self.root_actions,
+
+            evaluator: f(self.evaluator),
+            scorer: self.scorer,
+            expander: self.expander,
+
+            dirichlet_noise: self.dirichlet_noise,
+            forced_playouts: self.forced_playouts,
+            temperature_schedule: self.temperature_schedule,
+            draw_value: self.draw_value,
+            resign_threshold: self.resign_threshold,
+
+            store_states: self.store_states,
+            node_buffer: NodeArena::new(),
+            statistics: self.statistics,
+
+            _phantom: PhantomData,
+        }
+    }
 
-        self.evaluator = self.evaluator.with_seed(seed);
-        self.expander = self.expander.with_seed(seed);
+    pub fn with_scorer(mut self, scorer: S) -> Self {
+        self.scorer = scorer;
 
         self
     }
@@ -83,27 +195,462 @@ impl<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> Mcts<G, E, S, X> {
         self
     }
 
+    pub fn with_forced_playouts(mut self, forced_playouts: ForcedPlayouts) -> Self {
+        self.forced_playouts = Some(forced_playouts);
+
+        self
+    }
+
     pub fn with_temperature_schedule(mut self, temperature_schedule: TemperatureSchedule) -> Self {
         self.temperature_schedule = Some(temperature_schedule);
 
         self
     }
 
+    // NOTE - The value of a finished, drawn position for the searching player, in place of
+    // zero. A negative value is contempt: the search steers away from draws it would
+    // otherwise accept.
+
+    pub fn with_draw_value(mut self, draw_value: f32) -> Self {
+        self.draw_value = draw_value;
+
+        self
+    }
+
+    // NOTE - Resigns once the root value, from the searching player's point of view, falls
+    // below the threshold. The search still picks an action, so callers may ignore it.
+
+    pub fn with_resign_threshold(mut self, resign_threshold: f32) -> Self {
+        self.resign_threshold = Some(resign_threshold);
+
+        self
+    }
+
+    pub fn with_state_storage(mut self, state_storage: StateStorage) -> Self {
+        self.store_states = state_storage.stores_states::<G>();
+
+        self
+    }
+
+    pub fn with_adaptive_budget(mut self, adaptive_budget: AdaptiveBudget) -> Self {
+        self.adaptive_budget = Some(adaptive_budget);
+
+        self
+    }
+
+    // NOTE - Stops the search once the most visited root child cannot be overtaken with
+    // the simulations left. The chosen move is unchanged when playing greedily, but the
+    // visit distribution is cut short, so sampled moves and policy targets shift slightly.
+
+    pub fn with_early_termination(mut self, early_termination: bool) -> Self {
+        self.early_termination = early_termination;
+
+        self
+    }
+
+    // NOTE - Stops the search once the time is up, checking between batches of
+    // simulations as early termination does. At least one batch is always run, so a move
+    // is made however short the limit. The limit is changed in place, keeping the RNG and
+    // node arena, so a clock can resize each move's search without disturbing a seeded
+    // game.
+
+    pub fn set_time_limit(&mut self, time_limit: Duration) {
+        self.time_limit = Some(time_limit);
+    }
+
+    // NOTE - Caps the nodes a search may allocate, ending it early once the tree reaches
+    // the cap. A single expansion can still overshoot by one node per legal action.
+
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+
+        self
+    }
+
+    // NOTE - Splits the simulations across this many determinizations of the position and
+    // averages their root statistics, so hidden information is searched over rather than
+    // guessed once.
+
+    pub fn with_determinizations(mut self, determinizations: u32) -> Self {
+        assert!(
+            determinizations > 0,
+            "at least one determinization is required"
+        );
+
+        self.determinizations = determinizations;
+
+        self
+    }
+
+    // NOTE - Reseeds everything from `seed` and the position before each search, so a
+    // search depends only on the position, not on earlier searches or on how the player
+    // was cloned. Ties for the chosen action go to the earliest in the position's action
+    // order.
+
+    pub fn with_deterministic(mut self, seed: u64) -> Self {
+        self.deterministic_seed = Some(seed);
+
+        self
+    }
+
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.statistics.memory_usage
+    }
+
+    pub fn statistics(&self) -> SearchStatistics {
+        self.statistics
+    }
+
+    pub fn scorer(&self) -> &S {
+        &self.scorer
+    }
+
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    pub fn evaluator_mut(&mut self) -> &mut E {
+        &mut self.evaluator
+    }
+
     pub fn search(&mut self, game: &G, turn_number: u32) -> SearchResult<G> {
-        let mut tree = Tree::new(game.clone());
+        self.reseed_for(game);
 
-        for _ in 0..self.simulations {
-            let checkpoint = tree.game.create_checkpoint();
+        if self.determinizations > 1 {
+            return self.search_determinizations(game, turn_number);
+        }
 
-            let node_index = self.select(&mut tree);
-            let value = self.expand(&mut tree, node_index);
-            Self::backpropagate(&mut tree, node_index, value);
+        let tree = self.build_tree(game);
 
-            tree.game.restore_checkpoint(checkpoint);
+        let search_result = self.finish_search(&tree, turn_number);
+
+        self.node_buffer = tree.into_buffer();
+
+        search_result
+    }
+
+    // NOTE - Searches only the given root actions, for analysing a particular move or
+    // choosing among a shortlist. Illegal actions in the list are ignored, but at least one
+    // must be legal.
+
+    pub fn search_among(
+        &mut self,
+        game: &G,
+        turn_number: u32,
+        actions: &[G::Action],
+    ) -> SearchResult<G> {
+        self.root_actions = actions.to_vec();
+
+        let search_result = self.search(game, turn_number);
+
+        self.root_actions.clear();
+
+        search_result
+    }
+
+    pub fn search_with_tree(
+        &mut self,
+        game: &G,
+        turn_number: u32,
+        options: &TreeExportOptions,
+    ) -> (SearchResult<G>, TreeExportNode) {
+        self.reseed_for(game);
+
+        let tree = self.build_tree(game);
+
+        let search_result = self.finish_search(&tree, turn_number);
+        let export = tree.export(options);
+
+        self.node_buffer = tree.into_buffer();
+
+        (search_result, export)
+    }
+
+    // NOTE - A search can also be driven one simulation at a time, pausing where the leaf
+    // needs an evaluation, so a caller can batch the leaves of several searches into one
+    // inference call. It spends simulations exactly as `search` does.
+
+    pub fn start_search(&mut self, game: &G) -> PendingSearch<G> {
+        self.reseed_for(game);
+
+        self.begin_search(game)
+    }
+
+    fn begin_search(&mut self, game: &G) -> PendingSearch<G> {
+        debug_assert!(
+            game.chance_outcomes().is_empty(),
+            "chance nodes are resolved by the runner, not searched from"
+        );
+
+        // NOTE - Every simulation adds at least one node, so the arena is sized for the
+        // budget up front rather than growing mid-search. A timed search rarely spends its
+        // whole budget, so it only reserves the first batch.
+
+        let simulations = self
+            .adaptive_budget
+            .map_or(self.simulations, |budget| budget.max_simulations);
+
+        let simulations = if self.time_limit.is_some() {
+            simulations.min(AdaptiveBudget::CHECK_INTERVAL)
+        } else {
+            simulations
+        };
+
+        self.evaluator.start_search();
+
+        let mut nodes = mem::take(&mut self.node_buffer);
+
+        nodes.clear();
+        nodes.reserve(
+            self.max_nodes
+                .unwrap_or(usize::MAX)
+                .min(simulations as usize + 1),
+        );
+
+        let mut tree = Tree::with_buffer(game.determinize(&mut self.rng), nodes);
+
+        if !self.root_actions.is_empty() {
+            let root = &mut tree.nodes[tree.root_index];
+
+            root.unexplored_actions
+                .retain(|action| self.root_actions.contains(action));
+
+            assert!(
+                !root.unexplored_actions.is_empty(),
+                "none of the root actions are legal"
+            );
+        }
+
+        PendingSearch {
+            tree,
+
+            simulations: 0,
+            batch_remaining: 0,
+            deadline: self
+                .time_limit
+                .map(|time_limit| Instant::now() + time_limit),
+
+            path: vec![],
+            leaf: None,
+        }
+    }
+
+    // NOTE - Returns the leaf position to evaluate, or `None` once the budget is spent.
+
+    pub fn select_leaf<'a>(&mut self, search: &'a mut PendingSearch<G>) -> Option<&'a G> {
+        debug_assert!(search.leaf.is_none(), "previous leaf was not completed");
+
+        if self
+            .max_nodes
+            .is_some_and(|max_nodes| search.tree.nodes.len() >= max_nodes)
+        {
+            return None;
+        }
+
+        if search.batch_remaining == 0 {
+            search.batch_remaining = self.next_batch(search)?;
+        }
+
+        let checkpoint = search.tree.game.create_checkpoint();
+
+        self.select(&mut search.tree, &mut search.path);
+
+        search.leaf = Some(checkpoint);
+
+        Some(&search.tree.game)
+    }
+
+    pub fn complete_leaf(&mut self, search: &mut PendingSearch<G>, evaluation: Evaluation<G>) {
+        let checkpoint = search.leaf.take().expect("no leaf is awaiting evaluation");
+
+        let node_index = *search.path.last().expect("selection always reaches a node");
+
+        let value = self.expand(&mut search.tree, node_index, evaluation);
+        Self::backpropagate(&mut search.tree, &search.path, value);
+
+        search.tree.game.restore_checkpoint(checkpoint);
+
+        search.simulations += 1;
+        search.batch_remaining -= 1;
+    }
+
+    pub fn finish_pending_search(
+        &mut self,
+        search: PendingSearch<G>,
+        turn_number: u32,
+    ) -> SearchResult<G> {
+        let tree = self.finish_tree(search);
+
+        let search_result = self.finish_search(&tree, turn_number);
+
+        self.node_buffer = tree.into_buffer();
+
+        search_result
+    }
+
+    fn build_tree(&mut self, game: &G) -> Tree<G> {
+        let mut search = self.begin_search(game);
+
+        while let Some(game) = self.select_leaf(&mut search) {
+            let evaluation = self.evaluator.evaluate(game);
+
+            self.complete_leaf(&mut search, evaluation);
+        }
+
+        self.finish_tree(search)
+    }
+
+    fn reseed_for(&mut self, game: &G) {
+        if let Some(seed) = self.deterministic_seed {
+            let position = game
+                .hash()
+                .unwrap_or_else(|| hash_position(&game.to_notation()));
+
+            self.reseed(derive_seed(seed, position));
+        }
+    }
+
+    fn finish_tree(&mut self, search: PendingSearch<G>) -> Tree<G> {
+        self.statistics = SearchStatistics {
+            simulations: search.simulations,
+            memory_usage: search.tree.memory_usage(),
+        };
+
+        search.tree
+    }
+
+    // NOTE - An adaptive budget, early termination and the time limit are checked between
+    // batches of simulations, so the simulations spent are a multiple of the check interval
+    // (up to the maximum).
+
+    fn next_batch(&self, search: &PendingSearch<G>) -> Option<u32> {
+        let PendingSearch {
+            tree,
+            simulations,
+            deadline,
+            ..
+        } = search;
+
+        let simulations = *simulations;
+
+        if simulations > 0 && deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return None;
+        }
+
+        let (batch, limit) = match &self.adaptive_budget {
+            None if simulations < self.simulations => {
+                (self.simulations - simulations, self.simulations)
+            }
+            Some(budget)
+                if simulations == 0
+                    || budget.should_continue(tree, simulations, self.simulations) =>
+            {
+                (
+                    AdaptiveBudget::CHECK_INTERVAL
+                        .min(budget.max_simulations.saturating_sub(simulations))
+                        .max(1),
+                    budget.max_simulations,
+                )
+            }
+            _ => return None,
+        };
+
+        if self.early_termination
+            && simulations > 0
+            && is_decided(tree, limit.saturating_sub(simulations))
+        {
+            return None;
+        }
+
+        if !self.early_termination && deadline.is_none() {
+            return Some(batch);
+        }
+
+        Some(batch.min(AdaptiveBudget::CHECK_INTERVAL))
+    }
+
+    fn search_determinizations(&mut self, game: &G, turn_number: u32) -> SearchResult<G> {
+        let simulations = self.simulations;
+        let time_limit = self.time_limit;
+        let weight = 1.0 / self.determinizations as f32;
+
+        self.simulations = (simulations / self.determinizations).max(1);
+        self.time_limit = time_limit.map(|time_limit| time_limit / self.determinizations);
+
+        let mut evaluation = Evaluation {
+            policy: Policy::new(),
+            value: 0.0,
+        };
+
+        let mut statistics = SearchStatistics::default();
+
+        for _ in 0..self.determinizations {
+            let tree = self.build_tree(game);
+
+            let tree_evaluation = self.evaluate(&tree);
+
+            self.node_buffer = tree.into_buffer();
+
+            statistics.simulations += self.statistics.simulations;
+            statistics.memory_usage = self.statistics.memory_usage;
+
+            evaluation.value += weight * tree_evaluation.value;
+
+            for PolicyItem { action, prior } in tree_evaluation.policy {
+                match evaluation
+                    .policy
+                    .iter_mut()
+                    .find(|policy_item| policy_item.action == action)
+                {
+                    Some(policy_item) => policy_item.prior += weight * prior,
+                    None => evaluation.policy.push(PolicyItem {
+                        action,
+                        prior: weight * prior,
+                    }),
+                }
+            }
+        }
+
+        self.simulations = simulations;
+        self.time_limit = time_limit;
+        self.statistics = statistics;
+
+        self.order_policy(game, &mut evaluation);
+
+        self.finish_evaluation(evaluation, turn_number)
+    }
+
+    fn finish_search(&mut self, tree: &Tree<G>, turn_number: u32) -> SearchResult<G> {
+        let mut evaluation = self.evaluate(tree);
+
+        self.order_policy(&tree.game, &mut evaluation);
+
+        self.finish_evaluation(evaluation, turn_number)
+    }
+
+    // NOTE - Root edges are in expansion order, which some expanders shuffle. Deterministic
+    // searches put the policy back into the position's action order before choosing.
+
+    fn order_policy(&self, game: &G, evaluation: &mut Evaluation<G>) {
+        if self.deterministic_seed.is_none() {
+            return;
         }
 
-        let evaluation = Self::evaluate(&tree);
+        let actions = game.get_possible_actions();
+
+        evaluation.policy.sort_by_key(|policy_item| {
+            actions
+                .iter()
+                .position(|&action| action == policy_item.action)
+                .unwrap_or(usize::MAX)
+        });
+    }
 
+    fn finish_evaluation(
+        &mut self,
+        evaluation: Evaluation<G>,
+        turn_number: u32,
+    ) -> SearchResult<G> {
         let temperature = self
             .temperature_schedule
             .as_ref()
@@ -111,59 +658,113 @@ impl<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> Mcts<G, E, S, X> {
 
         let action = self.choose_action(&evaluation, temperature);
 
-        SearchResult { evaluation, action }
+        let resign = self
+            .resign_threshold
+            .is_some_and(|resign_threshold| evaluation.value < resign_threshold);
+
+        SearchResult {
+            evaluation,
+            action,
+            resign,
+        }
     }
 
-    fn select(&self, tree: &mut Tree<G>) -> usize {
+    // NOTE - Fills `path` with the nodes visited from the root, since a node reached
+    // through a transposition has more than one parent to back up through.
+
+    fn select(&self, tree: &mut Tree<G>, path: &mut Vec<NodeIndex>) {
         let mut node_index = tree.root_index;
 
+        path.clear();
+        path.push(node_index);
+
         loop {
             let node = &tree.nodes[node_index];
 
-            if node.child_indices.is_empty() || !node.unexplored_actions.is_empty() {
+            if node.edges.is_empty() || !node.unexplored_actions.is_empty() {
                 break;
             }
 
-            let (child_index, _) = node
-                .child_indices
-                .iter()
-                .map(|&child_index| {
-                    let score = self.scorer.score(node, &tree.nodes[child_index]);
+            let forced_edge = self
+                .forced_playouts
+                .filter(|_| node_index == tree.root_index && !node.chance)
+                .and_then(|forced_playouts| {
+                    node.edges.iter().find(|edge| {
+                        (tree.nodes[edge.child_index].visits as f32)
+                            < forced_playouts.forced_visits(edge.prior, node.visits)
+                    })
+                });
+
+            let edge = forced_edge.unwrap_or_else(|| {
+                node.edges
+                    .iter()
+                    .map(|edge| {
+                        let child = &tree.nodes[edge.child_index];
+
+                        // NOTE - A chance node follows the outcome furthest behind its share of
+                        // the visits, so the visits track the probabilities without sampling.
+
+                        let score = if node.chance {
+                            edge.prior * (node.visits + 1) as f32 - child.visits as f32
+                        } else {
+                            self.scorer.score(node, edge, child)
+                        };
+
+                        (edge, score)
+                    })
+                    .max_by(|(_, x), (_, y)| x.total_cmp(y))
+                    .expect("unable to determine best child")
+                    .0
+            });
 
-                    (child_index, score)
-                })
-                .max_by(|(_, x), (_, y)| x.total_cmp(y))
-                .expect("unable to determine best child");
+            let action = edge.action;
 
-            node_index = child_index;
+            node_index = edge.child_index;
+            path.push(node_index);
 
-            if let Some(action) = tree.nodes[node_index].action {
-                tree.game.apply_action(action);
+            if !self.store_states && tree.game.apply_action(action) {
+                tree.game.end_turn();
             }
         }
 
-        node_index
+        if self.store_states
+            && let Some(checkpoint) = tree.nodes[node_index].checkpoint
+        {
+            tree.game.restore_checkpoint(checkpoint);
+        }
     }
 
-    fn expand(&mut self, tree: &mut Tree<G>, node_index: usize) -> f32 {
-        let node = &tree.nodes[node_index];
-        let turn = node.turn;
-
-        let mut evaluation = self.evaluator.evaluate(&tree.game);
+    fn expand(
+        &mut self,
+        tree: &mut Tree<G>,
+        node_index: NodeIndex,
+        mut evaluation: Evaluation<G>,
+    ) -> f32 {
+        let turn = tree.nodes[node_index].turn;
 
         if node_index == tree.root_index {
+            self.restrict_root_actions(&mut evaluation);
             self.apply_dirichlet_noise(&mut evaluation);
         }
 
-        let value = if turn == tree.nodes[tree.root_index].turn {
+        let value = if tree.game.outcome() == Outcome::Draw {
+            self.draw_value
+        } else if turn == tree.nodes[tree.root_index].turn {
             evaluation.value
         } else {
             -evaluation.value
         };
 
-        let expansion = self
-            .expander
-            .expand(&mut tree.nodes[node_index], &evaluation);
+        let expansion = if tree.nodes[node_index].chance {
+            tree.game
+                .chance_outcomes()
+                .into_iter()
+                .map(|(action, prior)| PolicyItem { action, prior })
+                .collect()
+        } else {
+            self.expander
+                .expand(&mut tree.nodes[node_index], &evaluation)
+        };
 
         for PolicyItem { action, prior } in expansion {
             let checkpoint = tree.game.create_checkpoint();
@@ -174,31 +775,81 @@ impl<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> Mcts<G, E, S, X> {
                 tree.game.end_turn();
             }
 
-            let child_node = Node {
-                action: Some(action),
-                turn: if turn_complete { turn.advance() } else { turn },
+            let child_turn = if turn_complete { turn.advance() } else { turn };
+
+            let key = tree.game.hash().map(|hash| (hash, child_turn, turn));
+
+            if let Some(&child_index) = key.and_then(|key| tree.transpositions.get(&key)) {
+                tree.game.restore_checkpoint(checkpoint);
+
+                tree.nodes[node_index].edges.push(Edge {
+                    action,
+                    prior,
+
+                    child_index,
+                });
+
+                continue;
+            }
+
+            let chance = !tree.game.chance_outcomes().is_empty();
 
-                parent_index: Some(node_index),
-                child_indices: vec![],
+            let child_node = Node {
+                turn: child_turn,
+                chance,
 
-                unexplored_actions: tree.game.get_possible_actions(),
+                edges: vec![],
 
+                perspective: turn,
                 visits: 0,
                 total_value: 0.0,
-                prior,
+
+                unexplored_actions: if chance {
+                    ActionList::new()
+                } else {
+                    tree.game.get_possible_actions()
+                },
+                checkpoint: self.store_states.then(|| tree.game.create_checkpoint()),
             };
 
             tree.game.restore_checkpoint(checkpoint);
 
-            let child_index = tree.nodes.len();
+            let child_index = tree.nodes.push(child_node);
+            tree.nodes[node_index].edges.push(Edge {
+                action,
+                prior,
+
+                child_index,
+            });
 
-            tree.nodes.push(child_node);
-            tree.nodes[node_index].child_indices.push(child_index);
+            if let Some(key) = key {
+                tree.transpositions.insert(key, child_index);
+            }
         }
 
         value
     }
 
+    fn restrict_root_actions(&self, evaluation: &mut Evaluation<G>) {
+        if self.root_actions.is_empty() {
+            return;
+        }
+
+        evaluation
+            .policy
+            .retain(|policy_item| self.root_actions.contains(&policy_item.action));
+
+        let total: f32 = evaluation
+            .policy
+            .iter()
+            .map(|policy_item| policy_item.prior)
+            .sum();
+
+        for policy_item in &mut evaluation.policy {
+            policy_item.prior /= total.max(f32::EPSILON);
+        }
+    }
+
     fn apply_dirichlet_noise(&mut self, evaluation: &mut Evaluation<G>) {
         let Some(DirichletNoise { alpha, epsilon }) = self.dirichlet_noise else {
             return;
@@ -220,40 +871,34 @@ impl<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> Mcts<G, E, S, X> {
             });
     }
 
-    fn backpropagate(tree: &mut Tree<G>, mut node_index: usize, value: f32) {
-        loop {
+    fn backpropagate(tree: &mut Tree<G>, path: &[NodeIndex], value: f32) {
+        let root_turn = tree.nodes[tree.root_index].turn;
+
+        for &node_index in path {
             let node = &mut tree.nodes[node_index];
 
             node.visits += 1;
-            node.total_value += value;
-
-            if let Some(parent_index) = node.parent_index {
-                node_index = parent_index;
+            node.total_value += if node.perspective == root_turn {
+                value
             } else {
-                break;
-            }
+                -value
+            };
         }
     }
 
-    fn evaluate(tree: &Tree<G>) -> Evaluation<G> {
+    fn evaluate(&self, tree: &Tree<G>) -> Evaluation<G> {
         let root = &tree.nodes[tree.root_index];
 
-        let total_visits: u32 = root
-            .child_indices
-            .iter()
-            .map(|&i| tree.nodes[i].visits)
-            .sum();
+        let visits = self.target_visits(tree);
+        let total_visits: u32 = visits.iter().sum();
 
         let policy = root
-            .child_indices
+            .edges
             .iter()
-            .filter_map(|&i| {
-                let node = &tree.nodes[i];
-
-                let action = node.action?;
-                let prior = node.visits as f32 / total_visits.max(1) as f32;
-
-                Some(PolicyItem { action, prior })
+            .zip(visits)
+            .map(|(edge, visits)| PolicyItem {
+                action: edge.action,
+                prior: visits as f32 / total_visits.max(1) as f32,
             })
             .collect();
 
@@ -262,19 +907,91 @@ impl<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> Mcts<G, E, S, X> {
         Evaluation { policy, value }
     }
 
-    fn choose_action(&mut self, evaluation: &Evaluation<G>, temperature: f32) -> G::Action {
-        if temperature == 0.0 {
-            return evaluation
-                .policy
-                .iter()
-                .max_by(|x, y| x.prior.total_cmp(&y.prior))
-                .expect("unable to choose action")
-                .action;
-        }
+    // NOTE - With forced playouts, each root child other than the most visited gives back
+    // as many of its forced visits as it can while still scoring below the most visited,
+    // keeping its mean value. A child left with a single visit is dropped entirely.
 
-        let weights: Vec<f32> = evaluation
-            .policy
-            .iter()
+    fn target_visits(&self, tree: &Tree<G>) -> Vec<u32> {
+        let root = &tree.nodes[tree.root_index];
+
+        let mut visits: Vec<u32> = root
+            .edges
+            .iter()
+            .map(|edge| tree.nodes[edge.child_index].visits)
+            .collect();
+
+        let Some(forced_playouts) = self.forced_playouts else {
+            return visits;
+        };
+
+        let Some(best_index) = (0..visits.len()).max_by_key(|&index| visits[index]) else {
+            return visits;
+        };
+
+        let best_edge = &root.edges[best_index];
+        let best_score = self
+            .scorer
+            .score(root, best_edge, &tree.nodes[best_edge.child_index]);
+
+        for (index, edge) in root.edges.iter().enumerate() {
+            let child = &tree.nodes[edge.child_index];
+
+            if index == best_index || child.visits == 0 {
+                continue;
+            }
+
+            let forced_visits = forced_playouts.forced_visits(edge.prior, root.visits);
+            let mean_value = child.total_value / child.visits as f32;
+
+            let mut target = child.visits;
+
+            while target > 0 && (child.visits - target + 1) as f32 <= forced_visits {
+                let pruned = Node {
+                    turn: child.turn,
+                    chance: child.chance,
+
+                    edges: vec![],
+
+                    perspective: child.perspective,
+                    visits: target - 1,
+                    total_value: mean_value * (target - 1) as f32,
+
+                    unexplored_actions: ActionList::new(),
+                    checkpoint: None,
+                };
+
+                if self.scorer.score(root, edge, &pruned) >= best_score {
+                    break;
+                }
+
+                target -= 1;
+            }
+
+            visits[index] = if target <= 1 { 0 } else { target };
+        }
+
+        visits
+    }
+
+    fn choose_action(&mut self, evaluation: &Evaluation<G>, temperature: f32) -> G::Action {
+        // NOTE - `max_by` keeps the last of equal priors, so deterministic searches scan the
+        // ordered policy backwards to favour the earliest action.
+
+        if temperature == 0.0 {
+            let compare = |x: &&PolicyItem<G>, y: &&PolicyItem<G>| x.prior.total_cmp(&y.prior);
+
+            let best = if self.deterministic_seed.is_some() {
+                evaluation.policy.iter().rev().max_by(compare)
+            } else {
+                evaluation.policy.iter().max_by(compare)
+            };
+
+            return best.expect("unable to choose action").action;
+        }
+
+        let weights: Vec<f32> = evaluation
+            .policy
+            .iter()
             .map(|policy_item| policy_item.prior.powf(1.0 / temperature))
             .collect();
 
@@ -295,6 +1012,7 @@ pub struct MtcsOptions<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> {
     pub expander: X,
 
     pub dirichlet_noise: Option<DirichletNoise>,
+    pub forced_playouts: Option<ForcedPlayouts>,
     pub temperature_schedule: Option<TemperatureSchedule>,
 
     pub phantom: PhantomData<G>,
@@ -310,6 +1028,7 @@ impl<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> MtcsOptions<G, E, S
             expander,
 
             dirichlet_noise: None,
+            forced_playouts: None,
             temperature_schedule: None,
 
             phantom: PhantomData,
@@ -323,6 +1042,13 @@ impl<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> MtcsOptions<G, E, S
         self
     }
 
+    #[allow(dead_code)]
+    pub fn with_forced_playouts(mut self, forced_playouts: ForcedPlayouts) -> Self {
+        self.forced_playouts = Some(forced_playouts);
+
+        self
+    }
+
     #[allow(dead_code)]
     pub fn with_temperature_schedule(mut self, schedule: TemperatureSchedule) -> Self {
         self.temperature_schedule = Some(schedule);
@@ -334,4 +1060,718 @@ impl<G: Game, E: Evaluator<G>, S: Scorer<G>, X: Expander<G>> MtcsOptions<G, E, S
 pub struct SearchResult<G: Game> {
     pub evaluation: Evaluation<G>,
     pub action: G::Action,
+    pub resign: bool,
+}
+
+pub struct PendingSearch<G: Game> {
+    tree: Tree<G>,
+
+    simulations: u32,
+    batch_remaining: u32,
+    deadline: Option<Instant>,
+
+    path: Vec<NodeIndex>,
+    leaf: Option<G::Checkpoint>,
+}
+
+impl<G: Game> PendingSearch<G> {
+    pub fn leaf(&self) -> Option<&G> {
+        self.leaf.is_some().then_some(&self.tree.game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fmt;
+
+    use crate::core::{ActionList, DefaultRngFactory, Outcome, Turn};
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+    use crate::player::mcts::evaluator::RolloutEvaluator;
+    use crate::player::mcts::expander::{CompleteExpander, RandomExpander};
+    use crate::player::mcts::scorer::{PuctScorer, Ucb1Scorer};
+
+    fn make_mcts(
+        simulations: u32,
+        state_storage: StateStorage,
+    ) -> Mcts<TicTacToe, RolloutEvaluator, Ucb1Scorer, RandomExpander> {
+        let options = MtcsOptions::new(
+            simulations,
+            RolloutEvaluator::new(),
+            Ucb1Scorer::new(),
+            RandomExpander::new(),
+        )
+        .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+        Mcts::new(options, DefaultRngFactory::default())
+            .with_seed(0)
+            .with_state_storage(state_storage)
+    }
+
+    fn make_forced_mcts(
+        simulations: u32,
+    ) -> Mcts<TicTacToe, RolloutEvaluator, PuctScorer, CompleteExpander> {
+        let options = MtcsOptions::new(
+            simulations,
+            RolloutEvaluator::new(),
+            PuctScorer::new(),
+            CompleteExpander::new(),
+        )
+        .with_forced_playouts(ForcedPlayouts { k: 2.0 })
+        .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+        Mcts::new(options, DefaultRngFactory::default()).with_seed(0)
+    }
+
+    // NOTE - Play safe for a draw, or gamble on a coin that wins with `win_chance`.
+
+    #[derive(Clone, Copy, Debug)]
+    struct CoinGame {
+        win_chance: f32,
+
+        flipping: bool,
+        result: Option<Outcome>,
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum CoinAction {
+        Safe,
+        Gamble,
+        Heads,
+        Tails,
+    }
+
+    impl fmt::Display for CoinAction {
+        fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(formatter, "{self:?}")
+        }
+    }
+
+    impl fmt::Display for CoinGame {
+        fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(formatter, "{self:?}")
+        }
+    }
+
+    impl Game for CoinGame {
+        type Phase = ();
+        type Action = CoinAction;
+        type Checkpoint = CoinGame;
+        type Config = ();
+
+        fn new_with_config(_config: ()) -> Self {
+            CoinGame {
+                win_chance: 0.5,
+
+                flipping: false,
+                result: None,
+            }
+        }
+
+        fn get_possible_actions(&self) -> ActionList<CoinAction> {
+            if self.flipping || self.result.is_some() {
+                return ActionList::new();
+            }
+
+            [CoinAction::Safe, CoinAction::Gamble].into_iter().collect()
+        }
+
+        fn apply_action(&mut self, action: CoinAction) -> bool {
+            match action {
+                CoinAction::Safe => self.result = Some(Outcome::Draw),
+                CoinAction::Gamble => self.flipping = true,
+                CoinAction::Heads => self.result = Some(Outcome::Win),
+                CoinAction::Tails => self.result = Some(Outcome::Loss),
+            }
+
+            if self.result.is_some() {
+                self.flipping = false;
+            }
+
+            action != CoinAction::Gamble
+        }
+
+        fn end_turn(&mut self) {
+            self.result = self.result.map(Outcome::flip);
+        }
+
+        fn outcome(&self) -> Outcome {
+            self.result.unwrap_or(Outcome::InProgress)
+        }
+
+        fn chance_outcomes(&self) -> Vec<(CoinAction, f32)> {
+            if !self.flipping {
+                return vec![];
+            }
+
+            vec![
+                (CoinAction::Heads, self.win_chance),
+                (CoinAction::Tails, 1.0 - self.win_chance),
+            ]
+        }
+
+        fn create_checkpoint(&self) -> CoinGame {
+            *self
+        }
+
+        fn restore_checkpoint(&mut self, checkpoint: CoinGame) {
+            *self = checkpoint;
+        }
+
+        fn symmetries(&self) -> u8 {
+            1
+        }
+
+        fn transform(&self, _symmetry: u8) -> Self {
+            *self
+        }
+
+        fn transform_action(&self, action: CoinAction, _symmetry: u8) -> CoinAction {
+            action
+        }
+
+        fn display(&self, _turn: Turn) -> String {
+            format!("{self}")
+        }
+
+        fn to_notation(&self) -> String {
+            let state = if self.flipping {
+                "?"
+            } else {
+                result_notation(self.result)
+            };
+
+            format!("{} {state}", self.win_chance)
+        }
+
+        fn from_notation(notation: &str) -> Result<Self, String> {
+            let (win_chance, state) = notation
+                .split_once(' ')
+                .ok_or_else(|| format!("invalid notation: {notation}"))?;
+
+            let win_chance = win_chance
+                .parse()
+                .map_err(|_| format!("invalid win chance: {win_chance}"))?;
+
+            Ok(CoinGame {
+                win_chance,
+
+                flipping: state == "?",
+                result: if state == "?" {
+                    None
+                } else {
+                    parse_result_notation(state)?
+                },
+            })
+        }
+    }
+
+    // NOTE - Fold for a draw, or bet on a hidden card from 0 to 9 that loses only on zero.
+    // The observation hides the card, so only determinizations can be played out.
+
+    #[derive(Clone, Copy, Debug)]
+    struct HiddenCardGame {
+        card: Option<u8>,
+        result: Option<Outcome>,
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum HiddenCardAction {
+        Fold,
+        Bet,
+    }
+
+    impl fmt::Display for HiddenCardAction {
+        fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(formatter, "{self:?}")
+        }
+    }
+
+    impl fmt::Display for HiddenCardGame {
+        fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(formatter, "{self:?}")
+        }
+    }
+
+    impl Game for HiddenCardGame {
+        type Phase = ();
+        type Action = HiddenCardAction;
+        type Checkpoint = HiddenCardGame;
+        type Config = ();
+
+        fn new_with_config(_config: ()) -> Self {
+            HiddenCardGame {
+                card: Some(0),
+                result: None,
+            }
+        }
+
+        fn get_possible_actions(&self) -> ActionList<HiddenCardAction> {
+            if self.result.is_some() {
+                return ActionList::new();
+            }
+
+            [HiddenCardAction::Fold, HiddenCardAction::Bet]
+                .into_iter()
+                .collect()
+        }
+
+        fn apply_action(&mut self, action: HiddenCardAction) -> bool {
+            let card = self.card.expect("hidden card must be determinized");
+
+            self.result = Some(match action {
+                HiddenCardAction::Fold => Outcome::Draw,
+                HiddenCardAction::Bet if card == 0 => Outcome::Loss,
+                HiddenCardAction::Bet => Outcome::Win,
+            });
+
+            true
+        }
+
+        fn end_turn(&mut self) {
+            self.result = self.result.map(Outcome::flip);
+        }
+
+        fn outcome(&self) -> Outcome {
+            self.result.unwrap_or(Outcome::InProgress)
+        }
+
+        fn observation(&self) -> Self {
+            HiddenCardGame {
+                card: None,
+                ..*self
+            }
+        }
+
+        fn determinize(&self, rng: &mut impl rand::Rng) -> Self {
+            use rand::RngExt;
+
+            HiddenCardGame {
+                card: Some(self.card.unwrap_or_else(|| rng.random_range(0..10))),
+                ..*self
+            }
+        }
+
+        fn create_checkpoint(&self) -> HiddenCardGame {
+            *self
+        }
+
+        fn restore_checkpoint(&mut self, checkpoint: HiddenCardGame) {
+            *self = checkpoint;
+        }
+
+        fn symmetries(&self) -> u8 {
+            1
+        }
+
+        fn transform(&self, _symmetry: u8) -> Self {
+            *self
+        }
+
+        fn transform_action(&self, action: HiddenCardAction, _symmetry: u8) -> HiddenCardAction {
+            action
+        }
+
+        fn display(&self, _turn: Turn) -> String {
+            format!("{self}")
+        }
+
+        fn to_notation(&self) -> String {
+            let card = self
+                .card
+                .map_or_else(|| "?".to_string(), |card| card.to_string());
+
+            format!("{card} {}", result_notation(self.result))
+        }
+
+        fn from_notation(notation: &str) -> Result<Self, String> {
+            let (card, result) = notation
+                .split_once(' ')
+                .ok_or_else(|| format!("invalid notation: {notation}"))?;
+
+            let card = match card {
+                "?" => None,
+                _ => Some(card.parse().map_err(|_| format!("invalid card: {card}"))?),
+            };
+
+            Ok(HiddenCardGame {
+                card,
+                result: parse_result_notation(result)?,
+            })
+        }
+    }
+
+    // NOTE - Notation shared by the games above for a result from the side to move's point
+    // of view, with `-` while still in progress.
+
+    fn result_notation(result: Option<Outcome>) -> &'static str {
+        match result {
+            None | Some(Outcome::InProgress) => "-",
+            Some(Outcome::Win) => "W",
+            Some(Outcome::Loss) => "L",
+            Some(Outcome::Draw) => "D",
+        }
+    }
+
+    fn parse_result_notation(notation: &str) -> Result<Option<Outcome>, String> {
+        match notation {
+            "-" => Ok(None),
+            "W" => Ok(Some(Outcome::Win)),
+            "L" => Ok(Some(Outcome::Loss)),
+            "D" => Ok(Some(Outcome::Draw)),
+            _ => Err(format!("invalid result: {notation}")),
+        }
+    }
+
+    fn parse_game(rows: [&str; 3]) -> TicTacToe {
+        [
+            "╔═══╤═══╤═══╗",
+            rows[0],
+            "╟───┼───┼───╢",
+            rows[1],
+            "╟───┼───┼───╢",
+            rows[2],
+            "╚═══╧═══╧═══╝",
+        ]
+        .join("\n")
+        .parse()
+        .expect("unable to parse game")
+    }
+
+    mod search {
+        use super::*;
+
+        #[test]
+        fn should_match_between_stored_and_replayed_states() {
+            let game = TicTacToe::new();
+
+            let replayed = make_mcts(200, StateStorage::Replay).search(&game, 1);
+            let stored = make_mcts(200, StateStorage::Stored).search(&game, 1);
+
+            let priors = |search_result: &SearchResult<TicTacToe>| {
+                search_result
+                    .evaluation
+                    .policy
+                    .iter()
+                    .map(|policy_item| policy_item.prior)
+                    .collect::<Vec<_>>()
+            };
+
+            assert_eq!(priors(&replayed), priors(&stored));
+            assert!(replayed.action == stored.action);
+        }
+
+        #[test]
+        fn should_evaluate_lost_position_from_side_to_move() {
+            let game = parse_game(["║ O │ O │   ║", "║ O │ X │   ║", "║   │   │ X ║"]);
+
+            for state_storage in [StateStorage::Replay, StateStorage::Stored] {
+                let search_result = make_mcts(2000, state_storage).search(&game, 5);
+
+                assert!(search_result.evaluation.value < -0.5);
+            }
+        }
+
+        #[test]
+        fn should_resign_lost_positions_below_the_threshold() {
+            let lost = parse_game(["║ O │ O │   ║", "║ O │ X │   ║", "║   │   │ X ║"]);
+            let blocked = parse_game(["║ O │ O │   ║", "║   │ X │   ║", "║   │   │   ║"]);
+
+            let mut mcts = make_mcts(2000, StateStorage::Stored).with_resign_threshold(-0.5);
+
+            assert!(mcts.search(&lost, 5).resign);
+            assert!(!mcts.search(&blocked, 3).resign);
+        }
+
+        #[test]
+        fn should_block_opponent_threat() {
+            let game = parse_game(["║ O │ O │   ║", "║   │ X │   ║", "║   │   │   ║"]);
+
+            for state_storage in [StateStorage::Replay, StateStorage::Stored] {
+                let search_result = make_mcts(2000, state_storage).search(&game, 3);
+
+                assert!(search_result.action == TicTacToeAction::Place { index: 2 });
+            }
+        }
+
+        #[test]
+        fn should_force_playouts_of_every_root_child() {
+            let mut mcts = make_forced_mcts(400);
+
+            let tree = mcts.build_tree(&TicTacToe::new());
+
+            let root = &tree.nodes[tree.root_index];
+
+            for edge in &root.edges {
+                let forced_visits =
+                    ForcedPlayouts { k: 2.0 }.forced_visits(edge.prior, root.visits);
+
+                assert!(tree.nodes[edge.child_index].visits as f32 >= forced_visits - 1.0);
+            }
+        }
+
+        #[test]
+        fn should_prune_forced_playouts_from_the_policy() {
+            let game = parse_game(["║ O │ O │   ║", "║   │ X │   ║", "║   │   │   ║"]);
+
+            let mut mcts = make_forced_mcts(400);
+
+            let tree = mcts.build_tree(&game);
+
+            let root = &tree.nodes[tree.root_index];
+
+            let visits: Vec<u32> = root
+                .edges
+                .iter()
+                .map(|edge| tree.nodes[edge.child_index].visits)
+                .collect();
+
+            let best_visits = *visits.iter().max().unwrap();
+
+            let target_visits = mcts.target_visits(&tree);
+
+            assert!(target_visits.contains(&best_visits));
+            assert!(target_visits.iter().sum::<u32>() < visits.iter().sum::<u32>());
+
+            let best_prior = mcts
+                .evaluate(&tree)
+                .policy
+                .iter()
+                .map(|policy_item| policy_item.prior)
+                .fold(0.0, f32::max);
+
+            assert!(best_prior > best_visits as f32 / visits.iter().sum::<u32>() as f32);
+        }
+
+        #[test]
+        fn should_weigh_chance_outcomes_by_probability() {
+            for (win_chance, expected_action) in
+                [(0.2, CoinAction::Safe), (0.8, CoinAction::Gamble)]
+            {
+                for state_storage in [StateStorage::Replay, StateStorage::Stored] {
+                    let options = MtcsOptions::new(
+                        400,
+                        RolloutEvaluator::new(),
+                        Ucb1Scorer::new(),
+                        RandomExpander::new(),
+                    )
+                    .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+                    let mut mcts = Mcts::new(options, DefaultRngFactory::default())
+                        .with_seed(0)
+                        .with_state_storage(state_storage);
+
+                    let game = CoinGame {
+                        win_chance,
+                        ..CoinGame::new()
+                    };
+
+                    assert_eq!(mcts.search(&game, 1).action, expected_action);
+                }
+            }
+        }
+
+        #[test]
+        fn should_avoid_draws_with_contempt() {
+            for (draw_value, expected_action) in
+                [(0.0, CoinAction::Safe), (-0.5, CoinAction::Gamble)]
+            {
+                let options = MtcsOptions::new(
+                    400,
+                    RolloutEvaluator::new(),
+                    Ucb1Scorer::new(),
+                    RandomExpander::new(),
+                )
+                .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+                let mut mcts = Mcts::new(options, DefaultRngFactory::default())
+                    .with_seed(0)
+                    .with_draw_value(draw_value);
+
+                let game = CoinGame {
+                    win_chance: 0.4,
+                    ..CoinGame::new()
+                };
+
+                assert_eq!(mcts.search(&game, 1).action, expected_action);
+            }
+        }
+
+        #[test]
+        fn should_search_over_determinizations_of_hidden_information() {
+            let options = MtcsOptions::new(
+                1000,
+                RolloutEvaluator::new(),
+                Ucb1Scorer::new(),
+                RandomExpander::new(),
+            )
+            .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+            let mut mcts = Mcts::new(options, DefaultRngFactory::default())
+                .with_seed(0)
+                .with_determinizations(10);
+
+            let game = HiddenCardGame::new();
+
+            assert_eq!(mcts.search(&game, 1).action, HiddenCardAction::Fold);
+
+            let search_result = mcts.search(&game.observation(), 1);
+
+            assert_eq!(search_result.action, HiddenCardAction::Bet);
+            assert_eq!(mcts.statistics().simulations, 1000);
+            assert!(search_result.evaluation.value > 0.5);
+        }
+
+        #[test]
+        fn should_report_memory_usage_of_the_last_tree() {
+            let mut mcts = make_mcts(50, StateStorage::Stored);
+
+            assert_eq!(mcts.memory_usage(), MemoryUsage::default());
+
+            mcts.search(&TicTacToe::new(), 1);
+
+            let MemoryUsage { nodes, bytes } = mcts.memory_usage();
+
+            assert_eq!(nodes, 51);
+            assert!(bytes >= nodes * size_of::<Node<TicTacToe>>());
+        }
+
+        #[test]
+        fn should_share_nodes_between_transpositions() {
+            let mut mcts = make_mcts(2000, StateStorage::Stored);
+
+            let tree = mcts.build_tree(&TicTacToe::new());
+
+            let mut parents = vec![0; tree.nodes.len()];
+
+            for edge in tree.nodes.iter().flat_map(|node| &node.edges) {
+                parents[edge.child_index as usize] += 1;
+            }
+
+            assert!(parents.iter().any(|&count| count > 1));
+        }
+
+        #[test]
+        fn should_stop_at_the_node_cap() {
+            let mut mcts = make_mcts(400, StateStorage::Stored).with_max_nodes(20);
+
+            mcts.search(&TicTacToe::new(), 1);
+
+            assert_eq!(mcts.statistics().simulations, 19);
+            assert_eq!(mcts.memory_usage().nodes, 20);
+        }
+
+        #[test]
+        fn should_repeat_deterministic_searches_regardless_of_history() {
+            let options = MtcsOptions::new(
+                200,
+                RolloutEvaluator::new(),
+                Ucb1Scorer::new(),
+                RandomExpander::new(),
+            );
+
+            let mut first = Mcts::new(options, DefaultRngFactory::default()).with_deterministic(7);
+            let mut second = first.clone();
+
+            second.search(
+                &parse_game(["║ X │   │   ║", "║   │   │   ║", "║   │   │   ║"]),
+                2,
+            );
+
+            let game = TicTacToe::new();
+
+            let priors = |search_result: SearchResult<TicTacToe>| -> Vec<(String, u32)> {
+                search_result
+                    .evaluation
+                    .policy
+                    .iter()
+                    .map(|policy_item| {
+                        (policy_item.action.to_string(), policy_item.prior.to_bits())
+                    })
+                    .collect()
+            };
+
+            assert_eq!(
+                priors(first.search(&game, 1)),
+                priors(second.search(&game, 1))
+            );
+        }
+
+        #[test]
+        fn should_only_search_the_given_root_actions() {
+            let game = parse_game(["║ O │ O │   ║", "║   │ X │   ║", "║   │   │   ║"]);
+
+            let actions = [
+                TicTacToeAction::Place { index: 5 },
+                TicTacToeAction::Place { index: 7 },
+            ];
+
+            let is_restricted = |search_result: &SearchResult<TicTacToe>| {
+                actions.contains(&search_result.action)
+                    && search_result
+                        .evaluation
+                        .policy
+                        .iter()
+                        .all(|policy_item| actions.contains(&policy_item.action))
+            };
+
+            let mut mcts = make_mcts(200, StateStorage::Stored);
+
+            assert!(is_restricted(&mcts.search_among(&game, 3, &actions)));
+            assert_eq!(mcts.search(&game, 3).evaluation.policy.len(), 6);
+
+            let mut mcts = make_forced_mcts(200);
+
+            assert!(is_restricted(&mcts.search_among(&game, 3, &actions)));
+            assert_eq!(mcts.search(&game, 3).evaluation.policy.len(), 6);
+        }
+
+        #[test]
+        fn should_record_simulations_spent() {
+            let mut mcts = make_mcts(50, StateStorage::Stored);
+
+            mcts.search(&TicTacToe::new(), 1);
+
+            assert_eq!(mcts.statistics().simulations, 50);
+        }
+
+        #[test]
+        fn should_spend_fewer_simulations_when_one_move_dominates() {
+            let game = parse_game(["║ X │ O │ X ║", "║ X │ O │ O ║", "║ O │ X │   ║"]);
+
+            let mut mcts =
+                make_mcts(400, StateStorage::Stored).with_adaptive_budget(AdaptiveBudget::new(400));
+
+            mcts.search(&game, 9);
+
+            assert!(mcts.statistics().simulations < 400);
+        }
+
+        #[test]
+        fn should_stop_once_the_best_move_is_decided() {
+            let game = parse_game(["║ X │ O │ X ║", "║ X │ O │ O ║", "║ O │ X │   ║"]);
+
+            let mut mcts = make_mcts(400, StateStorage::Stored).with_early_termination(true);
+
+            let search_result = mcts.search(&game, 9);
+
+            assert!(mcts.statistics().simulations < 400);
+            assert!(search_result.action == TicTacToeAction::Place { index: 8 });
+        }
+
+        #[test]
+        fn should_stay_within_adaptive_budget() {
+            let budget = AdaptiveBudget::new(400);
+
+            let mut mcts = make_mcts(400, StateStorage::Stored).with_adaptive_budget(budget);
+
+            mcts.search(&TicTacToe::new(), 1);
+
+            let simulations = mcts.statistics().simulations;
+
+            assert!(simulations >= budget.min_simulations);
+            assert!(simulations <= budget.max_simulations);
+        }
+    }
 }
@@ -0,0 +1,8 @@
+// NOTE - For a search tree, `nodes` counts its nodes. For an evaluation or prediction cache
+// it counts the entries held.
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MemoryUsage {
+    pub nodes: usize,
+    pub bytes: usize,
+}
@@ -1,15 +1,31 @@
 mod classic;
 mod evaluator;
 mod expander;
+mod forced_playouts;
 #[allow(clippy::module_inception)]
 mod mcts;
+mod memory_usage;
 mod neural_network;
+mod node_arena;
 mod noise;
 mod scorer;
+mod search_budget;
+mod search_statistics;
+mod state_storage;
 mod temperature;
 mod tree;
+mod tree_export;
 
 pub use classic::ClassicMctsPlayer;
+pub use evaluator::SymmetryMode;
+pub(crate) use evaluator::{Evaluator, NeuralNetworkEvaluator};
+pub use forced_playouts::ForcedPlayouts;
+pub(crate) use mcts::PendingSearch;
+pub use memory_usage::MemoryUsage;
 pub use neural_network::NeuralNetworkMctsPlayer;
 pub use noise::DirichletNoise;
+pub use search_budget::AdaptiveBudget;
+pub use search_statistics::SearchStatistics;
+pub use state_storage::StateStorage;
 pub use temperature::TemperatureSchedule;
+pub use tree_export::{TreeExportNode, TreeExportOptions};
@@ -1,19 +1,32 @@
-use crate::core::{Choice, Game, Player};
+use std::time::Duration;
+
+use crate::core::{Choice, DefaultRngFactory, Evaluation, Game, Player, RngFactory};
 use crate::neural_network::{ActionEncoder, NeuralNetwork, StateEncoder};
-use crate::player::mcts::evaluator::NeuralNetworkEvaluator;
+use crate::player::mcts::evaluator::{CachedEvaluator, NeuralNetworkEvaluator, SymmetryMode};
 use crate::player::mcts::expander::CompleteExpander;
-use crate::player::mcts::mcts::{Mcts, MtcsOptions, SearchResult};
+use crate::player::mcts::forced_playouts::ForcedPlayouts;
+use crate::player::mcts::mcts::{Mcts, MtcsOptions, PendingSearch, SearchResult};
+use crate::player::mcts::memory_usage::MemoryUsage;
 use crate::player::mcts::noise::DirichletNoise;
 use crate::player::mcts::scorer::PuctScorer;
+use crate::player::mcts::search_budget::AdaptiveBudget;
+use crate::player::mcts::search_statistics::SearchStatistics;
+use crate::player::mcts::state_storage::StateStorage;
 use crate::player::mcts::temperature::TemperatureSchedule;
+use crate::player::mcts::tree_export::{TreeExportNode, TreeExportOptions};
+
+type CachedNeuralNetworkEvaluator<G, SE, AE, NN> =
+    CachedEvaluator<G, NeuralNetworkEvaluator<G, SE, AE, NN>>;
 
+#[derive(Clone)]
 pub struct NeuralNetworkMctsPlayer<
     G: Game,
     SE: StateEncoder<G>,
     AE: ActionEncoder<G>,
     NN: NeuralNetwork,
+    F: RngFactory = DefaultRngFactory,
 > {
-    mcts: Mcts<G, NeuralNetworkEvaluator<G, SE, AE, NN>, PuctScorer, CompleteExpander>,
+    mcts: Mcts<G, CachedNeuralNetworkEvaluator<G, SE, AE, NN>, PuctScorer, CompleteExpander, F>,
 }
 
 impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork>
@@ -24,16 +37,39 @@ impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork>
         state_encoder: SE,
         action_encoder: AE,
         neural_network: NN,
+    ) -> Self {
+        Self::with_rng_factory(
+            simulations,
+            state_encoder,
+            action_encoder,
+            neural_network,
+            DefaultRngFactory::default(),
+        )
+    }
+}
+
+impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork, F: RngFactory>
+    NeuralNetworkMctsPlayer<G, SE, AE, NN, F>
+{
+    pub fn with_rng_factory(
+        simulations: u32,
+        state_encoder: SE,
+        action_encoder: AE,
+        neural_network: NN,
+        rng_factory: F,
     ) -> Self {
         let options = MtcsOptions::new(
             simulations,
-            NeuralNetworkEvaluator::new(state_encoder, action_encoder, neural_network),
+            CachedEvaluator::new(
+                NeuralNetworkEvaluator::new(state_encoder, action_encoder, neural_network),
+                0,
+            ),
             PuctScorer::new(),
             CompleteExpander::new(),
         );
 
         Self {
-            mcts: Mcts::new(options),
+            mcts: Mcts::new(options, rng_factory),
         }
     }
 
@@ -43,32 +79,271 @@ impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork>
         self
     }
 
+    pub fn with_c_puct(mut self, c_puct: f32) -> Self {
+        let scorer = self.mcts.scorer().clone().with_c_puct(c_puct);
+
+        self.mcts = self.mcts.with_scorer(scorer);
+
+        self
+    }
+
+    pub fn with_c_puct_base(mut self, c_puct_base: f32) -> Self {
+        let scorer = self.mcts.scorer().clone().with_c_puct_base(c_puct_base);
+
+        self.mcts = self.mcts.with_scorer(scorer);
+
+        self
+    }
+
+    pub fn with_fpu_reduction(mut self, fpu_reduction: f32) -> Self {
+        let scorer = self.mcts.scorer().clone().with_fpu_reduction(fpu_reduction);
+
+        self.mcts = self.mcts.with_scorer(scorer);
+
+        self
+    }
+
+    pub fn with_symmetry_mode(mut self, symmetry_mode: SymmetryMode) -> Self {
+        self.mcts = self.mcts.map_evaluator(|evaluator| {
+            evaluator.map_inner(|evaluator| evaluator.with_symmetry_mode(symmetry_mode))
+        });
+
+        self
+    }
+
+    // NOTE - Caches up to `capacity` leaf evaluations by canonical position, for one search
+    // or, with `reuse_across_moves`, for the whole game. Off by default.
+
+    pub fn with_evaluation_cache(mut self, capacity: usize, reuse_across_moves: bool) -> Self {
+        self.mcts = self.mcts.map_evaluator(|evaluator| {
+            CachedEvaluator::new(evaluator.into_inner(), capacity)
+                .with_reuse_across_moves(reuse_across_moves)
+        });
+
+        self
+    }
+
     pub fn with_dirichlet_noise(mut self, dirichlet_noise: DirichletNoise) -> Self {
         self.mcts = self.mcts.with_dirichlet_noise(dirichlet_noise);
 
         self
     }
 
+    pub fn with_forced_playouts(mut self, forced_playouts: ForcedPlayouts) -> Self {
+        self.mcts = self.mcts.with_forced_playouts(forced_playouts);
+
+        self
+    }
+
     pub fn with_temperature_schedule(mut self, temperature_schedule: TemperatureSchedule) -> Self {
         self.mcts = self.mcts.with_temperature_schedule(temperature_schedule);
 
         self
     }
+
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.mcts = self.mcts.with_max_nodes(max_nodes);
+
+        self
+    }
+
+    // NOTE - Also applies to the draw probability of networks with a win/draw/loss value
+    // head, not just to drawn positions the search reaches.
+
+    pub fn with_draw_value(mut self, draw_value: f32) -> Self {
+        self.mcts = self
+            .mcts
+            .with_draw_value(draw_value)
+            .map_evaluator(|evaluator| {
+                evaluator.map_inner(|evaluator| evaluator.with_draw_value(draw_value))
+            });
+
+        self
+    }
+
+    pub fn with_resign_threshold(mut self, resign_threshold: f32) -> Self {
+        self.mcts = self.mcts.with_resign_threshold(resign_threshold);
+
+        self
+    }
+
+    pub fn with_state_storage(mut self, state_storage: StateStorage) -> Self {
+        self.mcts = self.mcts.with_state_storage(state_storage);
+
+        self
+    }
+
+    pub fn with_adaptive_budget(mut self, adaptive_budget: AdaptiveBudget) -> Self {
+        self.mcts = self.mcts.with_adaptive_budget(adaptive_budget);
+
+        self
+    }
+
+    pub fn with_early_termination(mut self, early_termination: bool) -> Self {
+        self.mcts = self.mcts.with_early_termination(early_termination);
+
+        self
+    }
+
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.set_time_limit(time_limit);
+
+        self
+    }
+
+    pub fn set_time_limit(&mut self, time_limit: Duration) {
+        self.mcts.set_time_limit(time_limit);
+    }
+
+    pub fn with_determinizations(mut self, determinizations: u32) -> Self {
+        self.mcts = self.mcts.with_determinizations(determinizations);
+
+        self
+    }
+
+    pub fn with_deterministic(mut self, seed: u64) -> Self {
+        self.mcts = self.mcts.with_deterministic(seed);
+
+        self
+    }
+
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.mcts.memory_usage()
+    }
+
+    pub fn search_statistics(&self) -> SearchStatistics {
+        self.mcts.statistics()
+    }
+
+    pub fn evaluation_cache_hits(&self) -> u64 {
+        self.mcts.evaluator().hits()
+    }
+
+    pub fn evaluation_cache_misses(&self) -> u64 {
+        self.mcts.evaluator().misses()
+    }
+
+    pub fn evaluation_cache_memory_usage(&self) -> MemoryUsage {
+        self.mcts.evaluator().memory_usage()
+    }
+
+    pub fn neural_network(&self) -> &NN {
+        self.mcts.evaluator().inner().neural_network()
+    }
+
+    pub fn choose_action_among(
+        &mut self,
+        game: &G,
+        turn_number: u32,
+        actions: &[G::Action],
+    ) -> Choice<G> {
+        let SearchResult {
+            action,
+            evaluation,
+            resign,
+        } = self.mcts.search_among(game, turn_number, actions);
+
+        Choice {
+            action,
+            evaluation: Some(evaluation),
+            resign,
+        }
+    }
+
+    pub fn choose_action_with_tree(
+        &mut self,
+        game: &G,
+        turn_number: u32,
+        options: &TreeExportOptions,
+    ) -> (Choice<G>, TreeExportNode) {
+        let (
+            SearchResult {
+                action,
+                evaluation,
+                resign,
+            },
+            tree,
+        ) = self.mcts.search_with_tree(game, turn_number, options);
+
+        let choice = Choice {
+            action,
+            evaluation: Some(evaluation),
+            resign,
+        };
+
+        (choice, tree)
+    }
+
+    // NOTE - Stepwise search, for callers that batch leaf evaluations across games. See
+    // `Mcts::start_search`.
+
+    pub(crate) fn start_search(&mut self, game: &G) -> PendingSearch<G> {
+        self.mcts.start_search(game)
+    }
+
+    pub(crate) fn select_leaf<'a>(&mut self, search: &'a mut PendingSearch<G>) -> Option<&'a G> {
+        self.mcts.select_leaf(search)
+    }
+
+    pub(crate) fn complete_leaf(
+        &mut self,
+        search: &mut PendingSearch<G>,
+        evaluation: Evaluation<G>,
+    ) {
+        self.mcts.complete_leaf(search, evaluation);
+    }
+
+    pub(crate) fn finish_search(
+        &mut self,
+        search: PendingSearch<G>,
+        turn_number: u32,
+    ) -> Choice<G> {
+        let SearchResult {
+            action,
+            evaluation,
+            resign,
+        } = self.mcts.finish_pending_search(search, turn_number);
+
+        Choice {
+            action,
+            evaluation: Some(evaluation),
+            resign,
+        }
+    }
+
+    pub(crate) fn evaluate_batch(&mut self, games: &[&G]) -> Vec<Evaluation<G>> {
+        self.mcts
+            .evaluator_mut()
+            .evaluate_batch_with(games, NeuralNetworkEvaluator::evaluate_batch)
+    }
 }
 
-impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork> Player<G>
-    for NeuralNetworkMctsPlayer<G, SE, AE, NN>
+impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork, F: RngFactory> Player<G>
+    for NeuralNetworkMctsPlayer<G, SE, AE, NN, F>
 {
     fn name(&self) -> &'static str {
         "MCTS - Neural Network"
     }
 
     fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
-        let SearchResult { action, evaluation } = self.mcts.search(game, turn_number);
+        let SearchResult {
+            action,
+            evaluation,
+            resign,
+        } = self.mcts.search(game, turn_number);
 
         Choice {
             action,
             evaluation: Some(evaluation),
+            resign,
         }
     }
+
+    fn reseed(&mut self, seed: u64) {
+        self.mcts.reseed(seed);
+    }
+
+    fn new_game(&mut self) {
+        self.mcts.new_game();
+    }
 }
@@ -0,0 +1,156 @@
+use std::mem::{size_of, take};
+use std::ops::{Index, IndexMut};
+
+use crate::core::Game;
+use crate::player::mcts::tree::{Edge, Node};
+
+pub type NodeIndex = u32;
+
+// NOTE - Nodes are addressed by `u32` to keep edges small. Clearing the arena keeps the
+// node slots and each node's edge list, so later searches reuse their allocations.
+
+pub struct NodeArena<G: Game> {
+    nodes: Vec<Node<G>>,
+    spare_edges: Vec<Vec<Edge<G>>>,
+}
+
+impl<G: Game> NodeArena<G> {
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![],
+            spare_edges: vec![],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+    }
+
+    pub fn clear(&mut self) {
+        for mut node in self.nodes.drain(..) {
+            node.edges.clear();
+
+            self.spare_edges.push(take(&mut node.edges));
+        }
+    }
+
+    pub fn push(&mut self, mut node: Node<G>) -> NodeIndex {
+        let index = NodeIndex::try_from(self.nodes.len()).expect("too many nodes for the arena");
+
+        if node.edges.capacity() == 0
+            && let Some(edges) = self.spare_edges.pop()
+        {
+            node.edges = edges;
+        }
+
+        self.nodes.push(node);
+
+        index
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Node<G>> {
+        self.nodes.iter()
+    }
+
+    // NOTE - Counts reserved capacity rather than length, since that is what stays
+    // allocated between searches.
+
+    pub fn heap_bytes(&self) -> usize {
+        let edge_bytes = |edges: &Vec<Edge<G>>| edges.capacity() * size_of::<Edge<G>>();
+
+        let node_bytes: usize = self
+            .iter()
+            .map(|node| {
+                let action_bytes = if node.unexplored_actions.spilled() {
+                    node.unexplored_actions.capacity() * size_of::<G::Action>()
+                } else {
+                    0
+                };
+
+                edge_bytes(&node.edges) + action_bytes
+            })
+            .sum();
+
+        let spare_bytes: usize = self.spare_edges.iter().map(edge_bytes).sum();
+
+        self.nodes.capacity() * size_of::<Node<G>>()
+            + self.spare_edges.capacity() * size_of::<Vec<Edge<G>>>()
+            + node_bytes
+            + spare_bytes
+    }
+}
+
+impl<G: Game> Default for NodeArena<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Game> Index<NodeIndex> for NodeArena<G> {
+    type Output = Node<G>;
+
+    fn index(&self, index: NodeIndex) -> &Node<G> {
+        &self.nodes[index as usize]
+    }
+}
+
+impl<G: Game> IndexMut<NodeIndex> for NodeArena<G> {
+    fn index_mut(&mut self, index: NodeIndex) -> &mut Node<G> {
+        &mut self.nodes[index as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{ActionList, Turn};
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    fn make_node() -> Node<TicTacToe> {
+        Node {
+            turn: Turn::Player1,
+            chance: false,
+
+            edges: vec![],
+
+            perspective: Turn::Player1,
+            visits: 0,
+            total_value: 0.0,
+
+            unexplored_actions: ActionList::new(),
+            checkpoint: None,
+        }
+    }
+
+    mod clear {
+        use super::*;
+
+        #[test]
+        fn should_reuse_edge_lists_of_cleared_nodes() {
+            let mut arena = NodeArena::new();
+
+            let index = arena.push(make_node());
+
+            arena[index].edges.push(Edge {
+                action: TicTacToeAction::Place { index: 0 },
+                prior: 1.0,
+
+                child_index: 0,
+            });
+
+            arena.clear();
+
+            assert_eq!(arena.len(), 0);
+
+            let index = arena.push(make_node());
+
+            assert!(arena[index].edges.is_empty());
+            assert!(arena[index].edges.capacity() > 0);
+        }
+    }
+}
@@ -2,25 +2,68 @@ use std::f32;
 
 use crate::core::Game;
 use crate::player::mcts::scorer::scorer::Scorer;
-use crate::player::mcts::tree::Node;
+use crate::player::mcts::tree::{Edge, Node};
 
 #[derive(Clone)]
 pub struct PuctScorer {
     pub c_puct: f32,
+
+    // NOTE - AlphaZero's growing exploration: `c_puct + ln((1 + N + base) / base)` for a
+    // parent with `N` visits, which stays near `c_puct` until `N` nears the base.
+    pub c_puct_base: Option<f32>,
+
+    // NOTE - First-play urgency: an unvisited child is valued at its parent's value less
+    // this margin, rather than at zero, so search favors the children it has already
+    // found good.
+    pub fpu_reduction: Option<f32>,
 }
 
 impl PuctScorer {
     pub fn new() -> Self {
         Self {
             c_puct: f32::consts::SQRT_2,
+            c_puct_base: None,
+
+            fpu_reduction: None,
         }
     }
+
+    pub fn with_c_puct(mut self, c_puct: f32) -> Self {
+        self.c_puct = c_puct;
+
+        self
+    }
+
+    pub fn with_c_puct_base(mut self, c_puct_base: f32) -> Self {
+        self.c_puct_base = Some(c_puct_base);
+
+        self
+    }
+
+    pub fn with_fpu_reduction(mut self, fpu_reduction: f32) -> Self {
+        self.fpu_reduction = Some(fpu_reduction);
+
+        self
+    }
 }
 
 impl<G: Game> Scorer<G> for PuctScorer {
-    fn score(&self, parent: &Node<G>, child: &Node<G>) -> f32 {
+    fn score(&self, parent: &Node<G>, edge: &Edge<G>, child: &Node<G>) -> f32 {
         let exploitation = if child.visits == 0 {
-            0.0
+            self.fpu_reduction.map_or(0.0, |fpu_reduction| {
+                let parent_value = parent.total_value / parent.visits.max(1) as f32;
+
+                // NOTE - The parent's value is stored for whoever chose it, which is the
+                // side now choosing only when it is mid-turn.
+
+                let parent_value = if parent.perspective == parent.turn {
+                    parent_value
+                } else {
+                    -parent_value
+                };
+
+                parent_value - fpu_reduction
+            })
         } else {
             child.total_value / child.visits as f32
         };
@@ -28,8 +71,84 @@ impl<G: Game> Scorer<G> for PuctScorer {
         let child_visits = child.visits as f32;
         let parent_visits = parent.visits as f32;
 
-        let exploration = self.c_puct * child.prior * (parent_visits.sqrt() / (1.0 + child_visits));
+        let c_puct = self.c_puct_base.map_or(self.c_puct, |c_puct_base| {
+            self.c_puct + ((1.0 + parent_visits + c_puct_base) / c_puct_base).ln()
+        });
+
+        let exploration = c_puct * edge.prior * (parent_visits.sqrt() / (1.0 + child_visits));
 
         exploitation + exploration
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{ActionList, Turn};
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    fn make_node(perspective: Turn, visits: u32, total_value: f32) -> Node<TicTacToe> {
+        Node {
+            turn: Turn::Player1,
+            chance: false,
+
+            edges: vec![],
+
+            perspective,
+            visits,
+            total_value,
+
+            unexplored_actions: ActionList::new(),
+            checkpoint: None,
+        }
+    }
+
+    mod score {
+        use super::*;
+
+        #[test]
+        fn should_value_unvisited_children_below_their_parent() {
+            let scorer = PuctScorer::new().with_fpu_reduction(0.25);
+
+            let edge = Edge {
+                action: TicTacToeAction::Place { index: 0 },
+                prior: 0.0,
+
+                child_index: 1,
+            };
+
+            let child = make_node(Turn::Player1, 0, 0.0);
+
+            let root = make_node(Turn::Player1, 4, 2.0);
+            let reply = make_node(Turn::Player2, 4, 2.0);
+
+            assert!((scorer.score(&root, &edge, &child) - 0.25).abs() < f32::EPSILON);
+            assert!((scorer.score(&reply, &edge, &child) + 0.75).abs() < f32::EPSILON);
+        }
+
+        #[test]
+        fn should_grow_exploration_with_parent_visits() {
+            let scorer = PuctScorer::new();
+            let growing = PuctScorer::new().with_c_puct_base(100.0);
+
+            let edge = Edge {
+                action: TicTacToeAction::Place { index: 0 },
+                prior: 1.0,
+
+                child_index: 1,
+            };
+
+            let child = make_node(Turn::Player1, 1, 0.0);
+
+            let early = make_node(Turn::Player1, 0, 0.0);
+            let late = make_node(Turn::Player1, 10_000, 0.0);
+
+            assert!(
+                (growing.score(&early, &edge, &child) - scorer.score(&early, &edge, &child)).abs()
+                    < 0.01
+            );
+            assert!(growing.score(&late, &edge, &child) > 2.0 * scorer.score(&late, &edge, &child));
+        }
+    }
+}
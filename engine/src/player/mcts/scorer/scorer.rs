@@ -1,6 +1,6 @@
 use crate::core::Game;
-use crate::player::mcts::tree::Node;
+use crate::player::mcts::tree::{Edge, Node};
 
 pub trait Scorer<G: Game>: Clone {
-    fn score(&self, parent: &Node<G>, child: &Node<G>) -> f32;
+    fn score(&self, parent: &Node<G>, edge: &Edge<G>, child: &Node<G>) -> f32;
 }
@@ -2,7 +2,7 @@ use std::f32;
 
 use crate::core::Game;
 use crate::player::mcts::scorer::scorer::Scorer;
-use crate::player::mcts::tree::Node;
+use crate::player::mcts::tree::{Edge, Node};
 
 #[derive(Clone)]
 pub struct Ucb1Scorer {
@@ -15,10 +15,16 @@ impl Ucb1Scorer {
             c: f32::consts::SQRT_2,
         }
     }
+
+    pub fn with_exploration(mut self, c: f32) -> Self {
+        self.c = c;
+
+        self
+    }
 }
 
 impl<G: Game> Scorer<G> for Ucb1Scorer {
-    fn score(&self, parent: &Node<G>, child: &Node<G>) -> f32 {
+    fn score(&self, parent: &Node<G>, _edge: &Edge<G>, child: &Node<G>) -> f32 {
         if child.visits == 0 {
             return f32::INFINITY;
         }
@@ -0,0 +1,93 @@
+use crate::core::Game;
+use crate::player::mcts::tree::Tree;
+
+// NOTE - True when the most visited root child leads the runner-up by more than the
+// simulations left, so no outcome of those simulations can change which is most visited.
+
+pub(crate) fn is_decided<G: Game>(tree: &Tree<G>, remaining: u32) -> bool {
+    let root = &tree.nodes[tree.root_index];
+
+    let mut first = 0;
+    let mut second = 0;
+
+    for edge in &root.edges {
+        let visits = tree.nodes[edge.child_index].visits;
+
+        if visits > first {
+            second = first;
+            first = visits;
+        } else if visits > second {
+            second = visits;
+        }
+    }
+
+    first > second + remaining
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdaptiveBudget {
+    pub min_simulations: u32,
+    pub max_simulations: u32,
+
+    pub dominant_share: f32,
+    pub close_margin: f32,
+}
+
+impl AdaptiveBudget {
+    pub const CHECK_INTERVAL: u32 = 16;
+
+    pub fn new(simulations: u32) -> Self {
+        Self {
+            min_simulations: simulations / 4,
+            max_simulations: simulations.saturating_mul(2),
+
+            dominant_share: 0.9,
+            close_margin: 0.05,
+        }
+    }
+
+    // NOTE - The search always runs the minimum and never exceeds the maximum. In between,
+    // it stops as soon as the most visited root child holds the dominant share of visits.
+    // Past the base budget, it only continues while the two most visited root children
+    // have values within the close margin of each other.
+
+    pub(crate) fn should_continue<G: Game>(&self, tree: &Tree<G>, spent: u32, base: u32) -> bool {
+        if spent < self.min_simulations {
+            return true;
+        }
+
+        if spent >= self.max_simulations {
+            return false;
+        }
+
+        let root = &tree.nodes[tree.root_index];
+
+        let mut first: Option<(u32, f32)> = None;
+        let mut second: Option<(u32, f32)> = None;
+        let mut total_visits = 0;
+
+        for edge in &root.edges {
+            let child = &tree.nodes[edge.child_index];
+            let entry = (child.visits, child.total_value / child.visits.max(1) as f32);
+
+            total_visits += child.visits;
+
+            if first.is_none_or(|(visits, _)| child.visits > visits) {
+                second = first;
+                first = Some(entry);
+            } else if second.is_none_or(|(visits, _)| child.visits > visits) {
+                second = Some(entry);
+            }
+        }
+
+        let (Some((first_visits, first_value)), Some((_, second_value))) = (first, second) else {
+            return false;
+        };
+
+        if first_visits as f32 >= self.dominant_share * total_visits as f32 {
+            return false;
+        }
+
+        spent < base || (first_value - second_value).abs() < self.close_margin
+    }
+}
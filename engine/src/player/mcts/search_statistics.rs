@@ -0,0 +1,7 @@
+use crate::player::mcts::memory_usage::MemoryUsage;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SearchStatistics {
+    pub simulations: u32,
+    pub memory_usage: MemoryUsage,
+}
@@ -0,0 +1,28 @@
+use std::mem::size_of;
+
+use crate::core::Game;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum StateStorage {
+    Replay,
+    Stored,
+    #[default]
+    Automatic,
+}
+
+impl StateStorage {
+    // NOTE - Checkpoints up to this size are cheaper to copy into every node than to
+    // rebuild by replaying actions from the root.
+
+    const AUTOMATIC_MAX_CHECKPOINT_SIZE: usize = 64;
+
+    pub fn stores_states<G: Game>(self) -> bool {
+        match self {
+            StateStorage::Replay => false,
+            StateStorage::Stored => true,
+            StateStorage::Automatic => {
+                size_of::<G::Checkpoint>() <= Self::AUTOMATIC_MAX_CHECKPOINT_SIZE
+            }
+        }
+    }
+}
@@ -1,47 +1,100 @@
-use crate::core::{Game, Turn};
+use std::collections::HashMap;
+use std::mem::size_of;
+
+use crate::core::{ActionList, Game, Turn};
+use crate::player::mcts::memory_usage::MemoryUsage;
+use crate::player::mcts::node_arena::{NodeArena, NodeIndex};
+
+// NOTE - Positions reached along different paths share a node when the game hashes them,
+// which turns the tree into a DAG. A node is only shared between parents whose side to
+// move is the same, so its value keeps a single perspective.
 
 pub struct Tree<G: Game> {
-    pub nodes: Vec<Node<G>>,
-    pub root_index: usize,
+    pub nodes: NodeArena<G>,
+    pub root_index: NodeIndex,
+
+    pub transpositions: HashMap<TranspositionKey, NodeIndex>,
 
     pub game: G,
 }
 
+pub type TranspositionKey = (u64, Turn, Turn);
+
 impl<G: Game> Tree<G> {
-    pub fn new(game: G) -> Self {
+    // NOTE - Reusing the arena from a previous search preserves its allocations.
+
+    pub fn with_buffer(game: G, mut nodes: NodeArena<G>) -> Self {
+        let chance = !game.chance_outcomes().is_empty();
+
         let node = Node {
-            action: None,
             turn: Turn::Player1,
+            chance,
 
-            parent_index: None,
-            child_indices: vec![],
+            edges: vec![],
 
-            unexplored_actions: game.get_possible_actions(),
+            unexplored_actions: if chance {
+                ActionList::new()
+            } else {
+                game.get_possible_actions()
+            },
+            checkpoint: Some(game.create_checkpoint()),
 
+            perspective: Turn::Player1,
             visits: 0,
             total_value: 0.0,
-            prior: 0.0,
         };
 
+        nodes.clear();
+
+        let root_index = nodes.push(node);
+
         Self {
-            nodes: vec![node],
-            root_index: 0,
+            nodes,
+            root_index,
+
+            transpositions: HashMap::new(),
 
             game,
         }
     }
+
+    pub fn into_buffer(self) -> NodeArena<G> {
+        self.nodes
+    }
+
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let transposition_bytes =
+            self.transpositions.capacity() * size_of::<(TranspositionKey, NodeIndex)>();
+
+        MemoryUsage {
+            nodes: self.nodes.len(),
+            bytes: self.nodes.heap_bytes() + transposition_bytes,
+        }
+    }
 }
 
 pub struct Node<G: Game> {
-    pub action: Option<G::Action>,
     pub turn: Turn,
 
-    pub parent_index: Option<usize>,
-    pub child_indices: Vec<usize>,
+    // NOTE - Chance nodes are expanded with every outcome at once, using its probability
+    // as the prior, and their children are visited in proportion to it.
+    pub chance: bool,
 
+    pub edges: Vec<Edge<G>>,
+
+    // NOTE - From the perspective of the player who chose the action leading to this
+    // node, or of the root player for the root itself.
+    pub perspective: Turn,
     pub visits: u32,
     pub total_value: f32,
+
+    pub unexplored_actions: ActionList<G::Action>,
+    pub checkpoint: Option<G::Checkpoint>,
+}
+
+pub struct Edge<G: Game> {
+    pub action: G::Action,
     pub prior: f32,
 
-    pub unexplored_actions: Vec<G::Action>,
+    pub child_index: NodeIndex,
 }
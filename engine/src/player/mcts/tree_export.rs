@@ -0,0 +1,226 @@
+use std::cmp::Reverse;
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::core::Game;
+use crate::player::mcts::node_arena::NodeIndex;
+use crate::player::mcts::tree::{Edge, Tree};
+
+#[derive(Clone, Copy, Debug)]
+pub struct TreeExportOptions {
+    pub max_depth: usize,
+    pub min_visits: u32,
+}
+
+impl TreeExportOptions {
+    pub fn new() -> Self {
+        Self {
+            max_depth: 3,
+            min_visits: 1,
+        }
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+
+        self
+    }
+
+    pub fn with_min_visits(mut self, min_visits: u32) -> Self {
+        self.min_visits = min_visits;
+
+        self
+    }
+}
+
+impl Default for TreeExportOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// NOTE - `name` and `children` follow the shape expected by `d3.hierarchy`. Transpositions
+// are written out once under each parent that reaches them.
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TreeExportNode {
+    pub name: String,
+    pub visits: u32,
+    pub q: f32,
+    pub prior: f32,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<TreeExportNode>,
+}
+
+impl TreeExportNode {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("unable to serialize search tree")
+    }
+
+    // NOTE - Graphviz, for `dot -Tsvg`. Nodes are numbered in preorder, and each is
+    // labelled with its action and statistics.
+
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph tree {\n    node [shape=box];\n");
+
+        self.write_dot(&mut dot, &mut 0);
+
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    fn write_dot(&self, dot: &mut String, next_id: &mut usize) -> usize {
+        let id = *next_id;
+
+        *next_id += 1;
+
+        let _ = writeln!(
+            dot,
+            "    n{id} [label=\"{}\\nN={} Q={:.3} P={:.3}\"];",
+            self.name.replace('"', "\\\""),
+            self.visits,
+            self.q,
+            self.prior
+        );
+
+        for child in &self.children {
+            let child_id = child.write_dot(dot, next_id);
+
+            let _ = writeln!(dot, "    n{id} -> n{child_id};");
+        }
+
+        id
+    }
+}
+
+impl<G: Game> Tree<G> {
+    pub fn export(&self, options: &TreeExportOptions) -> TreeExportNode {
+        self.export_node(self.root_index, None, 0, options)
+    }
+
+    fn export_node(
+        &self,
+        node_index: NodeIndex,
+        edge: Option<&Edge<G>>,
+        depth: usize,
+        options: &TreeExportOptions,
+    ) -> TreeExportNode {
+        let node = &self.nodes[node_index];
+
+        let q = node.total_value / node.visits.max(1) as f32;
+
+        let mut children = vec![];
+
+        if depth < options.max_depth {
+            children = node
+                .edges
+                .iter()
+                .filter(|edge| self.nodes[edge.child_index].visits >= options.min_visits)
+                .map(|edge| self.export_node(edge.child_index, Some(edge), depth + 1, options))
+                .collect();
+
+            children.sort_by_key(|child| Reverse(child.visits));
+        }
+
+        TreeExportNode {
+            name: edge.map_or_else(|| "root".to_string(), |edge| edge.action.to_string()),
+            visits: node.visits,
+            q,
+            prior: edge.map_or(0.0, |edge| edge.prior),
+
+            children,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::ActionList;
+    use crate::game::tic_tac_toe::TicTacToe;
+    use crate::player::mcts::node_arena::NodeArena;
+    use crate::player::mcts::tree::Node;
+
+    fn make_tree() -> Tree<TicTacToe> {
+        let mut tree = Tree::with_buffer(TicTacToe::new(), NodeArena::new());
+
+        let actions = tree.game.get_possible_actions();
+
+        for (i, &action) in actions.iter().take(2).enumerate() {
+            let parent_index = if i == 0 { tree.root_index } else { 1 };
+
+            let child_index = tree.nodes.push(Node {
+                turn: tree.nodes[parent_index].turn.advance(),
+                chance: false,
+
+                edges: vec![],
+
+                perspective: tree.nodes[parent_index].turn,
+                visits: 4,
+                total_value: 2.0,
+
+                unexplored_actions: ActionList::new(),
+                checkpoint: None,
+            });
+
+            tree.nodes[parent_index].edges.push(Edge {
+                action,
+                prior: 0.5,
+
+                child_index,
+            });
+        }
+
+        tree.nodes[tree.root_index].visits = 4;
+
+        tree
+    }
+
+    mod export {
+        use super::*;
+
+        #[test]
+        fn should_report_mean_value_as_q() {
+            let mut tree = make_tree();
+
+            tree.nodes[2].total_value = -1.0;
+
+            let root = tree.export(&TreeExportOptions::new());
+
+            assert_eq!(root.name, "root");
+            assert!((root.children[0].q - 0.5).abs() < f32::EPSILON);
+            assert!((root.children[0].children[0].q + 0.25).abs() < f32::EPSILON);
+        }
+
+        #[test]
+        fn should_respect_max_depth_and_min_visits() {
+            let tree = make_tree();
+
+            let root = tree.export(&TreeExportOptions::new().with_max_depth(1));
+
+            assert_eq!(root.children.len(), 1);
+            assert!(root.children[0].children.is_empty());
+
+            let root = tree.export(&TreeExportOptions::new().with_min_visits(5));
+
+            assert!(root.children.is_empty());
+            assert!(!root.to_json().contains("children"));
+        }
+
+        #[test]
+        fn should_write_graphviz_edges_in_preorder() {
+            let tree = make_tree();
+
+            let dot = tree.export(&TreeExportOptions::new()).to_dot();
+
+            assert!(dot.starts_with("digraph tree {"));
+            assert!(dot.contains("n0 [label=\"root\\nN=4 Q=0.000 P=0.000\"];"));
+            assert!(dot.contains("n0 -> n1;"));
+            assert!(dot.contains("n1 -> n2;"));
+        }
+    }
+}
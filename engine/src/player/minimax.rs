@@ -1,11 +1,179 @@
-use crate::core::{Choice, Game, Outcome, Player};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::core::{ActionList, Choice, Game, Outcome, Player};
+use crate::player::heuristic::{Heuristic, NullHeuristic};
 
 #[derive(Clone)]
-pub struct MinimaxPlayer {
+pub struct MinimaxPlayer<H = NullHeuristic> {
+    depth: usize,
+    time_limit: Option<Duration>,
+    threads: usize,
+    heuristic: H,
+}
+
+// NOTE - Results of finished nodes, keyed by position and by whose value is being
+// maximized, and shared by every thread searching the move. A value is reused by any
+// search at most as deep as the one that stored it, either outright or to narrow the
+// window, depending on whether it was cut off. Games without a position key never store
+// anything.
+
+struct TranspositionTable<A> {
+    shards: Vec<Mutex<TableShard<A>>>,
+}
+
+type TableShard<A> = HashMap<(u64, Objective), TableEntry<A>>;
+
+#[derive(Clone, Copy)]
+struct TableEntry<A> {
     depth: usize,
+    value: f32,
+    bound: Bound,
+    best_action: Option<A>,
+    horizon_reached: bool,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+impl<A: Copy> TranspositionTable<A> {
+    const SHARDS: usize = 64;
+
+    fn new() -> Self {
+        Self {
+            shards: (0..Self::SHARDS)
+                .map(|_| Mutex::new(HashMap::new()))
+                .collect(),
+        }
+    }
+
+    fn shard(&self, hash: u64) -> &Mutex<TableShard<A>> {
+        &self.shards[hash.to_le_bytes()[0] as usize % Self::SHARDS]
+    }
+
+    fn get(&self, key: (u64, Objective)) -> Option<TableEntry<A>> {
+        self.shard(key.0)
+            .lock()
+            .expect("transposition table poisoned")
+            .get(&key)
+            .copied()
+    }
+
+    fn insert(&self, key: (u64, Objective), entry: TableEntry<A>) {
+        let mut shard = self
+            .shard(key.0)
+            .lock()
+            .expect("transposition table poisoned");
+
+        if shard
+            .get(&key)
+            .is_none_or(|existing| existing.depth <= entry.depth)
+        {
+            shard.insert(key, entry);
+        }
+    }
+}
+
+// NOTE - State of one thread's searches of one move. The clock is only read every so
+// many nodes, and never without a time limit, so untimed searches also run where there
+// is no system clock.
+//
+// Actions are tried best first: the best action found for the position by an earlier,
+// shallower search, then the two most recent actions to cause a cutoff at the same ply,
+// then by how much their cutoffs have pruned so far. Games without a position key skip
+// the first.
+
+struct Search<'a, A> {
+    table: &'a TranspositionTable<A>,
+    stop: &'a AtomicBool,
+
+    deadline: Option<Instant>,
+    nodes: u64,
+    horizon_reached: bool,
+    extending: bool,
+
+    ply: usize,
+    killers: Vec<[Option<A>; 2]>,
+    history: HashMap<A, u64>,
+}
+
+impl<'a, A: Copy + Eq + Hash> Search<'a, A> {
+    const CLOCK_INTERVAL: u64 = 1024;
+
+    fn new(table: &'a TranspositionTable<A>, stop: &'a AtomicBool) -> Self {
+        Self {
+            table,
+            stop,
+
+            deadline: None,
+            nodes: 0,
+            horizon_reached: false,
+            extending: false,
+
+            ply: 0,
+            killers: vec![],
+            history: HashMap::new(),
+        }
+    }
+
+    fn order_actions(&self, actions: &mut ActionList<A>, best_action: Option<A>) {
+        let killers = self.killers.get(self.ply).copied().unwrap_or_default();
+
+        actions.sort_by_cached_key(|action| {
+            let rank = if best_action == Some(*action) {
+                0
+            } else if killers[0] == Some(*action) {
+                1
+            } else if killers[1] == Some(*action) {
+                2
+            } else {
+                3
+            };
+
+            (
+                rank,
+                Reverse(self.history.get(action).copied().unwrap_or(0)),
+            )
+        });
+    }
+
+    fn record_cutoff(&mut self, action: A, depth: usize) {
+        if self.killers.len() <= self.ply {
+            self.killers.resize(self.ply + 1, [None, None]);
+        }
+
+        let killers = &mut self.killers[self.ply];
+
+        if killers[0] != Some(action) {
+            killers[1] = killers[0];
+            killers[0] = Some(action);
+        }
+
+        *self.history.entry(action).or_insert(0) += (depth * depth) as u64;
+    }
+
+    fn is_out_of_time(&mut self) -> bool {
+        self.nodes += 1;
+
+        if self.stop.load(Ordering::Relaxed) {
+            return true;
+        }
+
+        self.deadline.is_some_and(|deadline| {
+            self.nodes.is_multiple_of(Self::CLOCK_INTERVAL) && Instant::now() >= deadline
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 enum Objective {
     Maximize,
     Minimize,
@@ -29,26 +197,191 @@ impl Objective {
 
 impl MinimaxPlayer {
     pub fn new(depth: usize) -> Self {
-        Self { depth }
+        Self {
+            depth,
+            time_limit: None,
+            threads: 1,
+            heuristic: NullHeuristic,
+        }
     }
+}
+
+impl<H> MinimaxPlayer<H> {
+    // NOTE - Positions still in progress at the search horizon are scored with the
+    // heuristic instead of as draws.
+
+    pub fn with_heuristic<H2>(self, heuristic: H2) -> MinimaxPlayer<H2> {
+        MinimaxPlayer {
+            depth: self.depth,
+            time_limit: self.time_limit,
+            threads: self.threads,
+            heuristic,
+        }
+    }
+
+    // NOTE - Searches one ply deeper at a time until the depth or the time runs out, and
+    // plays the best action of the deepest search to finish. The first ply is always
+    // completed, so a move is made however short the limit.
+
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.set_time_limit(time_limit);
+
+        self
+    }
+
+    pub fn set_time_limit(&mut self, time_limit: Duration) {
+        self.time_limit = Some(time_limit);
+    }
+
+    // NOTE - Runs helper threads alongside the search, each deepening the same move on
+    // its own and sharing what they find through the transposition table, and plays the
+    // action of the main search once it finishes. Helpers start every other one a ply
+    // deeper so they spread over more of the tree. Threads are ignored on the web, where
+    // the search always runs alone.
+
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+
+        self
+    }
+
+    fn search<G: Game + Send>(&self, game: &G) -> Option<G::Action>
+    where
+        G::Action: Send,
+        H: Heuristic<G> + Sync,
+    {
+        self.search_with_table(game, &TranspositionTable::new())
+    }
+
+    fn search_with_table<G: Game + Send>(
+        &self,
+        game: &G,
+        table: &TranspositionTable<G::Action>,
+    ) -> Option<G::Action>
+    where
+        G::Action: Send,
+        H: Heuristic<G> + Sync,
+    {
+        let stop = AtomicBool::new(false);
+
+        let deadline = self
+            .time_limit
+            .map(|time_limit| Instant::now() + time_limit);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.threads > 1 {
+            return std::thread::scope(|scope| {
+                for helper in 1..self.threads {
+                    let game = game.clone();
+
+                    let stop = &stop;
+
+                    scope.spawn(move || {
+                        let search = Search::new(table, stop);
+
+                        self.deepen(search, &game, deadline, 1 + helper % 2)
+                    });
+                }
+
+                let best_action = self.deepen(Search::new(table, &stop), game, deadline, 1);
+
+                stop.store(true, Ordering::Relaxed);
+
+                best_action
+            });
+        }
+
+        self.deepen(Search::new(table, &stop), game, deadline, 1)
+    }
+
+    fn deepen<G: Game>(
+        &self,
+        mut search: Search<G::Action>,
+        game: &G,
+        deadline: Option<Instant>,
+        start_depth: usize,
+    ) -> Option<G::Action>
+    where
+        H: Heuristic<G>,
+    {
+        let mut best_action = None;
+
+        for depth in start_depth.min(self.depth)..=self.depth {
+            if depth > 1 && deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                break;
+            }
+
+            search.deadline = deadline.filter(|_| depth > 1);
+            search.horizon_reached = false;
+
+            let Some((_, action)) = self.minimax(
+                &mut search,
+                &mut game.clone(),
+                depth,
+                Objective::Maximize,
+                f32::NEG_INFINITY,
+                f32::INFINITY,
+            ) else {
+                break;
+            };
+
+            best_action = action;
+
+            // NOTE - A search that ended every line before the horizon would not change
+            // by going deeper.
+
+            if !search.horizon_reached {
+                break;
+            }
+        }
+
+        best_action
+    }
+
+    // NOTE - Returns `None` if the time runs out before the search finishes.
 
     fn minimax<G: Game>(
+        &self,
+        search: &mut Search<G::Action>,
         game: &mut G,
         depth: usize,
         objective: Objective,
         alpha: f32,
         beta: f32,
-    ) -> (f32, Option<G::Action>) {
+    ) -> Option<(f32, Option<G::Action>)>
+    where
+        H: Heuristic<G>,
+    {
+        if search.is_out_of_time() {
+            return None;
+        }
+
         let outcome = game.outcome();
 
+        // NOTE - Unstable positions at the horizon are searched one ply further, once
+        // per line, so the heuristic is not left to score them halfway through a
+        // turn.
+
+        let extend = depth == 0
+            && outcome == Outcome::InProgress
+            && !search.extending
+            && self.heuristic.is_unstable(game);
+
+        let depth = if extend { 1 } else { depth };
+
+        if depth == 0 && outcome == Outcome::InProgress {
+            search.horizon_reached = true;
+        }
+
         if depth == 0 || outcome != Outcome::InProgress {
             let value = match outcome {
                 Outcome::Win => objective.sign(),
                 Outcome::Loss => -objective.sign(),
-                _ => 0.0,
+                Outcome::Draw => 0.0,
+                Outcome::InProgress => objective.sign() * self.heuristic.evaluate(game),
             };
 
-            return (value, None);
+            return Some((value, None));
         }
 
         let mut best_value = match objective {
@@ -56,21 +389,52 @@ impl MinimaxPlayer {
             Objective::Minimize => f32::INFINITY,
         };
 
-        let mut best_action = None;
+        let key = game.position_key().map(|key| (key, objective));
+        let entry = key.and_then(|key| search.table.get(key));
+
+        if let Some(entry) = entry.filter(|entry| entry.depth >= depth) {
+            let cutoff = match entry.bound {
+                Bound::Exact => true,
+                Bound::Lower => entry.value >= beta,
+                Bound::Upper => entry.value <= alpha,
+            };
+
+            if cutoff {
+                search.horizon_reached |= entry.horizon_reached;
+
+                return Some((entry.value, entry.best_action));
+            }
+        }
+
+        let (alpha_start, beta_start) = (alpha, beta);
 
         let mut alpha = alpha;
         let mut beta = beta;
 
+        let mut best_action = None;
+
+        let horizon_reached = std::mem::take(&mut search.horizon_reached);
+        let extending = search.extending;
+
+        search.extending |= extend;
+
         let checkpoint = game.create_checkpoint();
 
-        for action in game.get_possible_actions() {
+        let mut actions = game.get_possible_actions();
+
+        search.order_actions(&mut actions, entry.and_then(|entry| entry.best_action));
+
+        for action in actions {
             let turn_complete = game.apply_action(action);
 
             if turn_complete {
                 game.end_turn();
             }
 
-            let (value, _) = Self::minimax(
+            search.ply += 1;
+
+            let (value, _) = self.minimax(
+                search,
                 game,
                 depth - 1,
                 if turn_complete {
@@ -80,7 +444,9 @@ impl MinimaxPlayer {
                 },
                 alpha,
                 beta,
-            );
+            )?;
+
+            search.ply -= 1;
 
             game.restore_checkpoint(checkpoint);
 
@@ -104,33 +470,239 @@ impl MinimaxPlayer {
             }
 
             if beta <= alpha {
+                search.record_cutoff(action, depth);
+
                 break;
             }
         }
 
-        (best_value, best_action)
+        if let Some(key) = key {
+            let bound = if best_value <= alpha_start {
+                Bound::Upper
+            } else if best_value >= beta_start {
+                Bound::Lower
+            } else {
+                Bound::Exact
+            };
+
+            search.table.insert(
+                key,
+                TableEntry {
+                    depth,
+                    value: best_value,
+                    bound,
+                    best_action,
+                    horizon_reached: search.horizon_reached,
+                },
+            );
+        }
+
+        search.horizon_reached |= horizon_reached;
+        search.extending = extending;
+
+        Some((best_value, best_action))
     }
 }
 
-impl<G: Game> Player<G> for MinimaxPlayer {
+impl<G: Game + Send, H: Heuristic<G> + Sync> Player<G> for MinimaxPlayer<H>
+where
+    G::Action: Send,
+{
     fn name(&self) -> &'static str {
         "Minimax with Alpha-Beta Pruning"
     }
 
     fn choose_action(&mut self, game: &G, _turn_number: u32) -> Choice<G> {
-        let (_, action) = Self::minimax(
-            &mut game.clone(),
-            self.depth,
-            Objective::Maximize,
-            f32::NEG_INFINITY,
-            f32::INFINITY,
-        );
-
-        let action = action.expect("no legal actions available");
+        let action = self.search(game).expect("no legal actions available");
 
         Choice {
             evaluation: None,
             action,
+            resign: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::boop::Boop;
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    #[derive(Clone)]
+    struct CenterHeuristic;
+
+    impl Heuristic<TicTacToe> for CenterHeuristic {
+        fn evaluate(&self, game: &TicTacToe) -> f32 {
+            let center = 1u16 << 4;
+
+            if game.player_marks & center != 0 {
+                0.5
+            } else if game.opponent_marks & center != 0 {
+                -0.5
+            } else {
+                0.0
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct UnstableHeuristic;
+
+    impl Heuristic<TicTacToe> for UnstableHeuristic {
+        fn evaluate(&self, _game: &TicTacToe) -> f32 {
+            0.0
+        }
+
+        fn is_unstable(&self, _game: &TicTacToe) -> bool {
+            true
+        }
+    }
+
+    mod order_actions {
+        use super::*;
+
+        #[test]
+        fn should_try_the_best_action_then_killers_then_history() {
+            let place = |index| TicTacToeAction::Place { index };
+
+            let table = TranspositionTable::new();
+            let stop = AtomicBool::new(false);
+
+            let mut search = Search::new(&table, &stop);
+
+            search.ply = 2;
+
+            search.record_cutoff(place(5), 1);
+            search.record_cutoff(place(1), 1);
+            search.history.insert(place(8), 10);
+
+            let mut actions: ActionList<_> = (0..9).map(place).collect();
+
+            search.order_actions(&mut actions, Some(place(3)));
+
+            assert_eq!(
+                &actions[..5],
+                [place(3), place(1), place(5), place(8), place(0)]
+            );
+        }
+
+        #[test]
+        fn should_try_the_stored_best_action_first_on_boop() {
+            let table = TranspositionTable::new();
+            let stop = AtomicBool::new(false);
+
+            let game = Boop::new();
+
+            let best_action = MinimaxPlayer::new(2)
+                .deepen(Search::new(&table, &stop), &game, None, 1)
+                .unwrap();
+
+            let key = (game.position_key().unwrap(), Objective::Maximize);
+            let entry = table.get(key).expect("search stored the root");
+
+            let mut actions = game.get_possible_actions();
+
+            let mut search = Search::new(&table, &stop);
+
+            for &action in actions.iter().filter(|&&action| action != best_action) {
+                search.record_cutoff(action, 1);
+            }
+
+            search.order_actions(&mut actions, entry.best_action);
+
+            assert_eq!(entry.best_action, Some(best_action));
+            assert_eq!(actions[0], best_action);
+        }
+    }
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_score_the_horizon_with_the_heuristic() {
+            let mut player = MinimaxPlayer::new(1).with_heuristic(CenterHeuristic);
+
+            let Choice { action, .. } = player.choose_action(&TicTacToe::new(), 0);
+
+            assert_eq!(action, TicTacToeAction::Place { index: 4 });
+        }
+
+        #[test]
+        fn should_play_the_deepest_finished_search_within_the_time_limit() {
+            let mut player =
+                MinimaxPlayer::new(usize::MAX).with_time_limit(Duration::from_millis(50));
+
+            let game = Boop::new();
+
+            let start = Instant::now();
+
+            let Choice { action, .. } = player.choose_action(&game, 0);
+
+            assert!(start.elapsed() < Duration::from_secs(5));
+            assert!(game.get_possible_actions().contains(&action));
+        }
+
+        #[test]
+        fn should_search_unstable_positions_one_ply_past_the_horizon() {
+            let mut game = TicTacToe::new();
+
+            for index in [8, 4, 7] {
+                if game.apply_action(TicTacToeAction::Place { index }) {
+                    game.end_turn();
+                }
+            }
+
+            let mut player = MinimaxPlayer::new(1).with_heuristic(UnstableHeuristic);
+
+            let Choice { action, .. } = player.choose_action(&game, 3);
+
+            assert_eq!(action, TicTacToeAction::Place { index: 6 });
+        }
+
+        #[test]
+        fn should_find_the_same_forced_win_with_helper_threads() {
+            let mut game = TicTacToe::new();
+
+            for index in [0, 3, 1, 4] {
+                if game.apply_action(TicTacToeAction::Place { index }) {
+                    game.end_turn();
+                }
+            }
+
+            let mut player = MinimaxPlayer::new(9).with_threads(4);
+
+            let Choice { action, .. } = player.choose_action(&game, 4);
+
+            assert_eq!(action, TicTacToeAction::Place { index: 2 });
+        }
+    }
+
+    mod search_with_table {
+        use super::*;
+
+        #[test]
+        fn should_fill_a_shared_table_on_boop_from_helper_threads() {
+            let table = TranspositionTable::new();
+            let stop = AtomicBool::new(false);
+
+            let game = Boop::new();
+            let player = MinimaxPlayer::new(2);
+
+            std::thread::scope(|scope| {
+                scope.spawn(|| player.deepen(Search::new(&table, &stop), &game, None, 2));
+            });
+
+            let key = (game.position_key().unwrap(), Objective::Maximize);
+            let entry = table.get(key).expect("helper stored the root");
+
+            assert_eq!(entry.depth, 2);
+            assert!(entry.best_action.is_some());
+
+            let action = player.with_threads(4).search_with_table(&game, &table);
+
+            assert!(game.get_possible_actions().contains(&action.unwrap()));
         }
     }
 }
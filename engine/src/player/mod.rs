@@ -1,9 +1,42 @@
+mod analyzing;
+mod callback;
+mod ensemble;
+mod epsilon_greedy;
+mod greedy;
+mod greedy_value;
+mod heuristic;
+mod human;
+mod hybrid;
 mod manual;
 mod mcts;
 mod minimax;
+mod opening_book;
+mod policy;
 mod random;
+mod remote;
+mod skill_limited;
+mod timed;
 
+pub use analyzing::{AnalyzingPlayer, MoveAnalysis};
+pub use callback::{CallbackHandle, CallbackPlayer};
+pub use ensemble::{EnsembleMode, EnsemblePlayer};
+pub use epsilon_greedy::EpsilonGreedyPlayer;
+pub use greedy::GreedyPlayer;
+pub use greedy_value::GreedyValuePlayer;
+pub use heuristic::{Heuristic, NullHeuristic};
+pub use human::HumanPlayer;
+pub use hybrid::HybridPlayer;
 pub use manual::ManualPlayer;
-pub use mcts::{ClassicMctsPlayer, DirichletNoise, NeuralNetworkMctsPlayer, TemperatureSchedule};
+pub(crate) use mcts::PendingSearch;
+pub use mcts::{
+    AdaptiveBudget, ClassicMctsPlayer, DirichletNoise, ForcedPlayouts, MemoryUsage,
+    NeuralNetworkMctsPlayer, SearchStatistics, StateStorage, SymmetryMode, TemperatureSchedule,
+    TreeExportNode, TreeExportOptions,
+};
 pub use minimax::MinimaxPlayer;
+pub use opening_book::{OpeningBook, OpeningBookPlayer};
+pub use policy::PolicyPlayer;
 pub use random::RandomPlayer;
+pub use remote::RemotePlayer;
+pub use skill_limited::SkillLimitedPlayer;
+pub use timed::TimedPlayer;
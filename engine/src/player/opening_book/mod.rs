@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod opening_book;
+mod opening_book_player;
+
+pub use opening_book::OpeningBook;
+pub use opening_book_player::OpeningBookPlayer;
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Game, canonical_position_key};
+use crate::record::PortableGame;
+
+// NOTE - Weighted moves for positions, keyed by the position key of the position's
+// canonical symmetry so one entry covers every symmetric position. Moves are kept in the
+// canonical frame and mapped onto the position's own actions when read. Games without a
+// position key cannot have a book, and adding to one does nothing.
+
+pub struct OpeningBook<G: Game> {
+    entries: HashMap<u64, BookEntry<G>>,
+}
+
+struct BookEntry<G: Game> {
+    position: G,
+    moves: Vec<(G::Action, f32)>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct PortableOpeningBook {
+    game: String,
+    positions: Vec<PortableBookPosition>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct PortableBookPosition {
+    position: String,
+    moves: Vec<PortableBookMove>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct PortableBookMove {
+    action: String,
+    weight: f32,
+}
+
+impl<G: Game> Clone for OpeningBook<G> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self
+                .entries
+                .iter()
+                .map(|(&key, entry)| {
+                    (
+                        key,
+                        BookEntry {
+                            position: entry.position.clone(),
+                            moves: entry.moves.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<G: Game> Default for OpeningBook<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Game> OpeningBook<G> {
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // NOTE - Weights of a move added more than once are summed.
+
+    pub fn add(&mut self, game: &G, action: G::Action, weight: f32) {
+        let Some((key, symmetry)) = canonical_position_key(game) else {
+            return;
+        };
+
+        let action = Self::transform_action(game, action, symmetry);
+
+        let entry = self.entries.entry(key).or_insert_with(|| BookEntry {
+            position: Self::transform(game, symmetry),
+            moves: vec![],
+        });
+
+        match entry
+            .moves
+            .iter_mut()
+            .find(|(existing, _)| *existing == action)
+        {
+            Some((_, total)) => *total += weight,
+            None => entry.moves.push((action, weight)),
+        }
+    }
+
+    // NOTE - Booked moves that are not legal in the position are left out.
+
+    pub fn moves(&self, game: &G) -> Vec<(G::Action, f32)> {
+        let Some((key, symmetry)) = canonical_position_key(game) else {
+            return vec![];
+        };
+
+        let Some(entry) = self.entries.get(&key) else {
+            return vec![];
+        };
+
+        game.get_possible_actions()
+            .into_iter()
+            .filter_map(|action| {
+                let canonical_action = Self::transform_action(game, action, symmetry);
+
+                entry
+                    .moves
+                    .iter()
+                    .find(|(booked, _)| *booked == canonical_action)
+                    .map(|&(_, weight)| (action, weight))
+            })
+            .collect()
+    }
+
+    fn transform(game: &G, symmetry: u8) -> G {
+        if symmetry == 0 {
+            game.clone()
+        } else {
+            game.transform(symmetry)
+        }
+    }
+
+    fn transform_action(game: &G, action: G::Action, symmetry: u8) -> G::Action {
+        if symmetry == 0 {
+            action
+        } else {
+            game.transform_action(action, symmetry)
+        }
+    }
+}
+
+impl<G: PortableGame> OpeningBook<G> {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = fs::read_to_string(path)
+            .map_err(|error| format!("unable to read {}: {error}", path.display()))?;
+
+        Self::from_json(&json)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_json())
+            .map_err(|error| format!("unable to write {}: {error}", path.display()))
+    }
+
+    // NOTE - Positions are written in notation rather than by hash, so a book outlives
+    // changes to how positions are hashed.
+
+    pub fn to_json(&self) -> String {
+        let mut positions: Vec<PortableBookPosition> = self
+            .entries
+            .values()
+            .map(|entry| PortableBookPosition {
+                position: entry.position.to_notation(),
+                moves: entry
+                    .moves
+                    .iter()
+                    .map(|&(action, weight)| PortableBookMove {
+                        action: G::format_action(action),
+                        weight,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        positions.sort_by(|a, b| a.position.cmp(&b.position));
+
+        serde_json::to_string(&PortableOpeningBook {
+            game: G::NAME.to_string(),
+            positions,
+        })
+        .expect("unable to serialize opening book")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let book: PortableOpeningBook =
+            serde_json::from_str(json).map_err(|error| format!("invalid opening book: {error}"))?;
+
+        if book.game != G::NAME {
+            return Err(format!(
+                "opening book is for {}, not {}",
+                book.game,
+                G::NAME
+            ));
+        }
+
+        let mut opening_book = Self::new();
+
+        for PortableBookPosition { position, moves } in book.positions {
+            let game = G::from_notation(&position)?;
+
+            for PortableBookMove { action, weight } in moves {
+                opening_book.add(&game, G::parse_action(&action)?, weight);
+            }
+        }
+
+        Ok(opening_book)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::boop::{Boop, BoopAction, BoopPiece};
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    fn after(index: u8) -> TicTacToe {
+        let mut game = TicTacToe::new();
+
+        if game.apply_action(TicTacToeAction::Place { index }) {
+            game.end_turn();
+        }
+
+        game
+    }
+
+    mod moves {
+        use super::*;
+
+        #[test]
+        fn should_share_booked_moves_between_symmetric_positions() {
+            let mut book = OpeningBook::new();
+
+            book.add(&after(0), TicTacToeAction::Place { index: 8 }, 2.0);
+            book.add(&after(0), TicTacToeAction::Place { index: 8 }, 1.0);
+
+            assert_eq!(book.len(), 1);
+            assert_eq!(
+                book.moves(&after(2)),
+                vec![(TicTacToeAction::Place { index: 6 }, 3.0)]
+            );
+            assert!(book.moves(&after(4)).is_empty());
+        }
+
+        #[test]
+        fn should_book_moves_for_games_keyed_by_position() {
+            let mut book = OpeningBook::new();
+
+            let action = BoopAction::Place {
+                piece: BoopPiece::Kitten,
+                index: 14,
+            };
+
+            book.add(&Boop::new(), action, 1.0);
+
+            assert_eq!(book.len(), 1);
+            assert_eq!(book.moves(&Boop::new()), vec![(action, 1.0)]);
+        }
+    }
+
+    mod from_json {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_through_json() {
+            let mut book = OpeningBook::new();
+
+            book.add(&TicTacToe::new(), TicTacToeAction::Place { index: 4 }, 1.0);
+            book.add(&after(4), TicTacToeAction::Place { index: 0 }, 0.5);
+
+            let loaded = OpeningBook::<TicTacToe>::from_json(&book.to_json()).unwrap();
+
+            assert_eq!(loaded.len(), 2);
+            assert_eq!(
+                loaded.moves(&TicTacToe::new()),
+                vec![(TicTacToeAction::Place { index: 4 }, 1.0)]
+            );
+            assert_eq!(loaded.moves(&after(4)).len(), 1);
+        }
+    }
+}
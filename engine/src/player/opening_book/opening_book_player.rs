@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use rand::distr::Distribution;
+use rand::distr::weighted::WeightedIndex;
+
+use crate::core::{Choice, DefaultRngFactory, Game, Player, RngFactory, derive_seed};
+use crate::player::opening_book::opening_book::OpeningBook;
+
+// NOTE - Plays from the book, picking among the booked moves in proportion to their
+// weights, while the turn number is below `max_turns` and the position is in the book.
+// Every other move is left to the inner player.
+
+pub struct OpeningBookPlayer<G: Game, P, F: RngFactory = DefaultRngFactory> {
+    book: OpeningBook<G>,
+    max_turns: u32,
+
+    player: P,
+    rng_factory: F,
+    rng: F::Rng,
+}
+
+impl<G: Game, P: Clone, F: RngFactory> Clone for OpeningBookPlayer<G, P, F> {
+    fn clone(&self) -> Self {
+        Self {
+            book: self.book.clone(),
+            max_turns: self.max_turns,
+
+            player: self.player.clone(),
+            rng_factory: self.rng_factory.clone(),
+            rng: self.rng_factory.create(),
+        }
+    }
+}
+
+impl<G: Game, P: Player<G>> OpeningBookPlayer<G, P> {
+    pub fn new(book: OpeningBook<G>, max_turns: u32, player: P) -> Self {
+        Self::with_rng_factory(book, max_turns, player, DefaultRngFactory::default())
+    }
+}
+
+impl<G: Game, P: Player<G>, F: RngFactory> OpeningBookPlayer<G, P, F> {
+    pub fn with_rng_factory(
+        book: OpeningBook<G>,
+        max_turns: u32,
+        player: P,
+        rng_factory: F,
+    ) -> Self {
+        Self {
+            book,
+            max_turns,
+
+            player,
+            rng: rng_factory.create(),
+            rng_factory,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = self.rng_factory.create_seeded(seed);
+
+        self
+    }
+
+    pub fn book(&self) -> &OpeningBook<G> {
+        &self.book
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.player
+    }
+
+    fn book_action(&mut self, game: &G, turn_number: u32) -> Option<G::Action> {
+        if turn_number >= self.max_turns {
+            return None;
+        }
+
+        let moves = self.book.moves(game);
+
+        let distribution = WeightedIndex::new(moves.iter().map(|&(_, weight)| weight)).ok()?;
+
+        Some(moves[distribution.sample(&mut self.rng)].0)
+    }
+}
+
+impl<G: Game, P: Player<G>, F: RngFactory> Player<G> for OpeningBookPlayer<G, P, F> {
+    fn name(&self) -> &str {
+        self.player.name()
+    }
+
+    fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
+        match self.book_action(game, turn_number) {
+            Some(action) => Choice {
+                evaluation: None,
+                action,
+                resign: false,
+            },
+            None => self.player.choose_action(game, turn_number),
+        }
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = self.rng_factory.create_seeded(seed);
+
+        self.player.reseed(derive_seed(seed, 0));
+    }
+
+    fn new_game(&mut self) {
+        self.player.new_game();
+    }
+
+    fn set_clock(&mut self, remaining: Duration, increment: Duration) {
+        self.player.set_clock(remaining, increment);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+    use crate::player::ManualPlayer;
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_play_from_the_book_then_defer_to_the_inner_player() {
+            let mut book = OpeningBook::new();
+
+            book.add(&TicTacToe::new(), TicTacToeAction::Place { index: 4 }, 1.0);
+
+            let mut player = OpeningBookPlayer::new(book, 1, ManualPlayer::new());
+
+            let game = TicTacToe::new();
+
+            assert_eq!(
+                player.choose_action(&game, 0).action,
+                TicTacToeAction::Place { index: 4 }
+            );
+
+            player
+                .player
+                .queue_action(TicTacToeAction::Place { index: 0 });
+
+            assert_eq!(
+                player.choose_action(&game, 1).action,
+                TicTacToeAction::Place { index: 0 }
+            );
+        }
+    }
+    mod reseed {
+        use super::*;
+
+        use crate::player::RandomPlayer;
+
+        #[test]
+        fn should_reseed_the_inner_player_from_a_derived_seed() {
+            let game = TicTacToe::new();
+
+            let mut player = OpeningBookPlayer::new(OpeningBook::new(), 0, RandomPlayer::new());
+            let mut inner = RandomPlayer::new();
+
+            player.reseed(5);
+            Player::<TicTacToe>::reseed(&mut inner, derive_seed(5, 0));
+
+            for _ in 0..20 {
+                assert_eq!(
+                    player.choose_action(&game, 0).action,
+                    inner.choose_action(&game, 0).action
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use rand::distr::Distribution;
+use rand::distr::weighted::WeightedIndex;
+
+use crate::core::{Choice, DefaultRngFactory, Game, Player, PolicyItem, RngFactory};
+use crate::neural_network::{ActionEncoder, NeuralNetwork, StateEncoder};
+use crate::player::mcts::{Evaluator, NeuralNetworkEvaluator, SymmetryMode, TemperatureSchedule};
+
+// NOTE - Plays straight from the network's policy over the legal actions, without any
+// search, to measure how strong the policy is on its own. The most likely action is played
+// unless a temperature schedule says otherwise.
+
+#[derive(Clone)]
+pub struct PolicyPlayer<
+    G: Game,
+    SE: StateEncoder<G>,
+    AE: ActionEncoder<G>,
+    NN: NeuralNetwork,
+    F: RngFactory = DefaultRngFactory,
+> {
+    evaluator: NeuralNetworkEvaluator<G, SE, AE, NN>,
+    temperature_schedule: TemperatureSchedule,
+    rng_factory: F,
+    rng: F::Rng,
+}
+
+impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork>
+    PolicyPlayer<G, SE, AE, NN>
+{
+    pub fn new(state_encoder: SE, action_encoder: AE, neural_network: NN) -> Self {
+        Self::with_rng_factory(
+            state_encoder,
+            action_encoder,
+            neural_network,
+            DefaultRngFactory::default(),
+        )
+    }
+}
+
+impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, NN: NeuralNetwork, F: RngFactory>
+    PolicyPlayer<G, SE, AE, NN, F>
+{
+    pub fn with_rng_factory(
+        state_encoder: SE,
+        action_encoder: AE,
+        neural_network: NN,
+        rng_factory: F,
+    ) -> Self {
+        Self {
+            evaluator: NeuralNetworkEvaluator::new(state_encoder, action_encoder, neural_network),
+            temperature_schedule: TemperatureSchedule::Constant(0.0),
+            rng: rng_factory.create(),
+            rng_factory,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        <Self as Player<G>>::reseed(&mut self, seed);
+
+        self
+    }
+
+    pub fn with_symmetry_mode(mut self, symmetry_mode: SymmetryMode) -> Self {
+        self.evaluator = self.evaluator.with_symmetry_mode(symmetry_mode);
+
+        self
+    }
+
+    pub fn with_temperature_schedule(mut self, temperature_schedule: TemperatureSchedule) -> Self {
+        self.temperature_schedule = temperature_schedule;
+
+        self
+    }
+}
+
+impl<G, SE, AE, NN, F> Player<G> for PolicyPlayer<G, SE, AE, NN, F>
+where
+    G: Game,
+    SE: StateEncoder<G>,
+    AE: ActionEncoder<G>,
+    NN: NeuralNetwork,
+    F: RngFactory,
+{
+    fn name(&self) -> &'static str {
+        "Raw Policy"
+    }
+
+    fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
+        let evaluation = self.evaluator.evaluate(game);
+
+        let temperature = self.temperature_schedule.get_temperature(turn_number);
+
+        let action = if temperature == 0.0 {
+            evaluation
+                .policy
+                .iter()
+                .max_by(|x, y| x.prior.total_cmp(&y.prior))
+                .expect("no legal actions available")
+                .action
+        } else {
+            let weights = evaluation
+                .policy
+                .iter()
+                .map(|policy_item| policy_item.prior.powf(1.0 / temperature));
+
+            let distribution =
+                WeightedIndex::new(weights).expect("unable to create weighted distribution");
+
+            let PolicyItem { action, .. } = evaluation.policy[distribution.sample(&mut self.rng)];
+
+            action
+        };
+
+        Choice {
+            evaluation: Some(evaluation),
+            action,
+            resign: false,
+        }
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.evaluator.reseed(seed);
+
+        self.rng = self.rng_factory.create_seeded(seed);
+    }
+
+    fn new_game(&mut self) {
+        self.evaluator.new_game();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::{
+        TicTacToe, TicTacToeAction, TicTacToeActionEncoder, TicTacToeStateEncoder,
+    };
+    use crate::neural_network::ConstantNeuralNetwork;
+
+    fn make_player()
+    -> PolicyPlayer<TicTacToe, TicTacToeStateEncoder, TicTacToeActionEncoder, ConstantNeuralNetwork>
+    {
+        let mut policy_logits = vec![0.0; 9];
+        policy_logits[4] = 1.0;
+        policy_logits[0] = 3.0;
+
+        let neural_network = ConstantNeuralNetwork::new(9).with_policy_logits(policy_logits);
+
+        PolicyPlayer::new(
+            TicTacToeStateEncoder,
+            TicTacToeActionEncoder,
+            neural_network,
+        )
+    }
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_play_the_most_likely_legal_action() {
+            let mut game = TicTacToe::new();
+
+            game.apply_action(TicTacToeAction::Place { index: 0 });
+            game.end_turn();
+
+            let Choice { action, .. } = make_player().choose_action(&game, 1);
+
+            assert_eq!(action, TicTacToeAction::Place { index: 4 });
+        }
+
+        #[test]
+        fn should_sample_the_policy_with_a_temperature() {
+            let mut player = make_player()
+                .with_temperature_schedule(TemperatureSchedule::Constant(1.0))
+                .with_seed(7);
+
+            let game = TicTacToe::new();
+
+            let actions: Vec<TicTacToeAction> = (0..50)
+                .map(|_| player.choose_action(&game, 0).action)
+                .collect();
+
+            assert!(actions.contains(&TicTacToeAction::Place { index: 0 }));
+            assert!(
+                actions
+                    .iter()
+                    .any(|&action| action != TicTacToeAction::Place { index: 0 })
+            );
+        }
+    }
+    mod with_rng_factory {
+        use super::*;
+
+        use crate::core::StdRngFactory;
+
+        #[test]
+        fn should_replay_seeded_samples() {
+            let actions = || {
+                let mut player = PolicyPlayer::with_rng_factory(
+                    TicTacToeStateEncoder,
+                    TicTacToeActionEncoder,
+                    ConstantNeuralNetwork::new(9),
+                    StdRngFactory,
+                )
+                .with_temperature_schedule(TemperatureSchedule::Constant(1.0))
+                .with_seed(7);
+
+                (0..20)
+                    .map(|_| player.choose_action(&TicTacToe::new(), 0).action)
+                    .collect::<Vec<TicTacToeAction>>()
+            };
+
+            assert_eq!(actions(), actions());
+        }
+    }
+}
@@ -1,30 +1,37 @@
-use rand::rngs::StdRng;
 use rand::seq::IndexedRandom;
-use rand::{SeedableRng, rng};
 
-use crate::core::{Choice, Game, Player};
+use crate::core::{Choice, DefaultRngFactory, Game, Player, RngFactory};
 
-pub struct RandomPlayer {
-    rng: StdRng,
+pub struct RandomPlayer<F: RngFactory = DefaultRngFactory> {
+    rng_factory: F,
+    rng: F::Rng,
 }
 
-impl Clone for RandomPlayer {
+impl<F: RngFactory> Clone for RandomPlayer<F> {
     fn clone(&self) -> Self {
         Self {
-            rng: StdRng::from_rng(&mut rng()),
+            rng_factory: self.rng_factory.clone(),
+            rng: self.rng_factory.create(),
         }
     }
 }
 
 impl RandomPlayer {
     pub fn new() -> Self {
+        Self::with_rng_factory(DefaultRngFactory::default())
+    }
+}
+
+impl<F: RngFactory> RandomPlayer<F> {
+    pub fn with_rng_factory(rng_factory: F) -> Self {
         Self {
-            rng: StdRng::from_rng(&mut rng()),
+            rng: rng_factory.create(),
+            rng_factory,
         }
     }
 
     pub fn with_seed(mut self, seed: u64) -> Self {
-        self.rng = StdRng::seed_from_u64(seed);
+        self.rng = self.rng_factory.create_seeded(seed);
 
         self
     }
@@ -36,7 +43,7 @@ impl Default for RandomPlayer {
     }
 }
 
-impl<G: Game> Player<G> for RandomPlayer {
+impl<G: Game, F: RngFactory> Player<G> for RandomPlayer<F> {
     fn name(&self) -> &'static str {
         "Random"
     }
@@ -48,8 +55,13 @@ impl<G: Game> Player<G> for RandomPlayer {
             Some(action) => Choice {
                 evaluation: None,
                 action: *action,
+                resign: false,
             },
             None => panic!("no legal actions available"),
         }
     }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = self.rng_factory.create_seeded(seed);
+    }
 }
@@ -0,0 +1,245 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Choice, Game, Player};
+
+// NOTE - Plays through another process over a stream of newline-delimited JSON. Each turn
+// sends the position in notation with the legal actions as text, and expects the number
+// of the chosen one back, counting from zero:
+//
+//   -> {"position":"...","turn_number":3,"actions":["...","..."]}
+//   <- {"action":1}
+//
+// Replying `{"resign":true}` resigns instead. The other process is not trusted to behave:
+// a closed connection, a malformed reply or an action outside the list is taken as
+// resigning, and the reason is kept in `last_error` rather than ending the run.
+
+pub struct RemotePlayer<S = TcpStream> {
+    stream: BufReader<S>,
+
+    last_error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Request<'a> {
+    position: &'a str,
+    turn_number: u32,
+    actions: &'a [String],
+}
+
+#[derive(Deserialize)]
+struct Response {
+    action: Option<usize>,
+    #[serde(default)]
+    resign: bool,
+}
+
+impl RemotePlayer {
+    pub fn connect(address: impl ToSocketAddrs) -> io::Result<Self> {
+        let stream = TcpStream::connect(address)?;
+
+        stream.set_nodelay(true)?;
+
+        Ok(Self::new(stream))
+    }
+}
+
+impl<S: Read + Write> RemotePlayer<S> {
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+
+            last_error: None,
+        }
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    fn choose_index(&mut self, request: &Request) -> Result<Option<usize>, String> {
+        let response = self.request(request)?;
+
+        if response.resign {
+            return Ok(None);
+        }
+
+        match response.action {
+            Some(index) if index < request.actions.len() => Ok(Some(index)),
+            Some(index) => Err(format!("no action numbered {index}")),
+            None => Err("reply has neither an action nor a resignation".to_string()),
+        }
+    }
+
+    fn request(&mut self, request: &Request) -> Result<Response, String> {
+        let mut line = serde_json::to_string(request).map_err(|error| error.to_string())?;
+
+        line.push('\n');
+
+        let stream = self.stream.get_mut();
+
+        stream
+            .write_all(line.as_bytes())
+            .and_then(|()| stream.flush())
+            .map_err(|error| format!("unable to send position: {error}"))?;
+
+        line.clear();
+
+        let read = self
+            .stream
+            .read_line(&mut line)
+            .map_err(|error| format!("unable to read reply: {error}"))?;
+
+        if read == 0 {
+            return Err("connection closed".to_string());
+        }
+
+        serde_json::from_str(&line).map_err(|error| format!("invalid reply: {error}"))
+    }
+}
+
+impl<G: Game, S: Read + Write> Player<G> for RemotePlayer<S> {
+    fn name(&self) -> &'static str {
+        "Remote"
+    }
+
+    fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
+        let actions = game.get_possible_actions();
+
+        let descriptions: Vec<String> = actions.iter().map(ToString::to_string).collect();
+
+        let index = self.choose_index(&Request {
+            position: &game.to_notation(),
+            turn_number,
+            actions: &descriptions,
+        });
+
+        let index = index.unwrap_or_else(|error| {
+            self.last_error = Some(error);
+
+            None
+        });
+
+        match index {
+            Some(index) => Choice {
+                evaluation: None,
+                action: actions[index],
+                resign: false,
+            },
+            None => Choice {
+                evaluation: None,
+                action: actions[0],
+                resign: true,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    struct ScriptedStream {
+        input: Cursor<Vec<u8>>,
+        output: Vec<u8>,
+    }
+
+    impl Read for ScriptedStream {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.input.read(buf)
+        }
+    }
+
+    impl Write for ScriptedStream {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.output.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn remote(replies: &str) -> RemotePlayer<ScriptedStream> {
+        RemotePlayer::new(ScriptedStream {
+            input: Cursor::new(replies.as_bytes().to_vec()),
+            output: vec![],
+        })
+    }
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_play_the_action_chosen_by_the_remote_process() {
+            let mut player = remote("{\"action\":4}\n");
+
+            let game = TicTacToe::new();
+
+            let choice = player.choose_action(&game, 0);
+
+            assert_eq!(choice.action, TicTacToeAction::Place { index: 4 });
+            assert!(!choice.resign);
+
+            let sent: serde_json::Value =
+                serde_json::from_slice(&player.stream.get_ref().output).unwrap();
+
+            assert_eq!(sent["position"], game.to_notation());
+            assert_eq!(sent["turn_number"], 0);
+            assert_eq!(sent["actions"].as_array().map(Vec::len), Some(9));
+        }
+
+        #[test]
+        fn should_resign_when_the_remote_process_does() {
+            let mut player = remote("{\"resign\":true}\n");
+
+            let choice: Choice<TicTacToe> = player.choose_action(&TicTacToe::new(), 0);
+
+            assert!(choice.resign);
+            assert_eq!(player.last_error(), None);
+        }
+
+        #[test]
+        fn should_resign_and_keep_the_error_when_the_remote_process_misbehaves() {
+            for (replies, error) in [
+                ("{\"action\":9}\n", "no action numbered 9"),
+                ("not json\n", "invalid reply"),
+                ("", "connection closed"),
+            ] {
+                let mut player = remote(replies);
+
+                let choice: Choice<TicTacToe> = player.choose_action(&TicTacToe::new(), 0);
+
+                assert!(choice.resign);
+                assert!(player.last_error().unwrap().starts_with(error));
+            }
+        }
+    }
+
+    mod run {
+        use super::*;
+
+        use crate::core::{Runner, StatisticsRunnerEventSink};
+        use crate::player::RandomPlayer;
+
+        #[test]
+        fn should_play_through_the_runner() {
+            let mut runner = Runner::<TicTacToe, _, _, _>::new(
+                1,
+                remote(&"{\"action\":0}\n".repeat(9)),
+                RandomPlayer::new(),
+                StatisticsRunnerEventSink::new(),
+            );
+
+            runner.run();
+
+            assert_eq!(runner.sink().total_games, 1);
+        }
+    }
+}
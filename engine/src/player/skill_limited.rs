@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use rand::RngExt;
+use rand::distr::Distribution;
+use rand::distr::weighted::WeightedIndex;
+use rand::seq::IndexedRandom;
+
+use crate::core::{Choice, DefaultRngFactory, Game, Player, PolicyItem, RngFactory, derive_seed};
+
+// NOTE - Weakens an inner player by a controlled amount. With `blunder_probability` a
+// random legal action replaces its choice outright. Otherwise, given a temperature, the
+// action is sampled from the `top_k` most likely actions of its policy instead of taken
+// as chosen. Players that return no policy are only affected by blunders.
+
+pub struct SkillLimitedPlayer<P, F: RngFactory = DefaultRngFactory> {
+    player: P,
+
+    top_k: usize,
+    temperature: f32,
+    blunder_probability: f32,
+
+    rng_factory: F,
+    rng: F::Rng,
+}
+
+impl<P: Clone, F: RngFactory> Clone for SkillLimitedPlayer<P, F> {
+    fn clone(&self) -> Self {
+        Self {
+            player: self.player.clone(),
+
+            top_k: self.top_k,
+            temperature: self.temperature,
+            blunder_probability: self.blunder_probability,
+
+            rng_factory: self.rng_factory.clone(),
+            rng: self.rng_factory.create(),
+        }
+    }
+}
+
+impl<P> SkillLimitedPlayer<P> {
+    pub fn new(player: P) -> Self {
+        Self::with_rng_factory(player, DefaultRngFactory::default())
+    }
+}
+
+impl<P, F: RngFactory> SkillLimitedPlayer<P, F> {
+    pub fn with_rng_factory(player: P, rng_factory: F) -> Self {
+        Self {
+            player,
+
+            top_k: usize::MAX,
+            temperature: 0.0,
+            blunder_probability: 0.0,
+
+            rng: rng_factory.create(),
+            rng_factory,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = self.rng_factory.create_seeded(seed);
+
+        self
+    }
+
+    pub fn with_temperature(mut self, temperature: f32, top_k: usize) -> Self {
+        self.temperature = temperature;
+        self.top_k = top_k.max(1);
+
+        self
+    }
+
+    pub fn with_blunder_probability(mut self, blunder_probability: f32) -> Self {
+        self.blunder_probability = blunder_probability;
+
+        self
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.player
+    }
+
+    fn sample_top_k<G: Game>(&mut self, policy: &[PolicyItem<G>]) -> Option<G::Action> {
+        let mut policy = policy.to_vec();
+
+        policy.sort_by(|x, y| y.prior.total_cmp(&x.prior));
+        policy.truncate(self.top_k);
+
+        let weights = policy
+            .iter()
+            .map(|policy_item| policy_item.prior.powf(1.0 / self.temperature));
+
+        let distribution = WeightedIndex::new(weights).ok()?;
+
+        Some(policy[distribution.sample(&mut self.rng)].action)
+    }
+}
+
+impl<G: Game, P: Player<G>, F: RngFactory> Player<G> for SkillLimitedPlayer<P, F> {
+    fn name(&self) -> &str {
+        self.player.name()
+    }
+
+    fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
+        let mut choice = self.player.choose_action(game, turn_number);
+
+        if choice.resign {
+            return choice;
+        }
+
+        if self.blunder_probability > 0.0 && self.rng.random::<f32>() < self.blunder_probability {
+            if let Some(&action) = game.get_possible_actions().choose(&mut self.rng) {
+                choice.action = action;
+            }
+
+            return choice;
+        }
+
+        if self.temperature > 0.0
+            && let Some(evaluation) = &choice.evaluation
+            && let Some(action) = self.sample_top_k(&evaluation.policy)
+        {
+            choice.action = action;
+        }
+
+        choice
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = self.rng_factory.create_seeded(seed);
+
+        self.player.reseed(derive_seed(seed, 0));
+    }
+
+    fn new_game(&mut self) {
+        self.player.new_game();
+    }
+
+    fn set_clock(&mut self, remaining: Duration, increment: Duration) {
+        self.player.set_clock(remaining, increment);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Evaluation;
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    #[derive(Clone)]
+    struct FavouritePlayer;
+
+    impl Player<TicTacToe> for FavouritePlayer {
+        fn name(&self) -> &'static str {
+            "Favourite"
+        }
+
+        fn choose_action(&mut self, _game: &TicTacToe, _turn_number: u32) -> Choice<TicTacToe> {
+            let priors = [(4, 0.5), (0, 0.3), (8, 0.2)];
+
+            Choice {
+                evaluation: Some(Evaluation {
+                    policy: priors
+                        .iter()
+                        .map(|&(index, prior)| PolicyItem {
+                            action: TicTacToeAction::Place { index },
+                            prior,
+                        })
+                        .collect(),
+                    value: 0.0,
+                }),
+                action: TicTacToeAction::Place { index: 4 },
+                resign: false,
+            }
+        }
+    }
+
+    fn actions(player: &mut SkillLimitedPlayer<FavouritePlayer>) -> Vec<u8> {
+        (0..100)
+            .map(
+                |_| match player.choose_action(&TicTacToe::new(), 0).action {
+                    TicTacToeAction::Place { index } => index,
+                },
+            )
+            .collect()
+    }
+
+    mod choose_action {
+        use super::*;
+
+        #[test]
+        fn should_keep_the_inner_choice_by_default() {
+            let mut player = SkillLimitedPlayer::new(FavouritePlayer).with_seed(3);
+
+            assert!(actions(&mut player).iter().all(|&index| index == 4));
+        }
+
+        #[test]
+        fn should_only_sample_among_the_top_actions() {
+            let mut player = SkillLimitedPlayer::new(FavouritePlayer)
+                .with_temperature(1.0, 2)
+                .with_seed(3);
+
+            let actions = actions(&mut player);
+
+            assert!(actions.contains(&0));
+            assert!(actions.iter().all(|&index| index == 0 || index == 4));
+        }
+
+        #[test]
+        fn should_blunder_with_the_given_probability() {
+            let mut player = SkillLimitedPlayer::new(FavouritePlayer)
+                .with_blunder_probability(1.0)
+                .with_seed(3);
+
+            let actions = actions(&mut player);
+
+            assert!(actions.iter().any(|&index| ![0, 4, 8].contains(&index)));
+        }
+    }
+    mod reseed {
+        use super::*;
+
+        use crate::player::RandomPlayer;
+
+        #[test]
+        fn should_reseed_the_inner_player_from_a_derived_seed() {
+            let game = TicTacToe::new();
+
+            let mut player = SkillLimitedPlayer::new(RandomPlayer::new());
+            let mut inner = RandomPlayer::new();
+
+            Player::<TicTacToe>::reseed(&mut player, 5);
+            Player::<TicTacToe>::reseed(&mut inner, derive_seed(5, 0));
+
+            for _ in 0..20 {
+                assert_eq!(
+                    player.choose_action(&game, 0).action,
+                    inner.choose_action(&game, 0).action
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use std::time::Duration;
+
+use crate::core::{Choice, Game, Player};
+
+// NOTE - Sizes an inner player's search to the clock of a timed game. Each move is given
+// an even share of the remaining time over `moves_to_go` more moves, plus most of the
+// increment, but never more than half the time left. The budget is handed to the inner
+// player with `set_time_limit`, such as `MinimaxPlayer::set_time_limit` or
+// `ClassicMctsPlayer::set_time_limit`, which change the limit in place and leave the
+// player's seeded RNG alone.
+
+pub struct TimedPlayer<P> {
+    player: P,
+
+    set_time_limit: fn(&mut P, Duration),
+    moves_to_go: u32,
+}
+
+impl<P: Clone> Clone for TimedPlayer<P> {
+    fn clone(&self) -> Self {
+        Self {
+            player: self.player.clone(),
+
+            set_time_limit: self.set_time_limit,
+            moves_to_go: self.moves_to_go,
+        }
+    }
+}
+
+impl<P> TimedPlayer<P> {
+    const DEFAULT_MOVES_TO_GO: u32 = 30;
+
+    pub fn new(player: P, set_time_limit: fn(&mut P, Duration)) -> Self {
+        Self {
+            player,
+
+            set_time_limit,
+            moves_to_go: Self::DEFAULT_MOVES_TO_GO,
+        }
+    }
+
+    pub fn with_moves_to_go(mut self, moves_to_go: u32) -> Self {
+        self.moves_to_go = moves_to_go.max(1);
+
+        self
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.player
+    }
+
+    fn move_budget(&self, remaining: Duration, increment: Duration) -> Duration {
+        let budget = remaining / self.moves_to_go + increment * 3 / 4;
+
+        budget.min(remaining / 2)
+    }
+}
+
+impl<G: Game, P: Player<G>> Player<G> for TimedPlayer<P> {
+    fn name(&self) -> &str {
+        self.player.name()
+    }
+
+    fn choose_action(&mut self, game: &G, turn_number: u32) -> Choice<G> {
+        self.player.choose_action(game, turn_number)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.player.reseed(seed);
+    }
+
+    fn new_game(&mut self) {
+        self.player.new_game();
+    }
+
+    fn set_clock(&mut self, remaining: Duration, increment: Duration) {
+        let budget = self.move_budget(remaining, increment);
+
+        (self.set_time_limit)(&mut self.player, budget);
+
+        self.player.set_clock(remaining, increment);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{EventSink, Runner, RunnerEvent, RunnerEventKind, TimeControl};
+    use crate::game::tic_tac_toe::TicTacToe;
+    use crate::player::{ClassicMctsPlayer, RandomPlayer};
+
+    #[derive(Default)]
+    struct ActionRecorder {
+        actions: Vec<(u32, String)>,
+    }
+
+    impl EventSink<RunnerEvent<TicTacToe>> for ActionRecorder {
+        fn emit(&mut self, event: RunnerEvent<TicTacToe>) {
+            if let (RunnerEventKind::ActionApplied { action }, Some(context)) =
+                (event.kind, event.context)
+            {
+                self.actions.push((context.game_number, action.to_string()));
+            }
+        }
+    }
+
+    mod set_clock {
+        use super::*;
+
+        #[derive(Default)]
+        struct BudgetRecorder {
+            player: RandomPlayer,
+            budgets: Vec<Duration>,
+        }
+
+        impl Player<TicTacToe> for BudgetRecorder {
+            fn name(&self) -> &'static str {
+                "Budget Recorder"
+            }
+
+            fn choose_action(&mut self, game: &TicTacToe, turn_number: u32) -> Choice<TicTacToe> {
+                self.player.choose_action(game, turn_number)
+            }
+        }
+
+        #[test]
+        fn should_share_the_remaining_time_between_the_moves_to_go() {
+            let mut player = TimedPlayer::new(BudgetRecorder::default(), |player, budget| {
+                player.budgets.push(budget);
+            })
+            .with_moves_to_go(10);
+
+            Player::<TicTacToe>::set_clock(
+                &mut player,
+                Duration::from_secs(10),
+                Duration::from_secs(2),
+            );
+            Player::<TicTacToe>::set_clock(&mut player, Duration::from_secs(2), Duration::ZERO);
+
+            assert_eq!(
+                player.inner().budgets,
+                [Duration::from_millis(2500), Duration::from_millis(200)]
+            );
+        }
+
+        #[test]
+        fn should_limit_an_mcts_search_to_its_share_of_the_clock() {
+            let mut player = TimedPlayer::new(
+                ClassicMctsPlayer::<TicTacToe>::new(u32::MAX).with_seed(0),
+                ClassicMctsPlayer::set_time_limit,
+            )
+            .with_moves_to_go(10);
+
+            player.set_clock(Duration::from_millis(200), Duration::ZERO);
+
+            player.choose_action(&TicTacToe::new(), 0);
+
+            let simulations = player.inner().search_statistics().simulations;
+
+            assert!(simulations > 0);
+            assert!(simulations < u32::MAX);
+        }
+    }
+
+    mod run {
+        use super::*;
+
+        fn record_timed_games(seed: u64) -> Vec<(u32, String)> {
+            let player = TimedPlayer::new(
+                ClassicMctsPlayer::<TicTacToe>::new(25),
+                ClassicMctsPlayer::set_time_limit,
+            );
+
+            let mut runner = Runner::new(2, player.clone(), player, ActionRecorder::default())
+                .with_seed(seed)
+                .with_time_control(TimeControl::new(Duration::from_secs(30), Duration::ZERO));
+
+            runner.run();
+
+            runner.sink().actions.clone()
+        }
+
+        #[test]
+        fn should_replay_seeded_timed_games() {
+            let actions = record_timed_games(5);
+
+            assert!(!actions.is_empty());
+            assert_eq!(actions, record_timed_games(5));
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub use crate::builder::{AnalysisBuilder, MatchBuilder, SelfPlayBuilder};
+pub use crate::core::{
+    EventSink, Game, Outcome, Player, Runner, Scoring, ShutdownSignal, StatisticsRunnerEventSink,
+    StdoutRunnerEventSink, Turn,
+};
+pub use crate::neural_network::{
+    ActionEncoder, ModelZoo, NeuralNetwork, OnnxNeuralNetwork, StateEncoder,
+};
+pub use crate::player::{
+    ClassicMctsPlayer, DirichletNoise, GreedyPlayer, HumanPlayer, MinimaxPlayer,
+    NeuralNetworkMctsPlayer, RandomPlayer, TemperatureSchedule,
+};
+pub use crate::record::GameRecord;
+pub use crate::render::RenderableGame;
+pub use crate::self_play::{BinarySampleSink, JsonSampleSink, Sample};
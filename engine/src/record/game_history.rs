@@ -0,0 +1,179 @@
+use crate::core::{Game, Outcome, Turn};
+use crate::record::game_record::{GameRecord, RecordedMove};
+
+// NOTE - A live game that remembers how it got here, for takebacks in interactive play.
+// Turns end as in the runner, which stops before ending the final turn, so `record`
+// replays to the same position.
+
+#[derive(Clone)]
+pub struct GameHistory<G: Game> {
+    game: G,
+    turn: Turn,
+
+    initial_turn: Turn,
+    actions: Vec<G::Action>,
+
+    undo_stack: Vec<(G::Checkpoint, Turn)>,
+}
+
+impl<G: Game> GameHistory<G> {
+    pub fn new(initial_turn: Turn) -> Self {
+        Self {
+            game: G::new(),
+            turn: initial_turn,
+
+            initial_turn,
+            actions: vec![],
+
+            undo_stack: vec![],
+        }
+    }
+
+    pub fn game(&self) -> &G {
+        &self.game
+    }
+
+    pub fn turn(&self) -> Turn {
+        self.turn
+    }
+
+    pub fn history(&self) -> &[G::Action] {
+        &self.actions
+    }
+
+    pub fn apply_action(&mut self, action: G::Action) -> Result<(), String> {
+        if !self.game.get_possible_actions().contains(&action) {
+            return Err(format!("action is not legal: {action}"));
+        }
+
+        self.undo_stack
+            .push((self.game.create_checkpoint(), self.turn));
+        self.actions.push(action);
+
+        let turn_complete = self.game.apply_action(action);
+
+        if turn_complete && self.game.outcome() == Outcome::InProgress {
+            self.game.end_turn();
+
+            self.turn = self.turn.advance();
+        }
+
+        Ok(())
+    }
+
+    pub fn undo(&mut self) -> Option<G::Action> {
+        let (checkpoint, turn) = self.undo_stack.pop()?;
+
+        self.game.restore_checkpoint(checkpoint);
+        self.turn = turn;
+
+        self.actions.pop()
+    }
+
+    pub fn record(&self) -> GameRecord<G> {
+        let mut record = GameRecord::new(self.initial_turn);
+
+        record.moves = self
+            .actions
+            .iter()
+            .zip(&self.undo_stack)
+            .map(|(&action, &(_, turn))| RecordedMove {
+                turn,
+                action,
+                value: None,
+            })
+            .collect();
+
+        // NOTE - A finished game's outcome is from the perspective of whoever moved last.
+
+        record.outcome = match self.turn {
+            Turn::Player1 => self.game.outcome(),
+            Turn::Player2 => self.game.outcome().flip(),
+        };
+
+        record
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    fn place(index: u8) -> TicTacToeAction {
+        TicTacToeAction::Place { index }
+    }
+
+    fn play(indices: &[u8]) -> GameHistory<TicTacToe> {
+        let mut history = GameHistory::new(Turn::Player1);
+
+        for &index in indices {
+            history
+                .apply_action(place(index))
+                .expect("unable to apply action");
+        }
+
+        history
+    }
+
+    mod apply_action {
+        use super::*;
+
+        #[test]
+        fn should_reject_illegal_actions() {
+            let mut history = play(&[4]);
+
+            assert!(history.apply_action(place(4)).is_err());
+            assert_eq!(history.history(), [place(4)]);
+        }
+    }
+
+    mod undo {
+        use super::*;
+
+        #[test]
+        fn should_restore_the_previous_position() {
+            let mut history = play(&[4, 0]);
+
+            assert_eq!(history.undo(), Some(place(0)));
+
+            let expected = play(&[4]);
+
+            assert_eq!(history.game(), expected.game());
+            assert_eq!(history.turn(), Turn::Player2);
+            assert_eq!(history.history(), [place(4)]);
+        }
+
+        #[test]
+        fn should_return_none_at_the_start() {
+            let mut history = play(&[]);
+
+            assert_eq!(history.undo(), None);
+        }
+    }
+
+    mod record {
+        use super::*;
+
+        #[test]
+        fn should_replay_to_the_current_position() {
+            let history = play(&[4, 0, 2, 6, 3, 5, 1, 7, 8]);
+
+            let record = history.record();
+
+            let (game, turn) = record.replay().expect("unable to replay record");
+
+            assert_eq!(&game, history.game());
+            assert_eq!(turn, history.turn());
+            assert_eq!(record.outcome, Outcome::Draw);
+        }
+
+        #[test]
+        fn should_score_wins_for_player_1() {
+            let history = play(&[0, 3, 1, 4, 2]);
+
+            assert_eq!(history.record().outcome, Outcome::Win);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::core::{Game, Outcome, Turn};
+#[cfg(test)]
+use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+#[derive(Clone)]
+pub struct GameRecord<G: Game> {
+    // NOTE - The position the game started from, which is not `G::new()` for games built
+    // from a config or from notation.
+    pub initial_game: G,
+    pub initial_turn: Turn,
+    pub moves: Vec<RecordedMove<G>>,
+
+    // NOTE - Always from Player 1's perspective, unlike `Game::outcome`.
+    pub outcome: Outcome,
+}
+
+#[derive(Clone, Copy)]
+pub struct RecordedMove<G: Game> {
+    pub turn: Turn,
+    pub action: G::Action,
+
+    // NOTE - The search value of the position the move was played from, from the
+    // mover's perspective, when the player reported one.
+    pub value: Option<f32>,
+}
+
+impl<G: Game> GameRecord<G> {
+    pub fn new(initial_turn: Turn) -> Self {
+        Self {
+            initial_game: G::new(),
+            initial_turn,
+            moves: vec![],
+
+            outcome: Outcome::InProgress,
+        }
+    }
+
+    pub fn with_initial_game(mut self, initial_game: G) -> Self {
+        self.initial_game = initial_game;
+
+        self
+    }
+
+    // NOTE - Test fixture for games with one action per turn, so the movers alternate.
+
+    #[cfg(test)]
+    pub(crate) fn from_actions(
+        initial_turn: Turn,
+        actions: impl IntoIterator<Item = G::Action>,
+        outcome: Outcome,
+    ) -> Self {
+        let mut record = Self::new(initial_turn);
+
+        let mut turn = initial_turn;
+
+        for action in actions {
+            record.moves.push(RecordedMove {
+                turn,
+                action,
+                value: None,
+            });
+
+            turn = turn.advance();
+        }
+
+        record.outcome = outcome;
+
+        record
+    }
+
+    // NOTE - The notation of the initial position for portable formats, left out when the
+    // game started from `G::new()`.
+
+    pub(crate) fn initial_position(&self) -> Option<String> {
+        let notation = self.initial_game.to_notation();
+
+        (notation != G::new().to_notation()).then_some(notation)
+    }
+
+    pub fn positions(&self) -> Result<Vec<(G, Turn)>, String> {
+        let mut positions = Vec::with_capacity(self.moves.len() + 1);
+
+        let mut game = self.initial_game.clone();
+        let mut turn = self.initial_turn;
+
+        positions.push((game.clone(), turn));
+
+        for (i, recorded_move) in self.moves.iter().enumerate() {
+            let RecordedMove {
+                turn: move_turn,
+                action,
+                ..
+            } = *recorded_move;
+
+            if move_turn != turn {
+                return Err(format!("move {} was played out of turn", i + 1));
+            }
+
+            if !game.get_possible_actions().contains(&action) {
+                return Err(format!("move {} is not legal: {action}", i + 1));
+            }
+
+            let turn_complete = game.apply_action(action);
+
+            // NOTE - Mirror the runner, which stops before ending the final turn.
+
+            if turn_complete && game.outcome() == Outcome::InProgress {
+                game.end_turn();
+
+                turn = turn.advance();
+            }
+
+            positions.push((game.clone(), turn));
+        }
+
+        Ok(positions)
+    }
+
+    pub fn replay(&self) -> Result<(G, Turn), String> {
+        let mut positions = self.positions()?;
+
+        Ok(positions
+            .pop()
+            .expect("positions should include the initial position"))
+    }
+}
+
+// NOTE - Test fixture for the analysis reports, which replay TicTacToe games that Player 1
+// starts.
+
+#[cfg(test)]
+impl GameRecord<TicTacToe> {
+    pub(crate) fn from_places(indices: &[u8], outcome: Outcome) -> Self {
+        Self::from_actions(
+            Turn::Player1,
+            indices
+                .iter()
+                .map(|&index| TicTacToeAction::Place { index }),
+            outcome,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game::mnk::{MnkAction, MnkConfig, MnkGame};
+
+    fn make_record() -> GameRecord<MnkGame> {
+        GameRecord::from_actions(
+            Turn::Player1,
+            [(6, 6), (0, 0)].map(|(row, col)| MnkAction::Place { row, col }),
+            Outcome::InProgress,
+        )
+        .with_initial_game(MnkGame::new_with_config(MnkConfig::new(7, 7, 4)))
+    }
+
+    mod positions {
+        use super::*;
+
+        #[test]
+        fn should_replay_from_the_initial_game() {
+            let mut record = make_record();
+
+            let (game, turn) = record.replay().expect("unable to replay");
+
+            assert_eq!(game.to_notation(), "O6/7/7/7/7/7/6X 4");
+            assert_eq!(turn, Turn::Player1);
+
+            record.initial_game = MnkGame::new();
+
+            assert!(record.positions().is_err());
+        }
+    }
+}
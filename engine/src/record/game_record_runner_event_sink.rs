@@ -0,0 +1,70 @@
+use crate::core::{EventSink, Game, RunnerEvent, RunnerEventContext, RunnerEventKind, Turn};
+use crate::record::game_record::{GameRecord, RecordedMove};
+
+pub struct GameRecordRunnerEventSink<G: Game, S: EventSink<GameRecord<G>>> {
+    pending_record: Option<GameRecord<G>>,
+    pending_value: Option<f32>,
+
+    sink: S,
+}
+
+impl<G: Game, S: EventSink<GameRecord<G>>> GameRecordRunnerEventSink<G, S> {
+    pub fn new(sink: S) -> Self {
+        Self {
+            pending_record: None,
+            pending_value: None,
+
+            sink,
+        }
+    }
+
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+}
+
+impl<G: Game, S: EventSink<GameRecord<G>>> EventSink<RunnerEvent<G>>
+    for GameRecordRunnerEventSink<G, S>
+{
+    fn emit(&mut self, event: RunnerEvent<G>) {
+        let RunnerEvent { kind, context } = event;
+
+        let Some(RunnerEventContext { game, turn, .. }) = context else {
+            return;
+        };
+
+        match kind {
+            RunnerEventKind::GameStarted => {
+                self.pending_record = Some(GameRecord::new(turn).with_initial_game(game));
+                self.pending_value = None;
+            }
+            RunnerEventKind::PositionEvaluated { evaluation } => {
+                self.pending_value = Some(evaluation.value);
+            }
+            RunnerEventKind::ActionApplied { action } => {
+                if let Some(record) = &mut self.pending_record {
+                    record.moves.push(RecordedMove {
+                        turn,
+                        action,
+                        value: self.pending_value.take(),
+                    });
+                }
+            }
+            RunnerEventKind::GameFinished { outcome } => {
+                let Some(mut record) = self.pending_record.take() else {
+                    return;
+                };
+
+                // NOTE - `outcome` is from the perspective of whoever made the last move.
+
+                record.outcome = match turn {
+                    Turn::Player1 => outcome,
+                    Turn::Player2 => outcome.flip(),
+                };
+
+                self.sink.emit(record);
+            }
+            _ => {}
+        }
+    }
+}
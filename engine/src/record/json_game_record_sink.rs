@@ -0,0 +1,22 @@
+use std::io::Write;
+
+use crate::core::EventSink;
+use crate::record::game_record::GameRecord;
+use crate::record::portable_game::PortableGame;
+
+pub struct JsonGameRecordSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonGameRecordSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<G: PortableGame, W: Write> EventSink<GameRecord<G>> for JsonGameRecordSink<W> {
+    fn emit(&mut self, record: GameRecord<G>) {
+        writeln!(&mut self.writer, "{}", record.to_portable_json())
+            .expect("unable to write game record");
+    }
+}
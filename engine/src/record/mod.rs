@@ -0,0 +1,13 @@
+mod game_history;
+mod game_record;
+mod game_record_runner_event_sink;
+mod json_game_record_sink;
+mod portable_game;
+mod portable_json;
+mod sgf;
+
+pub use game_history::GameHistory;
+pub use game_record::{GameRecord, RecordedMove};
+pub use game_record_runner_event_sink::GameRecordRunnerEventSink;
+pub use json_game_record_sink::JsonGameRecordSink;
+pub use portable_game::PortableGame;
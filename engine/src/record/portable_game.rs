@@ -0,0 +1,39 @@
+use crate::core::Game;
+
+pub trait PortableGame: Game {
+    const NAME: &'static str;
+
+    fn board_size() -> usize;
+
+    fn format_action(action: Self::Action) -> String;
+
+    fn parse_action(value: &str) -> Result<Self::Action, String>;
+
+    fn format_point(index: u8) -> String {
+        let size = Self::board_size();
+
+        let row = index as usize / size;
+        let col = index as usize % size;
+
+        [col, row]
+            .iter()
+            .map(|&coordinate| char::from(b'a' + u8::try_from(coordinate).unwrap()))
+            .collect()
+    }
+
+    fn parse_point(value: &str) -> Result<u8, String> {
+        let size = Self::board_size();
+
+        let coordinates: Vec<usize> = value
+            .bytes()
+            .map(|byte| byte.wrapping_sub(b'a') as usize)
+            .collect();
+
+        match coordinates[..] {
+            [col, row] if col < size && row < size => {
+                u8::try_from(row * size + col).map_err(|error| error.to_string())
+            }
+            _ => Err(format!("invalid point: {value}")),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Outcome, Turn};
+use crate::record::game_record::{GameRecord, RecordedMove};
+use crate::record::portable_game::PortableGame;
+
+#[derive(Deserialize, Serialize)]
+struct PortableGameRecord {
+    game: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    initial_position: Option<String>,
+    initial_turn: PortableTurn,
+    moves: Vec<PortableMove>,
+    result: Option<PortableResult>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct PortableMove {
+    turn: PortableTurn,
+    action: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<f32>,
+}
+
+#[derive(Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum PortableTurn {
+    Player1,
+    Player2,
+}
+
+#[derive(Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum PortableResult {
+    Player1,
+    Player2,
+    Draw,
+}
+
+impl From<Turn> for PortableTurn {
+    fn from(turn: Turn) -> Self {
+        match turn {
+            Turn::Player1 => PortableTurn::Player1,
+            Turn::Player2 => PortableTurn::Player2,
+        }
+    }
+}
+
+impl From<PortableTurn> for Turn {
+    fn from(turn: PortableTurn) -> Self {
+        match turn {
+            PortableTurn::Player1 => Turn::Player1,
+            PortableTurn::Player2 => Turn::Player2,
+        }
+    }
+}
+
+impl<G: PortableGame> GameRecord<G> {
+    pub fn to_portable_json(&self) -> String {
+        let record = PortableGameRecord {
+            game: G::NAME.to_string(),
+            initial_position: self.initial_position(),
+            initial_turn: self.initial_turn.into(),
+            moves: self
+                .moves
+                .iter()
+                .map(
+                    |RecordedMove {
+                         turn,
+                         action,
+                         value,
+                     }| PortableMove {
+                        turn: (*turn).into(),
+                        action: G::format_action(*action),
+                        value: *value,
+                    },
+                )
+                .collect(),
+            result: match self.outcome {
+                Outcome::Win => Some(PortableResult::Player1),
+                Outcome::Loss => Some(PortableResult::Player2),
+                Outcome::Draw => Some(PortableResult::Draw),
+                Outcome::InProgress => None,
+            },
+        };
+
+        serde_json::to_string(&record).expect("unable to serialize game record")
+    }
+
+    pub fn from_portable_json(json: &str) -> Result<Self, String> {
+        let record: PortableGameRecord =
+            serde_json::from_str(json).map_err(|error| error.to_string())?;
+
+        if record.game != G::NAME {
+            return Err(format!(
+                "expected a {} game, found {}",
+                G::NAME,
+                record.game
+            ));
+        }
+
+        let moves = record
+            .moves
+            .iter()
+            .map(
+                |PortableMove {
+                     turn,
+                     action,
+                     value,
+                 }| {
+                    Ok(RecordedMove {
+                        turn: (*turn).into(),
+                        action: G::parse_action(action)?,
+                        value: *value,
+                    })
+                },
+            )
+            .collect::<Result<_, String>>()?;
+
+        let initial_game = match &record.initial_position {
+            Some(notation) => G::from_notation(notation)?,
+            None => G::new(),
+        };
+
+        Ok(GameRecord {
+            initial_game,
+            initial_turn: record.initial_turn.into(),
+            moves,
+
+            outcome: match record.result {
+                Some(PortableResult::Player1) => Outcome::Win,
+                Some(PortableResult::Player2) => Outcome::Loss,
+                Some(PortableResult::Draw) => Outcome::Draw,
+                None => Outcome::InProgress,
+            },
+        })
+    }
+}
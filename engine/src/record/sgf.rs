@@ -0,0 +1,261 @@
+use std::fmt::Write;
+
+use crate::core::{Outcome, Turn};
+use crate::record::game_record::{GameRecord, RecordedMove};
+use crate::record::portable_game::PortableGame;
+
+type SgfNode = Vec<(String, String)>;
+
+impl<G: PortableGame> GameRecord<G> {
+    pub fn to_sgf(&self) -> String {
+        let mut sgf = String::new();
+
+        let _ = write!(
+            sgf,
+            "(;FF[4]AP[hermes]GN[{}]SZ[{}]PL[{}]",
+            escape(G::NAME),
+            G::board_size(),
+            color(self.initial_turn)
+        );
+
+        // NOTE - `IP` is a private property holding the initial position in notation.
+
+        if let Some(initial_position) = self.initial_position() {
+            let _ = write!(sgf, "IP[{}]", escape(&initial_position));
+        }
+
+        match self.outcome {
+            Outcome::Win => sgf.push_str("RE[B+]"),
+            Outcome::Loss => sgf.push_str("RE[W+]"),
+            Outcome::Draw => sgf.push_str("RE[0]"),
+            Outcome::InProgress => {}
+        }
+
+        for RecordedMove { turn, action, .. } in &self.moves {
+            let _ = write!(
+                sgf,
+                ";{}[{}]",
+                color(*turn),
+                escape(&G::format_action(*action))
+            );
+        }
+
+        sgf.push(')');
+
+        sgf
+    }
+
+    pub fn from_sgf(sgf: &str) -> Result<Self, String> {
+        let nodes = parse_nodes(sgf)?;
+
+        let (root, move_nodes) = nodes.split_first().ok_or("missing root node")?;
+
+        let mut record = GameRecord::new(Turn::Player1);
+
+        for (identifier, value) in root {
+            match identifier.as_str() {
+                "GN" if value != G::NAME => {
+                    return Err(format!("expected a {} game, found {value}", G::NAME));
+                }
+                "SZ" if value.parse::<usize>() != Ok(G::board_size()) => {
+                    return Err(format!("unsupported board size: {value}"));
+                }
+                "PL" => record.initial_turn = parse_color(value)?,
+                "IP" => record.initial_game = G::from_notation(value)?,
+                "RE" => {
+                    record.outcome = match value.chars().next() {
+                        Some('B') => Outcome::Win,
+                        Some('W') => Outcome::Loss,
+                        Some('0' | 'D') => Outcome::Draw,
+                        _ => Outcome::InProgress,
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        for node in move_nodes {
+            for (identifier, value) in node {
+                if identifier == "B" || identifier == "W" {
+                    record.moves.push(RecordedMove {
+                        turn: parse_color(identifier)?,
+                        action: G::parse_action(value)?,
+                        value: None,
+                    });
+                }
+            }
+        }
+
+        Ok(record)
+    }
+}
+
+fn color(turn: Turn) -> &'static str {
+    match turn {
+        Turn::Player1 => "B",
+        Turn::Player2 => "W",
+    }
+}
+
+fn parse_color(value: &str) -> Result<Turn, String> {
+    match value {
+        "B" => Ok(Turn::Player1),
+        "W" => Ok(Turn::Player2),
+        _ => Err(format!("invalid color: {value}")),
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace(']', "\\]")
+}
+
+fn parse_nodes(sgf: &str) -> Result<Vec<SgfNode>, String> {
+    let body = sgf
+        .trim()
+        .strip_prefix('(')
+        .and_then(|sgf| sgf.strip_suffix(')'))
+        .ok_or("game tree must be enclosed in parentheses")?;
+
+    let mut nodes: Vec<SgfNode> = vec![];
+
+    let mut identifier = String::new();
+    let mut after_value = false;
+
+    let mut characters = body.chars();
+
+    while let Some(character) = characters.next() {
+        match character {
+            ';' => {
+                nodes.push(vec![]);
+
+                identifier.clear();
+                after_value = false;
+            }
+            '[' => {
+                let mut value = String::new();
+
+                loop {
+                    match characters.next() {
+                        Some('\\') => value.extend(characters.next()),
+                        Some(']') => break,
+                        Some(character) => value.push(character),
+                        None => return Err("unterminated property value".to_string()),
+                    }
+                }
+
+                if identifier.is_empty() {
+                    return Err("property value without identifier".to_string());
+                }
+
+                nodes
+                    .last_mut()
+                    .ok_or("property outside of a node")?
+                    .push((identifier.clone(), value));
+
+                after_value = true;
+            }
+            character if character.is_ascii_uppercase() => {
+                if after_value {
+                    identifier.clear();
+                    after_value = false;
+                }
+
+                identifier.push(character);
+            }
+            character if character.is_whitespace() => {}
+            '(' | ')' => return Err("variations are not supported".to_string()),
+            character => return Err(format!("unexpected character: {character}")),
+        }
+    }
+
+    Ok(nodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Game;
+    use crate::game::boop::{Boop, BoopAction, BoopPiece};
+    use crate::game::mnk::{MnkAction, MnkConfig, MnkGame};
+
+    fn make_record() -> GameRecord<Boop> {
+        GameRecord::from_actions(
+            Turn::Player2,
+            [0, 14, 35].map(|index| BoopAction::Place {
+                piece: BoopPiece::Kitten,
+                index,
+            }),
+            Outcome::InProgress,
+        )
+    }
+
+    mod to_sgf {
+        use super::*;
+
+        #[test]
+        fn should_write_moves_with_colors() {
+            let sgf = make_record().to_sgf();
+
+            assert_eq!(
+                sgf,
+                "(;FF[4]AP[hermes]GN[boop]SZ[6]PL[W];W[kaa];B[kcc];W[kff])"
+            );
+        }
+    }
+
+    mod from_sgf {
+        use super::*;
+
+        #[test]
+        fn should_round_trip() {
+            let record = make_record();
+
+            let parsed = GameRecord::<Boop>::from_sgf(&record.to_sgf()).expect("unable to parse");
+
+            assert_eq!(parsed.initial_turn, record.initial_turn);
+            assert_eq!(parsed.outcome, record.outcome);
+            assert_eq!(
+                parsed.replay().expect("unable to replay"),
+                record.replay().expect("unable to replay")
+            );
+        }
+
+        #[test]
+        fn should_round_trip_the_initial_game() {
+            let record = GameRecord::from_actions(
+                Turn::Player1,
+                [MnkAction::Place { row: 6, col: 6 }],
+                Outcome::InProgress,
+            )
+            .with_initial_game(MnkGame::new_with_config(MnkConfig::new(7, 7, 4)));
+
+            let parsed =
+                GameRecord::<MnkGame>::from_sgf(&record.to_sgf()).expect("unable to parse");
+
+            assert_eq!(
+                parsed.replay().expect("unable to replay").0.to_notation(),
+                "7/7/7/7/7/7/6O 4"
+            );
+        }
+
+        #[test]
+        fn should_reject_other_games() {
+            let result = GameRecord::<Boop>::from_sgf("(;GN[tic_tac_toe]SZ[3];B[aa])");
+
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn should_parse_graduate_actions() {
+            let record = GameRecord::<Boop>::from_sgf("(;GN[boop]SZ[6];B[gaabacb])")
+                .expect("unable to parse");
+
+            let BoopAction::Graduate { mask } = record.moves[0].action else {
+                panic!("expected a graduate action");
+            };
+
+            assert_eq!(mask, 0b1 | 0b10 | (1 << 8));
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod policy_overlay;
+mod renderable_game;
+mod svg_renderer;
+
+pub use policy_overlay::PolicyOverlay;
+pub use renderable_game::{PieceShape, RenderableGame, RenderedPiece};
+pub use svg_renderer::SvgRenderer;
+
+#[cfg(test)]
+pub(crate) use renderable_game::weighted_squares;
@@ -0,0 +1,87 @@
+use std::fmt::Write;
+
+use crate::core::Evaluation;
+use crate::render::renderable_game::RenderableGame;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PolicyOverlay {
+    Shades,
+    Percentages,
+}
+
+impl PolicyOverlay {
+    const SHADES: [char; 4] = ['░', '▒', '▓', '█'];
+
+    // NOTE - Squares without any policy weight are drawn as a dot. Shades are relative to
+    // the heaviest square, percentages are absolute.
+
+    pub fn render<G: RenderableGame>(self, game: &G, evaluation: &Evaluation<G>) -> String {
+        let (rows, cols) = game.grid_size();
+
+        let weights = game.square_weights(evaluation);
+        let max_weight = weights.iter().copied().fold(0.0, f64::max);
+
+        let mut overlay = String::new();
+
+        for row in 0..rows {
+            let cells: Vec<String> = weights[row * cols..(row + 1) * cols]
+                .iter()
+                .map(|&weight| match self {
+                    PolicyOverlay::Shades if weight < 0.005 => "·".to_string(),
+                    PolicyOverlay::Percentages if weight < 0.005 => format!("{:>3}", "·"),
+                    PolicyOverlay::Shades => Self::shade(weight / max_weight).to_string(),
+                    PolicyOverlay::Percentages => format!("{:>3.0}", weight * 100.0),
+                })
+                .collect();
+
+            let _ = writeln!(overlay, "{}", cells.join(" "));
+        }
+
+        overlay
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn shade(share: f64) -> char {
+        let level = (share * Self::SHADES.len() as f64).ceil() as usize;
+
+        Self::SHADES[level.clamp(1, Self::SHADES.len()) - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{Game, Policy, PolicyItem};
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    fn make_evaluation() -> Evaluation<TicTacToe> {
+        let policy = [(0, 0.75), (4, 0.25)]
+            .into_iter()
+            .map(|(index, prior)| PolicyItem {
+                action: TicTacToeAction::Place { index },
+                prior,
+            })
+            .collect::<Policy<TicTacToe>>();
+
+        Evaluation { policy, value: 0.0 }
+    }
+
+    mod render {
+        use super::*;
+
+        #[test]
+        fn should_render_shades_relative_to_heaviest_square() {
+            let overlay = PolicyOverlay::Shades.render(&TicTacToe::new(), &make_evaluation());
+
+            assert_eq!(overlay, "█ · ·\n· ▒ ·\n· · ·\n");
+        }
+
+        #[test]
+        fn should_render_percentages() {
+            let overlay = PolicyOverlay::Percentages.render(&TicTacToe::new(), &make_evaluation());
+
+            assert_eq!(overlay, " 75   ·   ·\n  ·  25   ·\n  ·   ·   ·\n");
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::core::{Evaluation, Game, PolicyItem, Turn};
+
+pub trait RenderableGame: Game {
+    fn grid_size(&self) -> (usize, usize);
+
+    fn piece_at(&self, row: usize, col: usize, turn: Turn) -> Option<RenderedPiece>;
+
+    fn action_squares(&self, action: Self::Action) -> Vec<(usize, usize)>;
+
+    // NOTE - Each action's prior is split evenly across the squares it touches, in row-major
+    // order.
+
+    fn square_weights(&self, evaluation: &Evaluation<Self>) -> Vec<f64> {
+        let (rows, cols) = self.grid_size();
+
+        let mut weights = vec![0.0; rows * cols];
+
+        for PolicyItem { action, prior } in &evaluation.policy {
+            let squares = self.action_squares(*action);
+
+            if squares.is_empty() {
+                continue;
+            }
+
+            let share = f64::from(*prior) / squares.len() as f64;
+
+            for (row, col) in squares {
+                weights[row * cols + col] += share;
+            }
+        }
+
+        weights
+    }
+}
+
+// NOTE - Test fixture giving the squares a policy weighs, as row-major indices, so each
+// game can check how its actions land on its grid.
+
+#[cfg(test)]
+pub(crate) fn weighted_squares<G: RenderableGame>(
+    game: &G,
+    policy: &[(G::Action, f32)],
+) -> Vec<(usize, f64)> {
+    let evaluation = Evaluation {
+        policy: policy
+            .iter()
+            .map(|&(action, prior)| PolicyItem { action, prior })
+            .collect(),
+        value: 0.0,
+    };
+
+    game.square_weights(&evaluation)
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, weight)| weight > 0.0)
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RenderedPiece {
+    pub owner: Turn,
+    pub shape: PieceShape,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PieceShape {
+    Mark,
+    Small,
+    Large,
+}
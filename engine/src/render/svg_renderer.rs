@@ -0,0 +1,330 @@
+use std::fmt::Write;
+
+use crate::core::{Evaluation, Turn};
+use crate::render::renderable_game::{PieceShape, RenderableGame, RenderedPiece};
+
+#[derive(Clone, Debug)]
+pub struct SvgRenderer {
+    square_size: u32,
+
+    heatmap: bool,
+    labels: bool,
+    visits: Option<u32>,
+}
+
+impl SvgRenderer {
+    const BACKGROUND_COLOR: &'static str = "#f5e6c8";
+    const GRID_COLOR: &'static str = "#8d6e63";
+    const HEATMAP_COLOR: &'static str = "#e53935";
+    const PLAYER_1_COLOR: &'static str = "#fb8c00";
+    const PLAYER_2_COLOR: &'static str = "#757575";
+
+    pub fn new() -> Self {
+        Self {
+            square_size: 60,
+
+            heatmap: false,
+            labels: false,
+            visits: None,
+        }
+    }
+
+    pub fn with_square_size(mut self, square_size: u32) -> Self {
+        self.square_size = square_size.max(1);
+
+        self
+    }
+
+    pub fn with_heatmap(mut self) -> Self {
+        self.heatmap = true;
+
+        self
+    }
+
+    pub fn with_labels(mut self) -> Self {
+        self.labels = true;
+
+        self
+    }
+
+    // NOTE - Labels squares with visit counts rather than percentages, reading the policy
+    // of a search's evaluation as its share of the root's visits.
+
+    pub fn with_visit_counts(mut self, visits: u32) -> Self {
+        self.labels = true;
+        self.visits = Some(visits);
+
+        self
+    }
+
+    pub fn render<G: RenderableGame>(
+        &self,
+        game: &G,
+        turn: Turn,
+        evaluation: Option<&Evaluation<G>>,
+    ) -> String {
+        let (rows, cols) = game.grid_size();
+
+        let size = f64::from(self.square_size);
+        let width = size * cols as f64;
+        let height = size * rows as f64;
+
+        let mut svg = String::new();
+
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        );
+
+        let _ = writeln!(
+            svg,
+            r#"<rect width="{width}" height="{height}" fill="{}"/>"#,
+            Self::BACKGROUND_COLOR
+        );
+
+        // NOTE - Overlay
+
+        let weights = evaluation.map(|evaluation| game.square_weights(evaluation));
+
+        if let Some(weights) = &weights
+            && self.heatmap
+        {
+            let max_weight = weights.iter().copied().fold(0.0, f64::max);
+
+            for (index, &weight) in weights.iter().enumerate() {
+                if weight <= 0.0 {
+                    continue;
+                }
+
+                let (x, y) = self.square_origin(index, cols);
+
+                let _ = writeln!(
+                    svg,
+                    r#"<rect x="{x}" y="{y}" width="{size}" height="{size}" fill="{}" fill-opacity="{:.3}"/>"#,
+                    Self::HEATMAP_COLOR,
+                    0.75 * weight / max_weight
+                );
+            }
+        }
+
+        // NOTE - Grid
+
+        for row in 0..=rows {
+            let y = size * row as f64;
+
+            let _ = writeln!(
+                svg,
+                r#"<line x1="0" y1="{y}" x2="{width}" y2="{y}" stroke="{}" stroke-width="2"/>"#,
+                Self::GRID_COLOR
+            );
+        }
+
+        for col in 0..=cols {
+            let x = size * col as f64;
+
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{x}" y1="0" x2="{x}" y2="{height}" stroke="{}" stroke-width="2"/>"#,
+                Self::GRID_COLOR
+            );
+        }
+
+        // NOTE - Pieces
+
+        for row in 0..rows {
+            for col in 0..cols {
+                if let Some(piece) = game.piece_at(row, col, turn) {
+                    self.write_piece(&mut svg, row * cols + col, cols, piece);
+                }
+            }
+        }
+
+        // NOTE - Labels
+
+        if let Some(weights) = &weights
+            && self.labels
+        {
+            for (index, &weight) in weights.iter().enumerate() {
+                let label = match self.visits {
+                    Some(visits) => {
+                        let count = (weight * f64::from(visits)).round();
+
+                        if count < 1.0 {
+                            continue;
+                        }
+
+                        format!("{count}")
+                    }
+                    None if weight < 0.005 => continue,
+                    None => format!("{:.0}%", weight * 100.0),
+                };
+
+                let (x, y) = self.square_origin(index, cols);
+
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{}" y="{}" font-family="sans-serif" font-size="{:.1}" text-anchor="end">{label}</text>"#,
+                    x + size * 0.95,
+                    y + size * 0.95,
+                    size * 0.22
+                );
+            }
+        }
+
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+
+    fn write_piece(&self, svg: &mut String, index: usize, cols: usize, piece: RenderedPiece) {
+        let size = f64::from(self.square_size);
+
+        let (x, y) = self.square_origin(index, cols);
+        let (cx, cy) = (x + size / 2.0, y + size / 2.0);
+
+        let color = match piece.owner {
+            Turn::Player1 => Self::PLAYER_1_COLOR,
+            Turn::Player2 => Self::PLAYER_2_COLOR,
+        };
+
+        match piece.shape {
+            PieceShape::Mark => {
+                let character = match piece.owner {
+                    Turn::Player1 => 'X',
+                    Turn::Player2 => 'O',
+                };
+
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{cx}" y="{cy}" font-family="sans-serif" font-size="{:.1}" font-weight="bold" fill="{color}" text-anchor="middle" dominant-baseline="central">{character}</text>"#,
+                    size * 0.7
+                );
+            }
+            PieceShape::Small | PieceShape::Large => {
+                let radius = if piece.shape == PieceShape::Small {
+                    size * 0.25
+                } else {
+                    size * 0.4
+                };
+
+                let _ = writeln!(
+                    svg,
+                    r##"<circle cx="{cx}" cy="{cy}" r="{radius:.1}" fill="{color}" stroke="#212121" stroke-width="2"/>"##
+                );
+            }
+        }
+    }
+
+    fn square_origin(&self, index: usize, cols: usize) -> (f64, f64) {
+        let size = f64::from(self.square_size);
+
+        let row = index / cols;
+        let col = index % cols;
+
+        (size * col as f64, size * row as f64)
+    }
+}
+
+impl Default for SvgRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{Game, Policy, PolicyItem};
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeAction};
+
+    fn make_game() -> TicTacToe {
+        let mut game = TicTacToe::new();
+
+        game.apply_action(TicTacToeAction::Place { index: 4 });
+        game.end_turn();
+
+        game
+    }
+
+    fn make_evaluation() -> Evaluation<TicTacToe> {
+        let policy = [(0, 0.75), (8, 0.25)]
+            .into_iter()
+            .map(|(index, prior)| PolicyItem {
+                action: TicTacToeAction::Place { index },
+                prior,
+            })
+            .collect::<Policy<TicTacToe>>();
+
+        Evaluation { policy, value: 0.0 }
+    }
+
+    mod render {
+        use super::*;
+
+        #[test]
+        fn should_draw_the_board_and_its_pieces() {
+            let svg =
+                SvgRenderer::new()
+                    .with_square_size(10)
+                    .render(&make_game(), Turn::Player2, None);
+
+            assert!(
+                svg.starts_with(
+                    r#"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="30""#
+                )
+            );
+            assert_eq!(svg.matches("<line").count(), 8);
+            assert_eq!(svg.matches("<text").count(), 1);
+            assert!(svg.contains(r#"x="15" y="15""#));
+            assert!(svg.contains(r##"fill="#fb8c00""##));
+            assert!(svg.contains(">X</text>"));
+            assert!(svg.ends_with("</svg>\n"));
+        }
+
+        #[test]
+        fn should_scale_the_heatmap_to_the_heaviest_square() {
+            let evaluation = make_evaluation();
+
+            let svg = SvgRenderer::new().with_heatmap().render(
+                &make_game(),
+                Turn::Player2,
+                Some(&evaluation),
+            );
+
+            assert!(svg.contains(r##"<rect x="0" y="0" width="60" height="60" fill="#e53935" fill-opacity="0.750"/>"##));
+            assert!(svg.contains(r##"<rect x="120" y="120" width="60" height="60" fill="#e53935" fill-opacity="0.250"/>"##));
+            assert!(!svg.contains("%</text>"));
+        }
+
+        #[test]
+        fn should_label_squares_with_percentages() {
+            let evaluation = make_evaluation();
+
+            let svg = SvgRenderer::new().with_labels().render(
+                &make_game(),
+                Turn::Player2,
+                Some(&evaluation),
+            );
+
+            assert!(svg.contains(">75%</text>"));
+            assert!(svg.contains(">25%</text>"));
+            assert!(!svg.contains("fill-opacity"));
+        }
+
+        #[test]
+        fn should_label_squares_with_visit_counts() {
+            let evaluation = make_evaluation();
+
+            let svg = SvgRenderer::new().with_visit_counts(200).render(
+                &make_game(),
+                Turn::Player2,
+                Some(&evaluation),
+            );
+
+            assert!(svg.contains(">150</text>"));
+            assert!(svg.contains(">50</text>"));
+            assert!(!svg.contains("%</text>"));
+        }
+    }
+}
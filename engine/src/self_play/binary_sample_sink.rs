@@ -0,0 +1,61 @@
+use std::io::Write;
+
+use crate::core::EventSink;
+use crate::self_play::sample::Sample;
+use crate::self_play::sample_batch::SampleBatch;
+
+pub struct BinarySampleSink<W: Write> {
+    writer: W,
+
+    batch: SampleBatch,
+    batch_size: usize,
+}
+
+impl<W: Write> BinarySampleSink<W> {
+    const DEFAULT_BATCH_SIZE: usize = 4096;
+
+    pub fn new(writer: W, state_size: usize, policy_size: usize) -> Self {
+        Self {
+            writer,
+
+            batch: SampleBatch::with_capacity(state_size, policy_size, Self::DEFAULT_BATCH_SIZE),
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+
+        self
+    }
+
+    pub fn flush(&mut self) {
+        if self.batch.is_empty() {
+            return;
+        }
+
+        self.batch
+            .write_to(&mut self.writer)
+            .expect("unable to write sample batch");
+
+        self.writer.flush().expect("unable to flush writer");
+
+        self.batch.clear();
+    }
+}
+
+impl<W: Write> EventSink<Sample> for BinarySampleSink<W> {
+    fn emit(&mut self, sample: Sample) {
+        self.batch.push_sample(&sample);
+
+        if self.batch.len() >= self.batch_size {
+            self.flush();
+        }
+    }
+}
+
+impl<W: Write> Drop for BinarySampleSink<W> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
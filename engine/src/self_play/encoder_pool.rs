@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::self_play::sample::Sample;
+
+type EncodeJob = Box<dyn FnOnce() -> Vec<Sample> + Send>;
+
+// NOTE - Jobs may finish out of order, so results are buffered by sequence number and
+// released in submission order to keep the sample stream deterministic.
+
+pub struct EncoderPool {
+    jobs: Option<Sender<(u64, EncodeJob)>>,
+    results: Receiver<(u64, Vec<Sample>)>,
+    workers: Vec<JoinHandle<()>>,
+
+    submitted: u64,
+    released: u64,
+    completed: BTreeMap<u64, Vec<Sample>>,
+}
+
+impl EncoderPool {
+    pub fn new(threads: usize) -> Self {
+        let (job_sender, job_receiver) = mpsc::channel::<(u64, EncodeJob)>();
+        let (result_sender, result_receiver) = mpsc::channel();
+
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+
+        let workers = (0..threads.max(1))
+            .map(|_| {
+                let job_receiver = Arc::clone(&job_receiver);
+                let result_sender = result_sender.clone();
+
+                thread::spawn(move || {
+                    loop {
+                        let job = job_receiver
+                            .lock()
+                            .expect("encoder job queue poisoned")
+                            .recv();
+
+                        let Ok((sequence, job)) = job else {
+                            break;
+                        };
+
+                        if result_sender.send((sequence, job())).is_err() {
+                            break;
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        Self {
+            jobs: Some(job_sender),
+            results: result_receiver,
+            workers,
+
+            submitted: 0,
+            released: 0,
+            completed: BTreeMap::new(),
+        }
+    }
+
+    pub fn submit(&mut self, job: EncodeJob) {
+        self.jobs
+            .as_ref()
+            .expect("encoder pool is shut down")
+            .send((self.submitted, job))
+            .expect("encoder workers have stopped");
+
+        self.submitted += 1;
+    }
+
+    pub fn collect_ready(&mut self) -> Vec<Vec<Sample>> {
+        while let Ok((sequence, samples)) = self.results.try_recv() {
+            self.completed.insert(sequence, samples);
+        }
+
+        self.release()
+    }
+
+    pub fn collect_all(&mut self) -> Vec<Vec<Sample>> {
+        while self.released + (self.completed.len() as u64) < self.submitted {
+            let (sequence, samples) = self.results.recv().expect("encoder workers have stopped");
+
+            self.completed.insert(sequence, samples);
+        }
+
+        self.release()
+    }
+
+    fn release(&mut self) -> Vec<Vec<Sample>> {
+        let mut released = vec![];
+
+        while let Some(samples) = self.completed.remove(&self.released) {
+            released.push(samples);
+
+            self.released += 1;
+        }
+
+        released
+    }
+}
+
+impl Drop for EncoderPool {
+    fn drop(&mut self) {
+        self.jobs = None;
+
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
@@ -0,0 +1,489 @@
+use std::collections::BTreeMap;
+
+use crate::core::{
+    Choice, Evaluation, EventSink, Game, Player, RunnerEvent, RunnerEventKind, RunningGame,
+    ShutdownSignal, Turn, derive_seed, random_opening,
+};
+use crate::neural_network::{ActionEncoder, NeuralNetwork, StateEncoder};
+use crate::player::{NeuralNetworkMctsPlayer, PendingSearch};
+
+type NnPlayer<G, SE, AE, NN> = NeuralNetworkMctsPlayer<G, SE, AE, NN>;
+
+// NOTE - Plays several games at once on the calling thread. Each game's search pauses at
+// its next leaf, and the leaves of every game in flight are batched by the player whose
+// search reached them, so each player's network sees up to `concurrent_games` states per
+// inference call. Leaves go through the runner's own copy of each player, whose random
+// stream is not reseeded per game. With deterministic networks and a symmetry mode other
+// than `Random`, games, seeds and emitted events match `Runner` with the same settings,
+// since each search spends its simulations exactly as it would alone.
+
+pub struct InterleavedRunner<G, SE, AE, NN, S>
+where
+    G: Game,
+    SE: StateEncoder<G>,
+    AE: ActionEncoder<G>,
+    NN: NeuralNetwork + Clone,
+    S: EventSink<RunnerEvent<G>>,
+{
+    games: u32,
+    concurrent_games: usize,
+    max_turns: Option<u32>,
+    seed: Option<u64>,
+    opening_plies: u32,
+    shutdown_signal: Option<ShutdownSignal>,
+    initial_game: G,
+
+    player_1: NnPlayer<G, SE, AE, NN>,
+    player_2: NnPlayer<G, SE, AE, NN>,
+
+    sink: S,
+
+    inference_calls: u64,
+    evaluations: u64,
+}
+
+struct GameSlot<G, SE, AE, NN>
+where
+    G: Game,
+    SE: StateEncoder<G>,
+    AE: ActionEncoder<G>,
+    NN: NeuralNetwork,
+{
+    running_game: RunningGame<G>,
+
+    player_1: NnPlayer<G, SE, AE, NN>,
+    player_2: NnPlayer<G, SE, AE, NN>,
+
+    search: Option<PendingSearch<G>>,
+}
+
+impl<G, SE, AE, NN, S> InterleavedRunner<G, SE, AE, NN, S>
+where
+    G: Game,
+    SE: StateEncoder<G>,
+    AE: ActionEncoder<G>,
+    NN: NeuralNetwork + Clone,
+    S: EventSink<RunnerEvent<G>>,
+{
+    pub fn new(
+        games: u32,
+        player_1: NnPlayer<G, SE, AE, NN>,
+        player_2: NnPlayer<G, SE, AE, NN>,
+        sink: S,
+    ) -> Self {
+        Self {
+            games,
+            concurrent_games: 8,
+            max_turns: None,
+            seed: None,
+            opening_plies: 0,
+            shutdown_signal: None,
+            initial_game: G::new(),
+
+            player_1,
+            player_2,
+
+            sink,
+
+            inference_calls: 0,
+            evaluations: 0,
+        }
+    }
+
+    pub fn with_concurrent_games(mut self, concurrent_games: usize) -> Self {
+        self.concurrent_games = concurrent_games.max(1);
+
+        self
+    }
+
+    pub fn with_max_turns(mut self, max_turns: u32) -> Self {
+        self.max_turns = Some(max_turns);
+
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+
+        self
+    }
+
+    pub fn with_opening_plies(mut self, opening_plies: u32) -> Self {
+        self.opening_plies = opening_plies;
+
+        self
+    }
+
+    pub fn with_shutdown_signal(mut self, shutdown_signal: ShutdownSignal) -> Self {
+        self.shutdown_signal = Some(shutdown_signal);
+
+        self
+    }
+
+    pub fn with_initial_game(mut self, initial_game: G) -> Self {
+        self.initial_game = initial_game;
+
+        self
+    }
+
+    pub fn with_game_config(self, config: G::Config) -> Self {
+        self.with_initial_game(G::new_with_config(config))
+    }
+
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    pub fn mean_batch_size(&self) -> f32 {
+        self.evaluations as f32 / self.inference_calls.max(1) as f32
+    }
+
+    pub fn run(&mut self) {
+        self.sink.emit(RunnerEvent {
+            kind: RunnerEventKind::RunnerStarted,
+            context: None,
+        });
+
+        let opening_seed = match self.seed {
+            Some(seed) => derive_seed(seed, u64::MAX),
+            None => rand::random(),
+        };
+
+        let mut slots: Vec<GameSlot<G, SE, AE, NN>> = vec![];
+
+        let mut next_game_number = 0;
+
+        // NOTE - Games finish out of order, so their events are held until every earlier
+        // game has been emitted.
+
+        let mut finished_games: BTreeMap<u32, Vec<RunnerEvent<G>>> = BTreeMap::new();
+        let mut next_emitted = 0;
+
+        loop {
+            while slots.len() < self.concurrent_games
+                && next_game_number < self.games
+                && !self.shutdown_requested()
+            {
+                slots.push(self.start_game(next_game_number, opening_seed));
+
+                next_game_number += 1;
+            }
+
+            if slots.is_empty() {
+                break;
+            }
+
+            for slot in &mut slots {
+                slot.advance();
+            }
+
+            for turn in [Turn::Player1, Turn::Player2] {
+                let leaves: Vec<&G> = slots
+                    .iter()
+                    .filter(|slot| slot.running_game.turn == turn)
+                    .filter_map(|slot| slot.search.as_ref()?.leaf())
+                    .collect();
+
+                if leaves.is_empty() {
+                    continue;
+                }
+
+                self.inference_calls += 1;
+                self.evaluations += leaves.len() as u64;
+
+                let player = match turn {
+                    Turn::Player1 => &mut self.player_1,
+                    Turn::Player2 => &mut self.player_2,
+                };
+
+                let evaluations = player.evaluate_batch(&leaves);
+
+                let pending_slots = slots.iter_mut().filter(|slot| {
+                    slot.running_game.turn == turn
+                        && slot
+                            .search
+                            .as_ref()
+                            .is_some_and(|search| search.leaf().is_some())
+                });
+
+                for (slot, evaluation) in pending_slots.zip(evaluations) {
+                    slot.complete_leaf(evaluation);
+                }
+            }
+
+            let (finished, in_flight) = slots
+                .into_iter()
+                .partition(|slot| slot.running_game.is_finished());
+
+            slots = in_flight;
+
+            for slot in finished {
+                let running_game = slot.running_game;
+
+                finished_games.insert(running_game.game_number, running_game.events);
+            }
+
+            while let Some(events) = finished_games.remove(&next_emitted) {
+                for event in events {
+                    self.sink.emit(event);
+                }
+
+                next_emitted += 1;
+            }
+        }
+
+        self.sink.emit(RunnerEvent {
+            kind: RunnerEventKind::RunnerFinished,
+            context: None,
+        });
+    }
+
+    // NOTE - Leaves are evaluated through these players' networks, so a model swapped in by
+    // `new_game` as one game starts also serves the games already in flight from the next
+    // batch on.
+
+    fn start_game(&mut self, game_number: u32, opening_seed: u64) -> GameSlot<G, SE, AE, NN> {
+        self.player_1.new_game();
+        self.player_2.new_game();
+
+        let mut player_1 = self.player_1.clone();
+        let mut player_2 = self.player_2.clone();
+
+        if let Some(seed) = self.seed {
+            player_1.reseed(derive_seed(seed, u64::from(game_number) * 2));
+            player_2.reseed(derive_seed(seed, u64::from(game_number) * 2 + 1));
+        }
+
+        let initial_turn = if game_number.is_multiple_of(2) {
+            Turn::Player1
+        } else {
+            Turn::Player2
+        };
+
+        let opening = random_opening(
+            &self.initial_game,
+            self.opening_plies,
+            opening_seed,
+            game_number / 2,
+        );
+
+        GameSlot {
+            running_game: RunningGame::new(
+                game_number,
+                self.initial_game.clone(),
+                initial_turn,
+                opening,
+                self.max_turns,
+                self.seed,
+            ),
+
+            player_1,
+            player_2,
+
+            search: None,
+        }
+    }
+
+    fn shutdown_requested(&self) -> bool {
+        self.shutdown_signal
+            .as_ref()
+            .is_some_and(ShutdownSignal::is_requested)
+    }
+}
+
+impl<G, SE, AE, NN> GameSlot<G, SE, AE, NN>
+where
+    G: Game,
+    SE: StateEncoder<G>,
+    AE: ActionEncoder<G>,
+    NN: NeuralNetwork,
+{
+    fn player(&mut self) -> &mut NnPlayer<G, SE, AE, NN> {
+        match self.running_game.turn {
+            Turn::Player1 => &mut self.player_1,
+            Turn::Player2 => &mut self.player_2,
+        }
+    }
+
+    // NOTE - Plays the game forward until its search is waiting on a leaf evaluation or the
+    // game is over.
+
+    fn advance(&mut self) {
+        while !self.running_game.is_finished() {
+            let mut search = if let Some(search) = self.search.take() {
+                search
+            } else {
+                let forced_action = self
+                    .running_game
+                    .opening_action()
+                    .or_else(|| self.running_game.chance_action());
+
+                if let Some(action) = forced_action {
+                    self.running_game.play(Choice {
+                        action,
+                        evaluation: None,
+                        resign: false,
+                    });
+
+                    continue;
+                }
+
+                let game = self.running_game.game.observation();
+
+                self.player().start_search(&game)
+            };
+
+            if self.player().select_leaf(&mut search).is_some() {
+                self.search = Some(search);
+
+                return;
+            }
+
+            let turn_number = self.running_game.turn_number;
+
+            let choice = self.player().finish_search(search, turn_number);
+
+            self.running_game.play(choice);
+        }
+    }
+
+    fn complete_leaf(&mut self, evaluation: Evaluation<G>) {
+        let mut search = self
+            .search
+            .take()
+            .expect("no search is awaiting evaluation");
+
+        self.player().complete_leaf(&mut search, evaluation);
+
+        self.search = Some(search);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Runner;
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeActionEncoder, TicTacToeStateEncoder};
+    use crate::neural_network::Prediction;
+
+    // NOTE - Deterministic, but sensitive to the position, so the searches differ from
+    // game to game and any mix-up between leaves would show up in the games played.
+
+    #[derive(Clone)]
+    struct PositionalNeuralNetwork {
+        scale: f32,
+    }
+
+    impl NeuralNetwork for PositionalNeuralNetwork {
+        fn reseed(&mut self, _seed: u64) {}
+
+        fn predict(&mut self, input: &[f32]) -> Prediction {
+            Prediction {
+                policy_logits: input
+                    .iter()
+                    .take(9)
+                    .map(|x| (x * 2.0 - 1.0) * self.scale)
+                    .collect(),
+                value: (input.iter().sum::<f32>() * 0.1 * self.scale).tanh(),
+                draw_probability: None,
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct ActionRecorder {
+        actions: Vec<(u32, String)>,
+    }
+
+    impl EventSink<RunnerEvent<TicTacToe>> for ActionRecorder {
+        fn emit(&mut self, event: RunnerEvent<TicTacToe>) {
+            if let (RunnerEventKind::ActionApplied { action }, Some(context)) =
+                (event.kind, event.context)
+            {
+                self.actions.push((context.game_number, action.to_string()));
+            }
+        }
+    }
+
+    fn make_player()
+    -> NnPlayer<TicTacToe, TicTacToeStateEncoder, TicTacToeActionEncoder, PositionalNeuralNetwork>
+    {
+        make_scaled_player(1.0)
+    }
+
+    fn make_scaled_player(
+        scale: f32,
+    ) -> NnPlayer<TicTacToe, TicTacToeStateEncoder, TicTacToeActionEncoder, PositionalNeuralNetwork>
+    {
+        NeuralNetworkMctsPlayer::new(
+            20,
+            TicTacToeStateEncoder::new(),
+            TicTacToeActionEncoder,
+            PositionalNeuralNetwork { scale },
+        )
+    }
+
+    mod run {
+        use super::*;
+
+        #[test]
+        fn should_play_the_same_games_as_runner() {
+            let mut runner =
+                Runner::new(6, make_player(), make_player(), ActionRecorder::default())
+                    .with_seed(5)
+                    .with_opening_plies(1);
+
+            runner.run();
+
+            let mut interleaved_runner =
+                InterleavedRunner::new(6, make_player(), make_player(), ActionRecorder::default())
+                    .with_concurrent_games(4)
+                    .with_seed(5)
+                    .with_opening_plies(1);
+
+            interleaved_runner.run();
+
+            assert_eq!(interleaved_runner.sink().actions, runner.sink().actions);
+        }
+
+        #[test]
+        fn should_evaluate_each_players_leaves_with_its_own_network() {
+            let mut runner = Runner::new(
+                6,
+                make_scaled_player(1.0),
+                make_scaled_player(-0.5),
+                ActionRecorder::default(),
+            )
+            .with_seed(3);
+
+            runner.run();
+
+            let mut interleaved_runner = InterleavedRunner::new(
+                6,
+                make_scaled_player(1.0),
+                make_scaled_player(-0.5),
+                ActionRecorder::default(),
+            )
+            .with_concurrent_games(4)
+            .with_seed(3);
+
+            interleaved_runner.run();
+
+            assert_eq!(interleaved_runner.sink().actions, runner.sink().actions);
+        }
+
+        #[test]
+        fn should_batch_leaves_across_games() {
+            let mut runner =
+                InterleavedRunner::new(4, make_player(), make_player(), ActionRecorder::default())
+                    .with_concurrent_games(4)
+                    .with_seed(0);
+
+            runner.run();
+
+            assert!(runner.mean_batch_size() > 1.0);
+            assert!(runner.mean_batch_size() <= 4.0);
+        }
+    }
+}
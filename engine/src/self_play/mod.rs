@@ -1,7 +1,15 @@
+mod binary_sample_sink;
+#[cfg(not(target_arch = "wasm32"))]
+mod encoder_pool;
+mod interleaved_runner;
 mod json_sample_sink;
 mod sample;
+mod sample_batch;
 mod sample_runner_event_sink;
 
+pub use binary_sample_sink::BinarySampleSink;
+pub use interleaved_runner::InterleavedRunner;
 pub use json_sample_sink::JsonSampleSink;
 pub use sample::Sample;
+pub use sample_batch::SampleBatch;
 pub use sample_runner_event_sink::SampleRunnerEventSink;
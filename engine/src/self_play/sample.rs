@@ -4,5 +4,6 @@ use serde::Serialize;
 pub struct Sample {
     pub state: Vec<f32>,
     pub policy: Vec<f32>,
+    pub legal_mask: Vec<f32>,
     pub value: f32,
 }
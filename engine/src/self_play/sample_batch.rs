@@ -0,0 +1,196 @@
+use std::io::{self, Write};
+
+use crate::self_play::sample::Sample;
+
+// NOTE - Samples are stored as contiguous arrays so a whole batch can be written, and
+// read back by the trainer, without touching each sample individually.
+
+#[derive(Clone, Debug)]
+pub struct SampleBatch {
+    state_size: usize,
+    policy_size: usize,
+
+    states: Vec<f32>,
+    policies: Vec<f32>,
+    legal_masks: Vec<f32>,
+    values: Vec<f32>,
+}
+
+impl SampleBatch {
+    pub const MAGIC: &'static [u8; 4] = b"HSB2";
+
+    pub fn new(state_size: usize, policy_size: usize) -> Self {
+        Self::with_capacity(state_size, policy_size, 0)
+    }
+
+    pub fn with_capacity(state_size: usize, policy_size: usize, capacity: usize) -> Self {
+        Self {
+            state_size,
+            policy_size,
+
+            states: Vec::with_capacity(capacity * state_size),
+            policies: Vec::with_capacity(capacity * policy_size),
+            legal_masks: Vec::with_capacity(capacity * policy_size),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn states(&self) -> &[f32] {
+        &self.states
+    }
+
+    pub fn policies(&self) -> &[f32] {
+        &self.policies
+    }
+
+    pub fn legal_masks(&self) -> &[f32] {
+        &self.legal_masks
+    }
+
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    pub fn push(&mut self, state: &[f32], policy: &[f32], legal_mask: &[f32], value: f32) {
+        assert_eq!(state.len(), self.state_size, "unexpected state size");
+        assert_eq!(policy.len(), self.policy_size, "unexpected policy size");
+        assert_eq!(
+            legal_mask.len(),
+            self.policy_size,
+            "unexpected legal mask size"
+        );
+
+        self.states.extend_from_slice(state);
+        self.policies.extend_from_slice(policy);
+        self.legal_masks.extend_from_slice(legal_mask);
+        self.values.push(value);
+    }
+
+    pub fn push_sample(&mut self, sample: &Sample) {
+        self.push(
+            &sample.state,
+            &sample.policy,
+            &sample.legal_mask,
+            sample.value,
+        );
+    }
+
+    pub fn clear(&mut self) {
+        self.states.clear();
+        self.policies.clear();
+        self.legal_masks.clear();
+        self.values.clear();
+    }
+
+    // NOTE - Layout is the magic, then the state size, policy size, and sample count as
+    // little-endian u32s, followed by every state, every policy, every legal mask, and every
+    // value as little-endian f32s. Files are a concatenation of these blocks.
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(Self::MAGIC)?;
+
+        for size in [self.state_size, self.policy_size, self.len()] {
+            let size = u32::try_from(size)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+            writer.write_all(&size.to_le_bytes())?;
+        }
+
+        for values in [
+            &self.states,
+            &self.policies,
+            &self.legal_masks,
+            &self.values,
+        ] {
+            let bytes: Vec<u8> = values
+                .iter()
+                .flat_map(|value| value.to_le_bytes())
+                .collect();
+
+            writer.write_all(&bytes)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod write_to {
+        use super::*;
+
+        #[test]
+        fn should_write_arrays_contiguously() {
+            let mut batch = SampleBatch::new(2, 1);
+
+            batch.push(&[1.0, 2.0], &[0.5], &[1.0], 1.0);
+            batch.push(&[3.0, 4.0], &[0.25], &[0.0], -1.0);
+
+            let mut bytes = vec![];
+
+            batch.write_to(&mut bytes).expect("unable to write batch");
+
+            let words: Vec<[u8; 4]> = bytes
+                .chunks_exact(4)
+                .map(|chunk| chunk.try_into().unwrap())
+                .collect();
+
+            assert_eq!(&words[0], SampleBatch::MAGIC);
+            assert_eq!(
+                words[1..4]
+                    .iter()
+                    .map(|word| u32::from_le_bytes(*word))
+                    .collect::<Vec<_>>(),
+                [2, 1, 2]
+            );
+            assert_eq!(
+                words[4..]
+                    .iter()
+                    .map(|word| f32::from_le_bytes(*word))
+                    .collect::<Vec<_>>(),
+                [1.0, 2.0, 3.0, 4.0, 0.5, 0.25, 1.0, 0.0, 1.0, -1.0]
+            );
+        }
+    }
+    mod push_sample {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_the_legal_mask() {
+            let sample = Sample {
+                state: vec![1.0],
+                policy: vec![0.0, 0.75, 0.25],
+                legal_mask: vec![0.0, 1.0, 1.0],
+                value: 0.5,
+            };
+
+            let mut batch = SampleBatch::new(1, 3);
+
+            batch.push_sample(&sample);
+
+            let mut bytes = vec![];
+
+            batch.write_to(&mut bytes).expect("unable to write batch");
+
+            let values: Vec<f32> = bytes[16..]
+                .chunks_exact(4)
+                .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+                .collect();
+
+            assert_eq!(values[..1], sample.state);
+            assert_eq!(values[1..4], sample.policy);
+            assert_eq!(values[4..7], sample.legal_mask);
+            assert_eq!(values[7..], [sample.value]);
+        }
+    }
+}
@@ -1,10 +1,13 @@
 use std::marker::PhantomData;
 
 use crate::core::{
-    EventSink, Game, Outcome, PolicyItem, RunnerEvent, RunnerEventContext, RunnerEventKind, Turn,
+    EventSink, Game, Outcome, Policy, PolicyItem, RunnerEvent, RunnerEventContext, RunnerEventKind,
+    Turn,
 };
 use crate::neural_network::{ActionEncoder, StateEncoder};
 use crate::self_play::Sample;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::self_play::encoder_pool::EncoderPool;
 
 pub struct SampleRunnerEventSink<
     G: Game,
@@ -16,7 +19,10 @@ pub struct SampleRunnerEventSink<
     action_encoder: AE,
 
     use_symmetries: bool,
-    pending_samples: Vec<PendingSample>,
+    pending_positions: Vec<PendingPosition<G>>,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    encoder_pool: Option<EncoderPool>,
 
     sink: S,
 
@@ -32,52 +38,76 @@ impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, S: EventSink<Sample>>
             action_encoder,
 
             use_symmetries,
-            pending_samples: vec![],
+            pending_positions: vec![],
+
+            #[cfg(not(target_arch = "wasm32"))]
+            encoder_pool: None,
 
             sink,
 
             _phantom: PhantomData,
         }
     }
+
+    // NOTE - Encoding every symmetry of every position is expensive, so finished games
+    // can be handed to a pool of workers instead of stalling the thread running games.
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_encoding_threads(mut self, threads: usize) -> Self {
+        self.encoder_pool = (threads > 0).then(|| EncoderPool::new(threads));
+
+        self
+    }
+
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    fn emit_samples(&mut self, samples: Vec<Sample>) {
+        for sample in samples {
+            self.sink.emit(sample);
+        }
+    }
 }
 
-impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, S: EventSink<Sample>>
-    EventSink<RunnerEvent<G>> for SampleRunnerEventSink<G, SE, AE, S>
+impl<G, SE, AE, S> EventSink<RunnerEvent<G>> for SampleRunnerEventSink<G, SE, AE, S>
+where
+    G: Game + Send + 'static,
+    G::Action: Send,
+    SE: StateEncoder<G> + Send + 'static,
+    AE: ActionEncoder<G> + Send + 'static,
+    S: EventSink<Sample>,
 {
     fn emit(&mut self, event: RunnerEvent<G>) {
         let RunnerEvent { kind, context } = event;
 
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(encoder_pool) = &mut self.encoder_pool {
+            let samples = if matches!(kind, RunnerEventKind::RunnerFinished) {
+                encoder_pool.collect_all()
+            } else {
+                encoder_pool.collect_ready()
+            };
+
+            self.emit_samples(samples.into_iter().flatten().collect());
+        }
+
         let Some(RunnerEventContext { game, turn, .. }) = context else {
             return;
         };
 
         match kind {
             RunnerEventKind::GameStarted => {
-                self.pending_samples.clear();
+                self.pending_positions.clear();
             }
+            // NOTE - Searches with forced playouts have already pruned the forced visits
+            // out of the policy, so it is recorded as the target unchanged.
             RunnerEventKind::PositionEvaluated { evaluation } => {
-                let symmetries = if self.use_symmetries {
-                    game.symmetries()
-                } else {
-                    1
-                };
-
-                for symmetry in 0..symmetries {
-                    let state = self.state_encoder.encode(&game.transform(symmetry));
-
-                    let mut policy = vec![0.0; self.action_encoder.size()];
-
-                    for PolicyItem { action, prior } in &evaluation.policy {
-                        let action_index = self
-                            .action_encoder
-                            .encode(&game.transform_action(*action, symmetry));
-
-                        policy[action_index] = *prior;
-                    }
-
-                    self.pending_samples
-                        .push(PendingSample { state, policy, turn });
-                }
+                self.pending_positions.push(PendingPosition {
+                    game,
+                    policy: evaluation.policy,
+                    turn,
+                });
             }
             RunnerEventKind::GameFinished { outcome } => {
                 // `turn` here is whoever just made the last move. `outcome` is from their
@@ -89,35 +119,158 @@ impl<G: Game, SE: StateEncoder<G>, AE: ActionEncoder<G>, S: EventSink<Sample>>
                     Outcome::Draw => None,
                 };
 
-                for PendingSample {
-                    state,
-                    policy,
-                    turn: sample_turn,
-                } in self.pending_samples.drain(..)
-                {
-                    // Value is from the current player's perspective at each position,
-                    // matching the state encoding which always encodes from current player's
-                    // perspective (flip_perspective is called on end_turn).
-                    let value = match winner {
-                        Some(w) if w == sample_turn => 1.0,
-                        Some(_) => -1.0,
-                        None => 0.0,
-                    };
-
-                    self.sink.emit(Sample {
-                        state,
-                        policy,
-                        value,
-                    });
+                let positions = std::mem::take(&mut self.pending_positions);
+
+                let state_encoder = self.state_encoder;
+                let action_encoder = self.action_encoder;
+                let use_symmetries = self.use_symmetries;
+
+                let encode = move || {
+                    encode_game(
+                        state_encoder,
+                        action_encoder,
+                        use_symmetries,
+                        positions,
+                        winner,
+                    )
+                };
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(encoder_pool) = &mut self.encoder_pool {
+                    encoder_pool.submit(Box::new(encode));
+
+                    return;
                 }
+
+                self.emit_samples(encode());
             }
             _ => {}
         }
     }
 }
 
-struct PendingSample {
-    pub state: Vec<f32>,
-    pub policy: Vec<f32>,
+fn encode_game<G, SE, AE>(
+    state_encoder: SE,
+    action_encoder: AE,
+    use_symmetries: bool,
+    positions: Vec<PendingPosition<G>>,
+    winner: Option<Turn>,
+) -> Vec<Sample>
+where
+    G: Game,
+    SE: StateEncoder<G>,
+    AE: ActionEncoder<G>,
+{
+    let mut samples = vec![];
+
+    for PendingPosition { game, policy, turn } in positions {
+        // Value is from the current player's perspective at each position,
+        // matching the state encoding which always encodes from current player's
+        // perspective (flip_perspective is called on end_turn).
+        let value = match winner {
+            Some(w) if w == turn => 1.0,
+            Some(_) => -1.0,
+            None => 0.0,
+        };
+
+        let symmetries = if use_symmetries { game.symmetries() } else { 1 };
+
+        for symmetry in 0..symmetries {
+            let transformed_game = game.transform(symmetry);
+
+            let state = state_encoder.encode(&transformed_game);
+            let legal_mask = action_encoder.legal_mask(&transformed_game);
+
+            let mut encoded_policy = vec![0.0; action_encoder.size()];
+
+            for PolicyItem { action, prior } in &policy {
+                let action_index = action_encoder.encode(&game.transform_action(*action, symmetry));
+
+                encoded_policy[action_index] = *prior;
+            }
+
+            samples.push(Sample {
+                state,
+                policy: encoded_policy,
+                legal_mask,
+                value,
+            });
+        }
+    }
+
+    samples
+}
+
+struct PendingPosition<G: Game> {
+    pub game: G,
+    pub policy: Policy<G>,
     pub turn: Turn,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Runner;
+    use crate::game::tic_tac_toe::{TicTacToe, TicTacToeActionEncoder, TicTacToeStateEncoder};
+    use crate::player::ClassicMctsPlayer;
+
+    type RecordedSample = (Vec<f32>, Vec<f32>, Vec<f32>, f32);
+
+    #[derive(Default)]
+    struct SampleRecorder {
+        samples: Vec<RecordedSample>,
+    }
+
+    impl EventSink<Sample> for SampleRecorder {
+        fn emit(&mut self, sample: Sample) {
+            self.samples
+                .push((sample.state, sample.policy, sample.legal_mask, sample.value));
+        }
+    }
+
+    fn record_samples(encoding_threads: usize) -> Vec<RecordedSample> {
+        let sink = SampleRunnerEventSink::new(
+            TicTacToeStateEncoder::new(),
+            TicTacToeActionEncoder,
+            true,
+            SampleRecorder::default(),
+        )
+        .with_encoding_threads(encoding_threads);
+
+        let mut runner = Runner::new(
+            4,
+            ClassicMctsPlayer::<TicTacToe>::new(10),
+            ClassicMctsPlayer::<TicTacToe>::new(10),
+            sink,
+        )
+        .with_seed(3);
+
+        runner.run();
+
+        runner.sink().sink.samples.clone()
+    }
+
+    mod emit {
+        use super::*;
+
+        #[test]
+        fn should_match_inline_encoding_when_using_workers() {
+            let inline = record_samples(0);
+
+            assert!(!inline.is_empty());
+            assert_eq!(inline, record_samples(3));
+        }
+
+        #[test]
+        fn should_only_put_policy_on_legal_actions() {
+            for (_, policy, legal_mask, _) in record_samples(0) {
+                assert!(legal_mask.contains(&1.0));
+
+                for (prior, legal) in policy.iter().zip(&legal_mask) {
+                    assert!(*prior <= 0.0 || *legal > 0.0);
+                }
+            }
+        }
+    }
+}
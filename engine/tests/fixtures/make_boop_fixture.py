@@ -0,0 +1,124 @@
+"""Writes `boop_fixture.onnx`, a tiny model matching the Boop encoder shapes.
+
+The graph has no learned weights: the policy logits are the first 188 inputs scaled
+by 0.5, and the value is the tanh of the mean input. It is written with a minimal
+protobuf encoder so regenerating it needs nothing beyond the standard library.
+
+Also writes `boop_wdl_fixture.onnx`, which has the same policy but a win/draw/loss
+value head whose three logits are the first three inputs.
+"""
+
+import struct
+from pathlib import Path
+
+PLANE_COUNT = 10
+BOARD_SIZE = 6
+ACTION_COUNT = 188
+
+
+def varint(value):
+    out = bytearray()
+    while True:
+        byte = value & 0x7F
+        value >>= 7
+        if value:
+            out.append(byte | 0x80)
+        else:
+            out.append(byte)
+            return bytes(out)
+
+
+def field_varint(number, value):
+    return varint(number << 3) + varint(value)
+
+
+def field_bytes(number, value):
+    if isinstance(value, str):
+        value = value.encode()
+    return varint((number << 3) | 2) + varint(len(value)) + value
+
+
+def attribute_int(name, value):
+    return field_bytes(1, name) + field_varint(3, value) + field_varint(20, 2)
+
+
+def attribute_ints(name, values):
+    body = field_bytes(1, name)
+    for value in values:
+        body += field_varint(8, value)
+    return body + field_varint(20, 7)
+
+
+def node(op_type, inputs, outputs, attributes=()):
+    body = b"".join(field_bytes(1, name) for name in inputs)
+    body += b"".join(field_bytes(2, name) for name in outputs)
+    body += field_bytes(3, outputs[0]) + field_bytes(4, op_type)
+    body += b"".join(field_bytes(5, attribute) for attribute in attributes)
+    return field_bytes(1, body)
+
+
+def initializer(name, dims, data_type, raw_data):
+    body = b"".join(field_varint(1, dim) for dim in dims)
+    body += field_varint(2, data_type) + field_bytes(8, name) + field_bytes(9, raw_data)
+    return field_bytes(5, body)
+
+
+def value_info(number, name, dims):
+    shape = b"".join(field_bytes(1, field_varint(1, dim)) for dim in dims)
+    tensor_type = field_varint(1, 1) + field_bytes(2, shape)
+    return field_bytes(number, field_bytes(1, name) + field_bytes(2, field_bytes(1, tensor_type)))
+
+
+def int64(*values):
+    return struct.pack(f"<{len(values)}q", *values)
+
+
+def write_model(name, value_parts, value_size):
+    graph = b"".join(
+        [
+            node("Flatten", ["state"], ["flat"], [attribute_int("axis", 1)]),
+            node("Slice", ["flat", "starts", "ends", "axes"], ["sliced"]),
+            node("Mul", ["sliced", "scale"], ["policy"]),
+            *value_parts,
+            field_bytes(2, name),
+            initializer("starts", [1], 7, int64(0)),
+            initializer("ends", [1], 7, int64(ACTION_COUNT)),
+            initializer("axes", [1], 7, int64(1)),
+            initializer("scale", [1], 1, struct.pack("<f", 0.5)),
+            value_info(11, "state", [1, PLANE_COUNT, BOARD_SIZE, BOARD_SIZE]),
+            value_info(12, "policy", [1, ACTION_COUNT]),
+            value_info(12, "value", [1, value_size]),
+        ]
+    )
+
+    model = field_varint(1, 8) + field_bytes(2, "hermes") + field_bytes(7, graph)
+    model += field_bytes(8, field_bytes(1, "") + field_varint(2, 13))
+
+    path = Path(__file__).with_name(f"{name}.onnx")
+    path.write_bytes(model)
+
+    print(f"Wrote {len(model)} bytes to {path}")
+
+
+def main():
+    write_model(
+        "boop_fixture",
+        [
+            node("ReduceMean", ["flat"], ["mean"], [attribute_ints("axes", [1])]),
+            node("Tanh", ["mean"], ["value"]),
+        ],
+        1,
+    )
+
+    write_model(
+        "boop_wdl_fixture",
+        [
+            node("Slice", ["flat", "starts", "wdl_ends", "axes"], ["value"]),
+            initializer("wdl_ends", [1], 7, int64(3)),
+        ],
+        3,
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,236 @@
+use hermes_engine::boop::{Boop, BoopActionEncoder, BoopStateEncoder};
+use hermes_engine::{
+    ActionEncoder, ConstantNeuralNetwork, Game, ModelShapeError, NeuralNetwork,
+    NeuralNetworkMctsPlayer, OnnxNeuralNetwork, Player, Prediction, StateEncoder,
+    TemperatureSchedule,
+};
+
+// NOTE - Regenerate with `python3 tests/fixtures/make_boop_fixture.py`.
+
+const BOOP_FIXTURE: &[u8] = include_bytes!("fixtures/boop_fixture.onnx");
+const BOOP_WDL_FIXTURE: &[u8] = include_bytes!("fixtures/boop_wdl_fixture.onnx");
+
+fn load_fixture() -> OnnxNeuralNetwork<Boop, BoopStateEncoder> {
+    OnnxNeuralNetwork::new_from_bytes(BOOP_FIXTURE, BoopStateEncoder::new(), BoopActionEncoder)
+        .expect("unable to load fixture model")
+}
+
+#[test]
+fn should_load_fixture_from_path() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/boop_fixture.onnx"
+    );
+
+    assert!(OnnxNeuralNetwork::new(path, BoopStateEncoder::new(), BoopActionEncoder).is_ok());
+}
+
+#[derive(Clone, Copy)]
+struct SmallBoardStateEncoder;
+
+impl StateEncoder<Boop> for SmallBoardStateEncoder {
+    fn shape(&self) -> Vec<usize> {
+        vec![1, 10, 4, 4]
+    }
+
+    fn encode(&self, _state: &Boop) -> Vec<f32> {
+        vec![0.0; 160]
+    }
+}
+
+#[test]
+fn should_reject_models_exported_for_another_state_encoder() {
+    let error = OnnxNeuralNetwork::<Boop, _>::new_from_bytes(
+        BOOP_FIXTURE,
+        SmallBoardStateEncoder,
+        BoopActionEncoder,
+    )
+    .err()
+    .expect("model loaded for the wrong encoder");
+
+    assert_eq!(
+        error.downcast_ref::<ModelShapeError>(),
+        Some(&ModelShapeError::Input {
+            expected: vec![1, 10, 4, 4],
+            actual: vec![Some(1), Some(10), Some(6), Some(6)],
+        })
+    );
+}
+
+// NOTE - Encodes Boop actions into fewer ids than the fixture's policy has.
+
+#[derive(Clone, Copy)]
+struct TruncatedActionEncoder;
+
+impl ActionEncoder<Boop> for TruncatedActionEncoder {
+    const ACTION_COUNT: usize = 100;
+
+    fn encode(&self, action: &<Boop as Game>::Action) -> usize {
+        BoopActionEncoder.encode(action) % Self::ACTION_COUNT
+    }
+
+    fn decode(&self, action_id: usize) -> <Boop as Game>::Action {
+        BoopActionEncoder.decode(action_id)
+    }
+}
+
+#[test]
+fn should_reject_models_exported_for_another_action_encoder() {
+    let error = OnnxNeuralNetwork::<Boop, _>::new_from_bytes(
+        BOOP_FIXTURE,
+        BoopStateEncoder::new(),
+        TruncatedActionEncoder,
+    )
+    .err()
+    .expect("model loaded for the wrong encoder");
+
+    assert!(matches!(
+        error.downcast_ref::<ModelShapeError>(),
+        Some(ModelShapeError::Policy { expected: 100, .. })
+    ));
+}
+
+#[test]
+fn should_predict_with_encoder_shapes() {
+    let state_encoder = BoopStateEncoder::new();
+
+    let state = state_encoder.encode(&Boop::new());
+
+    let prediction = load_fixture().predict(&state);
+
+    assert_eq!(
+        prediction.policy_logits.len(),
+        BoopActionEncoder::ACTION_COUNT
+    );
+    assert!(prediction.value.abs() <= 1.0);
+
+    let mean = state.iter().sum::<f32>() / state.len() as f32;
+
+    assert!((prediction.value - mean.tanh()).abs() < 1e-5);
+    assert!(
+        prediction
+            .policy_logits
+            .iter()
+            .zip(&state)
+            .all(|(logit, input)| (logit - 0.5 * input).abs() < 1e-6)
+    );
+}
+
+#[test]
+fn should_predict_batches_like_single_states() {
+    let state_encoder = BoopStateEncoder::new();
+
+    let mut game = Boop::new();
+
+    let first = state_encoder.encode(&game);
+
+    let action = game.get_possible_actions()[0];
+
+    if game.apply_action(action) {
+        game.end_turn();
+    }
+
+    let second = state_encoder.encode(&game);
+
+    let mut neural_network = load_fixture();
+
+    let batch = neural_network.predict_batch(&[first.clone(), second.clone()]);
+
+    assert_eq!(batch.len(), 2);
+
+    for (prediction, state) in batch.iter().zip([first, second]) {
+        let expected = neural_network.predict(&state);
+
+        assert!((prediction.value - expected.value).abs() < 1e-6);
+        assert!(
+            prediction
+                .policy_logits
+                .iter()
+                .zip(&expected.policy_logits)
+                .all(|(x, y)| (x - y).abs() < 1e-6)
+        );
+    }
+}
+
+#[test]
+fn should_read_win_draw_loss_value_heads() {
+    let state_encoder = BoopStateEncoder::new();
+
+    let mut game = Boop::new();
+
+    let first = state_encoder.encode(&game);
+
+    let action = game.get_possible_actions()[0];
+
+    if game.apply_action(action) {
+        game.end_turn();
+    }
+
+    let second = state_encoder.encode(&game);
+
+    let mut neural_network = OnnxNeuralNetwork::<Boop, _>::new_from_bytes(
+        BOOP_WDL_FIXTURE,
+        BoopStateEncoder::new(),
+        BoopActionEncoder,
+    )
+    .expect("unable to load fixture model");
+
+    assert_eq!(load_fixture().predict(&first).draw_probability, None);
+
+    let batch = neural_network.predict_batch(&[first.clone(), second.clone()]);
+
+    for (prediction, state) in batch.iter().zip([first, second]) {
+        let expected = Prediction::from_wdl_logits(vec![], [state[0], state[1], state[2]]);
+
+        assert!((prediction.value - expected.value).abs() < 1e-6);
+        assert!(
+            (prediction.draw_probability.unwrap() - expected.draw_probability.unwrap()).abs()
+                < 1e-6
+        );
+        assert!((neural_network.predict(&state).value - prediction.value).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn should_play_legal_moves_with_fixture() {
+    let mut player = NeuralNetworkMctsPlayer::new(
+        16,
+        BoopStateEncoder::new(),
+        BoopActionEncoder::new(),
+        load_fixture(),
+    )
+    .with_seed(0)
+    .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+    let mut game = Boop::new();
+
+    for turn_number in 1..=4 {
+        let choice = player.choose_action(&game, turn_number);
+
+        assert!(game.get_possible_actions().contains(&choice.action));
+
+        if game.apply_action(choice.action) {
+            game.end_turn();
+        }
+    }
+}
+
+#[test]
+fn should_follow_constant_network_policy() {
+    let action_encoder = BoopActionEncoder::new();
+
+    let game = Boop::new();
+    let target = game.get_possible_actions()[7];
+
+    let mut policy_logits = vec![-10.0; BoopActionEncoder::ACTION_COUNT];
+    policy_logits[action_encoder.encode(&target)] = 10.0;
+
+    let neural_network = ConstantNeuralNetwork::new(BoopActionEncoder::ACTION_COUNT)
+        .with_policy_logits(policy_logits);
+
+    let mut player =
+        NeuralNetworkMctsPlayer::new(8, BoopStateEncoder::new(), action_encoder, neural_network)
+            .with_temperature_schedule(TemperatureSchedule::Constant(0.0));
+
+    assert!(player.choose_action(&game, 1).action == target);
+}
@@ -1,7 +1,7 @@
 """
 Data loading utilities for Boop AlphaZero training.
 
-Handles loading JSONL files produced by your Rust self-play,
+Handles loading JSONL and binary files produced by your Rust self-play,
 converting to PyTorch tensors, and creating data loaders.
 """
 
@@ -150,6 +150,90 @@ def load_jsonl(filepath: str | Path) -> DataBatch:
     return states, policies, values
 
 
+def load_binary(filepath: str | Path) -> DataBatch:
+    """
+    Load training data from a binary sample file produced by Rust.
+
+    The file is a sequence of batches, each a b"HSB2" magic, then the state size,
+    policy size, and sample count as little-endian u32s, then every state, every
+    policy, every legal mask, and every value as little-endian f32s. Older b"HSB1"
+    batches, which have no legal masks, are also accepted.
+
+    Args:
+        filepath: Path to .bin file
+
+    Returns:
+        states: (N, 10, 6, 6) numpy array
+        policies: (N, 188) numpy array
+        values: (N,) numpy array
+    """
+    path = Path(filepath)
+
+    if not path.exists():
+        raise FileNotFoundError(f"File not found: {path}")
+
+    print(f"Loading data from {path}...")
+
+    data = path.read_bytes()
+    offset = 0
+
+    all_states = []
+    all_policies = []
+    all_values = []
+
+    while offset < len(data):
+        magic = data[offset:offset + 4]
+
+        if magic not in (b'HSB1', b'HSB2'):
+            raise ValueError(f"Invalid batch header at byte {offset} in {path}")
+
+        state_size, policy_size, count = np.frombuffer(data, dtype='<u4', count=3, offset=offset + 4)
+        offset += 16
+
+        if state_size != 360 or policy_size != 188:
+            raise ValueError(f"Unexpected sample sizes ({state_size}, {policy_size}) in {path}")
+
+        arrays = []
+
+        sizes = [count * state_size, count * policy_size, count]
+
+        if magic == b'HSB2':
+            sizes.insert(2, count * policy_size)
+
+        for size in sizes:
+            arrays.append(np.frombuffer(data, dtype='<f4', count=int(size), offset=offset))
+            offset += int(size) * 4
+
+        # NOTE - Legal masks are not used for training yet, so they are skipped.
+
+        states, policies, values = arrays[0], arrays[1], arrays[-1]
+
+        all_states.append(states.reshape(-1, 10, 6, 6))
+        all_policies.append(policies.reshape(-1, 188))
+        all_values.append(values)
+
+    if len(all_values) == 0:
+        raise ValueError(f"No valid samples found in {path}")
+
+    states = np.concatenate(all_states, axis=0).astype(np.float32)
+    policies = np.concatenate(all_policies, axis=0).astype(np.float32)
+    values = np.concatenate(all_values, axis=0).astype(np.float32)
+
+    print(f"Loaded {len(states):,} samples")
+
+    return states, policies, values
+
+
+def load_samples(filepath: str | Path) -> DataBatch:
+    """
+    Load training data from either a JSONL or a binary sample file, based on its extension.
+    """
+    if Path(filepath).suffix == '.bin':
+        return load_binary(filepath)
+
+    return load_jsonl(filepath)
+
+
 def load_multiple_jsonl(filepaths: list[str | Path]) -> DataBatch:
     """
     Load and concatenate data from multiple JSONL files.
@@ -169,7 +253,7 @@ def load_multiple_jsonl(filepaths: list[str | Path]) -> DataBatch:
     all_values = []
 
     for filepath in filepaths:
-        states, policies, values = load_jsonl(filepath)
+        states, policies, values = load_samples(filepath)
         all_states.append(states)
         all_policies.append(policies)
         all_values.append(values)
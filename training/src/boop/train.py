@@ -17,11 +17,12 @@ from pathlib import Path
 import time
 from datetime import datetime
 import json
+import struct
 from collections.abc import Sized
 from typing import Any, TypedDict, cast
 
 from network import BoopNetwork, create_network
-from data import load_jsonl, load_multiple_jsonl, create_data_loader, validate_data
+from data import load_samples, load_multiple_jsonl, create_data_loader, validate_data
 
 
 class TrainMetrics(TypedDict):
@@ -378,12 +379,52 @@ def export_to_onnx(model: BoopNetwork, filepath: str | Path, opset_version: int
     print(f"Exported ONNX model to {filepath}")
 
 
+def export_to_safetensors(model: BoopNetwork, filepath: str | Path) -> None:
+    """
+    Export model weights as safetensors for the candle backend in Rust.
+    
+    The file is written directly, so it needs no package beyond torch: an 8-byte
+    header length, a JSON header of tensor offsets, then the raw float32 data.
+    
+    Args:
+        model: Trained BoopNetwork
+        filepath: Path to save .safetensors file
+    """
+    filepath = Path(filepath)
+    filepath.parent.mkdir(parents=True, exist_ok=True)
+    
+    header: dict[str, Any] = {}
+    data = bytearray()
+    
+    for name, tensor in model.state_dict().items():
+        if not tensor.is_floating_point():
+            continue
+        
+        raw = tensor.detach().cpu().float().contiguous().numpy().tobytes()
+        header[name] = {
+            'dtype': 'F32',
+            'shape': list(tensor.shape),
+            'data_offsets': [len(data), len(data) + len(raw)]
+        }
+        data += raw
+    
+    header_bytes = json.dumps(header).encode()
+    header_bytes += b' ' * (-len(header_bytes) % 8)
+    
+    with open(filepath, 'wb') as f:
+        f.write(struct.pack('<Q', len(header_bytes)))
+        f.write(header_bytes)
+        f.write(data)
+    
+    print(f"Exported safetensors weights to {filepath}")
+
+
 def main():
     parser = argparse.ArgumentParser(description='Train Boop AlphaZero network')
     
     # Data
     parser.add_argument('--data', type=str, required=True,
-                       help='Path to training data (.jsonl file)')
+                       help='Path to training data (.jsonl or .bin file)')
     parser.add_argument('--replay', type=str, nargs='+',
                        help='Additional data files for experience replay')
     
@@ -410,6 +451,8 @@ def main():
                        help='Output checkpoint path')
     parser.add_argument('--onnx', type=str,
                        help='Export ONNX model path (optional)')
+    parser.add_argument('--safetensors', type=str,
+                       help='Export safetensors weights path for the candle backend (optional)')
     parser.add_argument('--log-dir', type=str, default='runs',
                        help='TensorBoard log directory')
     
@@ -436,7 +479,7 @@ def main():
         filepaths = [args.data] + args.replay
         states, policies, values = load_multiple_jsonl(filepaths)
     else:
-        states, policies, values = load_jsonl(args.data)
+        states, policies, values = load_samples(args.data)
     
     validate_data(states, policies, values)
     
@@ -484,6 +527,11 @@ def main():
         print(f"\nExporting to ONNX...")
         export_to_onnx(model, args.onnx)
     
+    # Export safetensors
+    if args.safetensors:
+        print(f"\nExporting to safetensors...")
+        export_to_safetensors(model, args.safetensors)
+    
     # Save training history
     history_path = Path(args.output).parent / 'training_history.json'
     with open(history_path, 'w') as f:
@@ -496,6 +544,8 @@ def main():
     print(f"\nCheckpoint: {args.output}")
     if args.onnx:
         print(f"ONNX model: {args.onnx}")
+    if args.safetensors:
+        print(f"Safetensors weights: {args.safetensors}")
     print(f"\nView training progress: tensorboard --logdir {args.log_dir}")
 
 
@@ -90,6 +90,12 @@ pub enum WasmBoopSquare {
     Player2Cat,
 }
 
+impl Default for WasmBoop {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[wasm_bindgen(js_class = "Boop")]
 impl WasmBoop {
     #[wasm_bindgen(constructor)]
@@ -67,6 +67,12 @@ pub struct WasmTicTacToe {
     queued_action: Option<TicTacToeAction>,
 }
 
+impl Default for WasmTicTacToe {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[wasm_bindgen(js_class = "TicTacToe")]
 impl WasmTicTacToe {
     #[wasm_bindgen(constructor)]